@@ -0,0 +1,61 @@
+//! This crate exists only to ensure that `regiface` continues to build for `no_std` targets.
+//!
+//! It is built for a bare metal target in CI, which will fail to compile should `regiface`, or
+//! any of its dependencies, begin to depend on the standard library.
+#![no_std]
+
+use regiface::{
+    i2c, register, spi, FromByteArray, ReadableRegister, ToByteArray, WritableRegister,
+};
+
+#[register(0x0Fu8)]
+#[derive(ReadableRegister, WritableRegister)]
+pub struct Config {
+    pub value: u8,
+}
+
+impl FromByteArray for Config {
+    type Error = core::convert::Infallible;
+    type Array = [u8; 1];
+
+    fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+        Ok(Self { value: bytes[0] })
+    }
+}
+
+impl ToByteArray for Config {
+    type Error = core::convert::Infallible;
+    type Array = [u8; 1];
+
+    fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+        Ok([self.value])
+    }
+}
+
+pub fn configure_i2c<D: embedded_hal::i2c::I2c>(
+    device: &mut D,
+) -> Result<(), regiface::errors::Error> {
+    let config: Config = i2c::blocking::read_register(device, 0x48)?;
+    i2c::blocking::write_register(
+        device,
+        0x48,
+        Config {
+            value: config.value | 0x01,
+        },
+    )?;
+    Ok(())
+}
+
+pub async fn configure_spi<D: embedded_hal_async::spi::SpiDevice>(
+    device: &mut D,
+) -> Result<(), regiface::errors::Error> {
+    let config: Config = spi::r#async::read_register(device).await?;
+    spi::r#async::write_register(
+        device,
+        Config {
+            value: config.value | 0x01,
+        },
+    )
+    .await?;
+    Ok(())
+}
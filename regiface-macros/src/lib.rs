@@ -1,10 +1,22 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse::Parse, parse::ParseStream, parse_macro_input, DeriveInput, Ident, LitInt};
+use syn::{
+    parse::Parse, parse::ParseStream, parse_macro_input, punctuated::Punctuated, Data, DeriveInput,
+    Ident, LitInt, Token,
+};
 
 struct RegisterAttr {
     value: LitInt,
     ty: Ident,
+    reserved: Option<LitInt>,
+    required: Option<LitInt>,
+}
+
+/// Re-emits a bit mask as an unsuffixed `u128` literal, regardless of how it was written
+fn parse_mask(input: ParseStream) -> syn::Result<LitInt> {
+    let lit = input.parse::<LitInt>()?;
+    let mask = lit.base10_parse::<u128>()?;
+    Ok(LitInt::new(&format!("{mask:#x}"), lit.span()))
 }
 
 impl Parse for RegisterAttr {
@@ -24,26 +36,83 @@ impl Parse for RegisterAttr {
         // Create an Ident from the suffix
         let ty = Ident::new(suffix, lit.span());
 
-        Ok(RegisterAttr { value: lit, ty })
+        // Parse any optional `reserved = ...` or `required = ...` bit masks
+        let mut reserved = None;
+        let mut required = None;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let key = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let slot = if key == "reserved" {
+                &mut reserved
+            } else if key == "required" {
+                &mut required
+            } else {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "Expected `reserved` or `required`",
+                ));
+            };
+            if slot.is_some() {
+                return Err(syn::Error::new(key.span(), "Duplicate bit mask"));
+            }
+            *slot = Some(parse_mask(input)?);
+        }
+
+        Ok(RegisterAttr {
+            value: lit,
+            ty,
+            reserved,
+            required,
+        })
     }
 }
 
 #[proc_macro_attribute]
 pub fn register(attr: TokenStream, item: TokenStream) -> TokenStream {
     let attr = parse_macro_input!(attr as RegisterAttr);
-    let input = parse_macro_input!(item as DeriveInput);
+    let mut input = parse_macro_input!(item as DeriveInput);
+
+    // Document the register ID on the type, separated from any existing documentation
+    let id_repr = attr.value.to_string();
+    let id_repr = id_repr.trim_end_matches(attr.value.suffix());
+    let id_doc = format!("Register ID: `{id_repr}` (`{}`)", attr.ty);
+    if input.attrs.iter().any(|attr| attr.path().is_ident("doc")) {
+        input.attrs.push(syn::parse_quote!(#[doc = ""]));
+    }
+    input.attrs.push(syn::parse_quote!(#[doc = #id_doc]));
 
     let name = &input.ident;
     let value = &attr.value;
     let ty = &attr.ty;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let reserved = attr
+        .reserved
+        .as_ref()
+        .map(|mask| quote! { const RESERVED_MASK: u128 = #mask; });
+    let required = attr
+        .required
+        .as_ref()
+        .map(|mask| quote! { const REQUIRED_MASK: u128 = #mask; });
 
     let expanded = quote! {
         #input
 
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// The ID of the register
+            pub const ID: #ty = #value;
+        }
+
         impl #impl_generics regiface::Register for #name #ty_generics #where_clause {
             type IdType = #ty;
 
+            #reserved
+            #required
+
             fn id() -> Self::IdType {
                 #value
             }
@@ -78,3 +147,420 @@ pub fn derive_writable_register(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+#[proc_macro_derive(TryFromBytes)]
+pub fn derive_try_from_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let expanded = quote! {
+        impl #impl_generics ::core::convert::TryFrom<<#name #ty_generics as regiface::FromByteArray>::Array>
+            for #name #ty_generics #where_clause
+        {
+            type Error = <#name #ty_generics as regiface::FromByteArray>::Error;
+
+            fn try_from(
+                bytes: <#name #ty_generics as regiface::FromByteArray>::Array,
+            ) -> ::core::result::Result<Self, Self::Error> {
+                <#name #ty_generics as regiface::FromByteArray>::from_bytes(bytes)
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+#[proc_macro_derive(ValidateWidth, attributes(validate_width))]
+pub fn derive_validate_width(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    if !input.generics.params.is_empty() {
+        return syn::Error::new_spanned(
+            &input.generics,
+            "ValidateWidth does not support generic types",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return syn::Error::new_spanned(name, "ValidateWidth can only be derived for structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+    let field_types = fields.iter().map(|field| &field.ty);
+
+    // Validate against `FromByteArray` unless the traits to validate are explicitly listed
+    let mut traits = Vec::new();
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("validate_width"))
+    {
+        match attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated) {
+            Ok(idents) => traits.extend(idents),
+            Err(err) => return err.to_compile_error().into(),
+        }
+    }
+    if traits.is_empty() {
+        traits.push(Ident::new("FromByteArray", name.span()));
+    }
+
+    let mut assertions = Vec::new();
+    for tr in &traits {
+        if tr != "FromByteArray" && tr != "ToByteArray" {
+            return syn::Error::new(tr.span(), "Expected `FromByteArray` or `ToByteArray`")
+                .to_compile_error()
+                .into();
+        }
+
+        let message = format!(
+            "the total width of the fields of `{name}` does not match the length of its `{tr}::Array`"
+        );
+        let field_types = field_types.clone();
+        assertions.push(quote! {
+            const _: () = assert!(
+                0 #(+ ::core::mem::size_of::<#field_types>())*
+                    == <<#name as regiface::#tr>::Array as regiface::ByteArray>::LEN,
+                #message
+            );
+        });
+    }
+
+    let expanded = quote! {
+        #(#assertions)*
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// The integer types supported by the `FromByteArray` and `ToByteArray` derives
+const INTEGER_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128",
+];
+
+/// A field of a struct deriving `FromByteArray` or `ToByteArray`
+struct ByteField {
+    member: syn::Member,
+    ty: syn::Type,
+    little_endian: bool,
+}
+
+/// Parses the byte order from any `#[regiface(endian = "..")]` attributes, returning `None` if
+/// no byte order is specified
+fn parse_endian(attrs: &[syn::Attribute]) -> syn::Result<Option<bool>> {
+    let mut little_endian = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("regiface")) {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("endian") {
+                return Err(meta.error("Expected `endian`"));
+            }
+
+            let value = meta.value()?.parse::<syn::LitStr>()?;
+            little_endian = Some(match value.value().as_str() {
+                "little" => true,
+                "big" => false,
+                _ => {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        "Expected `\"little\"` or `\"big\"`",
+                    ))
+                }
+            });
+            Ok(())
+        })?;
+    }
+    Ok(little_endian)
+}
+
+/// Collects the fields of a struct deriving `FromByteArray` or `ToByteArray`, resolving the byte
+/// order of each field from the field and struct level attributes
+fn byte_fields(input: &DeriveInput, derive: &str) -> syn::Result<Vec<ByteField>> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            format!("{derive} can not be derived for generic types"),
+        ));
+    }
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            format!("{derive} can only be derived for structs and fieldless enums"),
+        ));
+    };
+
+    // Integer fields are big-endian unless otherwise specified, matching the primitive impls
+    let default_little_endian = parse_endian(&input.attrs)?.unwrap_or(false);
+
+    data.fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let is_integer = matches!(
+                &field.ty,
+                syn::Type::Path(path) if path.qself.is_none()
+                    && path.path.get_ident().is_some_and(|ident| {
+                        INTEGER_TYPES.iter().any(|ty| ident == ty)
+                    })
+            );
+            if !is_integer {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    format!("{derive} can only be derived for structs of integer fields"),
+                ));
+            }
+
+            let member = match &field.ident {
+                Some(ident) => syn::Member::Named(ident.clone()),
+                None => syn::Member::Unnamed(index.into()),
+            };
+
+            Ok(ByteField {
+                member,
+                ty: field.ty.clone(),
+                little_endian: parse_endian(&field.attrs)?.unwrap_or(default_little_endian),
+            })
+        })
+        .collect()
+}
+
+/// A fieldless enum deriving `FromByteArray` or `ToByteArray`
+struct ByteEnum {
+    repr: Ident,
+    little_endian: bool,
+    variants: Vec<Ident>,
+}
+
+/// Collects the variants of a fieldless enum deriving `FromByteArray` or `ToByteArray`, resolving
+/// the integer type of its discriminant from any `#[repr(..)]` attribute
+fn byte_enum(input: &DeriveInput, derive: &str) -> syn::Result<ByteEnum> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            format!("{derive} can not be derived for generic types"),
+        ));
+    }
+
+    let Data::Enum(data) = &input.data else {
+        unreachable!("byte_enum called for a type that is not an enum")
+    };
+
+    // The discriminant is serialized as a `u8` unless a different integer repr is specified
+    let mut repr = Ident::new("u8", input.ident.span());
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        let idents = attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+        if let Some(ident) = idents
+            .into_iter()
+            .find(|ident| INTEGER_TYPES.iter().any(|ty| ident == ty))
+        {
+            repr = ident;
+        }
+    }
+
+    let variants = data
+        .variants
+        .iter()
+        .map(|variant| match variant.fields {
+            syn::Fields::Unit => Ok(variant.ident.clone()),
+            _ => Err(syn::Error::new_spanned(
+                variant,
+                format!("{derive} can only be derived for enums without fields"),
+            )),
+        })
+        .collect::<syn::Result<_>>()?;
+
+    Ok(ByteEnum {
+        repr,
+        little_endian: parse_endian(&input.attrs)?.unwrap_or(false),
+        variants,
+    })
+}
+
+/// Asserts at compile time that the discriminant of every variant of an enum fits in its repr
+fn discriminant_assertions(name: &Ident, byte_enum: &ByteEnum) -> proc_macro2::TokenStream {
+    let repr = &byte_enum.repr;
+    let variants = &byte_enum.variants;
+    let message = format!("a discriminant of `{name}` does not fit in a `{repr}`");
+    quote! {
+        const _: () = {
+            #(assert!(#name::#variants as i128 == (#name::#variants as #repr) as i128, #message);)*
+        };
+    }
+}
+
+/// Returns the byte offset of each field, followed by the total length of all fields
+fn byte_offsets(fields: &[ByteField]) -> Vec<proc_macro2::TokenStream> {
+    let mut offsets = vec![quote! { 0 }];
+    for field in fields {
+        let offset = offsets.last().unwrap();
+        let ty = &field.ty;
+        let next = quote! { #offset + ::core::mem::size_of::<#ty>() };
+        offsets.push(next);
+    }
+    offsets
+}
+
+#[proc_macro_derive(FromByteArray, attributes(regiface))]
+pub fn derive_from_byte_array(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    if let Data::Enum(_) = &input.data {
+        let byte_enum = match byte_enum(&input, "FromByteArray") {
+            Ok(byte_enum) => byte_enum,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        let assertions = discriminant_assertions(name, &byte_enum);
+        let ByteEnum {
+            repr,
+            little_endian,
+            variants,
+        } = byte_enum;
+        let from_bytes = if little_endian {
+            quote! { from_le_bytes }
+        } else {
+            quote! { from_be_bytes }
+        };
+
+        let expanded = quote! {
+            #assertions
+
+            impl regiface::FromByteArray for #name {
+                type Error = regiface::errors::InvalidVariant<#repr>;
+                type Array = [u8; ::core::mem::size_of::<#repr>()];
+
+                fn from_bytes(bytes: Self::Array) -> ::core::result::Result<Self, Self::Error> {
+                    let value = <#repr>::#from_bytes(bytes);
+                    #(
+                        if value == Self::#variants as #repr {
+                            return ::core::result::Result::Ok(Self::#variants);
+                        }
+                    )*
+                    ::core::result::Result::Err(regiface::errors::InvalidVariant(value))
+                }
+            }
+        };
+
+        return TokenStream::from(expanded);
+    }
+
+    let fields = match byte_fields(&input, "FromByteArray") {
+        Ok(fields) => fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let offsets = byte_offsets(&fields);
+    let len = offsets.last().unwrap();
+
+    let members = fields.iter().map(|field| &field.member);
+    let values = fields.iter().zip(&offsets).map(|(field, offset)| {
+        let ty = &field.ty;
+        let from_bytes = if field.little_endian {
+            quote! { from_le_bytes }
+        } else {
+            quote! { from_be_bytes }
+        };
+        quote! {
+            {
+                let mut field = [0u8; ::core::mem::size_of::<#ty>()];
+                field.copy_from_slice(&bytes[#offset..#offset + ::core::mem::size_of::<#ty>()]);
+                <#ty>::#from_bytes(field)
+            }
+        }
+    });
+
+    let expanded = quote! {
+        impl regiface::FromByteArray for #name {
+            type Error = ::core::convert::Infallible;
+            type Array = [u8; #len];
+
+            fn from_bytes(bytes: Self::Array) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::Ok(Self {
+                    #(#members: #values,)*
+                })
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+#[proc_macro_derive(ToByteArray, attributes(regiface))]
+pub fn derive_to_byte_array(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    if let Data::Enum(_) = &input.data {
+        let byte_enum = match byte_enum(&input, "ToByteArray") {
+            Ok(byte_enum) => byte_enum,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        let assertions = discriminant_assertions(name, &byte_enum);
+        let repr = &byte_enum.repr;
+        let to_bytes = if byte_enum.little_endian {
+            quote! { to_le_bytes }
+        } else {
+            quote! { to_be_bytes }
+        };
+
+        let expanded = quote! {
+            #assertions
+
+            impl regiface::ToByteArray for #name {
+                type Error = ::core::convert::Infallible;
+                type Array = [u8; ::core::mem::size_of::<#repr>()];
+
+                fn to_bytes(self) -> ::core::result::Result<Self::Array, Self::Error> {
+                    ::core::result::Result::Ok((self as #repr).#to_bytes())
+                }
+            }
+        };
+
+        return TokenStream::from(expanded);
+    }
+
+    let fields = match byte_fields(&input, "ToByteArray") {
+        Ok(fields) => fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let offsets = byte_offsets(&fields);
+    let len = offsets.last().unwrap();
+
+    let writes = fields.iter().zip(&offsets).map(|(field, offset)| {
+        let ty = &field.ty;
+        let member = &field.member;
+        let to_bytes = if field.little_endian {
+            quote! { to_le_bytes }
+        } else {
+            quote! { to_be_bytes }
+        };
+        quote! {
+            bytes[#offset..#offset + ::core::mem::size_of::<#ty>()]
+                .copy_from_slice(&self.#member.#to_bytes());
+        }
+    });
+
+    let expanded = quote! {
+        impl regiface::ToByteArray for #name {
+            type Error = ::core::convert::Infallible;
+            type Array = [u8; #len];
+
+            fn to_bytes(self) -> ::core::result::Result<Self::Array, Self::Error> {
+                let mut bytes = [0u8; #len];
+                #(#writes)*
+                ::core::result::Result::Ok(bytes)
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
@@ -0,0 +1,239 @@
+//! A collection of utility functions for accessing memory-like I2C devices at runtime addresses
+//!
+//! Devices such as EEPROMs and FRAMs do not expose a small set of registers, but a linear memory
+//! selected by a multi-byte memory address written before the data is streamed. Unlike the
+//! register model, where the ID of each register is fixed by [`Register::id`](crate::Register::id),
+//! the memory address is a runtime value, and the data is an arbitrary number of bytes rather than
+//! a typed register value.
+//!
+//! The memory address may be any [`Id`], and is serialized with [`Id::to_id_bytes`], thus a
+//! [`u16`] is transmitted as two big-endian bytes, as expected by most EEPROMs. The
+//! [`LeId`](crate::id::LeId) wrapper may be used for devices expecting a little-endian address.
+//!
+//! When the `log` feature is enabled, each operation emits a trace level log event containing the
+//! memory address and the bytes transferred.
+//!
+//! # Example
+#![cfg_attr(feature = "blocking", doc = "```")]
+#![cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+//! # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+//! use regiface::addressed;
+//! # struct Eeprom([u8; 0x100]);
+//! # impl ErrorType for Eeprom { type Error = core::convert::Infallible; }
+//! # impl I2c for Eeprom {
+//! #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+//! #         let [Operation::Write(addr), op] = ops else { unreachable!() };
+//! #         let start = usize::from(u16::from_be_bytes([addr[0], addr[1]]));
+//! #         match op {
+//! #             Operation::Read(buf) => buf.copy_from_slice(&self.0[start..][..buf.len()]),
+//! #             Operation::Write(data) => self.0[start..][..data.len()].copy_from_slice(data),
+//! #         }
+//! #         Ok(())
+//! #     }
+//! # }
+//! # let mut eeprom = Eeprom([0xFF; 0x100]);
+//!
+//! addressed::blocking::write_at(&mut eeprom, 0x50, 0x0040u16, b"serial").unwrap();
+//!
+//! let mut buf = [0; 6];
+//! addressed::blocking::read_at(&mut eeprom, 0x50, 0x0040u16, &mut buf).unwrap();
+//! assert_eq!(&buf, b"serial");
+//! ```
+
+#[cfg(any(feature = "blocking", feature = "async", doc))]
+use crate::{byte_array::ByteArray as _, id::Id};
+
+#[cfg(feature = "async")]
+pub mod r#async {
+    use super::*;
+    use embedded_hal_async::i2c::Operation;
+
+    /// Read a range of memory from an I2C device.
+    ///
+    /// This function performs a single I2C transaction, writing the serialized memory address and
+    /// then reading enough bytes to fill `buf`. Most devices advance their internal address after
+    /// each byte, thus `buf` receives the contents of consecutive memory addresses.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `mem_addr` - The memory address of the first byte to read
+    /// * `buf` - The buffer to read the memory into
+    ///
+    /// # Errors
+    /// Returns the bus error if communication with the device failed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::addressed;
+    /// async fn read_calibration<D: I2c<u8>>(device: &mut D) -> [u8; 16] {
+    ///     let mut buf = [0; 16];
+    ///     addressed::r#async::read_at(device, 0x50, 0x0100u16, &mut buf).await.unwrap();
+    ///     buf
+    /// }
+    /// ```
+    pub async fn read_at<D, A, M>(
+        device: &mut D,
+        device_addr: A,
+        mem_addr: M,
+        buf: &mut [u8],
+    ) -> Result<(), D::Error>
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        M: Id,
+    {
+        let mem_addr = mem_addr.to_id_bytes();
+
+        device
+            .write_read(device_addr, mem_addr.as_ref(), buf)
+            .await?;
+
+        trace!(
+            "addressed: read: addr={:02X?} bytes={:02X?}",
+            mem_addr.as_ref(),
+            buf
+        );
+
+        Ok(())
+    }
+
+    /// Write a range of memory to an I2C device.
+    ///
+    /// This function performs a single I2C transaction, writing the serialized memory address
+    /// followed by `data`. Many EEPROMs wrap writes around at the end of a page, thus a write must
+    /// not cross a page boundary of the device, and may need to be followed by a delay or
+    /// acknowledge polling while the device commits the page.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `mem_addr` - The memory address of the first byte to write
+    /// * `data` - The bytes to write
+    ///
+    /// # Errors
+    /// Returns the bus error if communication with the device failed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::addressed;
+    /// async fn store_serial<D: I2c<u8>>(device: &mut D, serial: &[u8; 8]) {
+    ///     addressed::r#async::write_at(device, 0x50, 0x0000u16, serial).await.unwrap();
+    /// }
+    /// ```
+    pub async fn write_at<D, A, M>(
+        device: &mut D,
+        device_addr: A,
+        mem_addr: M,
+        data: &[u8],
+    ) -> Result<(), D::Error>
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        M: Id,
+    {
+        let mem_addr = mem_addr.to_id_bytes();
+
+        device
+            .transaction(
+                device_addr,
+                &mut [Operation::Write(mem_addr.as_ref()), Operation::Write(data)],
+            )
+            .await?;
+
+        trace!(
+            "addressed: write: addr={:02X?} bytes={:02X?}",
+            mem_addr.as_ref(),
+            data
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use super::*;
+    use embedded_hal::i2c::Operation;
+
+    /// Read a range of memory from an I2C device.
+    ///
+    /// Blocking variant of `r#async::read_at`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::i2c::I2c;
+    /// # use regiface::addressed;
+    /// fn read_calibration<D: I2c<u8>>(device: &mut D) -> [u8; 16] {
+    ///     let mut buf = [0; 16];
+    ///     addressed::blocking::read_at(device, 0x50, 0x0100u16, &mut buf).unwrap();
+    ///     buf
+    /// }
+    /// ```
+    pub fn read_at<D, A, M>(
+        device: &mut D,
+        device_addr: A,
+        mem_addr: M,
+        buf: &mut [u8],
+    ) -> Result<(), D::Error>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+        M: Id,
+    {
+        let mem_addr = mem_addr.to_id_bytes();
+
+        device.write_read(device_addr, mem_addr.as_ref(), buf)?;
+
+        trace!(
+            "addressed: read: addr={:02X?} bytes={:02X?}",
+            mem_addr.as_ref(),
+            buf
+        );
+
+        Ok(())
+    }
+
+    /// Write a range of memory to an I2C device.
+    ///
+    /// Blocking variant of `r#async::write_at`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::i2c::I2c;
+    /// # use regiface::addressed;
+    /// fn store_serial<D: I2c<u8>>(device: &mut D, serial: &[u8; 8]) {
+    ///     addressed::blocking::write_at(device, 0x50, 0x0000u16, serial).unwrap();
+    /// }
+    /// ```
+    pub fn write_at<D, A, M>(
+        device: &mut D,
+        device_addr: A,
+        mem_addr: M,
+        data: &[u8],
+    ) -> Result<(), D::Error>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+        M: Id,
+    {
+        let mem_addr = mem_addr.to_id_bytes();
+
+        device.transaction(
+            device_addr,
+            &mut [Operation::Write(mem_addr.as_ref()), Operation::Write(data)],
+        )?;
+
+        trace!(
+            "addressed: write: addr={:02X?} bytes={:02X?}",
+            mem_addr.as_ref(),
+            data
+        );
+
+        Ok(())
+    }
+}
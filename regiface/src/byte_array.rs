@@ -1,8 +1,13 @@
 use core::convert::Infallible;
 
-use crate::NoParameters;
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+use crate::{errors::ElementError, ByRef, LengthPrefixed, NoParameters, Scaled, Zeros, I24, U24};
 
 pub trait ByteArray: private::Sealed {
+    /// The number of bytes in the array
+    const LEN: usize;
+
     fn new() -> Self;
     fn as_ref(&self) -> &[u8];
     fn as_mut(&mut self) -> &mut [u8];
@@ -15,6 +20,8 @@ mod private {
 }
 
 impl<const LEN: usize> ByteArray for [u8; LEN] {
+    const LEN: usize = LEN;
+
     #[inline]
     fn new() -> Self {
         [0; LEN]
@@ -31,10 +38,254 @@ impl<const LEN: usize> ByteArray for [u8; LEN] {
     }
 }
 
+/// A trait implemented by byte arrays that may be concatenated with an array of type `Rhs`
+///
+/// [`Output`](Concat::Output) is the array whose length is the sum of the two, which allows a
+/// buffer holding both a register or command ID and its data to be named without const generic
+/// arithmetic. As stable Rust cannot yet express the sum of two const generics, this is implemented
+/// for left-hand arrays of the lengths of the built-in [`Id`](crate::id::Id) types (1, 2, 3, 4, 8,
+/// and 16 bytes) and right-hand arrays of up to 32 bytes.
+///
+/// ```
+/// use regiface::{concat, id::Id, Concat, ToByteArray};
+///
+/// let id = 0x2Au8.to_id_bytes();
+/// let data = 0x1234u16.to_bytes().unwrap();
+///
+/// let buf: <[u8; 1] as Concat<[u8; 2]>>::Output = concat(&id, &data);
+/// assert_eq!(buf, [0x2A, 0x12, 0x34]);
+/// ```
+pub trait Concat<Rhs: ByteArray>: ByteArray {
+    /// The array holding the bytes of `Self` followed by the bytes of `Rhs`
+    type Output: ByteArray;
+}
+
+macro_rules! impl_concat {
+    ($($lhs:literal),*) => {
+        $(
+            impl_concat!(@rhs $lhs;
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+                17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+            );
+        )*
+    };
+    (@rhs $lhs:literal; $($rhs:literal),*) => {
+        $(
+            impl Concat<[u8; $rhs]> for [u8; $lhs] {
+                type Output = [u8; $lhs + $rhs];
+            }
+        )*
+    };
+}
+
+impl_concat!(1, 2, 3, 4, 8, 16);
+
+/// Concatenate two byte arrays into a single array, with the bytes of `lhs` first
+pub fn concat<A, B>(lhs: &A, rhs: &B) -> A::Output
+where
+    A: Concat<B>,
+    B: ByteArray,
+{
+    let mut out = A::Output::new();
+    let (head, tail) = out.as_mut().split_at_mut(A::LEN);
+    head.copy_from_slice(lhs.as_ref());
+    tail.copy_from_slice(rhs.as_ref());
+    out
+}
+
+/// A trait implemented by byte arrays that may be repeated `N` times
+///
+/// [`Output`](Repeat::Output) is the array holding `N` consecutive copies of `Self`, which allows
+/// arrays of [`FromByteArray`] values to be deserialized without const generic arithmetic. As
+/// stable Rust cannot yet express the product of two const generics, this is implemented for arrays
+/// of up to 16 bytes repeated up to 32 times.
+///
+/// ```
+/// use regiface::Repeat;
+///
+/// let buf = <[u8; 3] as Repeat<4>>::Output::default();
+/// assert_eq!(buf.len(), 12);
+/// ```
+pub trait Repeat<const N: usize>: ByteArray {
+    /// The array holding the bytes of `N` consecutive copies of `Self`
+    type Output: ByteArray;
+}
+
+macro_rules! impl_repeat {
+    ($($len:literal),*) => {
+        $(
+            impl_repeat!(@count $len;
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+                17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+            );
+        )*
+    };
+    (@count $len:literal; $($count:literal),*) => {
+        $(
+            impl Repeat<$count> for [u8; $len] {
+                type Output = [u8; $len * $count];
+            }
+        )*
+    };
+}
+
+impl_repeat!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+
 /// A trait to be implemented by any type that can be created from an array of bytes
 ///
 /// If the `packed_struct` feature is used, this trait will be derived for any type that implements derives
 /// `PackedStruct` from the [`packed_struct` crate](https://crates.io/crates/packed_struct)
+///
+/// The bytes passed to [`from_bytes`](FromByteArray::from_bytes) are always complete, as a short
+/// read is never deserialized. The I2C and SPI traits of `embedded-hal` either fill the entire
+/// read buffer or report an error, and do not expose a count of the bytes received, while the
+/// [`serial`](crate::serial) module reports a response that ends early as
+/// [`FrameError::UnexpectedEof`](crate::serial::FrameError::UnexpectedEof), or as
+/// [`FrameError::Length`](crate::serial::FrameError::Length) if its length byte is too short.
+///
+/// When implementing this trait by hand, the `ValidateWidth` derive may be used to assert at compile time
+/// that the total size of a struct's fields matches the length of the declared [`Array`](FromByteArray::Array).
+/// Use `#[validate_width(ToByteArray)]` or `#[validate_width(FromByteArray, ToByteArray)]` to instead
+/// validate against [`ToByteArray`], or both traits.
+///
+/// ```
+/// use regiface::{FromByteArray, ValidateWidth};
+///
+/// #[derive(ValidateWidth)]
+/// pub struct Sample {
+///     x: i16,
+///     y: i16,
+///     status: u8,
+/// }
+///
+/// impl FromByteArray for Sample {
+///     type Error = core::convert::Infallible;
+///     type Array = [u8; 5];
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         Ok(Self {
+///             x: i16::from_be_bytes([bytes[0], bytes[1]]),
+///             y: i16::from_be_bytes([bytes[2], bytes[3]]),
+///             status: bytes[4],
+///         })
+///     }
+/// }
+/// ```
+///
+/// A mismatch between the fields and the declared array fails to compile
+///
+/// ```compile_fail
+/// use regiface::{FromByteArray, ValidateWidth};
+///
+/// #[derive(ValidateWidth)]
+/// pub struct Sample {
+///     x: i16,
+///     y: i16,
+/// }
+///
+/// impl FromByteArray for Sample {
+///     type Error = core::convert::Infallible;
+///     type Array = [u8; 3];
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         todo!()
+///     }
+/// }
+/// ```
+///
+/// For structs consisting solely of integer fields, this trait may be derived. Fields are
+/// deserialized in declaration order, each occupying its full width, and are big-endian unless
+/// otherwise specified. A struct-level `#[regiface(endian = "little")]` attribute switches the
+/// default byte order of every field, while a field-level `#[regiface(endian = "..")]` attribute
+/// overrides the byte order of a single field. The same attributes are supported when deriving
+/// [`ToByteArray`].
+///
+/// ```
+/// use regiface::{FromByteArray, ToByteArray};
+///
+/// #[derive(FromByteArray, ToByteArray, Debug, PartialEq)]
+/// #[regiface(endian = "little")]
+/// pub struct Sample {
+///     x: i16,
+///     y: i16,
+///     #[regiface(endian = "big")]
+///     timestamp: u32,
+/// }
+///
+/// let bytes = [0x34, 0x12, 0xFE, 0xFF, 0x00, 0x00, 0x01, 0x00];
+/// let sample = Sample::from_bytes(bytes).unwrap();
+/// assert_eq!(sample, Sample { x: 0x1234, y: -2, timestamp: 0x100 });
+/// assert_eq!(sample.to_bytes().unwrap(), bytes);
+/// ```
+///
+/// Fieldless enums may also derive this trait, in which case the discriminant is serialized as a
+/// `u8`, or as the integer type of the enum's `#[repr(..)]` attribute, with the byte order selected
+/// as for structs. Reading a value that matches none of the discriminants fails with an
+/// [`InvalidVariant`](crate::errors::InvalidVariant) error holding the value read.
+///
+/// ```
+/// use regiface::{errors::InvalidVariant, FromByteArray, ToByteArray};
+///
+/// #[derive(FromByteArray, ToByteArray, Clone, Copy, Debug, PartialEq)]
+/// pub enum PowerMode {
+///     Off = 0x00,
+///     Standby = 0x01,
+///     Active = 0x03,
+/// }
+///
+/// assert_eq!(PowerMode::from_bytes([0x03]), Ok(PowerMode::Active));
+/// assert_eq!(PowerMode::from_bytes([0x02]), Err(InvalidVariant(0x02)));
+/// assert_eq!(PowerMode::Standby.to_bytes().unwrap(), [0x01]);
+///
+/// #[derive(FromByteArray, ToByteArray, Debug, PartialEq)]
+/// #[repr(u16)]
+/// #[regiface(endian = "little")]
+/// pub enum Rate {
+///     Slow = 0x0100,
+///     Fast = 0x0200,
+/// }
+///
+/// assert_eq!(Rate::from_bytes([0x00, 0x02]), Ok(Rate::Fast));
+/// assert_eq!(Rate::Slow.to_bytes().unwrap(), [0x00, 0x01]);
+/// ```
+///
+/// A discriminant that does not fit in the serialized integer type fails to compile
+///
+/// ```compile_fail
+/// use regiface::FromByteArray;
+///
+/// #[derive(FromByteArray)]
+/// pub enum Gain {
+///     Low = 0x00,
+///     High = 0x100,
+/// }
+/// ```
+///
+/// The `TryFromBytes` derive implements [`TryFrom`] from the declared [`Array`](FromByteArray::Array)
+/// by forwarding to [`FromByteArray::from_bytes`], allowing a value to be constructed with the
+/// standard conversion traits. A blanket implementation is not possible, as it would conflict with
+/// the reflexive [`TryFrom`] implementation provided by `core`.
+///
+/// ```
+/// use regiface::{FromByteArray, TryFromBytes};
+///
+/// #[derive(TryFromBytes, Debug, PartialEq)]
+/// pub struct Status(u8);
+///
+/// impl FromByteArray for Status {
+///     type Error = core::convert::Infallible;
+///     type Array = [u8; 1];
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         Ok(Self(bytes[0]))
+///     }
+/// }
+///
+/// assert_eq!(Status::try_from([0x42]), Ok(Status(0x42)));
+///
+/// let status: Status = [0x42].try_into().unwrap();
+/// assert_eq!(status, Status(0x42));
+/// ```
 pub trait FromByteArray: Sized {
     /// A type representing the types of error that may occur during conversion
     type Error;
@@ -55,6 +306,15 @@ impl FromByteArray for NoParameters {
     }
 }
 
+impl<const N: usize> FromByteArray for Zeros<N> {
+    type Error = Infallible;
+    type Array = [u8; N];
+
+    fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> {
+        Ok(Self)
+    }
+}
+
 impl FromByteArray for u8 {
     type Error = Infallible;
     type Array = [u8; 1];
@@ -100,6 +360,91 @@ impl FromByteArray for u128 {
     }
 }
 
+impl FromByteArray for U24 {
+    type Error = Infallible;
+    type Array = [u8; 3];
+
+    fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+        let [a, b, c] = bytes;
+        Ok(Self(u32::from_be_bytes([0, a, b, c])))
+    }
+}
+
+impl FromByteArray for I24 {
+    type Error = Infallible;
+    type Array = [u8; 3];
+
+    fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+        let [a, b, c] = bytes;
+        // Shifting the value back down from the top of an `i32` sign-extends it
+        Ok(Self(i32::from_be_bytes([a, b, c, 0]) >> 8))
+    }
+}
+
+impl<Raw, const NUM: i32, const DEN: i32, const OFFSET: i32> FromByteArray
+    for Scaled<Raw, NUM, DEN, OFFSET>
+where
+    Raw: FromByteArray,
+{
+    type Error = Raw::Error;
+    type Array = Raw::Array;
+
+    fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+        Raw::from_bytes(bytes).map(Self)
+    }
+}
+
+/// Arrays of values are deserialized element by element from consecutive chunks of bytes
+///
+/// The element array must implement [`Repeat`] for the number of elements, thus arrays of up to 32
+/// elements, each of up to 16 bytes, are supported. The error identifies the first element that
+/// could not be deserialized.
+///
+/// ```
+/// use regiface::{errors::{ElementError, InvalidVariant}, FromByteArray};
+///
+/// #[derive(FromByteArray, Debug, PartialEq)]
+/// enum Gain {
+///     Low = 0,
+///     High = 1,
+/// }
+///
+/// let gains = <[Gain; 3]>::from_bytes([0x01, 0x00, 0x01]).unwrap();
+/// assert_eq!(gains, [Gain::High, Gain::Low, Gain::High]);
+///
+/// let coefficients = <[u16; 2]>::from_bytes([0x12, 0x34, 0x56, 0x78]).unwrap();
+/// assert_eq!(coefficients, [0x1234, 0x5678]);
+///
+/// let err = <[Gain; 3]>::from_bytes([0x01, 0x07, 0x01]).unwrap_err();
+/// assert_eq!(err, ElementError { index: 1, error: InvalidVariant(0x07) });
+/// ```
+impl<T, const N: usize> FromByteArray for [T; N]
+where
+    T: FromByteArray,
+    T::Array: Repeat<N>,
+{
+    type Error = ElementError<T::Error>;
+    type Array = <T::Array as Repeat<N>>::Output;
+
+    fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+        let mut result = Ok(());
+        let elements: [Option<T>; N] = core::array::from_fn(|index| {
+            result.as_ref().ok()?;
+
+            let mut chunk = T::Array::new();
+            chunk
+                .as_mut()
+                .copy_from_slice(&bytes.as_ref()[index * T::Array::LEN..][..T::Array::LEN]);
+            T::from_bytes(chunk)
+                .map_err(|error| result = Err(ElementError { index, error }))
+                .ok()
+        });
+        result?;
+
+        Ok(elements.map(|element| element.expect("every element was deserialized")))
+    }
+}
+
 #[cfg(feature = "packed_struct")]
 impl<V, const LEN: usize> FromByteArray for V
 where
@@ -113,6 +458,99 @@ where
     }
 }
 
+#[cfg(feature = "postcard")]
+impl<T, const N: usize> FromByteArray for crate::Postcard<T, N>
+where
+    T: serde::de::DeserializeOwned,
+{
+    type Error = postcard::Error;
+    type Array = [u8; N];
+
+    fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+        postcard::from_bytes(&bytes).map(Self)
+    }
+}
+
+/// A trait to be implemented by any type that can be deserialized from a slice of bytes of
+/// variable length
+///
+/// Unlike [`FromByteArray`], the number of bytes is not known until they have been received, as is
+/// the case for responses delimited by a terminator byte, such as those read by
+/// `serial::blocking::invoke_command_until`.
+///
+/// # Example
+/// ```
+/// use regiface::FromByteSlice;
+///
+/// struct Firmware {
+///     major: u8,
+///     minor: u8,
+/// }
+///
+/// impl FromByteSlice for Firmware {
+///     type Error = ();
+///
+///     fn from_slice(bytes: &[u8]) -> Result<Self, Self::Error> {
+///         // Parses an ASCII version such as `1.12`
+///         let text = core::str::from_utf8(bytes).map_err(|_| ())?;
+///         let (major, minor) = text.split_once('.').ok_or(())?;
+///         Ok(Self {
+///             major: major.parse().map_err(|_| ())?,
+///             minor: minor.parse().map_err(|_| ())?,
+///         })
+///     }
+/// }
+///
+/// let firmware = Firmware::from_slice(b"1.12").unwrap();
+/// assert_eq!((firmware.major, firmware.minor), (1, 12));
+/// ```
+pub trait FromByteSlice: Sized {
+    /// A type representing the types of error that may occur during conversion
+    type Error;
+
+    fn from_slice(bytes: &[u8]) -> Result<Self, Self::Error>;
+}
+
+/// Collects the received bytes as-is, for responses whose contents are only known at runtime
+///
+/// This implementation is only available when the `alloc` feature is enabled.
+///
+/// ```
+/// use regiface::FromByteSlice;
+///
+/// let bytes = Vec::from_slice(&[0x01, 0x02, 0x03]).unwrap();
+/// assert_eq!(bytes, [0x01, 0x02, 0x03]);
+/// ```
+#[cfg(feature = "alloc")]
+impl FromByteSlice for alloc::vec::Vec<u8> {
+    type Error = Infallible;
+
+    #[inline]
+    fn from_slice(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Ok(bytes.to_vec())
+    }
+}
+
+/// Decodes the received bytes as UTF-8 text, such as the response of an AT command
+///
+/// This implementation is only available when the `alloc` feature is enabled.
+///
+/// ```
+/// use regiface::FromByteSlice;
+///
+/// assert_eq!(String::from_slice(b"OK").unwrap(), "OK");
+/// assert!(String::from_slice(&[0xFF]).is_err());
+/// ```
+#[cfg(feature = "alloc")]
+impl FromByteSlice for alloc::string::String {
+    type Error = core::str::Utf8Error;
+
+    #[inline]
+    fn from_slice(bytes: &[u8]) -> Result<Self, Self::Error> {
+        core::str::from_utf8(bytes).map(Into::into)
+    }
+}
+
 /// A trait to be implemented by any type that can be serialized into an array of bytes
 ///
 /// If the `packed_struct` feature is used, this trait will be derived for any type that implements derives
@@ -137,6 +575,169 @@ impl ToByteArray for NoParameters {
     }
 }
 
+impl<const N: usize> ToByteArray for Zeros<N> {
+    type Error = Infallible;
+    type Array = [u8; N];
+
+    fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+        Ok([0; N])
+    }
+}
+
+impl<T, const LEN: usize> ToByteArray for LengthPrefixed<T, LEN>
+where
+    T: ToByteArray,
+{
+    type Error = T::Error;
+    type Array = [u8; LEN];
+
+    fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+        const {
+            assert!(
+                LEN == T::Array::LEN + 1,
+                "LEN must be one more than the length of the payload"
+            );
+            assert!(
+                T::Array::LEN <= u8::MAX as usize,
+                "the length of the payload must fit in a single byte"
+            );
+        }
+
+        let payload = self.0.to_bytes()?;
+        let mut bytes = [0; LEN];
+        bytes[0] = T::Array::LEN as u8;
+        bytes[1..].copy_from_slice(payload.as_ref());
+        Ok(bytes)
+    }
+}
+
+/// A trait for comparing two values by their serialized bytes, implemented for every [`Copy`] type
+/// implementing [`ToByteArray`]
+///
+/// This is intended for diagnostics, such as logging which bits of a configuration register
+/// change between a value read from a device and the value about to be written to it.
+///
+/// # Example
+/// ```
+/// use regiface::{register, RegisterDiff, ToByteArray, WritableRegister};
+///
+/// #[register(0x20u8)]
+/// #[derive(WritableRegister, Clone, Copy)]
+/// struct Ctrl {
+///     enable: bool,
+///     rate: u8,
+/// }
+///
+/// impl ToByteArray for Ctrl {
+///     type Error = core::convert::Infallible;
+///     type Array = [u8; 1];
+///
+///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+///         Ok([(self.rate << 4) | u8::from(self.enable)])
+///     }
+/// }
+///
+/// let before = Ctrl { enable: false, rate: 0x3 };
+/// let after = Ctrl { enable: true, rate: 0x5 };
+///
+/// // The enable bit and bits 1 and 2 of the rate changed
+/// assert_eq!(before.diff(&after).unwrap(), [0b0110_0001]);
+/// assert_eq!(after.diff(&after).unwrap(), [0]);
+/// ```
+pub trait RegisterDiff: ToByteArray {
+    /// Returns the XOR of the serialized bytes of `self` and `other`, in which each set bit
+    /// differs between the two values
+    fn diff(&self, other: &Self) -> Result<Self::Array, Self::Error>;
+}
+
+impl<T: ToByteArray + Copy> RegisterDiff for T {
+    fn diff(&self, other: &Self) -> Result<Self::Array, Self::Error> {
+        let mut bytes = self.to_bytes()?;
+        let other = other.to_bytes()?;
+
+        for (byte, other) in bytes.as_mut().iter_mut().zip(other.as_ref()) {
+            *byte ^= other;
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// Error that can occur when formatting a value as hex with [`hex`]
+///
+/// Generic over the serialization error type `S`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum HexError<S> {
+    /// An error occurred while serializing the value
+    SerializationError(S),
+    /// The buffer is too small to hold the formatted value
+    BufferTooSmall {
+        /// The number of bytes required to hold the formatted value
+        required: usize,
+        /// The length of the provided buffer
+        capacity: usize,
+    },
+}
+
+/// Format the serialized bytes of a value as uppercase ASCII hex into a caller-provided buffer
+///
+/// Each byte is written as two hex digits, in the order the bytes would be written to the device,
+/// without any separator or prefix. The returned string borrows the start of `buf`, allowing
+/// register contents to be logged without allocating.
+///
+/// # Parameters
+/// * `value` - The value to format
+/// * `buf` - The buffer to format the value into, which must hold two bytes per serialized byte
+///
+/// # Errors
+/// * `HexError::SerializationError` - Failed to convert the value to bytes
+/// * `HexError::BufferTooSmall` - The buffer can not hold the formatted value
+///
+/// # Example
+/// ```
+/// use regiface::{hex, HexError};
+///
+/// let mut buf = [0u8; 8];
+/// assert_eq!(hex(0x12AB_CDEFu32, &mut buf), Ok("12ABCDEF"));
+///
+/// let mut small = [0u8; 3];
+/// assert_eq!(
+///     hex(0x1234u16, &mut small),
+///     Err(HexError::BufferTooSmall { required: 4, capacity: 3 })
+/// );
+/// ```
+pub fn hex<T: ToByteArray>(value: T, buf: &mut [u8]) -> Result<&str, HexError<T::Error>> {
+    const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+    let bytes = value.to_bytes().map_err(HexError::SerializationError)?;
+    let bytes = bytes.as_ref();
+
+    let required = bytes.len() * 2;
+    if buf.len() < required {
+        return Err(HexError::BufferTooSmall {
+            required,
+            capacity: buf.len(),
+        });
+    }
+
+    for (byte, out) in bytes.iter().zip(buf.chunks_exact_mut(2)) {
+        out[0] = DIGITS[usize::from(byte >> 4)];
+        out[1] = DIGITS[usize::from(byte & 0x0F)];
+    }
+
+    Ok(core::str::from_utf8(&buf[..required]).expect("hex digits are valid UTF-8"))
+}
+
+impl<T: ToByteArray + Copy> ToByteArray for ByRef<'_, T> {
+    type Error = T::Error;
+    type Array = T::Array;
+
+    fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+        (*self.0).to_bytes()
+    }
+}
+
 impl ToByteArray for u8 {
     type Error = Infallible;
     type Array = [u8; 1];
@@ -182,6 +783,49 @@ impl ToByteArray for u128 {
     }
 }
 
+impl ToByteArray for U24 {
+    type Error = Infallible;
+    type Array = [u8; 3];
+
+    fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+        let [_, a, b, c] = self.0.to_be_bytes();
+        Ok([a, b, c])
+    }
+}
+
+/// Serializes an [`I24`] as three bytes in two's complement
+///
+/// ```
+/// use regiface::{FromByteArray, ToByteArray, I24};
+///
+/// let value = I24::new(-2).unwrap();
+/// assert_eq!(value.to_bytes().unwrap(), [0xFF, 0xFF, 0xFE]);
+/// assert_eq!(I24::from_bytes([0x7F, 0xFF, 0xFF]).unwrap(), I24::MAX);
+/// assert_eq!(I24::from_bytes([0x80, 0x00, 0x00]).unwrap(), I24::MIN);
+/// ```
+impl ToByteArray for I24 {
+    type Error = Infallible;
+    type Array = [u8; 3];
+
+    fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+        let [_, a, b, c] = self.0.to_be_bytes();
+        Ok([a, b, c])
+    }
+}
+
+impl<Raw, const NUM: i32, const DEN: i32, const OFFSET: i32> ToByteArray
+    for Scaled<Raw, NUM, DEN, OFFSET>
+where
+    Raw: ToByteArray,
+{
+    type Error = Raw::Error;
+    type Array = Raw::Array;
+
+    fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+        self.0.to_bytes()
+    }
+}
+
 #[cfg(feature = "packed_struct")]
 impl<V, const LEN: usize> ToByteArray for V
 where
@@ -194,3 +838,238 @@ where
         self.pack()
     }
 }
+
+#[cfg(feature = "postcard")]
+impl<T, const N: usize> ToByteArray for crate::Postcard<T, N>
+where
+    T: serde::Serialize,
+{
+    type Error = postcard::Error;
+    type Array = [u8; N];
+
+    fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+        let mut bytes = [0; N];
+        postcard::to_slice(&self.0, &mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+/// Returns 2 raised to the power of `exponent`, which must be within the range of a normal [`f32`]
+fn pow2(exponent: i32) -> f32 {
+    f32::from_bits(((127 + exponent) as u32) << 23)
+}
+
+/// Scales `value` down by 2 raised to the power of `exponent` and rounds it to the nearest integer,
+/// returning `None` if the result does not lie within `min..=max`
+fn mantissa(value: f32, exponent: i8, min: i32, max: i32) -> Option<i32> {
+    if value.is_nan() {
+        return None;
+    }
+
+    let scaled = value * pow2(-i32::from(exponent));
+    let rounded = if scaled < 0.0 {
+        scaled - 0.5
+    } else {
+        scaled + 0.5
+    } as i32;
+
+    (min..=max).contains(&rounded).then_some(rounded)
+}
+
+/// A value in the PMBus Linear11 format, as used by most PMBus telemetry and limit commands
+///
+/// The 16-bit word consists of a 5-bit two's complement exponent `N` in its upper bits and an
+/// 11-bit two's complement mantissa `Y` in its lower bits, representing the value `Y * 2^N`. As
+/// with all SMBus words, the value is serialized least significant byte first.
+///
+/// # Example
+#[cfg_attr(feature = "blocking", doc = "```")]
+#[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+/// use embedded_hal::i2c::{ErrorType, I2c, Operation};
+/// use regiface::{i2c, register, FromByteArray, Linear11, ReadableRegister, ToByteArray};
+///
+/// #[register(0x8Cu8)]
+/// #[derive(ReadableRegister)]
+/// struct ReadIout(Linear11);
+///
+/// impl FromByteArray for ReadIout {
+///     type Array = [u8; 2];
+///     type Error = core::convert::Infallible;
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         Linear11::from_bytes(bytes).map(Self)
+///     }
+/// }
+/// # struct Device;
+/// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+/// # impl I2c for Device {
+/// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+/// #         let [Operation::Write([0x8C]), Operation::Read(buf)] = ops else { panic!() };
+/// #         buf.copy_from_slice(&[0x40, 0xCA]);
+/// #         Ok(())
+/// #     }
+/// # }
+/// # let mut device = Device;
+///
+/// // The device reports 0xCA40, a mantissa of 576 with an exponent of -7
+/// let iout: ReadIout = i2c::blocking::read_register(&mut device, 0x40).unwrap();
+/// assert_eq!(iout.0.mantissa(), 576);
+/// assert_eq!(iout.0.exponent(), -7);
+/// assert_eq!(iout.0.to_f32(), 4.5);
+///
+/// // Negative mantissas are sign-extended
+/// assert_eq!(Linear11::from_raw(0x07FF).to_f32(), -1.0);
+/// assert_eq!(Linear11::from_raw(0xF7FE).to_f32(), -0.5);
+///
+/// // Values round-trip through a fixed exponent
+/// let limit = Linear11::from_f32(4.5, -7).unwrap();
+/// assert_eq!(limit.raw(), 0xCA40);
+/// assert_eq!(Linear11::from_bytes(limit.to_bytes().unwrap()).unwrap(), limit);
+///
+/// // Values that do not fit in the mantissa at the given exponent are rejected
+/// assert_eq!(Linear11::from_f32(8.0, -7), None);
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub struct Linear11(u16);
+
+impl Linear11 {
+    /// Create a new [`Linear11`] from its raw 16-bit word
+    pub const fn from_raw(raw: u16) -> Self {
+        Self(raw)
+    }
+
+    /// Create a new [`Linear11`] from its mantissa and exponent, returning `None` if the mantissa
+    /// does not fit in 11 bits or the exponent does not fit in 5 bits
+    pub const fn new(mantissa: i16, exponent: i8) -> Option<Self> {
+        if mantissa < -1024 || mantissa > 1023 || exponent < -16 || exponent > 15 {
+            return None;
+        }
+        Some(Self(((exponent as u16) << 11) | (mantissa as u16 & 0x07FF)))
+    }
+
+    /// Create a new [`Linear11`] approximating `value` with the given exponent, returning `None` if
+    /// the value is NaN or its mantissa does not fit in 11 bits at that exponent
+    pub fn from_f32(value: f32, exponent: i8) -> Option<Self> {
+        let mantissa = mantissa(value, exponent, -1024, 1023)?;
+        Self::new(mantissa as i16, exponent)
+    }
+
+    /// Returns the raw 16-bit word
+    pub const fn raw(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the 11-bit two's complement mantissa, sign-extended into an [`i16`]
+    pub const fn mantissa(self) -> i16 {
+        ((self.0 << 5) as i16) >> 5
+    }
+
+    /// Returns the 5-bit two's complement exponent, sign-extended into an [`i8`]
+    pub const fn exponent(self) -> i8 {
+        ((self.0 >> 8) as u8 as i8) >> 3
+    }
+
+    /// Returns the value represented
+    pub fn to_f32(self) -> f32 {
+        f32::from(self.mantissa()) * pow2(i32::from(self.exponent()))
+    }
+}
+
+impl FromByteArray for Linear11 {
+    type Error = Infallible;
+    type Array = [u8; 2];
+
+    fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+        Ok(Self(u16::from_le_bytes(bytes)))
+    }
+}
+
+impl ToByteArray for Linear11 {
+    type Error = Infallible;
+    type Array = [u8; 2];
+
+    fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+        Ok(self.0.to_le_bytes())
+    }
+}
+
+/// A value in the PMBus Linear16 format, as used by the output voltage commands
+///
+/// The 16-bit word is an unsigned mantissa `V`, representing the value `V * 2^N`. The exponent `N`
+/// is not part of the word, and is instead reported by the device in the lower five bits of its
+/// `VOUT_MODE` command as a 5-bit two's complement value. As with all SMBus words, the value is
+/// serialized least significant byte first.
+///
+/// # Example
+/// ```
+/// use regiface::{FromByteArray, Linear16, ToByteArray};
+///
+/// // A VOUT_MODE of 0x14 selects the linear format with an exponent of -12
+/// let vout_mode = 0x14u8;
+/// let exponent = ((vout_mode << 3) as i8) >> 3;
+/// assert_eq!(exponent, -12);
+///
+/// let vout = Linear16::from_bytes([0x00, 0x30]).unwrap();
+/// assert_eq!(vout.to_f32(exponent), Some(3.0));
+///
+/// // Values round-trip through the same exponent, to within its resolution
+/// let vout_command = Linear16::from_f32(1.2, exponent).unwrap();
+/// assert_eq!(vout_command.to_bytes().unwrap(), [0x33, 0x13]);
+/// assert!((vout_command.to_f32(exponent).unwrap() - 1.2).abs() < 1.0 / 4096.0);
+///
+/// // Negative values and exponents outside of 5 bits cannot be represented
+/// assert_eq!(Linear16::from_f32(-1.0, exponent), None);
+/// assert_eq!(vout.to_f32(-128), None);
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub struct Linear16(u16);
+
+impl Linear16 {
+    /// Create a new [`Linear16`] from its raw 16-bit mantissa
+    pub const fn from_raw(raw: u16) -> Self {
+        Self(raw)
+    }
+
+    /// Create a new [`Linear16`] approximating `value` with the given exponent, returning `None` if
+    /// the value is NaN, the exponent does not fit in 5 bits, or the mantissa does not fit in 16
+    /// bits at that exponent
+    pub fn from_f32(value: f32, exponent: i8) -> Option<Self> {
+        if !(-16..=15).contains(&exponent) {
+            return None;
+        }
+        let mantissa = mantissa(value, exponent, 0, i32::from(u16::MAX))?;
+        Some(Self(mantissa as u16))
+    }
+
+    /// Returns the raw 16-bit mantissa
+    pub const fn raw(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the value represented with the given exponent, or `None` if the exponent does not
+    /// fit in 5 bits
+    pub fn to_f32(self, exponent: i8) -> Option<f32> {
+        if !(-16..=15).contains(&exponent) {
+            return None;
+        }
+        Some(f32::from(self.0) * pow2(i32::from(exponent)))
+    }
+}
+
+impl FromByteArray for Linear16 {
+    type Error = Infallible;
+    type Array = [u8; 2];
+
+    fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+        Ok(Self(u16::from_le_bytes(bytes)))
+    }
+}
+
+impl ToByteArray for Linear16 {
+    type Error = Infallible;
+    type Array = [u8; 2];
+
+    fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+        Ok(self.0.to_le_bytes())
+    }
+}
@@ -0,0 +1,410 @@
+//! A shadow register layer for devices with write-only registers
+//!
+//! Many devices, such as LED drivers, DACs, and display controllers, have registers that can be
+//! written but not read back, thus updating a single field of such a register requires the driver
+//! to remember the value it last wrote. A [`CachedInterface`] wraps any `RegisterBus` and keeps the
+//! last value successfully written to each register implementing [`ShadowedRegister`], which may
+//! then be retrieved with [`CachedInterface::cached`] without any bus traffic.
+//!
+//! Shadowed registers are written and read through the `blocking::ShadowedBus` and
+//! `async::ShadowedBus` traits. Reading a shadowed register returns its cached value, only reading
+//! the register from the device if it is not cached. All other operations, including
+//! `RegisterBus::read_register`, are passed through to the wrapped interface, and always access the
+//! bus. A write of a shadowed register that fails leaves the previously cached value in place,
+//! while a write of a register through `RegisterBus::write_register` removes it from the cache, as
+//! its value is not retained.
+//!
+//! The cache holds up to `N` registers of at most [`MAX_SHADOW_LEN`] bytes each. When full, the
+//! register written least recently is evicted to make room for another.
+//!
+//! # Example
+#![cfg_attr(feature = "blocking", doc = "```")]
+#![cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+//! # use embedded_hal::i2c::{ErrorKind, ErrorType, I2c, Operation};
+//! use regiface::{
+//!     cache::{blocking::ShadowedBus, CachedInterface, ShadowedRegister},
+//!     device::I2cInterface,
+//!     register, FromByteArray, ReadableRegister, ToByteArray, WritableRegister,
+//! };
+//!
+//! #[register(0x02u8)]
+//! #[derive(ReadableRegister, WritableRegister, Clone, Copy, Debug, PartialEq)]
+//! struct Brightness(u8);
+//!
+//! impl ToByteArray for Brightness {
+//!     type Array = [u8; 1];
+//!     type Error = core::convert::Infallible;
+//!
+//!     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+//!         Ok([self.0])
+//!     }
+//! }
+//!
+//! impl FromByteArray for Brightness {
+//!     type Array = [u8; 1];
+//!     type Error = core::convert::Infallible;
+//!
+//!     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+//!         Ok(Self(bytes[0]))
+//!     }
+//! }
+//!
+//! impl ShadowedRegister for Brightness {}
+//! # struct Bus { fail: bool }
+//! # impl ErrorType for Bus { type Error = ErrorKind; }
+//! # impl I2c for Bus {
+//! #     fn transaction(&mut self, _: u8, _: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+//! #         if self.fail { Err(ErrorKind::Bus) } else { Ok(()) }
+//! #     }
+//! # }
+//! # let bus = Bus { fail: false };
+//!
+//! let mut device: CachedInterface<_, 4> = CachedInterface::new(I2cInterface::new(bus, 0x60));
+//!
+//! // Nothing has been written yet
+//! assert_eq!(device.cached::<Brightness>(), Ok(None));
+//!
+//! // A successful write is cached, and reading the register returns the cached value
+//! device.write_shadowed(Brightness(0x40)).unwrap();
+//! assert_eq!(device.cached::<Brightness>(), Ok(Some(Brightness(0x40))));
+//! assert_eq!(device.read_shadowed::<Brightness>(), Ok(Brightness(0x40)));
+//!
+//! // A failed write leaves the cached value in place
+//! # device.inner_mut().bus_mut().fail = true;
+//! assert!(device.write_shadowed(Brightness(0x80)).is_err());
+//! assert_eq!(device.cached::<Brightness>(), Ok(Some(Brightness(0x40))));
+//!
+//! // Invalidated registers are no longer cached, and are instead read from the device
+//! device.invalidate(0x02u8);
+//! assert_eq!(device.cached::<Brightness>(), Ok(None));
+//! # device.inner_mut().bus_mut().fail = false;
+//! assert_eq!(device.read_shadowed::<Brightness>(), Ok(Brightness(0x00)));
+//! ```
+
+use crate::{
+    byte_array::ByteArray as _, errors::RegisterId, id::Id, FromByteArray, ToByteArray,
+    WritableRegister,
+};
+#[cfg(any(feature = "blocking", feature = "async"))]
+use crate::{
+    errors::{CommandError, ReadRegisterError, WriteRegisterError},
+    Command, ReadableRegister, Register,
+};
+
+/// The maximum length in bytes of a register held by a [`CachedInterface`]
+pub const MAX_SHADOW_LEN: usize = 16;
+
+/// A marker trait for registers whose last written value is held by a [`CachedInterface`]
+///
+/// The value is cached as serialized by [`ToByteArray`] and restored with [`FromByteArray`], thus
+/// both must use the same array, of at most [`MAX_SHADOW_LEN`] bytes.
+pub trait ShadowedRegister: WritableRegister + FromByteArray + Copy {}
+
+/// Asserts at compile time that a register may be held by a [`CachedInterface`]
+fn check_len<R: ShadowedRegister>() {
+    const {
+        assert!(
+            <R as ToByteArray>::Array::LEN == <R as FromByteArray>::Array::LEN,
+            "shadowed registers must serialize to and from arrays of the same length"
+        );
+        assert!(
+            <R as ToByteArray>::Array::LEN <= MAX_SHADOW_LEN,
+            "shadowed registers must be at most MAX_SHADOW_LEN bytes"
+        );
+    }
+}
+
+/// The last value written to a single register
+#[derive(Debug, Clone, Copy)]
+struct Shadow {
+    id: RegisterId,
+    value: [u8; MAX_SHADOW_LEN],
+}
+
+impl Shadow {
+    /// Create a [`Shadow`] holding the serialized value of a register
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    fn new<R: ShadowedRegister>(bytes: &<R as ToByteArray>::Array) -> Self {
+        check_len::<R>();
+
+        let mut value = [0; MAX_SHADOW_LEN];
+        value[..bytes.as_ref().len()].copy_from_slice(bytes.as_ref());
+        Self {
+            id: RegisterId::new(R::writeable_id()),
+            value,
+        }
+    }
+}
+
+/// A register value already serialized by [`ToByteArray`], allowing it to be written to the
+/// wrapped interface without serializing it a second time
+#[cfg(any(feature = "blocking", feature = "async"))]
+struct Serialized<R: WritableRegister>(<R as ToByteArray>::Array);
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+impl<R: WritableRegister> Register for Serialized<R> {
+    type IdType = R::IdType;
+
+    const RESERVED_MASK: u128 = R::RESERVED_MASK;
+    const REQUIRED_MASK: u128 = R::REQUIRED_MASK;
+
+    #[inline]
+    fn id() -> Self::IdType {
+        R::id()
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+impl<R: WritableRegister> ToByteArray for Serialized<R> {
+    type Error = R::Error;
+    type Array = R::Array;
+
+    fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+        Ok(self.0)
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+impl<R: WritableRegister> WritableRegister for Serialized<R> {
+    #[inline]
+    fn writeable_id() -> Self::IdType {
+        R::writeable_id()
+    }
+}
+
+/// A register interface that caches the last value written to each [`ShadowedRegister`]
+#[derive(Debug)]
+pub struct CachedInterface<I, const N: usize> {
+    inner: I,
+    shadows: [Option<Shadow>; N],
+}
+
+impl<I, const N: usize> CachedInterface<I, N> {
+    /// Create a new [`CachedInterface`] wrapping the given register interface, with an empty cache
+    pub fn new(inner: I) -> Self {
+        Self {
+            inner,
+            shadows: [None; N],
+        }
+    }
+
+    /// Returns the last value successfully written to the register, or `None` if it is not cached
+    ///
+    /// # Errors
+    /// Returns an error if the cached bytes could not be converted into the register value
+    pub fn cached<R: ShadowedRegister>(&self) -> Result<Option<R>, <R as FromByteArray>::Error> {
+        check_len::<R>();
+
+        let id = RegisterId::new(R::writeable_id());
+        let Some(shadow) = self.shadows.iter().flatten().find(|shadow| shadow.id == id) else {
+            return Ok(None);
+        };
+
+        let mut bytes = <R as FromByteArray>::Array::new();
+        let len = bytes.as_ref().len();
+        bytes.as_mut().copy_from_slice(&shadow.value[..len]);
+        R::from_bytes(bytes).map(Some)
+    }
+
+    /// Remove the register with the given ID from the cache
+    pub fn invalidate<T: Id>(&mut self, id: T) {
+        let id = RegisterId::new(id);
+        for slot in &mut self.shadows {
+            if slot.is_some_and(|shadow| shadow.id == id) {
+                *slot = None;
+            }
+        }
+    }
+
+    /// Remove all registers from the cache
+    pub fn invalidate_all(&mut self) {
+        self.shadows = [None; N];
+    }
+
+    /// Returns a mutable reference to the wrapped register interface
+    ///
+    /// Registers written directly through the wrapped interface are not cached.
+    pub fn inner_mut(&mut self) -> &mut I {
+        &mut self.inner
+    }
+
+    /// Consumes the [`CachedInterface`], returning the wrapped register interface
+    pub fn release(self) -> I {
+        self.inner
+    }
+
+    /// Records a [`Shadow`] as the last value written to its register
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    fn store(&mut self, shadow: Shadow) {
+        // The most recently written register is kept last, thus the first is evicted when full
+        let index = self
+            .shadows
+            .iter()
+            .position(|slot| slot.is_some_and(|cached| cached.id == shadow.id))
+            .or_else(|| self.shadows.iter().position(Option::is_none))
+            .unwrap_or(0);
+        if N > 0 {
+            self.shadows[index..].rotate_left(1);
+            self.shadows[N - 1] = Some(shadow);
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub mod r#async {
+    use super::*;
+    use crate::device::r#async::RegisterBus;
+
+    /// A register interface able to write and read [`ShadowedRegister`]s, caching their value
+    #[allow(async_fn_in_trait)]
+    pub trait ShadowedBus: RegisterBus {
+        /// Write a register value to the device, caching the value if the write succeeds
+        async fn write_shadowed<R: ShadowedRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, <R as ToByteArray>::Error>>;
+
+        /// Returns the cached value of a register, reading it from the device if it is not cached
+        ///
+        /// A value read from the device is not cached, as only values written are held.
+        async fn read_shadowed<R: ShadowedRegister + ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, <R as FromByteArray>::Error>>;
+    }
+
+    impl<I: RegisterBus, const N: usize> ShadowedBus for CachedInterface<I, N> {
+        async fn write_shadowed<R: ShadowedRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, <R as ToByteArray>::Error>> {
+            let bytes = register
+                .to_bytes()
+                .map_err(WriteRegisterError::SerializationError)?;
+            let shadow = Shadow::new::<R>(&bytes);
+            self.inner.write_register(Serialized::<R>(bytes)).await?;
+            self.store(shadow);
+            Ok(())
+        }
+
+        async fn read_shadowed<R: ShadowedRegister + ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, <R as FromByteArray>::Error>> {
+            match self.cached::<R>() {
+                Ok(Some(register)) => Ok(register),
+                Ok(None) => self.inner.read_register().await,
+                Err(err) => Err(ReadRegisterError::DeserializationError(err)),
+            }
+        }
+    }
+
+    impl<I: RegisterBus, const N: usize> RegisterBus for CachedInterface<I, N> {
+        type Error = I::Error;
+
+        async fn read_register<R: ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, R::Error>> {
+            self.inner.read_register().await
+        }
+
+        async fn write_register<R: WritableRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, R::Error>> {
+            self.invalidate(R::writeable_id());
+            self.inner.write_register(register).await
+        }
+
+        async fn invoke_command<C: Command>(
+            &mut self,
+            cmd: C,
+        ) -> Result<
+            C::ResponseParameters,
+            CommandError<
+                Self::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        > {
+            self.inner.invoke_command(cmd).await
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use super::*;
+    use crate::device::blocking::RegisterBus;
+
+    /// A register interface able to write and read [`ShadowedRegister`]s, caching their value
+    pub trait ShadowedBus: RegisterBus {
+        /// Write a register value to the device, caching the value if the write succeeds
+        fn write_shadowed<R: ShadowedRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, <R as ToByteArray>::Error>>;
+
+        /// Returns the cached value of a register, reading it from the device if it is not cached
+        ///
+        /// A value read from the device is not cached, as only values written are held.
+        fn read_shadowed<R: ShadowedRegister + ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, <R as FromByteArray>::Error>>;
+    }
+
+    impl<I: RegisterBus, const N: usize> ShadowedBus for CachedInterface<I, N> {
+        fn write_shadowed<R: ShadowedRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, <R as ToByteArray>::Error>> {
+            let bytes = register
+                .to_bytes()
+                .map_err(WriteRegisterError::SerializationError)?;
+            let shadow = Shadow::new::<R>(&bytes);
+            self.inner.write_register(Serialized::<R>(bytes))?;
+            self.store(shadow);
+            Ok(())
+        }
+
+        fn read_shadowed<R: ShadowedRegister + ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, <R as FromByteArray>::Error>> {
+            match self.cached::<R>() {
+                Ok(Some(register)) => Ok(register),
+                Ok(None) => self.inner.read_register(),
+                Err(err) => Err(ReadRegisterError::DeserializationError(err)),
+            }
+        }
+    }
+
+    impl<I: RegisterBus, const N: usize> RegisterBus for CachedInterface<I, N> {
+        type Error = I::Error;
+
+        fn read_register<R: ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, R::Error>> {
+            self.inner.read_register()
+        }
+
+        fn write_register<R: WritableRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, R::Error>> {
+            self.invalidate(R::writeable_id());
+            self.inner.write_register(register)
+        }
+
+        fn invoke_command<C: Command>(
+            &mut self,
+            cmd: C,
+        ) -> Result<
+            C::ResponseParameters,
+            CommandError<
+                Self::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        > {
+            self.inner.invoke_command(cmd)
+        }
+    }
+}
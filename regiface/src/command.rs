@@ -1,4 +1,6 @@
-use crate::{id, FromByteArray, ToByteArray};
+#[cfg(any(feature = "blocking", feature = "async"))]
+use crate::byte_array::ByteArray as _;
+use crate::{errors::ValidationError, id, qspi::LineWidth, FromByteArray, ToByteArray};
 
 /// The core trait to be implemented for all types that represent an invokable command
 ///
@@ -58,9 +60,506 @@ pub trait Command {
     /// If the response has no parameters, the [`NoParameters`](crate::NoParameters) type can be used
     type ResponseParameters: FromByteArray;
 
+    /// Whether the command is expected to respond with data
+    ///
+    /// Declaring a response of [`NoParameters`](crate::NoParameters) for a command that does respond
+    /// results in a zero byte read, which silently succeeds without reading the response. When set,
+    /// invoking a command whose [`ResponseParameters`](Command::ResponseParameters) is read from a
+    /// zero byte array fails to compile. Defaults to `false`.
+    ///
+    /// ```compile_fail
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// use regiface::{i2c, Command, NoParameters};
+    ///
+    /// struct ReadSerialNumber;
+    ///
+    /// impl Command for ReadSerialNumber {
+    ///     type IdType = u8;
+    ///     type CommandParameters = NoParameters;
+    ///     // Mistakenly declared without a response
+    ///     type ResponseParameters = NoParameters;
+    ///
+    ///     const EXPECTS_RESPONSE: bool = true;
+    ///
+    ///     fn id() -> Self::IdType {
+    ///         0x36
+    ///     }
+    ///
+    ///     fn invoking_parameters(self) -> Self::CommandParameters {
+    ///         NoParameters::default()
+    ///     }
+    /// }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     fn transaction(&mut self, _: u8, _: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    ///
+    /// i2c::blocking::invoke_command(&mut Device, 0x20, ReadSerialNumber).unwrap();
+    /// ```
+    const EXPECTS_RESPONSE: bool = false;
+
+    /// The number of microseconds the device requires between receiving the command and its
+    /// response being available to be read
+    ///
+    /// This is only honored by the `invoke_command_delayed` functions, such as
+    /// `i2c::blocking::invoke_command_delayed`, and defaults to zero.
+    const RESPONSE_DELAY_US: u32 = 0;
+
+    /// Whether the device prefixes the command response with a one byte status code
+    ///
+    /// When set, the status byte is read before the [`ResponseParameters`](Command::ResponseParameters).
+    /// A status of zero indicates success, while any nonzero status is returned as a
+    /// [`CommandError::DeviceError`](crate::errors::CommandError::DeviceError) and the remainder of
+    /// the response is discarded. Defaults to `false`.
+    ///
+    #[cfg_attr(feature = "blocking", doc = "```")]
+    #[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// use regiface::{errors::CommandError, i2c, Command, NoParameters};
+    ///
+    /// struct ReadSerial;
+    ///
+    /// impl Command for ReadSerial {
+    ///     type IdType = u8;
+    ///     type CommandParameters = NoParameters;
+    ///     type ResponseParameters = u32;
+    ///
+    ///     const STATUS_BYTE: bool = true;
+    ///
+    ///     fn id() -> Self::IdType {
+    ///         0x0A
+    ///     }
+    ///
+    ///     fn invoking_parameters(self) -> Self::CommandParameters {
+    ///         NoParameters::default()
+    ///     }
+    /// }
+    /// # struct Device { status: u8 }
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         let [Operation::Write([0x0A]), Operation::Read(status), Operation::Read(response)] = ops else {
+    /// #             panic!("unexpected operations");
+    /// #         };
+    /// #         status.copy_from_slice(&[self.status]);
+    /// #         response.copy_from_slice(&[0x12, 0x34, 0x56, 0x78]);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    ///
+    /// // [Write([0x0A]), Read([status]), Read([_, _, _, _])]
+    /// let mut device = Device { status: 0x00 };
+    /// let serial = i2c::blocking::invoke_command(&mut device, 0x48, ReadSerial).unwrap();
+    /// assert_eq!(serial, 0x12345678);
+    ///
+    /// let mut device = Device { status: 0x05 };
+    /// let err = i2c::blocking::invoke_command(&mut device, 0x48, ReadSerial).unwrap_err();
+    /// assert!(matches!(err, CommandError::DeviceError(0x05)));
+    /// ```
+    const STATUS_BYTE: bool = false;
+
+    /// The number of bits the command ID is shifted left by to merge it into the first byte of the
+    /// command parameters, for devices without a separate ID byte
+    ///
+    /// Some minimal devices, such as I/O expanders, carry the command in the upper bits of the
+    /// first byte written, with the lower bits carrying parameters. When set, the ID, which must
+    /// serialize into a single byte, is shifted left by this number of bits and replaces the
+    /// corresponding bits of the first byte of the command parameters, and no separate ID byte is
+    /// written. Commands with [`NoParameters`](crate::NoParameters) write the shifted ID alone.
+    ///
+    /// This is honored by the `invoke_command` functions of the [`i2c`](crate::i2c) and
+    /// [`spi`](crate::spi) modules, and defaults to `None`, writing the ID as its own bytes.
+    ///
+    #[cfg_attr(feature = "blocking", doc = "```")]
+    #[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// use regiface::{i2c, Command, NoParameters};
+    ///
+    /// // The command occupies bits 7..5, and the output value bits 4..0
+    /// struct SetOutputs(u8);
+    ///
+    /// impl Command for SetOutputs {
+    ///     type IdType = u8;
+    ///     type CommandParameters = u8;
+    ///     type ResponseParameters = NoParameters;
+    ///
+    ///     const MERGED_ID_SHIFT: Option<u32> = Some(5);
+    ///
+    ///     fn id() -> Self::IdType {
+    ///         0b101
+    ///     }
+    ///
+    ///     fn invoking_parameters(self) -> Self::CommandParameters {
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// struct Reset;
+    ///
+    /// impl Command for Reset {
+    ///     type IdType = u8;
+    ///     type CommandParameters = NoParameters;
+    ///     type ResponseParameters = NoParameters;
+    ///
+    ///     const MERGED_ID_SHIFT: Option<u32> = Some(5);
+    ///
+    ///     fn id() -> Self::IdType {
+    ///         0b111
+    ///     }
+    ///
+    ///     fn invoking_parameters(self) -> Self::CommandParameters {
+    ///         NoParameters::default()
+    ///     }
+    /// }
+    /// # struct Device<'a>(&'a [u8]);
+    /// # impl ErrorType for Device<'_> { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device<'_> {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         let [Operation::Write(bytes)] = ops else { panic!() };
+    /// #         assert_eq!(*bytes, self.0);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    ///
+    /// // Writes the single byte 0b1011_0011
+    /// # let mut device = Device(&[0b1011_0011]);
+    /// i2c::blocking::invoke_command(&mut device, 0x20, SetOutputs(0b1_0011)).unwrap();
+    ///
+    /// // Writes the single byte 0b1110_0000
+    /// # let mut device = Device(&[0b1110_0000]);
+    /// i2c::blocking::invoke_command(&mut device, 0x20, Reset).unwrap();
+    /// ```
+    const MERGED_ID_SHIFT: Option<u32> = None;
+
     /// A method that returns the ID of the [`Command`]
     fn id() -> Self::IdType;
 
+    /// Bytes written before the command ID, such as a sync word
+    ///
+    /// This is honored by the `invoke_command` functions of the [`i2c`](crate::i2c),
+    /// [`spi`](crate::spi), and [`serial`](crate::serial) modules, and defaults to no bytes.
+    ///
+    #[cfg_attr(feature = "blocking", doc = "```")]
+    #[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+    /// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+    /// use regiface::{spi, Command, NoParameters};
+    ///
+    /// struct Start;
+    ///
+    /// impl Command for Start {
+    ///     type IdType = u8;
+    ///     type CommandParameters = u8;
+    ///     type ResponseParameters = NoParameters;
+    ///
+    ///     fn id() -> Self::IdType {
+    ///         0x08
+    ///     }
+    ///
+    ///     fn invoking_parameters(self) -> Self::CommandParameters {
+    ///         0x01
+    ///     }
+    ///
+    ///     fn preamble() -> &'static [u8] {
+    ///         &[0x55, 0xAA]
+    ///     }
+    ///
+    ///     fn postamble() -> &'static [u8] {
+    ///         &[0x0D]
+    ///     }
+    /// }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         assert!(matches!(
+    /// #             ops,
+    /// #             [
+    /// #                 Operation::Write([0x55, 0xAA]),
+    /// #                 Operation::Write([0x08]),
+    /// #                 Operation::Write([0x01]),
+    /// #                 Operation::Write([0x0D]),
+    /// #             ]
+    /// #         ));
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    ///
+    /// // Transmits [0x55, 0xAA, 0x08, 0x01, 0x0D]
+    /// spi::blocking::invoke_command(&mut device, Start).unwrap();
+    /// ```
+    #[inline]
+    fn preamble() -> &'static [u8] {
+        &[]
+    }
+
+    /// Bytes written between the command ID and the command parameters, such as the sub-address of
+    /// a command that shares an opcode with others
+    ///
+    /// This is honored by the same functions as [`Command::preamble`], and defaults to no bytes.
+    ///
+    #[cfg_attr(feature = "blocking", doc = "```")]
+    #[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation, SevenBitAddress};
+    /// use regiface::{i2c, Command, NoParameters};
+    ///
+    /// struct SetGain(u8);
+    ///
+    /// impl Command for SetGain {
+    ///     type IdType = u8;
+    ///     type CommandParameters = u8;
+    ///     type ResponseParameters = NoParameters;
+    ///
+    ///     fn id() -> Self::IdType {
+    ///         0x20
+    ///     }
+    ///
+    ///     fn sub_id() -> &'static [u8] {
+    ///         &[0x03]
+    ///     }
+    ///
+    ///     fn invoking_parameters(self) -> Self::CommandParameters {
+    ///         self.0
+    ///     }
+    /// }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c for Device {
+    /// #     fn transaction(
+    /// #         &mut self,
+    /// #         _: SevenBitAddress,
+    /// #         ops: &mut [Operation<'_>],
+    /// #     ) -> Result<(), Self::Error> {
+    /// #         assert!(matches!(
+    /// #             ops,
+    /// #             [
+    /// #                 Operation::Write([0x20]),
+    /// #                 Operation::Write([0x03]),
+    /// #                 Operation::Write([0x7F]),
+    /// #             ]
+    /// #         ));
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    ///
+    /// // Transmits [0x20, 0x03, 0x7F]
+    /// i2c::blocking::invoke_command(&mut device, 0x48, SetGain(0x7F)).unwrap();
+    /// ```
+    #[inline]
+    fn sub_id() -> &'static [u8] {
+        &[]
+    }
+
+    /// Bytes written after the command parameters, such as a stop byte
+    ///
+    /// This is honored by the same functions as [`Command::preamble`], and defaults to no bytes.
+    #[inline]
+    fn postamble() -> &'static [u8] {
+        &[]
+    }
+
     /// A method to retrieve the parameters from an instance of the [`Command`]
     fn invoking_parameters(self) -> Self::CommandParameters;
+
+    /// Checks the raw bytes of a command response before they are deserialized
+    ///
+    /// This allows device specific validity conditions, such as a fixed marker nibble or a parity
+    /// bit, to be checked by the `invoke_command` functions before
+    /// [`FromByteArray::from_bytes`] is called. A rejected response is reported as
+    /// [`CommandError::ValidationError`](crate::errors::CommandError::ValidationError). The status
+    /// byte, if [`Command::STATUS_BYTE`] is set, is checked first and is not included in `bytes`.
+    /// Defaults to accepting every response.
+    ///
+    #[cfg_attr(feature = "blocking", doc = "```")]
+    #[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// use regiface::{errors::{CommandError, ValidationError}, i2c, Command, NoParameters};
+    ///
+    /// struct ReadTemperature;
+    ///
+    /// impl Command for ReadTemperature {
+    ///     type IdType = u8;
+    ///     type CommandParameters = NoParameters;
+    ///     type ResponseParameters = u16;
+    ///
+    ///     fn id() -> Self::IdType {
+    ///         0xE3
+    ///     }
+    ///
+    ///     fn invoking_parameters(self) -> Self::CommandParameters {
+    ///         NoParameters::default()
+    ///     }
+    ///
+    ///     // The upper nibble of a valid response is always 0xA
+    ///     fn validate_response(bytes: &[u8]) -> Result<(), ValidationError> {
+    ///         match bytes {
+    ///             [first, _] if first >> 4 == 0xA => Ok(()),
+    ///             _ => Err(ValidationError),
+    ///         }
+    ///     }
+    /// }
+    /// # struct Device(&'static [u8]);
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         let [_, Operation::Read(buf)] = ops else { panic!() };
+    /// #         buf.copy_from_slice(self.0);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    ///
+    /// let mut device = Device(&[0xA1, 0x23]);
+    /// assert_eq!(i2c::blocking::invoke_command(&mut device, 0x40, ReadTemperature), Ok(0xA123));
+    ///
+    /// let mut device = Device(&[0xFF, 0xFF]);
+    /// assert_eq!(
+    ///     i2c::blocking::invoke_command(&mut device, 0x40, ReadTemperature),
+    ///     Err(CommandError::ValidationError(ValidationError))
+    /// );
+    /// ```
+    #[inline]
+    fn validate_response(bytes: &[u8]) -> Result<(), ValidationError> {
+        let _ = bytes;
+        Ok(())
+    }
+
+    /// The number of data lines used to send the command ID on multi-line buses
+    ///
+    /// This is only used by the [`qspi`](crate::qspi) module and defaults to a single line.
+    #[inline]
+    fn id_width() -> LineWidth {
+        LineWidth::Single
+    }
+
+    /// The number of data lines used to send the command parameters on multi-line buses
+    ///
+    /// This is only used by the [`qspi`](crate::qspi) module and defaults to a single line.
+    #[inline]
+    fn parameters_width() -> LineWidth {
+        LineWidth::Single
+    }
+
+    /// The number of data lines used to read the command response on multi-line buses
+    ///
+    /// This is only used by the [`qspi`](crate::qspi) module and defaults to a single line.
+    #[inline]
+    fn response_width() -> LineWidth {
+        LineWidth::Single
+    }
+}
+
+/// Asserts at compile time that a command expecting a response reads at least one byte
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) fn check_response<C: Command>() {
+    const {
+        assert!(
+            !C::EXPECTS_RESPONSE || <C::ResponseParameters as FromByteArray>::Array::LEN > 0,
+            "the command expects a response, but its ResponseParameters are zero bytes long"
+        );
+    }
+}
+
+/// Merges the command ID into the first byte of the command parameters if the command sets
+/// [`Command::MERGED_ID_SHIFT`], returning the ID and parameter bytes to be written
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) fn merge_id<'a, C: Command>(
+    id: &'a mut [u8],
+    parameters: &'a mut [u8],
+) -> (&'a [u8], &'a [u8]) {
+    let Some(shift) = C::MERGED_ID_SHIFT else {
+        return (id, parameters);
+    };
+
+    const {
+        assert!(
+            <C::IdType as id::Id>::Array::LEN == 1,
+            "merged command IDs must serialize into a single byte"
+        );
+    }
+    assert!(shift < 8, "MERGED_ID_SHIFT must be less than 8");
+
+    let merged = id[0] << shift;
+    let mask = u8::MAX << shift;
+
+    match parameters.first_mut() {
+        Some(first) => {
+            *first = (*first & !mask) | merged;
+            (&[], parameters)
+        }
+        None => {
+            id[0] = merged;
+            (id, parameters)
+        }
+    }
+}
+
+/// A trait to be implemented for commands whose ID is computed from the instance being invoked
+///
+/// On many devices the command ID encodes part of the command's payload, such as a channel number
+/// (`0x30 | channel`) or a page index. Rather than defining a distinct [`Command`] type per
+/// possible ID, a [`DynamicCommand`] computes its ID from the instance. Dynamic commands are
+/// invoked using the `invoke_dynamic_command` functions, such as
+/// `i2c::blocking::invoke_dynamic_command`.
+///
+/// # Migrating from [`Command`]
+///
+/// Existing [`Command`] implementations continue to work unchanged. To migrate a family of
+/// commands that differ only by ID into a single type:
+/// 1. Implement [`DynamicCommand`] rather than [`Command`], with the same associated types
+/// 2. Replace `fn id() -> Self::IdType` with `fn id(&self) -> Self::IdType`, computing the ID
+///    from the instance
+/// 3. Replace calls to `invoke_command` with `invoke_dynamic_command`
+///
+/// # Example
+///
+/// ```rust
+/// use regiface::{DynamicCommand, NoParameters};
+///
+/// struct SetChannel {
+///     channel: u8,
+///     value: u16,
+/// }
+///
+/// impl DynamicCommand for SetChannel {
+///     type IdType = u8;
+///     type CommandParameters = u16;
+///     type ResponseParameters = NoParameters;
+///
+///     fn id(&self) -> Self::IdType {
+///         0x30 | (self.channel & 0x0F)
+///     }
+///
+///     fn invoking_parameters(self) -> Self::CommandParameters {
+///         self.value
+///     }
+/// }
+///
+/// assert_eq!(SetChannel { channel: 2, value: 0x0FFF }.id(), 0x32);
+/// ```
+pub trait DynamicCommand {
+    /// The type used to represent the command's ID.
+    ///
+    /// Command ID types are any type that implement the [`Id`](id::Id) trait. This
+    /// trait provides default implementations for [`u8`], [`u16`], [`u32`], [`u64`], and [`u128`].
+    type IdType: id::Id;
+
+    /// The parameters included as part of the command invocation
+    ///
+    /// If the command has no parameters, the [`NoParameters`](crate::NoParameters) type can be used
+    type CommandParameters: ToByteArray;
+
+    /// The parameters expected as the response to the command
+    ///
+    /// If the response has no parameters, the [`NoParameters`](crate::NoParameters) type can be used
+    type ResponseParameters: FromByteArray;
+
+    /// A method that returns the ID of this instance of the [`DynamicCommand`]
+    fn id(&self) -> Self::IdType;
+
+    /// A method to retrieve the parameters from an instance of the [`DynamicCommand`]
+    fn invoking_parameters(self) -> Self::CommandParameters;
 }
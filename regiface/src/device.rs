@@ -0,0 +1,936 @@
+//! A wrapper bundling a bus handle with the information needed to address a device on that bus
+//!
+//! When multiple devices share a single bus, such as through the
+//! [`embedded-hal-bus`](https://crates.io/crates/embedded-hal-bus) crate, driver code otherwise has
+//! to thread both the bus handle and the device address through every register operation. A
+//! [`Device`] instead carries both, and implements the `blocking::RegisterBus` and
+//! `async::RegisterBus` traits so drivers can hold a single handle.
+//!
+//! For I2C, the [`Device`] holds the address of the target device. For SPI, the chip select is
+//! owned by the [`SpiDevice`](embedded_hal::spi::SpiDevice) implementation, thus no address is
+//! needed and the [`NoAddress`] marker is used. Alternatively, a SPI [`Device`] may hold the
+//! [`SpiAddressing`] convention of the device, which is then applied to every register access,
+//! as with the `_with` functions of the [`spi`] module.
+//!
+//! Both owned buses and shared buses work, as any type implementing the `embedded-hal` bus traits
+//! may be used.
+//!
+//! Drivers for devices offering both I2C and SPI interfaces may be written once against the
+//! `RegisterBus` traits, and constructed from either an [`I2cInterface`] or a [`SpiInterface`]:
+//!
+#![cfg_attr(feature = "blocking", doc = "```")]
+#![cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+//! # use embedded_hal::{i2c::{self, I2c}, spi::{self, SpiDevice}};
+//! use regiface::{
+//!     device::{blocking::RegisterBus, I2cInterface, SpiInterface},
+//!     errors::DeviceError,
+//!     register, FromByteArray, ReadableRegister,
+//! };
+//!
+//! #[register(0x0Fu8)]
+//! #[derive(ReadableRegister)]
+//! struct WhoAmI(u8);
+//!
+//! impl FromByteArray for WhoAmI {
+//!     type Array = [u8; 1];
+//!     type Error = core::convert::Infallible;
+//!
+//!     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+//!         Ok(Self(bytes[0]))
+//!     }
+//! }
+//!
+//! struct Accelerometer<B>(B);
+//!
+//! impl<B: RegisterBus> Accelerometer<B> {
+//!     fn who_am_i(&mut self) -> Result<u8, DeviceError<B::Error>> {
+//!         Ok(self.0.read_register::<WhoAmI>()?.0)
+//!     }
+//! }
+//! # struct Bus;
+//! # impl i2c::ErrorType for Bus { type Error = core::convert::Infallible; }
+//! # impl I2c for Bus {
+//! #     fn transaction(&mut self, _: u8, ops: &mut [i2c::Operation<'_>]) -> Result<(), Self::Error> {
+//! #         if let Some(i2c::Operation::Read(buf)) = ops.last_mut() { buf[0] = 0x33; }
+//! #         Ok(())
+//! #     }
+//! # }
+//! # impl spi::ErrorType for Bus { type Error = core::convert::Infallible; }
+//! # impl SpiDevice for Bus {
+//! #     fn transaction(&mut self, ops: &mut [spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+//! #         if let Some(spi::Operation::Read(buf)) = ops.last_mut() { buf[0] = 0x33; }
+//! #         Ok(())
+//! #     }
+//! # }
+//!
+//! let mut over_i2c = Accelerometer(I2cInterface::new(Bus, 0x19));
+//! let mut over_spi = Accelerometer(SpiInterface::new_unaddressed(Bus));
+//!
+//! assert_eq!(over_i2c.who_am_i(), Ok(0x33));
+//! assert_eq!(over_spi.who_am_i(), Ok(0x33));
+//! ```
+//!
+//! # Example
+#![cfg_attr(feature = "blocking", doc = "```")]
+#![cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+//! use core::cell::RefCell;
+//! use embedded_hal::i2c::I2c;
+//! use embedded_hal_bus::i2c::RefCellDevice;
+//! use regiface::{device::{blocking::RegisterBus, Device}, register, FromByteArray, ReadableRegister};
+//!
+//! #[register(0x0Fu8)]
+//! #[derive(ReadableRegister)]
+//! struct WhoAmI(u8);
+//!
+//! impl FromByteArray for WhoAmI {
+//!     type Array = [u8; 1];
+//!     type Error = core::convert::Infallible;
+//!
+//!     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+//!         Ok(Self(bytes[0]))
+//!     }
+//! }
+//!
+//! fn identify<B: RegisterBus>(device: &mut B) -> Option<u8> {
+//!     device.read_register::<WhoAmI>().ok().map(|id| id.0)
+//! }
+//!
+//! fn identify_all<I: I2c>(bus: I) {
+//!     let bus = RefCell::new(bus);
+//!     let mut accel = Device::new(RefCellDevice::new(&bus), 0x19);
+//!     let mut magnetometer = Device::new(RefCellDevice::new(&bus), 0x1E);
+//!
+//!     identify(&mut accel);
+//!     identify(&mut magnetometer);
+//! }
+//! ```
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+use core::marker::PhantomData;
+
+#[cfg(any(feature = "blocking", feature = "async", doc))]
+use crate::{
+    errors::{
+        CommandError, DeviceError, ReadRegisterError, ReadResult, WriteRegisterError, WriteResult,
+    },
+    i2c,
+    spi::{self, SpiAddressing},
+    Command, FromByteArray, ReadableRegister, Register, ToByteArray, WritableRegister,
+};
+
+/// A marker used as the address of a [`Device`] on buses that do not require an address, such as SPI
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Default)]
+pub struct NoAddress;
+
+/// A bus handle bundled with the address of the device on that bus
+#[derive(Debug)]
+pub struct Device<B, A = NoAddress> {
+    bus: B,
+    address: A,
+}
+
+/// A [`Device`] on an I2C bus, addressed with the address mode `A`
+pub type I2cInterface<B, A = u8> = Device<B, A>;
+
+/// A [`Device`] on a SPI bus, whose chip select is owned by the
+/// [`SpiDevice`](embedded_hal::spi::SpiDevice) implementation
+///
+/// Register IDs are transmitted unmodified, unless a [`SpiAddressing`] is provided in place of
+/// [`NoAddress`], in which case it is applied to every register read and write.
+///
+#[cfg_attr(feature = "blocking", doc = "```")]
+#[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+/// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+/// use regiface::{
+///     device::{blocking::RegisterBus, SpiInterface},
+///     register,
+///     spi::SpiAddressing,
+///     FromByteArray, ReadableRegister,
+/// };
+///
+/// #[register(0x0Fu8)]
+/// #[derive(ReadableRegister)]
+/// struct WhoAmI(u8);
+///
+/// impl FromByteArray for WhoAmI {
+///     type Array = [u8; 1];
+///     type Error = core::convert::Infallible;
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         Ok(Self(bytes[0]))
+///     }
+/// }
+/// # struct Bus;
+/// # impl ErrorType for Bus { type Error = core::convert::Infallible; }
+/// # impl SpiDevice for Bus {
+/// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+/// #         let [Operation::Write(id), Operation::Read(buf)] = ops else { panic!() };
+/// #         buf[0] = id[0];
+/// #         Ok(())
+/// #     }
+/// # }
+///
+/// // The read flag is set in the register ID of every read
+/// let mut device = SpiInterface::new(Bus, SpiAddressing::read_flag(0x80));
+/// assert_eq!(device.read_register::<WhoAmI>().unwrap().0, 0x8F);
+/// ```
+pub type SpiInterface<B, A = NoAddress> = Device<B, A>;
+
+impl<B, A> Device<B, A> {
+    /// Create a new [`Device`] from a bus handle and the address of the device on that bus
+    pub fn new(bus: B, address: A) -> Self {
+        Self { bus, address }
+    }
+
+    /// Returns the address of the device
+    pub fn address(&self) -> &A {
+        &self.address
+    }
+
+    /// Returns a mutable reference to the underlying bus handle
+    pub fn bus_mut(&mut self) -> &mut B {
+        &mut self.bus
+    }
+
+    /// Consumes the [`Device`], returning the underlying bus handle
+    pub fn release(self) -> B {
+        self.bus
+    }
+}
+
+impl<B> Device<B, NoAddress> {
+    /// Create a new [`Device`] from a bus handle that does not require an address, such as a
+    /// [`SpiDevice`](embedded_hal::spi::SpiDevice)
+    pub fn new_unaddressed(bus: B) -> Self {
+        Self::new(bus, NoAddress)
+    }
+}
+
+/// A device on an I2C bus reached through a channel of an I2C multiplexer, such as a TCA9548A
+///
+/// Before every register operation, the channel select byte is written to the multiplexer, thus
+/// several devices sharing an address may be placed behind different channels of a multiplexer and
+/// driven through the same `RegisterBus` traits as a plain [`Device`]. When
+/// [`MuxedI2cDevice::with_deselect`] is set, all channels are deselected again after every
+/// operation.
+///
+/// Selecting the channel and performing the operation are separate bus transactions. As described
+/// in the [`i2c`](crate::i2c#uninterrupted-sequences) module, drivers sharing a bus must hold
+/// exclusive access to it across both, such as by giving each [`MuxedI2cDevice`] a
+/// [`RefCellDevice`](https://docs.rs/embedded-hal-bus/latest/embedded_hal_bus/i2c/struct.RefCellDevice.html)
+/// and never performing operations concurrently, or by holding the lock of a mutex over the bus.
+///
+#[cfg_attr(feature = "blocking", doc = "```")]
+#[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+/// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+/// use regiface::{
+///     device::{blocking::RegisterBus, MuxedI2cDevice},
+///     register, FromByteArray, ReadableRegister,
+/// };
+///
+/// #[register(0x0Fu8)]
+/// #[derive(ReadableRegister)]
+/// struct WhoAmI(u8);
+///
+/// impl FromByteArray for WhoAmI {
+///     type Array = [u8; 1];
+///     type Error = core::convert::Infallible;
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         Ok(Self(bytes[0]))
+///     }
+/// }
+/// # #[register(0x20u8)]
+/// # #[derive(regiface::WritableRegister)]
+/// # struct Ctrl(u8);
+/// # impl regiface::ToByteArray for Ctrl {
+/// #     type Array = [u8; 1];
+/// #     type Error = core::convert::Infallible;
+/// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.0]) }
+/// # }
+/// # struct Reset;
+/// # impl regiface::Command for Reset {
+/// #     type IdType = u8;
+/// #     type CommandParameters = regiface::NoParameters;
+/// #     type ResponseParameters = regiface::NoParameters;
+/// #     fn id() -> Self::IdType { 0x7E }
+/// #     fn invoking_parameters(self) -> Self::CommandParameters { regiface::NoParameters::default() }
+/// # }
+/// # #[derive(Default)]
+/// # struct Bus(Vec<(u8, u8)>);
+/// # impl ErrorType for Bus { type Error = core::convert::Infallible; }
+/// # impl I2c for Bus {
+/// #     fn transaction(&mut self, addr: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+/// #         let Operation::Write(bytes) = &ops[0] else { unreachable!() };
+/// #         self.0.push((addr, bytes[0]));
+/// #         if let Some(Operation::Read(buf)) = ops.last_mut() { buf[0] = 0x33; }
+/// #         Ok(())
+/// #     }
+/// # }
+///
+/// // A sensor at address 0x48 behind channel 2 of the multiplexer at address 0x70
+/// let mut sensor = MuxedI2cDevice::new(Bus::default(), 0x70, 1 << 2, 0x48).with_deselect();
+/// assert_eq!(sensor.read_register::<WhoAmI>().unwrap().0, 0x33);
+///
+/// // The channel is selected before the register is read, and deselected afterwards
+/// assert_eq!(sensor.bus_mut().0, [(0x70, 0x04), (0x48, 0x0F), (0x70, 0x00)]);
+/// # sensor.bus_mut().0.clear();
+/// # sensor.write_register(Ctrl(0x01)).unwrap();
+/// # sensor.invoke_command(Reset).unwrap();
+/// # assert_eq!(
+/// #     sensor.bus_mut().0,
+/// #     [(0x70, 0x04), (0x48, 0x20), (0x70, 0x00), (0x70, 0x04), (0x48, 0x7E), (0x70, 0x00)]
+/// # );
+/// ```
+#[derive(Debug)]
+pub struct MuxedI2cDevice<B, A = u8> {
+    bus: B,
+    // The multiplexer is only accessed by the blocking and async submodules
+    #[cfg_attr(not(any(feature = "blocking", feature = "async")), allow(dead_code))]
+    mux_address: A,
+    channels: u8,
+    address: A,
+    #[cfg_attr(not(any(feature = "blocking", feature = "async")), allow(dead_code))]
+    deselect: bool,
+}
+
+impl<B, A> MuxedI2cDevice<B, A> {
+    /// Create a new [`MuxedI2cDevice`] from a bus handle, the address of the multiplexer, the
+    /// bitmask of multiplexer channels to select, and the address of the device on those channels
+    pub fn new(bus: B, mux_address: A, channels: u8, address: A) -> Self {
+        Self {
+            bus,
+            mux_address,
+            channels,
+            address,
+            deselect: false,
+        }
+    }
+
+    /// Returns the device with all channels of the multiplexer deselected after every operation
+    ///
+    /// This prevents the device from responding to operations addressed to other devices, such as
+    /// devices sharing its address behind other multiplexer channels.
+    pub fn with_deselect(self) -> Self {
+        Self {
+            deselect: true,
+            ..self
+        }
+    }
+
+    /// Returns the address of the device
+    pub fn address(&self) -> &A {
+        &self.address
+    }
+
+    /// Returns the bitmask of multiplexer channels selected for the device
+    pub fn channels(&self) -> u8 {
+        self.channels
+    }
+
+    /// Returns a mutable reference to the underlying bus handle
+    pub fn bus_mut(&mut self) -> &mut B {
+        &mut self.bus
+    }
+
+    /// Consumes the [`MuxedI2cDevice`], returning the underlying bus handle
+    pub fn release(self) -> B {
+        self.bus
+    }
+}
+
+#[cfg(feature = "async")]
+pub mod r#async {
+    use super::*;
+    use crate::transaction::{r#async::RunSteps, Transaction};
+
+    /// A handle capable of performing register operations on a single device
+    #[allow(async_fn_in_trait)]
+    pub trait RegisterBus {
+        /// The type of error that may occur while communicating over the bus
+        type Error;
+
+        /// Read a register value from the device
+        async fn read_register<R: ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, R::Error>>;
+
+        /// Write a register value to the device
+        async fn write_register<R: WritableRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, R::Error>>;
+
+        /// Invoke a command on the device and receive its response
+        #[allow(clippy::type_complexity)]
+        async fn invoke_command<C: Command>(
+            &mut self,
+            cmd: C,
+        ) -> Result<
+            C::ResponseParameters,
+            CommandError<
+                Self::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        >;
+
+        /// Perform each step of a [`Transaction`] in order, returning the result of each step
+        ///
+        /// See the [`transaction`](crate::transaction) module for details.
+        async fn execute<S: RunSteps>(
+            &mut self,
+            transaction: Transaction<S>,
+        ) -> S::Output<Self::Error>
+        where
+            Self: Sized,
+        {
+            transaction.steps.run(self).await
+        }
+
+        /// Returns a [`RegisterHandle`] performing repeated operations on the register `R`
+        ///
+        /// The handle exclusively borrows the interface, and the register type is named only once
+        /// rather than on every access.
+        fn register<R: Register>(&mut self) -> RegisterHandle<'_, Self, R>
+        where
+            Self: Sized,
+        {
+            RegisterHandle {
+                interface: self,
+                register: PhantomData,
+            }
+        }
+    }
+
+    /// A handle to the register `R` of a device, obtained with [`RegisterBus::register`]
+    #[derive(Debug)]
+    pub struct RegisterHandle<'a, I, R> {
+        interface: &'a mut I,
+        register: PhantomData<R>,
+    }
+
+    impl<I: RegisterBus, R> RegisterHandle<'_, I, R> {
+        /// Read the value of the register from the device
+        pub async fn read(&mut self) -> ReadResult<R, I::Error>
+        where
+            R: ReadableRegister,
+        {
+            self.interface.read_register().await
+        }
+
+        /// Write a value of the register to the device
+        pub async fn write(&mut self, value: R) -> WriteResult<R, I::Error>
+        where
+            R: WritableRegister,
+        {
+            self.interface.write_register(value).await
+        }
+
+        /// Read the value of the register, pass it to `f`, and write the returned value back to
+        /// the device
+        ///
+        /// The read and write are performed as separate bus transactions.
+        pub async fn modify<F>(&mut self, f: F) -> Result<(), DeviceError<I::Error>>
+        where
+            R: ReadableRegister + WritableRegister,
+            F: FnOnce(R) -> R,
+        {
+            let value = self.read().await?;
+            self.write(f(value)).await?;
+            Ok(())
+        }
+    }
+
+    impl<B, A> RegisterBus for Device<B, A>
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy,
+        B: embedded_hal_async::i2c::I2c<A>,
+    {
+        type Error = B::Error;
+
+        async fn read_register<R: ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, R::Error>> {
+            i2c::r#async::read_register(&mut self.bus, self.address).await
+        }
+
+        async fn write_register<R: WritableRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, R::Error>> {
+            i2c::r#async::write_register(&mut self.bus, self.address, register).await
+        }
+
+        async fn invoke_command<C: Command>(
+            &mut self,
+            cmd: C,
+        ) -> Result<
+            C::ResponseParameters,
+            CommandError<
+                Self::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        > {
+            i2c::r#async::invoke_command(&mut self.bus, self.address, cmd).await
+        }
+    }
+
+    impl<B> RegisterBus for Device<B, NoAddress>
+    where
+        B: embedded_hal_async::spi::SpiDevice,
+    {
+        type Error = B::Error;
+
+        async fn read_register<R: ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, R::Error>> {
+            spi::r#async::read_register(&mut self.bus).await
+        }
+
+        async fn write_register<R: WritableRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, R::Error>> {
+            spi::r#async::write_register(&mut self.bus, register).await
+        }
+
+        async fn invoke_command<C: Command>(
+            &mut self,
+            cmd: C,
+        ) -> Result<
+            C::ResponseParameters,
+            CommandError<
+                Self::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        > {
+            spi::r#async::invoke_command(&mut self.bus, cmd).await
+        }
+    }
+
+    impl<B> RegisterBus for Device<B, SpiAddressing>
+    where
+        B: embedded_hal_async::spi::SpiDevice,
+    {
+        type Error = B::Error;
+
+        async fn read_register<R: ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, R::Error>> {
+            spi::r#async::read_register_with(&mut self.bus, self.address).await
+        }
+
+        async fn write_register<R: WritableRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, R::Error>> {
+            spi::r#async::write_register_with(&mut self.bus, self.address, register).await
+        }
+
+        async fn invoke_command<C: Command>(
+            &mut self,
+            cmd: C,
+        ) -> Result<
+            C::ResponseParameters,
+            CommandError<
+                Self::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        > {
+            spi::r#async::invoke_command(&mut self.bus, cmd).await
+        }
+    }
+
+    /// Writes the channel select byte of a [`MuxedI2cDevice`] to its multiplexer
+    async fn select<B, A>(device: &mut MuxedI2cDevice<B, A>, channels: u8) -> Result<(), B::Error>
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy,
+        B: embedded_hal_async::i2c::I2c<A>,
+    {
+        device.bus.write(device.mux_address, &[channels]).await
+    }
+
+    impl<B, A> RegisterBus for MuxedI2cDevice<B, A>
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy,
+        B: embedded_hal_async::i2c::I2c<A>,
+    {
+        type Error = B::Error;
+
+        async fn read_register<R: ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, R::Error>> {
+            select(self, self.channels)
+                .await
+                .map_err(ReadRegisterError::BusError)?;
+            let result = i2c::r#async::read_register(&mut self.bus, self.address).await;
+            if self.deselect {
+                let deselected = select(self, 0).await.map_err(ReadRegisterError::BusError);
+                return result.and_then(|value| deselected.map(|()| value));
+            }
+            result
+        }
+
+        async fn write_register<R: WritableRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, R::Error>> {
+            select(self, self.channels)
+                .await
+                .map_err(WriteRegisterError::BusError)?;
+            let result = i2c::r#async::write_register(&mut self.bus, self.address, register).await;
+            if self.deselect {
+                let deselected = select(self, 0).await.map_err(WriteRegisterError::BusError);
+                return result.and_then(|value| deselected.map(|()| value));
+            }
+            result
+        }
+
+        async fn invoke_command<C: Command>(
+            &mut self,
+            cmd: C,
+        ) -> Result<
+            C::ResponseParameters,
+            CommandError<
+                Self::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        > {
+            select(self, self.channels)
+                .await
+                .map_err(CommandError::BusError)?;
+            let result = i2c::r#async::invoke_command(&mut self.bus, self.address, cmd).await;
+            if self.deselect {
+                let deselected = select(self, 0).await.map_err(CommandError::BusError);
+                return result.and_then(|value| deselected.map(|()| value));
+            }
+            result
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use super::*;
+    use crate::transaction::{blocking::RunSteps, Transaction};
+
+    /// A handle capable of performing register operations on a single device
+    pub trait RegisterBus {
+        /// The type of error that may occur while communicating over the bus
+        type Error;
+
+        /// Read a register value from the device
+        fn read_register<R: ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, R::Error>>;
+
+        /// Write a register value to the device
+        fn write_register<R: WritableRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, R::Error>>;
+
+        /// Invoke a command on the device and receive its response
+        #[allow(clippy::type_complexity)]
+        fn invoke_command<C: Command>(
+            &mut self,
+            cmd: C,
+        ) -> Result<
+            C::ResponseParameters,
+            CommandError<
+                Self::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        >;
+
+        /// Perform each step of a [`Transaction`] in order, returning the result of each step
+        ///
+        /// See the [`transaction`](crate::transaction) module for details.
+        fn execute<S: RunSteps>(&mut self, transaction: Transaction<S>) -> S::Output<Self::Error>
+        where
+            Self: Sized,
+        {
+            transaction.steps.run(self)
+        }
+
+        /// Returns a [`RegisterHandle`] performing repeated operations on the register `R`
+        ///
+        /// The handle exclusively borrows the interface, and the register type is named only once
+        /// rather than on every access.
+        ///
+        /// # Example
+        /// ```
+        /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+        /// use regiface::{
+        ///     device::{blocking::RegisterBus, I2cInterface},
+        ///     register, FromByteArray, ReadableRegister, ToByteArray, WritableRegister,
+        /// };
+        ///
+        /// #[register(0x39u8)]
+        /// #[derive(ReadableRegister, WritableRegister, Debug, PartialEq)]
+        /// struct FifoCtrl(u8);
+        ///
+        /// impl FromByteArray for FifoCtrl {
+        ///     type Array = [u8; 1];
+        ///     type Error = core::convert::Infallible;
+        ///
+        ///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+        ///         Ok(Self(bytes[0]))
+        ///     }
+        /// }
+        ///
+        /// impl ToByteArray for FifoCtrl {
+        ///     type Array = [u8; 1];
+        ///     type Error = core::convert::Infallible;
+        ///
+        ///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+        ///         Ok([self.0])
+        ///     }
+        /// }
+        /// # #[derive(Default)]
+        /// # struct Bus { ids: Vec<u8>, value: u8 }
+        /// # impl ErrorType for Bus { type Error = core::convert::Infallible; }
+        /// # impl I2c for Bus {
+        /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+        /// #         let Operation::Write(id) = &ops[0] else { unreachable!() };
+        /// #         self.ids.push(id[0]);
+        /// #         match &mut ops[1] {
+        /// #             Operation::Read(buf) => buf[0] = self.value,
+        /// #             Operation::Write(value) => self.value = value[0],
+        /// #         }
+        /// #         Ok(())
+        /// #     }
+        /// # }
+        ///
+        /// let mut device = I2cInterface::new(Bus::default(), 0x6A);
+        /// let mut fifo = device.register::<FifoCtrl>();
+        ///
+        /// fifo.write(FifoCtrl(0x10)).unwrap();
+        /// fifo.modify(|ctrl| FifoCtrl(ctrl.0 | 0x01)).unwrap();
+        /// assert_eq!(fifo.read().unwrap(), FifoCtrl(0x11));
+        ///
+        /// // Every access is made to the ID of the register
+        /// assert_eq!(device.bus_mut().ids, [0x39; 4]);
+        /// # assert_eq!(device.bus_mut().value, 0x11);
+        /// ```
+        fn register<R: Register>(&mut self) -> RegisterHandle<'_, Self, R>
+        where
+            Self: Sized,
+        {
+            RegisterHandle {
+                interface: self,
+                register: PhantomData,
+            }
+        }
+    }
+
+    /// A handle to the register `R` of a device, obtained with [`RegisterBus::register`]
+    #[derive(Debug)]
+    pub struct RegisterHandle<'a, I, R> {
+        interface: &'a mut I,
+        register: PhantomData<R>,
+    }
+
+    impl<I: RegisterBus, R> RegisterHandle<'_, I, R> {
+        /// Read the value of the register from the device
+        pub fn read(&mut self) -> ReadResult<R, I::Error>
+        where
+            R: ReadableRegister,
+        {
+            self.interface.read_register()
+        }
+
+        /// Write a value of the register to the device
+        pub fn write(&mut self, value: R) -> WriteResult<R, I::Error>
+        where
+            R: WritableRegister,
+        {
+            self.interface.write_register(value)
+        }
+
+        /// Read the value of the register, pass it to `f`, and write the returned value back to
+        /// the device
+        ///
+        /// The read and write are performed as separate bus transactions.
+        pub fn modify<F>(&mut self, f: F) -> Result<(), DeviceError<I::Error>>
+        where
+            R: ReadableRegister + WritableRegister,
+            F: FnOnce(R) -> R,
+        {
+            let value = self.read()?;
+            self.write(f(value))?;
+            Ok(())
+        }
+    }
+
+    impl<B, A> RegisterBus for Device<B, A>
+    where
+        A: embedded_hal::i2c::AddressMode + Copy,
+        B: embedded_hal::i2c::I2c<A>,
+    {
+        type Error = B::Error;
+
+        fn read_register<R: ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, R::Error>> {
+            i2c::blocking::read_register(&mut self.bus, self.address)
+        }
+
+        fn write_register<R: WritableRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, R::Error>> {
+            i2c::blocking::write_register(&mut self.bus, self.address, register)
+        }
+
+        fn invoke_command<C: Command>(
+            &mut self,
+            cmd: C,
+        ) -> Result<
+            C::ResponseParameters,
+            CommandError<
+                Self::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        > {
+            i2c::blocking::invoke_command(&mut self.bus, self.address, cmd)
+        }
+    }
+
+    impl<B> RegisterBus for Device<B, NoAddress>
+    where
+        B: embedded_hal::spi::SpiDevice,
+    {
+        type Error = B::Error;
+
+        fn read_register<R: ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, R::Error>> {
+            spi::blocking::read_register(&mut self.bus)
+        }
+
+        fn write_register<R: WritableRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, R::Error>> {
+            spi::blocking::write_register(&mut self.bus, register)
+        }
+
+        fn invoke_command<C: Command>(
+            &mut self,
+            cmd: C,
+        ) -> Result<
+            C::ResponseParameters,
+            CommandError<
+                Self::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        > {
+            spi::blocking::invoke_command(&mut self.bus, cmd)
+        }
+    }
+
+    impl<B> RegisterBus for Device<B, SpiAddressing>
+    where
+        B: embedded_hal::spi::SpiDevice,
+    {
+        type Error = B::Error;
+
+        fn read_register<R: ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, R::Error>> {
+            spi::blocking::read_register_with(&mut self.bus, self.address)
+        }
+
+        fn write_register<R: WritableRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, R::Error>> {
+            spi::blocking::write_register_with(&mut self.bus, self.address, register)
+        }
+
+        fn invoke_command<C: Command>(
+            &mut self,
+            cmd: C,
+        ) -> Result<
+            C::ResponseParameters,
+            CommandError<
+                Self::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        > {
+            spi::blocking::invoke_command(&mut self.bus, cmd)
+        }
+    }
+
+    /// Writes the channel select byte of a [`MuxedI2cDevice`] to its multiplexer
+    fn select<B, A>(device: &mut MuxedI2cDevice<B, A>, channels: u8) -> Result<(), B::Error>
+    where
+        A: embedded_hal::i2c::AddressMode + Copy,
+        B: embedded_hal::i2c::I2c<A>,
+    {
+        device.bus.write(device.mux_address, &[channels])
+    }
+
+    impl<B, A> RegisterBus for MuxedI2cDevice<B, A>
+    where
+        A: embedded_hal::i2c::AddressMode + Copy,
+        B: embedded_hal::i2c::I2c<A>,
+    {
+        type Error = B::Error;
+
+        fn read_register<R: ReadableRegister>(
+            &mut self,
+        ) -> Result<R, ReadRegisterError<Self::Error, R::Error>> {
+            select(self, self.channels).map_err(ReadRegisterError::BusError)?;
+            let result = i2c::blocking::read_register(&mut self.bus, self.address);
+            if self.deselect {
+                let deselected = select(self, 0).map_err(ReadRegisterError::BusError);
+                return result.and_then(|value| deselected.map(|()| value));
+            }
+            result
+        }
+
+        fn write_register<R: WritableRegister>(
+            &mut self,
+            register: R,
+        ) -> Result<(), WriteRegisterError<Self::Error, R::Error>> {
+            select(self, self.channels).map_err(WriteRegisterError::BusError)?;
+            let result = i2c::blocking::write_register(&mut self.bus, self.address, register);
+            if self.deselect {
+                let deselected = select(self, 0).map_err(WriteRegisterError::BusError);
+                return result.and_then(|value| deselected.map(|()| value));
+            }
+            result
+        }
+
+        fn invoke_command<C: Command>(
+            &mut self,
+            cmd: C,
+        ) -> Result<
+            C::ResponseParameters,
+            CommandError<
+                Self::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        > {
+            select(self, self.channels).map_err(CommandError::BusError)?;
+            let result = i2c::blocking::invoke_command(&mut self.bus, self.address, cmd);
+            if self.deselect {
+                let deselected = select(self, 0).map_err(CommandError::BusError);
+                return result.and_then(|value| deselected.map(|()| value));
+            }
+            result
+        }
+    }
+}
@@ -5,12 +5,53 @@
 //! 2. A generic [`Error`] type that can represent any error case but forgoes the specific error details
 //!
 //! This approach allows for detailed error handling when needed while also providing a simpler,
-//! unified error type when the specific details aren't required.
+//! unified error type when the specific details aren't required. Either may be annotated with the
+//! ID of the register or command that failed using [`WithId`].
+//!
+//! Between the two, [`DeviceError`] retains the bus error while discarding the serialization
+//! errors, and the [`ReadResult`], [`WriteResult`], and [`CommandResult`] aliases name the result
+//! of an operation on a given register or command without spelling out its error types.
+//!
+//! All error types implement [`Display`](core::fmt::Display), describing the phase of the
+//! operation that failed, and [`core::error::Error`] when their generic parameters do. The inner
+//! error, if any, is returned by [`source`](core::error::Error::source) rather than being included
+//! in the displayed message, allowing the chain of errors to be reported by tools such as `anyhow`.
+//!
+//! ```
+//! use core::error::Error as _;
+//! use regiface::errors::ReadRegisterError;
+//!
+//! #[derive(Debug)]
+//! struct Nack;
+//!
+//! impl core::fmt::Display for Nack {
+//!     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+//!         f.write_str("device did not acknowledge")
+//!     }
+//! }
+//!
+//! impl core::error::Error for Nack {}
+//!
+//! let err: ReadRegisterError<Nack, core::convert::Infallible> = ReadRegisterError::BusError(Nack);
+//! assert_eq!(err.to_string(), "bus error while reading register");
+//! assert_eq!(
+//!     err.source().unwrap().to_string(),
+//!     "device did not acknowledge"
+//! );
+//! ```
+
+use core::fmt;
+
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+use crate::{byte_array::ByteArray as _, id::Id, Command, FromByteArray, ToByteArray};
 
 /// Error that can occur when reading from a register.
 ///
 /// Generic over the bus error type `B` and deserialization error type `D`.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[non_exhaustive]
 pub enum ReadRegisterError<B, D> {
     /// An error occurred while communicating over the bus
     BusError(B),
@@ -21,7 +62,9 @@ pub enum ReadRegisterError<B, D> {
 /// Error that can occur when writing to a register.
 ///
 /// Generic over the bus error type `B` and serialization error type `S`.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[non_exhaustive]
 pub enum WriteRegisterError<B, S> {
     /// An error occurred while communicating over the bus
     BusError(B),
@@ -33,7 +76,9 @@ pub enum WriteRegisterError<B, S> {
 ///
 /// Generic over the bus error type `B`, serialization error type `S`,
 /// and deserialization error type `D`.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[non_exhaustive]
 pub enum CommandError<B, S, D> {
     /// An error occurred while communicating over the bus
     BusError(B),
@@ -41,6 +86,567 @@ pub enum CommandError<B, S, D> {
     SerializationError(S),
     /// An error occurred while deserializing the command response
     DeserializationError(D),
+    /// The device responded with a nonzero status byte, indicating the command failed
+    ///
+    /// This is only returned for commands that set [`Command::STATUS_BYTE`]
+    DeviceError(u8),
+    /// The command response was rejected by [`Command::validate_response`]
+    ValidationError(ValidationError),
+}
+
+/// The result of reading the register `R` over a bus with error type `B`
+pub type ReadResult<R, B> = Result<R, ReadRegisterError<B, <R as FromByteArray>::Error>>;
+
+/// The result of writing the register `R` over a bus with error type `B`
+pub type WriteResult<R, B> = Result<(), WriteRegisterError<B, <R as ToByteArray>::Error>>;
+
+/// The result of invoking the command `C` over a bus with error type `B`
+pub type CommandResult<C, B> = Result<
+    <C as Command>::ResponseParameters,
+    CommandError<
+        B,
+        <<C as Command>::CommandParameters as ToByteArray>::Error,
+        <<C as Command>::ResponseParameters as FromByteArray>::Error,
+    >,
+>;
+
+/// Error that can occur during any register read, register write, or command, retaining the bus
+/// error but discarding the serialization and deserialization errors.
+///
+/// Serialization errors are most often [`Infallible`](core::convert::Infallible) or carry no
+/// information, while the bus error is usually of interest to the caller. This makes
+/// `DeviceError<B>` a convenient error type for the public API of a driver, which may use `?` to
+/// convert each of [`ReadRegisterError`], [`WriteRegisterError`], and [`CommandError`] into it.
+///
+/// ```
+/// use regiface::errors::{DeviceError, ReadRegisterError, WriteRegisterError};
+///
+/// fn configure() -> Result<u8, DeviceError<&'static str>> {
+///     let id: Result<u8, ReadRegisterError<&'static str, ()>> = Ok(0x42);
+///     let written: Result<(), WriteRegisterError<&'static str, ()>> = Err(WriteRegisterError::BusError("nack"));
+///
+///     let id = id?;
+///     written?;
+///     Ok(id)
+/// }
+///
+/// assert_eq!(configure(), Err(DeviceError::BusError("nack")));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum DeviceError<B> {
+    /// An error occurred while communicating over the bus
+    BusError(B),
+    /// An error occurred while serializing a register value or command parameters
+    SerializationError,
+    /// An error occurred while deserializing a register value or command response
+    DeserializationError,
+    /// The device responded to a command with a nonzero status byte
+    CommandFailed(u8),
+}
+
+impl<B, D> From<ReadRegisterError<B, D>> for DeviceError<B> {
+    fn from(value: ReadRegisterError<B, D>) -> Self {
+        match value {
+            ReadRegisterError::BusError(e) => Self::BusError(e),
+            ReadRegisterError::DeserializationError(_) => Self::DeserializationError,
+        }
+    }
+}
+
+impl<B, S> From<WriteRegisterError<B, S>> for DeviceError<B> {
+    fn from(value: WriteRegisterError<B, S>) -> Self {
+        match value {
+            WriteRegisterError::BusError(e) => Self::BusError(e),
+            WriteRegisterError::SerializationError(_) => Self::SerializationError,
+        }
+    }
+}
+
+impl<B, S, D> From<CommandError<B, S, D>> for DeviceError<B> {
+    fn from(value: CommandError<B, S, D>) -> Self {
+        match value {
+            CommandError::BusError(e) => Self::BusError(e),
+            CommandError::SerializationError(_) => Self::SerializationError,
+            CommandError::DeserializationError(_) => Self::DeserializationError,
+            CommandError::DeviceError(status) => Self::CommandFailed(status),
+            CommandError::ValidationError(_) => Self::DeserializationError,
+        }
+    }
+}
+
+/// Error returned by [`Command::validate_response`] when a command response fails a device specific
+/// validity check
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct ValidationError;
+
+/// Error returned when deserializing an enum whose discriminant does not match any of its variants
+///
+/// This is the deserialization error of enums deriving [`FromByteArray`], holding the value that
+/// was read.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct InvalidVariant<T = u8>(pub T);
+
+/// Error returned when deserializing an array of values in which one of the elements could not be
+/// deserialized
+///
+/// Generic over the deserialization error type `E` of the elements.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct ElementError<E> {
+    /// The index of the element that could not be deserialized
+    pub index: usize,
+    /// The error that occurred while deserializing the element
+    pub error: E,
+}
+
+/// Error that can occur when writing to a register and verifying the value by reading it back.
+///
+/// Generic over the bus error type `B`, serialization error type `S`, and the byte array type `A`
+/// the register is serialized into.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum WriteVerifyError<B, S, A> {
+    /// An error occurred while communicating over the bus
+    BusError(B),
+    /// An error occurred while serializing the data to be sent
+    SerializationError(S),
+    /// The bytes read back from the register did not match the bytes written to it
+    VerificationFailed {
+        /// The bytes that were written to the register
+        written: A,
+        /// The bytes that were read back from the register
+        read_back: A,
+    },
+}
+
+/// Error that can occur when writing to a register and verifying the value by reading it back
+/// and comparing the deserialized values.
+///
+/// Generic over the bus error type `B`, serialization error type `S`, deserialization error
+/// type `D`, and the register type `R`.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum VerifyError<B, S, D, R> {
+    /// An error occurred while communicating over the bus
+    BusError(B),
+    /// An error occurred while serializing the data to be sent
+    SerializationError(S),
+    /// An error occurred while deserializing the received data
+    DeserializationError(D),
+    /// The register value read back did not match the value written
+    Mismatch {
+        /// The register value that was written
+        wrote: R,
+        /// The register value that was read back
+        read: R,
+    },
+}
+
+impl<B, S, D, R> From<WriteRegisterError<B, S>> for VerifyError<B, S, D, R> {
+    fn from(value: WriteRegisterError<B, S>) -> Self {
+        match value {
+            WriteRegisterError::BusError(e) => Self::BusError(e),
+            WriteRegisterError::SerializationError(e) => Self::SerializationError(e),
+        }
+    }
+}
+
+impl<B, S, D, R> From<ReadRegisterError<B, D>> for VerifyError<B, S, D, R> {
+    fn from(value: ReadRegisterError<B, D>) -> Self {
+        match value {
+            ReadRegisterError::BusError(e) => Self::BusError(e),
+            ReadRegisterError::DeserializationError(e) => Self::DeserializationError(e),
+        }
+    }
+}
+
+/// Error that can occur when polling a register until its value satisfies a condition.
+///
+/// Generic over the bus error type `B`, deserialization error type `D`, and the register type `R`.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum PollError<B, D, R> {
+    /// An error occurred while communicating over the bus
+    BusError(B),
+    /// An error occurred while deserializing the received data
+    DeserializationError(D),
+    /// The register value did not satisfy the condition within the allowed number of attempts
+    Timeout {
+        /// The last register value read, if any attempts were made
+        last: Option<R>,
+    },
+}
+
+impl<B, D, R> From<ReadRegisterError<B, D>> for PollError<B, D, R> {
+    fn from(value: ReadRegisterError<B, D>) -> Self {
+        match value {
+            ReadRegisterError::BusError(e) => Self::BusError(e),
+            ReadRegisterError::DeserializationError(e) => Self::DeserializationError(e),
+        }
+    }
+}
+
+/// Error that can occur when reading a [`WhoAmI`](crate::WhoAmI) register to verify the identity
+/// of a device.
+///
+/// Generic over the bus error type `B`, deserialization error type `D`, and the register type `R`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum WhoAmIError<B, D, R> {
+    /// An error occurred while communicating over the bus
+    BusError(B),
+    /// An error occurred while deserializing the received data
+    DeserializationError(D),
+    /// The register did not hold the expected value
+    Mismatch {
+        /// The value the register was expected to hold
+        expected: R,
+        /// The value read from the register
+        found: R,
+    },
+}
+
+impl<B, D, R> From<ReadRegisterError<B, D>> for WhoAmIError<B, D, R> {
+    fn from(value: ReadRegisterError<B, D>) -> Self {
+        match value {
+            ReadRegisterError::BusError(e) => Self::BusError(e),
+            ReadRegisterError::DeserializationError(e) => Self::DeserializationError(e),
+        }
+    }
+}
+
+/// Error that can occur when reading or writing samples of a FIFO data register, such as with
+/// `i2c::blocking::read_fifo` and `i2c::blocking::write_fifo`.
+///
+/// Generic over the bus error type `B`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum FifoError<B> {
+    /// The buffer can not hold the requested number of samples, and nothing was transferred
+    BufferTooSmall {
+        /// The number of bytes needed to hold the requested samples
+        required: usize,
+        /// The length of the buffer
+        available: usize,
+    },
+    /// The maximum transaction size of a write can not hold the register ID and a single sample,
+    /// and nothing was written
+    TransactionTooSmall {
+        /// The number of bytes of the register ID and a single sample
+        required: usize,
+        /// The maximum transaction size
+        maximum: usize,
+    },
+    /// An error occurred while communicating over the bus
+    ///
+    /// The first `samples` samples of the buffer were transferred successfully before the error.
+    /// When reading, this is only ever nonzero with [`FifoMode::PerSample`](crate::FifoMode),
+    /// while writes commit every sample of each completed transaction, allowing the remaining
+    /// samples to be written afterwards.
+    BusError {
+        /// The bus error
+        error: B,
+        /// The number of samples transferred before the error
+        samples: usize,
+    },
+}
+
+/// Error that can occur when reading a register once a device signals an interrupt, such as with
+/// `i2c::async::read_register_on_interrupt`.
+///
+/// Generic over the pin error type `P`, bus error type `B`, and deserialization error type `D`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum InterruptReadError<P, B, D> {
+    /// An error occurred while waiting on the interrupt pin
+    PinError(P),
+    /// An error occurred while communicating over the bus
+    BusError(B),
+    /// An error occurred while deserializing the received data
+    DeserializationError(D),
+}
+
+impl<P, B, D> From<ReadRegisterError<B, D>> for InterruptReadError<P, B, D> {
+    fn from(value: ReadRegisterError<B, D>) -> Self {
+        match value {
+            ReadRegisterError::BusError(e) => Self::BusError(e),
+            ReadRegisterError::DeserializationError(e) => Self::DeserializationError(e),
+        }
+    }
+}
+
+/// Error that can occur when awaiting an operation with `with_timeout`.
+///
+/// Generic over the error type `E` of the wrapped operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum TimeoutError<E> {
+    /// The operation completed with an error
+    Error(E),
+    /// The operation did not complete in time and was abandoned
+    ///
+    /// The operation may have been abandoned in the middle of a bus transaction, thus the state of
+    /// the device and bus is unknown.
+    Elapsed,
+}
+
+/// Error returned when an operation retried according to a [`Policy`](crate::retry::Policy) did
+/// not succeed.
+///
+/// Generic over the error type `E` of the retried operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct RetryError<E> {
+    /// The error of the last attempt
+    pub error: E,
+    /// The number of attempts made, including the last
+    pub attempts: u32,
+}
+
+/// The serialized ID of the register or command an operation was performed on
+///
+/// IDs of up to 16 bytes, the length of a [`u128`], are held in full. Longer IDs are truncated to
+/// their first 16 bytes.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub struct RegisterId {
+    bytes: [u8; 16],
+    len: u8,
+}
+
+impl RegisterId {
+    /// Serializes the given ID
+    pub fn new<I: Id>(id: I) -> Self {
+        let id = id.to_id_bytes();
+        let len = id.as_ref().len().min(16);
+
+        let mut bytes = [0; 16];
+        bytes[..len].copy_from_slice(&id.as_ref()[..len]);
+        Self {
+            bytes,
+            len: len as u8,
+        }
+    }
+
+    /// Returns the serialized ID
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..usize::from(self.len)]
+    }
+}
+
+impl fmt::Debug for RegisterId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RegisterId")
+            .field(&format_args!("{:02X?}", self.as_bytes()))
+            .finish()
+    }
+}
+
+impl fmt::Display for RegisterId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("0x")?;
+        for byte in self.as_bytes() {
+            write!(f, "{byte:02X}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt-03")]
+impl defmt::Format for RegisterId {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=[u8]:02X}", self.as_bytes())
+    }
+}
+
+/// An error annotated with the ID of the register or command the failed operation was performed on
+///
+/// When a driver performs many register operations, such as while initializing a device, a bare
+/// [`ReadRegisterError::BusError`] gives no indication of which operation failed. Wrapping the
+/// error of each operation in a [`WithId`] preserves that context as the error is propagated,
+/// without changing the error types returned by the bus modules.
+///
+/// # Example
+#[cfg_attr(feature = "blocking", doc = "```")]
+#[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+/// # use embedded_hal::i2c::{ErrorKind, ErrorType, I2c, Operation};
+/// use regiface::{
+///     errors::{Error, ReadRegisterError, RegisterId, WithId},
+///     i2c, register, FromByteArray, ReadableRegister, Register,
+/// };
+///
+/// #[register(0x0Fu8)]
+/// #[derive(ReadableRegister, Debug)]
+/// struct WhoAmI(u8);
+///
+/// impl FromByteArray for WhoAmI {
+///     type Array = [u8; 1];
+///     type Error = core::convert::Infallible;
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         Ok(Self(bytes[0]))
+///     }
+/// }
+/// # struct Device;
+/// # impl ErrorType for Device { type Error = ErrorKind; }
+/// # impl I2c for Device {
+/// #     fn transaction(&mut self, _: u8, _: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+/// #         Err(ErrorKind::Other)
+/// #     }
+/// # }
+/// # let mut device = Device;
+///
+/// let err = i2c::blocking::read_register::<_, _, WhoAmI>(&mut device, 0x19)
+///     .map_err(|e| WithId::new(WhoAmI::readable_id(), e))
+///     .unwrap_err();
+///
+/// assert_eq!(err.id.as_bytes(), [0x0F]);
+/// assert!(matches!(err.error, ReadRegisterError::BusError(_)));
+/// assert_eq!(err.to_string(), "operation on ID 0x0F failed");
+///
+/// // The simplified error type retains the ID
+/// let err = err.simplify();
+/// assert!(matches!(err.error, Error::BusError(_)));
+/// assert_eq!(err.id, RegisterId::new(0x0Fu8));
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct WithId<E> {
+    /// The ID of the register or command the operation was performed on
+    pub id: RegisterId,
+    /// The error that occurred
+    pub error: E,
+}
+
+impl<E> WithId<E> {
+    /// Annotates an error with the given register or command ID
+    pub fn new<I: Id>(id: I, error: E) -> Self {
+        Self {
+            id: RegisterId::new(id),
+            error,
+        }
+    }
+
+    /// Converts the error into the simplified [`Error`] type, retaining the ID
+    pub fn simplify(self) -> WithId<Error>
+    where
+        E: Into<Error>,
+    {
+        WithId {
+            id: self.id,
+            error: self.error.into(),
+        }
+    }
+}
+
+/// The kind of a bus error, unifying the I2C and SPI error kinds of `embedded-hal`
+///
+/// Drivers may use the kind to decide how to recover from a bus error, such as treating
+/// [`BusErrorKind::NoAcknowledge`] as an absent device while resetting the bus on
+/// [`BusErrorKind::ArbitrationLoss`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum BusErrorKind {
+    /// A misplaced start or stop condition was detected on an I2C bus
+    Bus,
+    /// Arbitration was lost on an I2C bus to another controller
+    ArbitrationLoss,
+    /// The address or data sent on an I2C bus was not acknowledged
+    NoAcknowledge,
+    /// The peripheral received data faster than it could be processed
+    Overrun,
+    /// A SPI peripheral detected a mode fault
+    ModeFault,
+    /// A SPI frame was received with an unexpected format
+    FrameFormat,
+    /// An error occurred while asserting or deasserting a SPI chip select
+    ChipSelectFault,
+    /// The bus error is of another kind, or its kind is unknown
+    #[default]
+    Other,
+}
+
+impl From<embedded_hal::i2c::ErrorKind> for BusErrorKind {
+    fn from(value: embedded_hal::i2c::ErrorKind) -> Self {
+        match value {
+            embedded_hal::i2c::ErrorKind::Bus => Self::Bus,
+            embedded_hal::i2c::ErrorKind::ArbitrationLoss => Self::ArbitrationLoss,
+            embedded_hal::i2c::ErrorKind::NoAcknowledge(_) => Self::NoAcknowledge,
+            embedded_hal::i2c::ErrorKind::Overrun => Self::Overrun,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl From<embedded_hal::spi::ErrorKind> for BusErrorKind {
+    fn from(value: embedded_hal::spi::ErrorKind) -> Self {
+        match value {
+            embedded_hal::spi::ErrorKind::Overrun => Self::Overrun,
+            embedded_hal::spi::ErrorKind::ModeFault => Self::ModeFault,
+            embedded_hal::spi::ErrorKind::FrameFormat => Self::FrameFormat,
+            embedded_hal::spi::ErrorKind::ChipSelectFault => Self::ChipSelectFault,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl fmt::Display for BusErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Bus => "bus fault",
+            Self::ArbitrationLoss => "arbitration lost",
+            Self::NoAcknowledge => "not acknowledged",
+            Self::Overrun => "overrun",
+            Self::ModeFault => "mode fault",
+            Self::FrameFormat => "frame format error",
+            Self::ChipSelectFault => "chip select fault",
+            Self::Other => "other",
+        })
+    }
+}
+
+impl BusErrorKind {
+    /// Returns whether an operation failing with this kind of bus error may succeed if retried
+    ///
+    /// Mode faults, chip select faults, and frame format errors indicate a misconfigured bus, and
+    /// are not considered transient. All other kinds, including a device not acknowledging while
+    /// it is busy, are.
+    pub fn is_transient(self) -> bool {
+        !matches!(
+            self,
+            Self::ModeFault | Self::ChipSelectFault | Self::FrameFormat
+        )
+    }
+}
+
+/// A classification of errors by whether the failed operation may succeed if retried
+///
+/// This is implemented by the error types of this module, allowing retry logic, such as
+/// [`retry`](crate::retry), to be written once rather than matching on each error type.
+///
+/// Bus errors are considered transient, while serialization, deserialization, and verification
+/// errors are not, as retrying would fail in the same way. The kind of a bus error is not known
+/// to the generic error types, thus errors may first be converted with a bus specific method,
+/// such as [`ReadRegisterError::simplify_i2c`], to refine the classification by
+/// [`BusErrorKind::is_transient`].
+///
+/// ```
+/// use embedded_hal::spi::ErrorKind;
+/// use regiface::errors::{ErrorClass, ReadRegisterError};
+///
+/// let err: ReadRegisterError<ErrorKind, ()> = ReadRegisterError::BusError(ErrorKind::ModeFault);
+/// assert!(err.is_transient());
+/// assert!(!err.simplify_spi().is_transient());
+///
+/// let err: ReadRegisterError<ErrorKind, ()> = ReadRegisterError::DeserializationError(());
+/// assert!(!err.is_transient());
+/// ```
+pub trait ErrorClass {
+    /// Returns whether the failed operation may succeed if retried
+    fn is_transient(&self) -> bool;
 }
 
 /// A simplified error type that represents any error that can occur during register operations.
@@ -48,40 +654,938 @@ pub enum CommandError<B, S, D> {
 /// This type intentionally discards the specific error details in favor of a simpler,
 /// unified error type. Use the specific error types ([`ReadRegisterError`], [`WriteRegisterError`],
 /// [`CommandError`]) when you need access to the underlying error information.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 pub enum Error {
     /// An error occurred while communicating over the bus
-    BusError,
+    ///
+    /// The kind of bus error is only known when the error was converted with a bus specific
+    /// method, such as [`ReadRegisterError::simplify_i2c`], and is otherwise
+    /// [`BusErrorKind::Other`].
+    BusError(BusErrorKind),
     /// An error occurred during data serialization
     SerializationError,
     /// An error occurred during data deserialization
     DeserializationError,
+    /// The value read back from a register did not match the value written to it
+    VerificationError,
+    /// An operation did not complete within the allowed number of attempts or time
+    Timeout,
+    /// The device reported that a command failed
+    DeviceError,
 }
 
-impl<B, D> From<ReadRegisterError<B, D>> for Error {
-    fn from(value: ReadRegisterError<B, D>) -> Self {
+/// Error that can occur during any register read, register write, or command, retaining the
+/// original error as a boxed trait object.
+///
+/// Where [`Error`] discards the bus, serialization, and deserialization errors, a [`DynError`]
+/// keeps the original error, and thus its message and source chain, alongside its simplified
+/// [`kind`](DynError::kind). Any error of this crate convertible into an [`Error`] whose inner
+/// errors implement [`core::error::Error`] may be converted into a [`DynError`] with `?`, making it
+/// a convenient single error type for host tooling.
+///
+/// The message of a [`DynError`] and its source are those of the original error.
+///
+/// This type is only available when the `std` feature is enabled.
+///
+/// ```
+/// use regiface::errors::{DynError, Error, ReadRegisterError, WriteRegisterError};
+/// # #[derive(Debug)]
+/// # struct Nack;
+/// # impl core::fmt::Display for Nack {
+/// #     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result { f.write_str("address not acknowledged") }
+/// # }
+/// # impl core::error::Error for Nack {}
+///
+/// fn configure() -> Result<(), DynError> {
+///     let err: WriteRegisterError<Nack, core::convert::Infallible> = WriteRegisterError::BusError(Nack);
+///     Err(err)?
+/// }
+///
+/// let err = configure().unwrap_err();
+/// assert!(matches!(err.kind, Error::BusError(_)));
+/// assert_eq!(err.to_string(), "bus error while writing register");
+/// assert_eq!(
+///     core::error::Error::source(&err).unwrap().to_string(),
+///     "address not acknowledged"
+/// );
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct DynError {
+    /// The simplified kind of the error
+    pub kind: Error,
+    /// The original error
+    pub error: std::boxed::Box<dyn core::error::Error + Send + Sync>,
+}
+
+#[cfg(feature = "std")]
+impl<E> From<E> for DynError
+where
+    E: core::error::Error + Send + Sync + 'static,
+    for<'a> Error: From<&'a E>,
+{
+    fn from(value: E) -> Self {
+        Self {
+            kind: <Error as From<&E>>::from(&value),
+            error: std::boxed::Box::new(value),
+        }
+    }
+}
+
+impl<B, D> From<&ReadRegisterError<B, D>> for Error {
+    fn from(value: &ReadRegisterError<B, D>) -> Self {
         match value {
-            ReadRegisterError::BusError(_) => Self::BusError,
+            ReadRegisterError::BusError(_) => Self::BusError(BusErrorKind::Other),
             ReadRegisterError::DeserializationError(_) => Self::DeserializationError,
         }
     }
 }
 
-impl<B, S> From<WriteRegisterError<B, S>> for Error {
-    fn from(value: WriteRegisterError<B, S>) -> Self {
+impl<B, D> From<ReadRegisterError<B, D>> for Error {
+    fn from(value: ReadRegisterError<B, D>) -> Self {
+        <Self as From<&ReadRegisterError<B, D>>>::from(&value)
+    }
+}
+
+impl<B, S> From<&WriteRegisterError<B, S>> for Error {
+    fn from(value: &WriteRegisterError<B, S>) -> Self {
         match value {
-            WriteRegisterError::BusError(_) => Self::BusError,
+            WriteRegisterError::BusError(_) => Self::BusError(BusErrorKind::Other),
             WriteRegisterError::SerializationError(_) => Self::SerializationError,
         }
     }
 }
 
-impl<B, D, S> From<CommandError<B, D, S>> for Error {
-    fn from(value: CommandError<B, D, S>) -> Self {
+impl<B, S> From<WriteRegisterError<B, S>> for Error {
+    fn from(value: WriteRegisterError<B, S>) -> Self {
+        <Self as From<&WriteRegisterError<B, S>>>::from(&value)
+    }
+}
+
+/// Converts a [`CommandError`] into the simplified [`Error`] type, discarding the inner error
+///
+/// ```
+/// use regiface::errors::{CommandError, Error};
+///
+/// let err: CommandError<(), (), ()> = CommandError::SerializationError(());
+/// assert!(matches!(Error::from(err), Error::SerializationError));
+///
+/// let err: CommandError<(), (), ()> = CommandError::DeserializationError(());
+/// assert!(matches!(Error::from(err), Error::DeserializationError));
+///
+/// let err: CommandError<(), (), ()> = CommandError::DeviceError(0x05);
+/// assert!(matches!(Error::from(err), Error::DeviceError));
+/// ```
+impl<B, S, D> From<&CommandError<B, S, D>> for Error {
+    fn from(value: &CommandError<B, S, D>) -> Self {
         match value {
-            CommandError::BusError(_) => Self::BusError,
+            CommandError::BusError(_) => Self::BusError(BusErrorKind::Other),
             CommandError::DeserializationError(_) => Self::DeserializationError,
             CommandError::SerializationError(_) => Self::SerializationError,
+            CommandError::DeviceError(_) => Self::DeviceError,
+            CommandError::ValidationError(_) => Self::DeserializationError,
+        }
+    }
+}
+
+impl<B, S, D> From<CommandError<B, S, D>> for Error {
+    fn from(value: CommandError<B, S, D>) -> Self {
+        <Self as From<&CommandError<B, S, D>>>::from(&value)
+    }
+}
+
+impl<B> From<&DeviceError<B>> for Error {
+    fn from(value: &DeviceError<B>) -> Self {
+        match value {
+            DeviceError::BusError(_) => Self::BusError(BusErrorKind::Other),
+            DeviceError::SerializationError => Self::SerializationError,
+            DeviceError::DeserializationError => Self::DeserializationError,
+            DeviceError::CommandFailed(_) => Self::DeviceError,
+        }
+    }
+}
+
+impl<B> From<DeviceError<B>> for Error {
+    fn from(value: DeviceError<B>) -> Self {
+        <Self as From<&DeviceError<B>>>::from(&value)
+    }
+}
+
+impl<B, S, A> From<&WriteVerifyError<B, S, A>> for Error {
+    fn from(value: &WriteVerifyError<B, S, A>) -> Self {
+        match value {
+            WriteVerifyError::BusError(_) => Self::BusError(BusErrorKind::Other),
+            WriteVerifyError::SerializationError(_) => Self::SerializationError,
+            WriteVerifyError::VerificationFailed { .. } => Self::VerificationError,
+        }
+    }
+}
+
+impl<B, S, A> From<WriteVerifyError<B, S, A>> for Error {
+    fn from(value: WriteVerifyError<B, S, A>) -> Self {
+        <Self as From<&WriteVerifyError<B, S, A>>>::from(&value)
+    }
+}
+
+impl<B, S, D, R> From<&VerifyError<B, S, D, R>> for Error {
+    fn from(value: &VerifyError<B, S, D, R>) -> Self {
+        match value {
+            VerifyError::BusError(_) => Self::BusError(BusErrorKind::Other),
+            VerifyError::SerializationError(_) => Self::SerializationError,
+            VerifyError::DeserializationError(_) => Self::DeserializationError,
+            VerifyError::Mismatch { .. } => Self::VerificationError,
         }
     }
 }
+
+impl<B, S, D, R> From<VerifyError<B, S, D, R>> for Error {
+    fn from(value: VerifyError<B, S, D, R>) -> Self {
+        <Self as From<&VerifyError<B, S, D, R>>>::from(&value)
+    }
+}
+
+impl<B, D, R> From<&PollError<B, D, R>> for Error {
+    fn from(value: &PollError<B, D, R>) -> Self {
+        match value {
+            PollError::BusError(_) => Self::BusError(BusErrorKind::Other),
+            PollError::DeserializationError(_) => Self::DeserializationError,
+            PollError::Timeout { .. } => Self::Timeout,
+        }
+    }
+}
+
+impl<B, D, R> From<PollError<B, D, R>> for Error {
+    fn from(value: PollError<B, D, R>) -> Self {
+        <Self as From<&PollError<B, D, R>>>::from(&value)
+    }
+}
+
+impl<B, D, R> From<&WhoAmIError<B, D, R>> for Error {
+    fn from(value: &WhoAmIError<B, D, R>) -> Self {
+        match value {
+            WhoAmIError::BusError(_) => Self::BusError(BusErrorKind::Other),
+            WhoAmIError::DeserializationError(_) => Self::DeserializationError,
+            WhoAmIError::Mismatch { .. } => Self::VerificationError,
+        }
+    }
+}
+
+impl<B, D, R> From<WhoAmIError<B, D, R>> for Error {
+    fn from(value: WhoAmIError<B, D, R>) -> Self {
+        <Self as From<&WhoAmIError<B, D, R>>>::from(&value)
+    }
+}
+
+impl<E: Into<Error>> From<RetryError<E>> for Error {
+    fn from(value: RetryError<E>) -> Self {
+        value.error.into()
+    }
+}
+
+impl<E: Into<Error>> From<TimeoutError<E>> for Error {
+    fn from(value: TimeoutError<E>) -> Self {
+        match value {
+            TimeoutError::Error(e) => e.into(),
+            TimeoutError::Elapsed => Self::Timeout,
+        }
+    }
+}
+
+impl<B, D> ReadRegisterError<B, D> {
+    /// Returns the kind of the I2C bus error, or `None` if the error did not occur on the bus
+    ///
+    /// ```
+    /// use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+    /// use regiface::errors::{BusErrorKind, Error, ReadRegisterError};
+    ///
+    /// let absent: ReadRegisterError<ErrorKind, ()> =
+    ///     ReadRegisterError::BusError(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+    /// assert!(matches!(absent.i2c_kind(), Some(ErrorKind::NoAcknowledge(_))));
+    /// assert!(matches!(absent.simplify_i2c(), Error::BusError(BusErrorKind::NoAcknowledge)));
+    ///
+    /// let contended: ReadRegisterError<ErrorKind, ()> =
+    ///     ReadRegisterError::BusError(ErrorKind::ArbitrationLoss);
+    /// assert!(matches!(contended.simplify_i2c(), Error::BusError(BusErrorKind::ArbitrationLoss)));
+    ///
+    /// let overrun: ReadRegisterError<ErrorKind, ()> = ReadRegisterError::BusError(ErrorKind::Overrun);
+    /// assert!(matches!(overrun.simplify_i2c(), Error::BusError(BusErrorKind::Overrun)));
+    ///
+    /// let invalid: ReadRegisterError<ErrorKind, ()> = ReadRegisterError::DeserializationError(());
+    /// assert_eq!(invalid.i2c_kind(), None);
+    /// assert!(matches!(invalid.simplify_i2c(), Error::DeserializationError));
+    /// ```
+    pub fn i2c_kind(&self) -> Option<embedded_hal::i2c::ErrorKind>
+    where
+        B: embedded_hal::i2c::Error,
+    {
+        match self {
+            Self::BusError(e) => Some(e.kind()),
+            _ => None,
+        }
+    }
+
+    /// Returns the kind of the SPI bus error, or `None` if the error did not occur on the bus
+    pub fn spi_kind(&self) -> Option<embedded_hal::spi::ErrorKind>
+    where
+        B: embedded_hal::spi::Error,
+    {
+        match self {
+            Self::BusError(e) => Some(e.kind()),
+            _ => None,
+        }
+    }
+
+    /// Converts the error into the simplified [`Error`] type, retaining the kind of I2C bus error
+    pub fn simplify_i2c(self) -> Error
+    where
+        B: embedded_hal::i2c::Error,
+    {
+        match self.i2c_kind() {
+            Some(kind) => Error::BusError(kind.into()),
+            None => self.into(),
+        }
+    }
+
+    /// Converts the error into the simplified [`Error`] type, retaining the kind of SPI bus error
+    pub fn simplify_spi(self) -> Error
+    where
+        B: embedded_hal::spi::Error,
+    {
+        match self.spi_kind() {
+            Some(kind) => Error::BusError(kind.into()),
+            None => self.into(),
+        }
+    }
+}
+
+impl<B, S> WriteRegisterError<B, S> {
+    /// Returns the kind of the I2C bus error, or `None` if the error did not occur on the bus
+    pub fn i2c_kind(&self) -> Option<embedded_hal::i2c::ErrorKind>
+    where
+        B: embedded_hal::i2c::Error,
+    {
+        match self {
+            Self::BusError(e) => Some(e.kind()),
+            _ => None,
+        }
+    }
+
+    /// Returns the kind of the SPI bus error, or `None` if the error did not occur on the bus
+    ///
+    /// ```
+    /// use embedded_hal::spi::ErrorKind;
+    /// use regiface::errors::{BusErrorKind, Error, WriteRegisterError};
+    ///
+    /// let err: WriteRegisterError<ErrorKind, ()> = WriteRegisterError::BusError(ErrorKind::ModeFault);
+    /// assert_eq!(err.spi_kind(), Some(ErrorKind::ModeFault));
+    /// assert!(matches!(err.simplify_spi(), Error::BusError(BusErrorKind::ModeFault)));
+    ///
+    /// let err: WriteRegisterError<ErrorKind, ()> =
+    ///     WriteRegisterError::BusError(ErrorKind::ChipSelectFault);
+    /// assert!(matches!(err.simplify_spi(), Error::BusError(BusErrorKind::ChipSelectFault)));
+    ///
+    /// // Converting without the bus specific method discards the kind
+    /// assert!(matches!(Error::from(err), Error::BusError(BusErrorKind::Other)));
+    /// ```
+    pub fn spi_kind(&self) -> Option<embedded_hal::spi::ErrorKind>
+    where
+        B: embedded_hal::spi::Error,
+    {
+        match self {
+            Self::BusError(e) => Some(e.kind()),
+            _ => None,
+        }
+    }
+
+    /// Converts the error into the simplified [`Error`] type, retaining the kind of I2C bus error
+    pub fn simplify_i2c(self) -> Error
+    where
+        B: embedded_hal::i2c::Error,
+    {
+        match self.i2c_kind() {
+            Some(kind) => Error::BusError(kind.into()),
+            None => self.into(),
+        }
+    }
+
+    /// Converts the error into the simplified [`Error`] type, retaining the kind of SPI bus error
+    pub fn simplify_spi(self) -> Error
+    where
+        B: embedded_hal::spi::Error,
+    {
+        match self.spi_kind() {
+            Some(kind) => Error::BusError(kind.into()),
+            None => self.into(),
+        }
+    }
+}
+
+impl<B, S, D> CommandError<B, S, D> {
+    /// Returns the kind of the I2C bus error, or `None` if the error did not occur on the bus
+    pub fn i2c_kind(&self) -> Option<embedded_hal::i2c::ErrorKind>
+    where
+        B: embedded_hal::i2c::Error,
+    {
+        match self {
+            Self::BusError(e) => Some(e.kind()),
+            _ => None,
+        }
+    }
+
+    /// Returns the kind of the SPI bus error, or `None` if the error did not occur on the bus
+    pub fn spi_kind(&self) -> Option<embedded_hal::spi::ErrorKind>
+    where
+        B: embedded_hal::spi::Error,
+    {
+        match self {
+            Self::BusError(e) => Some(e.kind()),
+            _ => None,
+        }
+    }
+
+    /// Converts the error into the simplified [`Error`] type, retaining the kind of I2C bus error
+    pub fn simplify_i2c(self) -> Error
+    where
+        B: embedded_hal::i2c::Error,
+    {
+        match self.i2c_kind() {
+            Some(kind) => Error::BusError(kind.into()),
+            None => self.into(),
+        }
+    }
+
+    /// Converts the error into the simplified [`Error`] type, retaining the kind of SPI bus error
+    pub fn simplify_spi(self) -> Error
+    where
+        B: embedded_hal::spi::Error,
+    {
+        match self.spi_kind() {
+            Some(kind) => Error::BusError(kind.into()),
+            None => self.into(),
+        }
+    }
+}
+
+impl<B, D> fmt::Display for ReadRegisterError<B, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BusError(_) => f.write_str("bus error while reading register"),
+            Self::DeserializationError(_) => f.write_str("failed to deserialize register value"),
+        }
+    }
+}
+
+impl<B, D> core::error::Error for ReadRegisterError<B, D>
+where
+    B: core::error::Error + 'static,
+    D: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::BusError(e) => Some(e),
+            Self::DeserializationError(e) => Some(e),
+        }
+    }
+}
+
+impl<B, S> fmt::Display for WriteRegisterError<B, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BusError(_) => f.write_str("bus error while writing register"),
+            Self::SerializationError(_) => f.write_str("failed to serialize register value"),
+        }
+    }
+}
+
+impl<B, S> core::error::Error for WriteRegisterError<B, S>
+where
+    B: core::error::Error + 'static,
+    S: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::BusError(e) => Some(e),
+            Self::SerializationError(e) => Some(e),
+        }
+    }
+}
+
+/// ```
+/// use regiface::errors::CommandError;
+///
+/// let err: CommandError<(), (), ()> = CommandError::DeviceError(0x05);
+/// assert_eq!(err.to_string(), "device reported command failure with status 0x05");
+///
+/// let err: CommandError<(), (), ()> = CommandError::DeserializationError(());
+/// assert_eq!(err.to_string(), "failed to deserialize command response");
+/// ```
+impl<B, S, D> fmt::Display for CommandError<B, S, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BusError(_) => f.write_str("bus error while invoking command"),
+            Self::SerializationError(_) => f.write_str("failed to serialize command parameters"),
+            Self::DeserializationError(_) => f.write_str("failed to deserialize command response"),
+            Self::DeviceError(status) => {
+                write!(
+                    f,
+                    "device reported command failure with status {status:#04X}"
+                )
+            }
+            Self::ValidationError(_) => f.write_str("command response failed validation"),
+        }
+    }
+}
+
+impl<B, S, D> core::error::Error for CommandError<B, S, D>
+where
+    B: core::error::Error + 'static,
+    S: core::error::Error + 'static,
+    D: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::BusError(e) => Some(e),
+            Self::SerializationError(e) => Some(e),
+            Self::DeserializationError(e) => Some(e),
+            Self::DeviceError(_) => None,
+            Self::ValidationError(e) => Some(e),
+        }
+    }
+}
+
+impl<B> fmt::Display for DeviceError<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BusError(_) => f.write_str("bus error while communicating with device"),
+            Self::SerializationError => f.write_str("failed to serialize data"),
+            Self::DeserializationError => f.write_str("failed to deserialize data"),
+            Self::CommandFailed(status) => {
+                write!(
+                    f,
+                    "device reported command failure with status {status:#04X}"
+                )
+            }
+        }
+    }
+}
+
+impl<B> core::error::Error for DeviceError<B>
+where
+    B: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::BusError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// ```
+/// use regiface::errors::InvalidVariant;
+///
+/// assert_eq!(InvalidVariant(0x07u8).to_string(), "invalid enum discriminant 0x07");
+/// ```
+impl<T: fmt::UpperHex> fmt::Display for InvalidVariant<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid enum discriminant {:#04X}", self.0)
+    }
+}
+
+impl<T: fmt::Debug + fmt::UpperHex> core::error::Error for InvalidVariant<T> {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid command response")
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+/// ```
+/// use regiface::errors::{ElementError, InvalidVariant};
+///
+/// let err = ElementError { index: 3, error: InvalidVariant(0x07u8) };
+/// assert_eq!(err.to_string(), "failed to deserialize element 3");
+/// ```
+impl<E> fmt::Display for ElementError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to deserialize element {}", self.index)
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for ElementError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl<B, S, A> fmt::Display for WriteVerifyError<B, S, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BusError(_) => f.write_str("bus error while writing or verifying register"),
+            Self::SerializationError(_) => f.write_str("failed to serialize register value"),
+            Self::VerificationFailed { .. } => {
+                f.write_str("register bytes read back did not match the bytes written")
+            }
+        }
+    }
+}
+
+impl<B, S, A> core::error::Error for WriteVerifyError<B, S, A>
+where
+    B: core::error::Error + 'static,
+    S: core::error::Error + 'static,
+    A: fmt::Debug,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::BusError(e) => Some(e),
+            Self::SerializationError(e) => Some(e),
+            Self::VerificationFailed { .. } => None,
+        }
+    }
+}
+
+impl<B, S, D, R> fmt::Display for VerifyError<B, S, D, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BusError(_) => f.write_str("bus error while writing or verifying register"),
+            Self::SerializationError(_) => f.write_str("failed to serialize register value"),
+            Self::DeserializationError(_) => f.write_str("failed to deserialize register value"),
+            Self::Mismatch { .. } => {
+                f.write_str("register value read back did not match the value written")
+            }
+        }
+    }
+}
+
+impl<B, S, D, R> core::error::Error for VerifyError<B, S, D, R>
+where
+    B: core::error::Error + 'static,
+    S: core::error::Error + 'static,
+    D: core::error::Error + 'static,
+    R: fmt::Debug,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::BusError(e) => Some(e),
+            Self::SerializationError(e) => Some(e),
+            Self::DeserializationError(e) => Some(e),
+            Self::Mismatch { .. } => None,
+        }
+    }
+}
+
+impl<B, D, R> fmt::Display for PollError<B, D, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BusError(_) => f.write_str("bus error while polling register"),
+            Self::DeserializationError(_) => f.write_str("failed to deserialize register value"),
+            Self::Timeout { .. } => {
+                f.write_str("register did not satisfy the condition within the allowed attempts")
+            }
+        }
+    }
+}
+
+impl<B, D, R> core::error::Error for PollError<B, D, R>
+where
+    B: core::error::Error + 'static,
+    D: core::error::Error + 'static,
+    R: fmt::Debug,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::BusError(e) => Some(e),
+            Self::DeserializationError(e) => Some(e),
+            Self::Timeout { .. } => None,
+        }
+    }
+}
+
+impl<B> fmt::Display for FifoError<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BufferTooSmall {
+                required,
+                available,
+            } => write!(
+                f,
+                "FIFO transfer requires {required} bytes but the buffer holds {available}"
+            ),
+            Self::TransactionTooSmall { required, maximum } => write!(
+                f,
+                "FIFO write requires {required} bytes per transaction but at most {maximum} are allowed"
+            ),
+            Self::BusError { samples, .. } => {
+                write!(f, "bus error while transferring FIFO after {samples} samples")
+            }
+        }
+    }
+}
+
+impl<B: core::error::Error + 'static> core::error::Error for FifoError<B> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::BufferTooSmall { .. } | Self::TransactionTooSmall { .. } => None,
+            Self::BusError { error, .. } => Some(error),
+        }
+    }
+}
+
+impl<P, B, D> fmt::Display for InterruptReadError<P, B, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PinError(_) => f.write_str("error while waiting for interrupt"),
+            Self::BusError(_) => f.write_str("bus error while reading register"),
+            Self::DeserializationError(_) => f.write_str("failed to deserialize register value"),
+        }
+    }
+}
+
+impl<P, B, D> core::error::Error for InterruptReadError<P, B, D>
+where
+    P: core::error::Error + 'static,
+    B: core::error::Error + 'static,
+    D: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::PinError(e) => Some(e),
+            Self::BusError(e) => Some(e),
+            Self::DeserializationError(e) => Some(e),
+        }
+    }
+}
+
+impl<B, D, R> fmt::Display for WhoAmIError<B, D, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BusError(_) => f.write_str("bus error while reading identification register"),
+            Self::DeserializationError(_) => f.write_str("failed to deserialize register value"),
+            Self::Mismatch { .. } => {
+                f.write_str("identification register did not hold the expected value")
+            }
+        }
+    }
+}
+
+impl<B, D, R> core::error::Error for WhoAmIError<B, D, R>
+where
+    B: core::error::Error + 'static,
+    D: core::error::Error + 'static,
+    R: fmt::Debug,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::BusError(e) => Some(e),
+            Self::DeserializationError(e) => Some(e),
+            Self::Mismatch { .. } => None,
+        }
+    }
+}
+
+impl<E> fmt::Display for TimeoutError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error(_) => f.write_str("operation failed"),
+            Self::Elapsed => f.write_str("operation did not complete in time"),
+        }
+    }
+}
+
+impl<E> fmt::Display for RetryError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "operation failed after {} attempts", self.attempts)
+    }
+}
+
+impl<E> core::error::Error for RetryError<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl<E> core::error::Error for TimeoutError<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Error(e) => Some(e),
+            Self::Elapsed => None,
+        }
+    }
+}
+
+/// ```
+/// use regiface::errors::{Error, ReadRegisterError};
+///
+/// let err = Error::from(ReadRegisterError::<(), ()>::BusError(()));
+/// assert_eq!(err.to_string(), "bus error");
+/// ```
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::BusError(BusErrorKind::Other) => "bus error",
+            Self::BusError(kind) => return write!(f, "bus error: {kind}"),
+            Self::SerializationError => "serialization error",
+            Self::DeserializationError => "deserialization error",
+            Self::VerificationError => "verification error",
+            Self::Timeout => "operation timed out",
+            Self::DeviceError => "device reported an error",
+        })
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl fmt::Display for DynError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for DynError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<E> fmt::Display for WithId<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "operation on ID {} failed", self.id)
+    }
+}
+
+impl<E> core::error::Error for WithId<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl<B, D> ErrorClass for ReadRegisterError<B, D> {
+    fn is_transient(&self) -> bool {
+        matches!(self, Self::BusError(_))
+    }
+}
+
+impl<B, S> ErrorClass for WriteRegisterError<B, S> {
+    fn is_transient(&self) -> bool {
+        matches!(self, Self::BusError(_))
+    }
+}
+
+impl<B, S, D> ErrorClass for CommandError<B, S, D> {
+    fn is_transient(&self) -> bool {
+        matches!(self, Self::BusError(_))
+    }
+}
+
+impl<B> ErrorClass for DeviceError<B> {
+    fn is_transient(&self) -> bool {
+        matches!(self, Self::BusError(_))
+    }
+}
+
+impl ErrorClass for ValidationError {
+    fn is_transient(&self) -> bool {
+        false
+    }
+}
+
+impl<T> ErrorClass for InvalidVariant<T> {
+    fn is_transient(&self) -> bool {
+        false
+    }
+}
+
+impl<E> ErrorClass for ElementError<E> {
+    fn is_transient(&self) -> bool {
+        false
+    }
+}
+
+impl<B, S, A> ErrorClass for WriteVerifyError<B, S, A> {
+    fn is_transient(&self) -> bool {
+        matches!(self, Self::BusError(_))
+    }
+}
+
+impl<B, S, D, R> ErrorClass for VerifyError<B, S, D, R> {
+    fn is_transient(&self) -> bool {
+        matches!(self, Self::BusError(_))
+    }
+}
+
+impl<B, D, R> ErrorClass for PollError<B, D, R> {
+    fn is_transient(&self) -> bool {
+        matches!(self, Self::BusError(_) | Self::Timeout { .. })
+    }
+}
+
+impl<B, D, R> ErrorClass for WhoAmIError<B, D, R> {
+    fn is_transient(&self) -> bool {
+        matches!(self, Self::BusError(_))
+    }
+}
+
+impl<B> ErrorClass for FifoError<B> {
+    fn is_transient(&self) -> bool {
+        matches!(self, Self::BusError { .. })
+    }
+}
+
+impl<P, B, D> ErrorClass for InterruptReadError<P, B, D> {
+    fn is_transient(&self) -> bool {
+        matches!(self, Self::BusError(_))
+    }
+}
+
+impl<E: ErrorClass> ErrorClass for RetryError<E> {
+    fn is_transient(&self) -> bool {
+        self.error.is_transient()
+    }
+}
+
+impl<E: ErrorClass> ErrorClass for TimeoutError<E> {
+    fn is_transient(&self) -> bool {
+        match self {
+            Self::Error(e) => e.is_transient(),
+            Self::Elapsed => true,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ErrorClass for DynError {
+    fn is_transient(&self) -> bool {
+        self.kind.is_transient()
+    }
+}
+
+impl ErrorClass for Error {
+    fn is_transient(&self) -> bool {
+        match self {
+            Self::BusError(kind) => kind.is_transient(),
+            Self::Timeout => true,
+            _ => false,
+        }
+    }
+}
+
+impl<E: ErrorClass> ErrorClass for WithId<E> {
+    fn is_transient(&self) -> bool {
+        self.error.is_transient()
+    }
+}
@@ -3,16 +3,313 @@
 //! This module provides both blocking and async variants of register read/write operations
 //! and command invocation for I2C devices. All operations handle device addressing and
 //! proper byte serialization/deserialization of register values.
+//!
+//! When the `log` feature is enabled, each operation emits a trace level log event containing the
+//! register or command ID and the bytes transferred.
+//!
+//! The functions of this module take the bus and device address separately. Where a driver talks to
+//! a single device, or several devices share a bus, the address may instead be fixed once by
+//! wrapping the bus in an [`I2cInterface`](crate::device::I2cInterface), whose `RegisterBus`
+//! methods delegate to this module:
+//!
+#![cfg_attr(feature = "blocking", doc = "```")]
+#![cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+//! # use core::cell::RefCell;
+//! # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+//! use embedded_hal_bus::i2c::RefCellDevice;
+//! use regiface::{
+//!     device::{blocking::RegisterBus, I2cInterface},
+//!     register, FromByteArray, ReadableRegister,
+//! };
+//!
+//! #[register(0x0Fu8)]
+//! #[derive(ReadableRegister)]
+//! struct WhoAmI(u8);
+//!
+//! impl FromByteArray for WhoAmI {
+//!     type Array = [u8; 1];
+//!     type Error = core::convert::Infallible;
+//!
+//!     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+//!         Ok(Self(bytes[0]))
+//!     }
+//! }
+//! # struct Bus;
+//! # impl ErrorType for Bus { type Error = core::convert::Infallible; }
+//! # impl I2c for Bus {
+//! #     fn transaction(&mut self, addr: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+//! #         if let Some(Operation::Read(buf)) = ops.last_mut() { buf[0] = addr; }
+//! #         Ok(())
+//! #     }
+//! # }
+//! # let bus = Bus;
+//!
+//! let bus = RefCell::new(bus);
+//! let mut accel = I2cInterface::new(RefCellDevice::new(&bus), 0x19);
+//! let mut magnetometer = I2cInterface::new(RefCellDevice::new(&bus), 0x1E);
+//!
+//! // Each register access is addressed to the device it was performed on
+//! assert_eq!(accel.read_register::<WhoAmI>().unwrap().0, 0x19);
+//! assert_eq!(magnetometer.read_register::<WhoAmI>().unwrap().0, 0x1E);
+//! ```
+//!
+//! # Uninterrupted sequences
+//!
+//! Each function of this module performs a single I2C transaction, thus no other user of a shared
+//! bus may interleave with it. A sequence of operations, such as setting a pointer register and
+//! then invoking a command, can not be combined into a single transaction, as adjacent writes
+//! within a transaction are merged into one write by the I2C bus implementation. Instead, the
+//! sequence should be performed while holding exclusive access to the underlying bus, such as the
+//! borrow of a bus shared through a [`RefCell`](core::cell::RefCell), or the lock of a bus shared
+//! through a mutex:
+//!
+#![cfg_attr(feature = "blocking", doc = "```")]
+#![cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+//! # use core::cell::RefCell;
+//! # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+//! use regiface::{errors::DeviceError, i2c, register, Command, NoParameters, ToByteArray, WritableRegister};
+//!
+//! #[register(0x01u8)]
+//! #[derive(WritableRegister)]
+//! struct Channel(u8);
+//!
+//! impl ToByteArray for Channel {
+//!     type Array = [u8; 1];
+//!     type Error = core::convert::Infallible;
+//!
+//!     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+//!         Ok([self.0])
+//!     }
+//! }
+//!
+//! struct Measure;
+//!
+//! impl Command for Measure {
+//!     type IdType = u8;
+//!     type CommandParameters = NoParameters;
+//!     type ResponseParameters = u16;
+//!
+//!     fn id() -> Self::IdType {
+//!         0x10
+//!     }
+//!
+//!     fn invoking_parameters(self) -> Self::CommandParameters {
+//!         NoParameters::default()
+//!     }
+//! }
+//! # struct Bus;
+//! # impl ErrorType for Bus { type Error = core::convert::Infallible; }
+//! # impl I2c for Bus {
+//! #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+//! #         if let Some(Operation::Read(buf)) = ops.last_mut() { buf.copy_from_slice(&[0x01, 0x23]); }
+//! #         Ok(())
+//! #     }
+//! # }
+//!
+//! fn measure<B: I2c>(bus: &RefCell<B>, channel: u8) -> Result<u16, DeviceError<B::Error>> {
+//!     // No other user of the bus may access it until the borrow is released
+//!     let mut bus = bus.borrow_mut();
+//!     i2c::blocking::write_register(&mut *bus, 0x48, Channel(channel))?;
+//!     Ok(i2c::blocking::invoke_command(&mut *bus, 0x48, Measure)?)
+//! }
+//!
+//! assert_eq!(measure(&RefCell::new(Bus), 2), Ok(0x0123));
+//! ```
 
+#[cfg(any(feature = "blocking", feature = "async"))]
+use core::convert::Infallible;
+
+#[cfg(any(feature = "blocking", feature = "async"))]
 use crate::{
     byte_array::ByteArray as _,
+    command::{check_response, merge_id},
     errors::CommandError,
-    errors::{ReadRegisterError, WriteRegisterError},
-    Command, FromByteArray, ReadableRegister, ToByteArray, WritableRegister,
+    errors::{
+        FifoError, PollError, ReadRegisterError, RetryError, VerifyError, WhoAmIError,
+        WriteRegisterError, WriteVerifyError,
+    },
+    id::{self, Id as _},
+    Command, DynamicCommand, FifoMode, FromByteArray, IndexedRegister, NoParameters,
+    ReadableRegister, ToByteArray, WhoAmI, WritableRegister,
 };
 
+/// The reserved address to which devices supporting the general call respond
+#[cfg(any(feature = "blocking", feature = "async"))]
+const GENERAL_CALL_ADDRESS: u8 = 0x00;
+
+/// Error that can occur when reading from an SMBus device using Packet Error Checking
+///
+/// Generic over the bus error type `B`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PecError<B> {
+    /// An error occurred while communicating over the bus
+    BusError(B),
+    /// The PEC received from the device did not match the PEC computed over the transaction
+    PecMismatch {
+        /// The PEC computed over the transaction
+        expected: u8,
+        /// The PEC received from the device
+        received: u8,
+    },
+}
+
+/// Error that can occur when performing an SMBus block read or block write
+///
+/// Generic over the bus error type `B`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockError<B> {
+    /// An error occurred while communicating over the bus
+    BusError(B),
+    /// The length of the block exceeded the capacity available for it
+    ///
+    /// For block reads, this is the length reported by the device exceeding the provided buffer,
+    /// in which case the buffer contains as many bytes of the block as fit. For block writes, this
+    /// is the data exceeding the 255 bytes representable by the length byte.
+    LengthExceeded {
+        /// The length of the block
+        length: usize,
+        /// The capacity available for the block
+        capacity: usize,
+    },
+}
+
+/// The set of 7-bit addresses found to acknowledge by a bus scan, such as with `blocking::scan`
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Default)]
+pub struct Addresses(u128);
+
+impl Addresses {
+    /// Returns whether the given address acknowledged
+    pub fn contains(&self, addr: u8) -> bool {
+        addr < 0x80 && self.0 & (1 << addr) != 0
+    }
+
+    /// Returns the number of addresses that acknowledged
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns whether no addresses acknowledged
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns an iterator over the addresses that acknowledged, in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..0x80).filter(|&addr| self.contains(addr))
+    }
+
+    /// Records the given address as having acknowledged
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    fn insert(&mut self, addr: u8) {
+        self.0 |= 1 << addr;
+    }
+}
+
+/// Returns the 7-bit addresses probed by a bus scan, excluding the addresses reserved by the I2C
+/// specification (`0x00..=0x07` and `0x78..=0x7F`) unless `include_reserved` is set
+#[cfg(any(feature = "blocking", feature = "async"))]
+fn scan_range(include_reserved: bool) -> core::ops::RangeInclusive<u8> {
+    if include_reserved {
+        0x00..=0x7F
+    } else {
+        0x08..=0x77
+    }
+}
+
+/// Computes the SMBus Packet Error Code, a CRC-8 with polynomial `0x07`, over the given parts of a
+/// transaction
+#[cfg(any(feature = "blocking", feature = "async"))]
+fn pec(parts: &[&[u8]]) -> u8 {
+    let crc = crate::serial::Checksum::CRC8;
+    parts
+        .iter()
+        .fold(crc.init(), |state, part| crc.update(state, part))
+}
+
+#[cfg(feature = "async")]
 pub mod r#async {
     use super::*;
+    use crate::{
+        errors::{InterruptReadError, TimeoutError},
+        interrupt::{wait_for, Edge},
+        retry::{retry_policy_async, Policy},
+        timeout::with_timeout,
+    };
+    use core::ops::ControlFlow;
+    use embedded_hal_async::i2c::Operation;
+
+    /// Builds the operations for writing a value: the register or command ID followed by the value
+    fn write_operations<'a>(id: &'a [u8], value: &'a [u8]) -> [Operation<'a>; 2] {
+        [Operation::Write(id), Operation::Write(value)]
+    }
+
+    /// Builds the operations for invoking a command: the preamble, the command ID, the sub-ID, the
+    /// command parameters, and the postamble, followed by a read of the status byte and command
+    /// response
+    ///
+    /// Empty preambles, command IDs, sub-IDs, command parameters, postambles, status bytes, and
+    /// responses are omitted, thus only the first `len` of the returned operations are to be
+    /// performed.
+    fn command_operations<'a>(
+        preamble: &'a [u8],
+        id: &'a [u8],
+        sub_id: &'a [u8],
+        parameters: &'a [u8],
+        postamble: &'a [u8],
+        status: &'a mut [u8],
+        response: &'a mut [u8],
+    ) -> ([Operation<'a>; 7], usize) {
+        let mut operations = [
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+        ];
+        let mut len = 0;
+
+        for write in [preamble, id, sub_id, parameters, postamble] {
+            if !write.is_empty() {
+                operations[len] = Operation::Write(write);
+                len += 1;
+            }
+        }
+
+        let (reads, reads_len) = read_operations(status, response);
+        for read in reads.into_iter().take(reads_len) {
+            operations[len] = read;
+            len += 1;
+        }
+
+        (operations, len)
+    }
+
+    /// Builds the operations for reading a command response: a read of the status byte, followed
+    /// by a read of the command response
+    ///
+    /// Empty status bytes and responses are omitted, thus only the first `len` of the returned
+    /// operations are to be performed.
+    fn read_operations<'a>(
+        status: &'a mut [u8],
+        response: &'a mut [u8],
+    ) -> ([Operation<'a>; 2], usize) {
+        let mut operations = [Operation::Write(&[]), Operation::Write(&[])];
+        let mut len = 0;
+
+        if !status.is_empty() {
+            operations[len] = Operation::Read(status);
+            len += 1;
+        }
+
+        if !response.is_empty() {
+            operations[len] = Operation::Read(response);
+            len += 1;
+        }
+
+        (operations, len)
+    }
 
     /// Read a register value from an I2C device.
     ///
@@ -55,273 +352,4366 @@ pub mod r#async {
     {
         let mut buf = <R as FromByteArray>::Array::new();
 
-        // Register ID types have compiler enforced infallible byte conversions, thus this unwrap is safe
-        let reg_id = R::readable_id().to_bytes().unwrap();
+        let reg_id = R::readable_id().to_id_bytes();
 
         device
             .write_read(device_addr, reg_id.as_ref(), buf.as_mut())
             .await
             .map_err(ReadRegisterError::BusError)?;
 
+        trace!(
+            "i2c: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
         R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
     }
 
-    /// Write a register value to an I2C device.
+    /// Read a register value from an I2C device, ending the register ID write with a stop
+    /// condition.
     ///
-    /// This function performs a write I2C transaction, sending both the register ID
-    /// and the serialized register value. The operation is atomic, using the device's
-    /// transaction capability to ensure both writes occur without interruption.
+    /// Unlike [`read_register`], which writes the register ID and reads the value in a single
+    /// transaction joined by a repeated start, this function performs two separate transactions: a
+    /// write of the register ID, terminated by a stop, then a read of the register value, starting
+    /// afresh. Some devices do not support repeated starts and require this sequence, while for all
+    /// others [`read_register`] should be preferred.
+    ///
+    /// As the bus is released between the two transactions, another controller on a multi-controller
+    /// bus may access the device in between, changing its register pointer.
     ///
     /// # Parameters
     /// * `device` - The I2C device to communicate with
     /// * `device_addr` - The I2C address of the target device
-    /// * `register` - The register value to write
     ///
     /// # Errors
-    /// * `WriteRegisterError::BusError` - Communication with the device failed
-    /// * `WriteRegisterError::SerializationError` - Failed to convert register value to bytes
+    /// * `ReadRegisterError::BusError` - Communication with the device failed
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
     ///
     /// # Example
     /// ```no_run
     /// # use embedded_hal_async::i2c::I2c;
-    /// # use regiface::{register, i2c, WritableRegister, ToByteArray};
-    /// #[register(42u8)]
-    /// #[derive(WritableRegister)]
-    /// # struct ConfigRegister{};
-    /// # impl ToByteArray for ConfigRegister {
-    /// #     type Array = [u8; 1];
+    /// # use regiface::{register, i2c, ReadableRegister, FromByteArray};
+    /// # #[register(42u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct TemperatureRegister;
+    /// # impl FromByteArray for TemperatureRegister {
+    /// #     type Array = [u8; 2];
     /// #     type Error = ();
-    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([0]) }
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { todo!() }
     /// # }
-    /// async fn configure<D: I2c<u8>>(device: &mut D) {
-    ///     i2c::r#async::write_register(device, 0x48, ConfigRegister{ /* ... */}).await.unwrap();
+    /// async fn read_temp<D: I2c<u8>>(device: &mut D) {
+    ///     let temp: TemperatureRegister = i2c::r#async::read_register_stop(device, 0x48).await.unwrap();
     /// }
     /// ```
-    pub async fn write_register<D, A, R>(
+    pub async fn read_register_stop<D, A, R>(
         device: &mut D,
         device_addr: A,
-        register: R,
-    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
     where
-        A: embedded_hal_async::i2c::AddressMode,
+        A: embedded_hal_async::i2c::AddressMode + Copy,
         D: embedded_hal_async::i2c::I2c<A>,
-        R: WritableRegister,
+        R: ReadableRegister,
     {
-        let buf = register
-            .to_bytes()
-            .map_err(WriteRegisterError::SerializationError)?;
+        let mut buf = <R as FromByteArray>::Array::new();
 
-        // Register ID types have compiler enforced infallible byte conversions, thus this unwrap is safe
-        let reg_id = R::writeable_id().to_bytes().unwrap();
+        let reg_id = R::readable_id().to_id_bytes();
 
         device
-            .transaction(
-                device_addr,
-                &mut [
-                    embedded_hal_async::i2c::Operation::Write(reg_id.as_ref()),
-                    embedded_hal_async::i2c::Operation::Write(buf.as_ref()),
-                ],
-            )
+            .write(device_addr, reg_id.as_ref())
+            .await
+            .map_err(ReadRegisterError::BusError)?;
+        device
+            .read(device_addr, buf.as_mut())
             .await
-            .map_err(WriteRegisterError::BusError)
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "i2c: read register (stop): id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
     }
 
-    /// Invoke a command on an I2C device and receive its response.
+    /// Read a register value from an I2C device, reading a fixed number of bytes regardless of
+    /// the size of the register.
     ///
-    /// This function performs a complete command transaction:
-    /// 1. Sends the command ID
-    /// 2. Sends the serialized command parameters
-    /// 3. Reads the command response
+    /// This function behaves like [`read_register`], except that `READ_LEN` bytes are read rather
+    /// than the length of the register's [`Array`](FromByteArray::Array). This is useful for
+    /// devices that always clock out a fixed frame, or for HALs with a minimum transfer size. The
+    /// register value is deserialized from the first bytes of the frame, and any trailing bytes are
+    /// discarded.
     ///
-    /// The entire operation is atomic, using the device's transaction capability to
-    /// ensure all steps occur without interruption.
+    /// `READ_LEN` must be at least the length of the register's [`Array`](FromByteArray::Array),
+    /// which is enforced at compile time.
     ///
     /// # Parameters
     /// * `device` - The I2C device to communicate with
     /// * `device_addr` - The I2C address of the target device
-    /// * `cmd` - The command to invoke
     ///
     /// # Errors
-    /// * `CommandError::BusError` - Communication with the device failed
-    /// * `CommandError::SerializationError` - Failed to convert command parameters to bytes
-    /// * `CommandError::DeserializationError` - Failed to convert received bytes into response parameters
+    /// * `ReadRegisterError::BusError` - Communication with the device failed
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
     ///
     /// # Example
     /// ```no_run
     /// # use embedded_hal_async::i2c::I2c;
-    /// # use regiface::{NoParameters, i2c, Command, FromByteArray, ToByteArray};
-    /// # struct SelfTestCommand{};
-    /// # struct SelfTestResponse;
-    /// # impl Command for SelfTestCommand {
-    /// #     type IdType = u8;
-    /// #     type CommandParameters = NoParameters;
-    /// #     type ResponseParameters = SelfTestResponse;
-    /// #     fn id() -> Self::IdType { 0xF0 }
-    /// #     fn invoking_parameters(self) -> Self::CommandParameters { todo!() }
-    /// # }
-    /// # impl FromByteArray for SelfTestResponse {
+    /// # use regiface::{register, i2c, ReadableRegister, FromByteArray};
+    /// # #[register(0x10u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct Status(u8);
+    /// # impl FromByteArray for Status {
     /// #     type Array = [u8; 1];
     /// #     type Error = ();
-    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { Ok(Self) }
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
     /// # }
-    /// async fn perform_self_test<D: I2c<u8>>(device: &mut D) {
-    ///     let result: SelfTestResponse = i2c::r#async::invoke_command(device, 0x48, SelfTestCommand{ /* ... */}).await.unwrap();
+    /// async fn read_status<D: I2c>(device: &mut D) -> u8 {
+    ///     // The device always clocks out a 4 byte frame
+    ///     let status: Status = i2c::r#async::read_register_sized::<_, _, Status, 4>(device, 0x2C).await.unwrap();
+    ///     status.0
     /// }
     /// ```
-    #[allow(clippy::type_complexity)]
-    pub async fn invoke_command<D, A, C>(
+    pub async fn read_register_sized<D, A, R, const READ_LEN: usize>(
         device: &mut D,
         device_addr: A,
-        cmd: C,
-    ) -> Result<
-        C::ResponseParameters,
-        CommandError<
-            D::Error,
-            <C::CommandParameters as ToByteArray>::Error,
-            <C::ResponseParameters as FromByteArray>::Error,
-        >,
-    >
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
     where
         A: embedded_hal_async::i2c::AddressMode,
         D: embedded_hal_async::i2c::I2c<A>,
-        C: Command,
+        R: ReadableRegister,
     {
-        let cmd_buf = cmd
-            .invoking_parameters()
-            .to_bytes()
-            .map_err(CommandError::SerializationError)?;
-        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+        const {
+            assert!(
+                READ_LEN >= <R as FromByteArray>::Array::LEN,
+                "READ_LEN must be at least the length of the register's FromByteArray::Array"
+            )
+        };
+
+        let mut buf = [0; READ_LEN];
 
-        // Register ID types have compiler enforced infallible byte conversions, thus this unwrap is safe
-        let reg_id = unsafe { C::id().to_bytes().unwrap_unchecked() };
+        let reg_id = R::readable_id().to_id_bytes();
 
         device
-            .transaction(
-                device_addr,
-                &mut [
-                    embedded_hal::i2c::Operation::Write(reg_id.as_ref()),
-                    embedded_hal::i2c::Operation::Write(cmd_buf.as_ref()),
-                    embedded_hal::i2c::Operation::Read(resp_buf.as_mut()),
-                ],
-            )
+            .write_read(device_addr, reg_id.as_ref(), &mut buf)
             .await
-            .map_err(CommandError::BusError)?;
+            .map_err(ReadRegisterError::BusError)?;
 
-        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
-    }
-}
+        trace!(
+            "i2c: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            &buf[..]
+        );
 
-pub mod blocking {
-    use super::*;
+        // Only the leading bytes of the oversized read make up the register value
+        let mut bytes = <R as FromByteArray>::Array::new();
+        bytes
+            .as_mut()
+            .copy_from_slice(&buf[..<R as FromByteArray>::Array::LEN]);
 
-    /// Read a register value from an I2C device.
+        R::from_bytes(bytes).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from an SMBus device using Packet Error Checking.
     ///
-    /// Blocking variant of [`read_register`](crate::i2c::async::read_register).
-    /// See the async function documentation for detailed behavior description.
+    /// This function performs the same transaction as [`read_register`], then reads a trailing PEC
+    /// byte. The PEC is verified against a CRC-8 computed over the entire transaction, including
+    /// the address bytes of both the write and read phases, as required by the SMBus specification.
+    /// Only 7-bit addresses are supported, as the address is part of the PEC.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The 7-bit I2C address of the target device
+    ///
+    /// # Errors
+    /// * `ReadRegisterError::BusError` - Communication with the device failed, or the received PEC
+    ///   did not match
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
     ///
     /// # Example
     /// ```no_run
-    /// # use embedded_hal::i2c::I2c;
+    /// # use embedded_hal_async::i2c::I2c;
     /// # use regiface::{register, i2c, ReadableRegister, FromByteArray};
-    /// # #[register(42u8)]
+    /// # #[register(0x09u8)]
     /// # #[derive(ReadableRegister)]
-    /// # struct TemperatureRegister;
-    /// # impl FromByteArray for TemperatureRegister {
+    /// # struct Voltage(u16);
+    /// # impl FromByteArray for Voltage {
     /// #     type Array = [u8; 2];
     /// #     type Error = ();
-    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> {todo!()}
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(u16::from_le_bytes(bytes))) }
     /// # }
-    /// fn read_temp<D: I2c<u8>>(device: &mut D) {
-    ///     let temp: TemperatureRegister = i2c::blocking::read_register(device, 0x48).unwrap();
+    /// async fn battery_voltage<D: I2c<u8>>(device: &mut D) -> u16 {
+    ///     let voltage: Voltage = i2c::r#async::read_register_pec(device, 0x0B).await.unwrap();
+    ///     voltage.0
     /// }
     /// ```
-    pub fn read_register<D, A, R>(
+    pub async fn read_register_pec<D, R>(
         device: &mut D,
-        device_addr: A,
-    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+        device_addr: u8,
+    ) -> Result<R, ReadRegisterError<PecError<D::Error>, R::Error>>
     where
-        A: embedded_hal::i2c::AddressMode,
-        D: embedded_hal::i2c::I2c<A>,
+        D: embedded_hal_async::i2c::I2c<u8>,
         R: ReadableRegister,
     {
         let mut buf = <R as FromByteArray>::Array::new();
+        let mut received = [0];
 
-        // Register ID types have compiler enforced infallible byte conversions, thus this unwrap is safe
-        let reg_id = R::readable_id().to_bytes().unwrap();
+        let reg_id = R::readable_id().to_id_bytes();
 
         device
-            .write_read(device_addr, reg_id.as_ref(), buf.as_mut())
-            .map_err(ReadRegisterError::BusError)?;
+            .transaction(
+                device_addr,
+                &mut [
+                    Operation::Write(reg_id.as_ref()),
+                    Operation::Read(buf.as_mut()),
+                    Operation::Read(&mut received),
+                ],
+            )
+            .await
+            .map_err(|e| ReadRegisterError::BusError(PecError::BusError(e)))?;
+
+        trace!(
+            "i2c: read register: id={:02X?} bytes={:02X?} pec={:02X}",
+            reg_id.as_ref(),
+            buf.as_ref(),
+            received[0]
+        );
+
+        let expected = pec(&[
+            &[device_addr << 1],
+            reg_id.as_ref(),
+            &[(device_addr << 1) | 1],
+            buf.as_ref(),
+        ]);
+        if received[0] != expected {
+            return Err(ReadRegisterError::BusError(PecError::PecMismatch {
+                expected,
+                received: received[0],
+            }));
+        }
 
         R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
     }
 
-    /// Write a register value to an I2C device.
+    /// Read an instance of an indexed register value from an I2C device.
     ///
-    /// Blocking variant of [`write_register`](crate::i2c::async::write_register).
-    /// See the async function documentation for detailed behavior description.
+    /// This function behaves exactly like [`read_register`], except that the register ID is
+    /// computed from the provided index using [`IndexedRegister::readable_indexed_id`].
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `index` - The index of the register instance to read
+    ///
+    /// # Errors
+    /// * `ReadRegisterError::BusError` - Communication with the device failed
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
     ///
     /// # Example
     /// ```no_run
-    /// # use embedded_hal::i2c::I2c;
-    /// # use regiface::{register, i2c, WritableRegister, ToByteArray};
-    /// #[register(42u8)]
-    /// #[derive(WritableRegister)]
-    /// # struct ConfigRegister;
-    /// # impl ToByteArray for ConfigRegister {
-    /// #     type Array = [u8; 1];
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::{register, i2c, IndexedRegister, ReadableRegister, FromByteArray};
+    /// # #[register(0x06u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct LedOnOff { on: u16, off: u16 }
+    /// # impl IndexedRegister for LedOnOff {
+    /// #     type Index = u8;
+    /// #     fn indexed_id(channel: Self::Index) -> Self::IdType { Self::ID + 4 * channel }
+    /// # }
+    /// # impl FromByteArray for LedOnOff {
+    /// #     type Array = [u8; 4];
     /// #     type Error = ();
-    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([0]) }
+    /// #     fn from_bytes(b: Self::Array) -> Result<Self, Self::Error> {
+    /// #         Ok(Self { on: u16::from_le_bytes([b[0], b[1]]), off: u16::from_le_bytes([b[2], b[3]]) })
+    /// #     }
     /// # }
-    /// fn configure<D: I2c<u8>>(device: &mut D) {
-    ///     i2c::blocking::write_register(device, 0x48, ConfigRegister{ /* ... */}).unwrap();
+    /// async fn read_channel<D: I2c>(device: &mut D, channel: u8) -> u16 {
+    ///     let led: LedOnOff = i2c::r#async::read_register_at(device, 0x40, channel).await.unwrap();
+    ///     led.off
     /// }
     /// ```
-    pub fn write_register<D, A, R>(
+    pub async fn read_register_at<D, A, R>(
         device: &mut D,
         device_addr: A,
-        register: R,
-    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+        index: R::Index,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
     where
-        A: embedded_hal::i2c::AddressMode,
-        D: embedded_hal::i2c::I2c<A>,
-        R: WritableRegister,
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        R: ReadableRegister + IndexedRegister,
     {
-        let buf = register
-            .to_bytes()
-            .map_err(WriteRegisterError::SerializationError)?;
+        let mut buf = <R as FromByteArray>::Array::new();
 
-        // Register ID types have compiler enforced infallible byte conversions, thus this unwrap is safe
-        let reg_id = R::writeable_id().to_bytes().unwrap();
+        let reg_id = R::readable_indexed_id(index).to_id_bytes();
 
         device
-            .transaction(
-                device_addr,
-                &mut [
-                    embedded_hal_async::i2c::Operation::Write(reg_id.as_ref()),
-                    embedded_hal_async::i2c::Operation::Write(buf.as_ref()),
-                ],
-            )
-            .map_err(WriteRegisterError::BusError)
+            .write_read(device_addr, reg_id.as_ref(), buf.as_mut())
+            .await
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "i2c: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
     }
 
-    /// Invoke a command on an I2C device and receive its response.
+    /// Read a register value from an I2C device, along with the number of bytes read.
     ///
-    /// Blocking variant of [`invoke_command`](crate::i2c::async::invoke_command).
-    /// See the async function documentation for detailed behavior description.
+    /// This function behaves identically to [`read_register`], but additionally returns the number
+    /// of bytes that the bus transfer placed into the register buffer. This allows the caller to
+    /// correctly interpret reads, such as FIFO or status reads, on buses capable of returning fewer
+    /// bytes than requested.
     ///
-    /// # Example
-    /// ```no_run
-    /// # use embedded_hal::i2c::I2c;
-    /// # use regiface::{NoParameters, i2c, Command, FromByteArray, ToByteArray};
-    /// # struct SelfTestCommand;
-    /// # struct SelfTestResponse;
-    /// # impl Command for SelfTestCommand {
-    /// #     type IdType = u8;
-    /// #     type CommandParameters = NoParameters;
-    /// #     type ResponseParameters = SelfTestResponse;
+    /// The `embedded-hal` I2C traits require that every read fills the entire buffer, thus the
+    /// count returned is always equal to the length of the register's [`Array`](FromByteArray::Array).
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    ///
+    /// # Errors
+    /// * `ReadRegisterError::BusError` - Communication with the device failed
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::{register, i2c, ReadableRegister, FromByteArray};
+    /// # #[register(42u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct FifoData;
+    /// # impl FromByteArray for FifoData {
+    /// #     type Array = [u8; 32];
+    /// #     type Error = ();
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { todo!() }
+    /// # }
+    /// async fn read_fifo<D: I2c<u8>>(device: &mut D) {
+    ///     let (data, count): (FifoData, usize) = i2c::r#async::read_register_counted(device, 0x48).await.unwrap();
+    /// }
+    /// ```
+    pub async fn read_register_counted<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+    ) -> Result<(R, usize), ReadRegisterError<D::Error, R::Error>>
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        R: ReadableRegister,
+    {
+        let count = <R as FromByteArray>::Array::new().as_ref().len();
+        let register = read_register(device, device_addr).await?;
+
+        Ok((register, count))
+    }
+
+    /// Read a contiguous block of registers from an I2C device in a single transaction.
+    ///
+    /// Many devices automatically increment their internal register pointer after each byte
+    /// is read, allowing a run of consecutive registers to be read with a single write of the
+    /// starting register ID followed by a single multi-byte read. This function performs exactly
+    /// that write-read I2C transaction, where `R` is a register type whose ID is the address of
+    /// the first register in the block and whose [`Array`](FromByteArray::Array) spans the entire
+    /// block.
+    ///
+    /// This behaves identically to [`read_register`], but the dedicated name makes the reliance on
+    /// the device's auto-increment behavior explicit in driver code. Devices that do not
+    /// auto-increment their register pointer must not be read with this function.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    ///
+    /// # Errors
+    /// * `ReadRegisterError::BusError` - Communication with the device failed
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal_async::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::{register, i2c, ReadableRegister, FromByteArray};
+    /// // OUT_X_L, OUT_X_H, OUT_Y_L, OUT_Y_H, OUT_Z_L, OUT_Z_H
+    /// #[register(0x28u8)]
+    /// #[derive(ReadableRegister)]
+    /// struct AccelSample {
+    ///     x: i16,
+    ///     y: i16,
+    ///     z: i16,
+    /// }
+    ///
+    /// impl FromByteArray for AccelSample {
+    ///     type Array = [u8; 6];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+    ///         Ok(Self {
+    ///             x: i16::from_le_bytes([bytes[0], bytes[1]]),
+    ///             y: i16::from_le_bytes([bytes[2], bytes[3]]),
+    ///             z: i16::from_le_bytes([bytes[4], bytes[5]]),
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c for Device {
+    /// #     async fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         let [Operation::Write([0x28]), Operation::Read(buf @ [_, _, _, _, _, _])] = ops else {
+    /// #             panic!("expected a single write of 0x28 followed by a 6 byte read");
+    /// #         };
+    /// #         buf.copy_from_slice(&[0x01, 0x00, 0x02, 0x00, 0xFD, 0xFF]);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// # let fut = core::pin::pin!(async {
+    /// // A single transaction writing 0x28 followed by a 6 byte read
+    /// let sample: AccelSample = i2c::r#async::read_registers_burst(&mut device, 0x19)
+    ///     .await
+    ///     .unwrap();
+    /// assert_eq!((sample.x, sample.y, sample.z), (1, 2, -3));
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    /// ```
+    pub async fn read_registers_burst<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        R: ReadableRegister,
+    {
+        read_register(device, device_addr).await
+    }
+
+    /// Read a number of samples from a FIFO data register of an I2C device.
+    ///
+    /// FIFO data registers, such as the FIFO output of an IMU or the receive holding register of a
+    /// UART bridge, are read by repeatedly addressing the same register. This function reads
+    /// `count` samples, each the length of the register's [`Array`](FromByteArray::Array), into
+    /// the start of `out`, without deserializing them. With [`FifoMode::Burst`], the register ID
+    /// is sent once and all samples are read in a single transaction. With
+    /// [`FifoMode::PerSample`], each sample is read in its own transaction, for devices that
+    /// require the register ID before every sample.
+    ///
+    /// Reading a `count` of zero performs no bus transactions.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `out` - The buffer to read the samples into
+    /// * `count` - The number of samples to read
+    /// * `mode` - Whether to read all samples in one transaction or one transaction per sample
+    ///
+    /// # Errors
+    /// * `FifoError::BufferTooSmall` - `out` can not hold `count` samples
+    /// * `FifoError::BusError` - Communication with the device failed, after reading the number of
+    ///   samples it holds
+    ///
+    /// # Returns
+    /// The number of samples read, which is always `count`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::{register, i2c, FifoMode, FromByteArray, ReadableRegister};
+    /// # #[register(0x3Fu8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct FifoData;
+    /// # impl FromByteArray for FifoData {
+    /// #     type Array = [u8; 6];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { Ok(Self) }
+    /// # }
+    /// async fn drain<D: I2c<u8>>(device: &mut D, available: usize) {
+    ///     let mut samples = [0; 6 * 32];
+    ///     i2c::r#async::read_fifo::<_, _, FifoData>(device, 0x4D, &mut samples, available.min(32), FifoMode::Burst)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn read_fifo<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        out: &mut [u8],
+        count: usize,
+        mode: FifoMode,
+    ) -> Result<usize, FifoError<D::Error>>
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy,
+        D: embedded_hal_async::i2c::I2c<A>,
+        R: ReadableRegister,
+    {
+        let required = crate::fifo_len::<<R as FromByteArray>::Array, _>(count, out.len())?;
+        if required == 0 {
+            return Ok(0);
+        }
+        let out = &mut out[..required];
+        let len = <R as FromByteArray>::Array::LEN;
+
+        let reg_id = R::readable_id().to_id_bytes();
+
+        match mode {
+            FifoMode::Burst => device
+                .write_read(device_addr, reg_id.as_ref(), out)
+                .await
+                .map_err(|error| FifoError::BusError { error, samples: 0 })?,
+            FifoMode::PerSample => {
+                for (samples, sample) in out.chunks_exact_mut(len).enumerate() {
+                    device
+                        .write_read(device_addr, reg_id.as_ref(), sample)
+                        .await
+                        .map_err(|error| FifoError::BusError { error, samples })?;
+                }
+            }
+        }
+
+        trace!(
+            "i2c: read fifo: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            out
+        );
+
+        Ok(count)
+    }
+
+    /// Repeatedly read a register from an I2C device until its value satisfies a condition.
+    ///
+    /// The register is read using [`read_register`] up to `max_attempts` times, waiting
+    /// `interval_us` microseconds between each attempt. The first register value for which
+    /// `condition` returns `true` is returned.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `delay` - The delay provider used to wait between attempts
+    /// * `interval_us` - The number of microseconds to wait between attempts
+    /// * `max_attempts` - The maximum number of times the register will be read
+    /// * `condition` - A predicate that returns `true` once the register holds the desired value
+    ///
+    /// # Errors
+    /// * `PollError::BusError` - Communication with the device failed
+    /// * `PollError::DeserializationError` - Failed to convert received bytes into register value
+    /// * `PollError::Timeout` - No register value satisfied `condition` within `max_attempts` reads
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+    /// # use regiface::{register, i2c, ReadableRegister, FromByteArray};
+    /// # #[register(0x27u8)]
+    /// # #[derive(ReadableRegister, Debug)]
+    /// # struct Status { ready: bool }
+    /// # impl FromByteArray for Status {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self { ready: bytes[0] & 0x01 != 0 }) }
+    /// # }
+    /// async fn wait_until_ready<D: I2c<u8>, T: DelayNs>(device: &mut D, delay: &mut T) {
+    ///     let status: Status = i2c::r#async::poll_register(device, 0x48, delay, 1_000, 10, |s: &Status| s.ready).await.unwrap();
+    /// }
+    /// ```
+    pub async fn poll_register<D, A, T, R, F>(
+        device: &mut D,
+        device_addr: A,
+        delay: &mut T,
+        interval_us: u32,
+        max_attempts: usize,
+        mut condition: F,
+    ) -> Result<R, PollError<D::Error, R::Error, R>>
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy,
+        D: embedded_hal_async::i2c::I2c<A>,
+        T: embedded_hal_async::delay::DelayNs,
+        R: ReadableRegister,
+        F: FnMut(&R) -> bool,
+    {
+        let mut last = None;
+
+        for attempt in 0..max_attempts {
+            if attempt > 0 {
+                delay.delay_us(interval_us).await;
+            }
+
+            let register: R = read_register(device, device_addr).await?;
+            if condition(&register) {
+                return Ok(register);
+            }
+            last = Some(register);
+        }
+
+        Err(PollError::Timeout { last })
+    }
+
+    /// Reads a register, first waiting `wait_us` microseconds if nonzero, returning the bus and
+    /// delay along with the result for the next sample of a [`poll_stream`]
+    #[cfg(feature = "stream")]
+    #[allow(clippy::type_complexity)]
+    async fn sample<'a, D, A, T, R>(
+        device: &'a mut D,
+        device_addr: A,
+        delay: &'a mut T,
+        wait_us: u32,
+    ) -> (
+        Result<R, ReadRegisterError<D::Error, R::Error>>,
+        (&'a mut D, &'a mut T),
+    )
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy,
+        D: embedded_hal_async::i2c::I2c<A>,
+        T: embedded_hal_async::delay::DelayNs,
+        R: ReadableRegister,
+    {
+        if wait_us > 0 {
+            delay.delay_us(wait_us).await;
+        }
+        (read_register(device, device_addr).await, (device, delay))
+    }
+
+    /// Periodically read a register value from an I2C device as a
+    /// [`Stream`](futures_core::Stream).
+    ///
+    /// The register is read as soon as the stream is first polled, then again each time the stream
+    /// is polled after waiting `period_us` microseconds, thus the period is measured from the end
+    /// of one read to the start of the next. Errors are yielded like any other item rather than
+    /// ending the stream, leaving the consumer to decide whether to continue. The stream never ends
+    /// on its own, and stops reading once dropped.
+    ///
+    /// This function is only available when the `stream` feature is enabled.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `delay` - The delay provider used to wait between reads
+    /// * `period_us` - The number of microseconds to wait between reads
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::ErrorKind;
+    /// # use embedded_hal_async::delay::DelayNs;
+    /// # use futures_core::Stream;
+    /// use regiface::{register, i2c, FromByteArray, ReadableRegister};
+    /// # #[register(0x05u8)]
+    /// # #[derive(ReadableRegister, Debug)]
+    /// # struct Temperature(u8);
+    /// # impl FromByteArray for Temperature {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # struct NoDelay;
+    /// # impl DelayNs for NoDelay { async fn delay_ns(&mut self, _: u32) {} }
+    /// # struct Sensor(u8);
+    /// # impl embedded_hal_async::i2c::ErrorType for Sensor { type Error = ErrorKind; }
+    /// # impl embedded_hal_async::i2c::I2c for Sensor {
+    /// #     async fn transaction(&mut self, _: u8, ops: &mut [embedded_hal_async::i2c::Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         self.0 += 1;
+    /// #         if self.0 == 2 { return Err(ErrorKind::ArbitrationLoss); }
+    /// #         if let [_, embedded_hal_async::i2c::Operation::Read(buf)] = ops { buf[0] = 20 + self.0; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let (mut device, mut delay) = (Sensor(0), NoDelay);
+    /// # let fut = core::pin::pin!(async {
+    /// let mut samples = core::pin::pin!(i2c::r#async::poll_stream::<_, _, _, Temperature>(&mut device, 0x48, &mut delay, 1_000_000));
+    /// # let mut next = async || core::future::poll_fn(|cx| samples.as_mut().poll_next(cx)).await.unwrap();
+    ///
+    /// // A failed read is yielded, and sampling continues
+    /// assert_eq!(next().await.unwrap().0, 21);
+    /// assert!(next().await.is_err());
+    /// assert_eq!(next().await.unwrap().0, 23);
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    /// ```
+    #[cfg(feature = "stream")]
+    pub fn poll_stream<'a, D, A, T, R>(
+        device: &'a mut D,
+        device_addr: A,
+        delay: &'a mut T,
+        period_us: u32,
+    ) -> impl futures_core::Stream<Item = Result<R, ReadRegisterError<D::Error, R::Error>>> + 'a
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy + 'a,
+        D: embedded_hal_async::i2c::I2c<A>,
+        T: embedded_hal_async::delay::DelayNs,
+        R: ReadableRegister + 'a,
+    {
+        crate::stream::Sampler::new(
+            sample(device, device_addr, delay, 0),
+            move |(device, delay)| sample(device, device_addr, delay, period_us),
+        )
+    }
+
+    /// Read a register value from an I2C device, retrying according to a [`Policy`].
+    ///
+    /// The register is read with [`read_register`] up to [`Policy::max_attempts`] times, waiting
+    /// [`Policy::delay_us`] microseconds after each failed attempt. Only errors that are
+    /// [transient](crate::errors::ErrorClass::is_transient) are retried, thus a register value that
+    /// could not be deserialized is returned immediately.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `delay` - The delay provider used to wait between attempts
+    /// * `policy` - The policy determining the number of attempts and the waits between them
+    ///
+    /// # Errors
+    /// Returns the error of the last attempt, along with the number of attempts made, if no attempt
+    /// succeeded.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+    /// # use regiface::{register, i2c, retry::Policy, ReadableRegister, FromByteArray};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister, Debug)]
+    /// # struct ChipId(u8);
+    /// # impl FromByteArray for ChipId {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// async fn identify<D: I2c<u8>, T: DelayNs>(device: &mut D, delay: &mut T) -> u8 {
+    ///     let policy = Policy::exponential(4, 500, 2);
+    ///     let id: ChipId = i2c::r#async::read_register_retry(device, 0x48, delay, &policy).await.unwrap();
+    ///     id.0
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn read_register_retry<D, A, T, R>(
+        device: &mut D,
+        device_addr: A,
+        delay: &mut T,
+        policy: &Policy,
+    ) -> Result<R, RetryError<ReadRegisterError<D::Error, R::Error>>>
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy,
+        D: embedded_hal_async::i2c::I2c<A>,
+        T: embedded_hal_async::delay::DelayNs,
+        R: ReadableRegister,
+    {
+        retry_policy_async(policy, delay, async || {
+            read_register(device, device_addr).await
+        })
+        .await
+    }
+
+    /// Write a register value to an I2C device, retrying according to a [`Policy`].
+    ///
+    /// The register is written with [`write_register`] up to [`Policy::max_attempts`] times,
+    /// waiting [`Policy::delay_us`] microseconds after each failed attempt. Only errors that are
+    /// [transient](crate::errors::ErrorClass::is_transient) are retried, thus a register value that
+    /// could not be serialized is returned immediately. The register value is cloned for each
+    /// attempt.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `register` - The register value to write
+    /// * `delay` - The delay provider used to wait between attempts
+    /// * `policy` - The policy determining the number of attempts and the waits between them
+    ///
+    /// # Errors
+    /// Returns the error of the last attempt, along with the number of attempts made, if no attempt
+    /// succeeded.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+    /// # use regiface::{register, i2c, retry::Policy, WritableRegister, ToByteArray};
+    /// # #[register(0x20u8)]
+    /// # #[derive(WritableRegister, Clone)]
+    /// # struct Control(u8);
+    /// # impl ToByteArray for Control {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.0]) }
+    /// # }
+    /// async fn power_up<D: I2c<u8>, T: DelayNs>(device: &mut D, delay: &mut T) {
+    ///     let policy = Policy::fixed(3, 1_000);
+    ///     i2c::r#async::write_register_retry(device, 0x48, Control(0x01), delay, &policy).await.unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn write_register_retry<D, A, T, R>(
+        device: &mut D,
+        device_addr: A,
+        register: R,
+        delay: &mut T,
+        policy: &Policy,
+    ) -> Result<(), RetryError<WriteRegisterError<D::Error, R::Error>>>
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy,
+        D: embedded_hal_async::i2c::I2c<A>,
+        T: embedded_hal_async::delay::DelayNs,
+        R: WritableRegister + Clone,
+    {
+        retry_policy_async(policy, delay, async || {
+            write_register(device, device_addr, register.clone()).await
+        })
+        .await
+    }
+
+    /// Invoke a command on an I2C device, retrying according to a [`Policy`].
+    ///
+    /// The command is invoked with [`invoke_command`] up to [`Policy::max_attempts`] times,
+    /// waiting [`Policy::delay_us`] microseconds after each failed attempt. Only errors that are
+    /// [transient](crate::errors::ErrorClass::is_transient) are retried. The command is cloned for
+    /// each attempt, thus a command that is not idempotent may take effect more than once if an
+    /// attempt fails after the device received it.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `cmd` - The command to invoke
+    /// * `delay` - The delay provider used to wait between attempts
+    /// * `policy` - The policy determining the number of attempts and the waits between them
+    ///
+    /// # Errors
+    /// Returns the error of the last attempt, along with the number of attempts made, if no attempt
+    /// succeeded.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+    /// # use regiface::{i2c, retry::Policy, Command, FromByteArray, NoParameters};
+    /// # #[derive(Clone)]
+    /// # struct ReadSerial;
+    /// # impl Command for ReadSerial {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = u32;
+    /// #     fn id() -> Self::IdType { 0x36 }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// async fn serial<D: I2c<u8>, T: DelayNs>(device: &mut D, delay: &mut T) -> u32 {
+    ///     let policy = Policy::exponential(3, 1_000, 4);
+    ///     i2c::r#async::invoke_command_retry(device, 0x48, ReadSerial, delay, &policy).await.unwrap()
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_command_retry<D, A, T, C>(
+        device: &mut D,
+        device_addr: A,
+        cmd: C,
+        delay: &mut T,
+        policy: &Policy,
+    ) -> Result<
+        C::ResponseParameters,
+        RetryError<
+            CommandError<
+                D::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        >,
+    >
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy,
+        D: embedded_hal_async::i2c::I2c<A>,
+        T: embedded_hal_async::delay::DelayNs,
+        C: Command + Clone,
+    {
+        retry_policy_async(policy, delay, async || {
+            invoke_command(device, device_addr, cmd.clone()).await
+        })
+        .await
+    }
+
+    /// Read a register value from an I2C device, abandoning the read if it does not complete in
+    /// time.
+    ///
+    /// The register is read with [`read_register`], raced against a delay of `timeout_us`
+    /// microseconds. If the timeout elapses, the operation is abandoned, possibly in the middle of
+    /// a bus transaction, thus the state of the device and bus is unknown. See [`with_timeout`] for
+    /// details.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `delay` - The delay used to measure the timeout
+    /// * `timeout_us` - The maximum number of microseconds to wait for the read to complete
+    ///
+    /// # Errors
+    /// * `TimeoutError::Error` - The read failed with a [`ReadRegisterError`]
+    /// * `TimeoutError::Elapsed` - The read did not complete within `timeout_us` microseconds
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+    /// # use regiface::{register, i2c, ReadableRegister, FromByteArray};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister, Debug)]
+    /// # struct ChipId(u8);
+    /// # impl FromByteArray for ChipId {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// async fn identify<D: I2c<u8>, T: DelayNs>(device: &mut D, delay: &mut T) -> u8 {
+    ///     let id: ChipId = i2c::r#async::read_register_timeout(device, 0x48, delay, 10_000).await.unwrap();
+    ///     id.0
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn read_register_timeout<D, A, T, R>(
+        device: &mut D,
+        device_addr: A,
+        delay: &mut T,
+        timeout_us: u32,
+    ) -> Result<R, TimeoutError<ReadRegisterError<D::Error, R::Error>>>
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        T: embedded_hal_async::delay::DelayNs,
+        R: ReadableRegister,
+    {
+        with_timeout(delay, timeout_us, read_register(device, device_addr)).await
+    }
+
+    /// Write a register value to an I2C device, abandoning the write if it does not complete in
+    /// time.
+    ///
+    /// The register is written with [`write_register`], raced against a delay of `timeout_us`
+    /// microseconds. If the timeout elapses, the operation is abandoned, possibly in the middle of
+    /// a bus transaction, thus the state of the device and bus is unknown. See [`with_timeout`] for
+    /// details.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `register` - The register value to write
+    /// * `delay` - The delay used to measure the timeout
+    /// * `timeout_us` - The maximum number of microseconds to wait for the write to complete
+    ///
+    /// # Errors
+    /// * `TimeoutError::Error` - The write failed with a [`WriteRegisterError`]
+    /// * `TimeoutError::Elapsed` - The write did not complete within `timeout_us` microseconds
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+    /// # use regiface::{register, i2c, WritableRegister, ToByteArray};
+    /// # #[register(0x20u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct Control(u8);
+    /// # impl ToByteArray for Control {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.0]) }
+    /// # }
+    /// async fn power_up<D: I2c<u8>, T: DelayNs>(device: &mut D, delay: &mut T) {
+    ///     i2c::r#async::write_register_timeout(device, 0x48, Control(0x01), delay, 10_000).await.unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn write_register_timeout<D, A, T, R>(
+        device: &mut D,
+        device_addr: A,
+        register: R,
+        delay: &mut T,
+        timeout_us: u32,
+    ) -> Result<(), TimeoutError<WriteRegisterError<D::Error, R::Error>>>
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        T: embedded_hal_async::delay::DelayNs,
+        R: WritableRegister,
+    {
+        with_timeout(
+            delay,
+            timeout_us,
+            write_register(device, device_addr, register),
+        )
+        .await
+    }
+
+    /// Invoke a command on an I2C device, abandoning the command if it does not complete in
+    /// time.
+    ///
+    /// The command is invoked with [`invoke_command`], raced against a delay of `timeout_us`
+    /// microseconds. If the timeout elapses, the operation is abandoned, possibly in the middle of
+    /// a bus transaction, thus the state of the device and bus is unknown. See [`with_timeout`] for
+    /// details.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `cmd` - The command to invoke
+    /// * `delay` - The delay used to measure the timeout
+    /// * `timeout_us` - The maximum number of microseconds to wait for the command to complete
+    ///
+    /// # Errors
+    /// * `TimeoutError::Error` - The command failed with a [`CommandError`]
+    /// * `TimeoutError::Elapsed` - The command did not complete within `timeout_us` microseconds
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+    /// use regiface::{errors::TimeoutError, i2c, Command, NoParameters};
+    /// # struct ReadSerial;
+    /// # impl Command for ReadSerial {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = u8;
+    /// #     fn id() -> Self::IdType { 0x36 }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// # struct NoDelay;
+    /// # impl DelayNs for NoDelay { async fn delay_ns(&mut self, _: u32) {} }
+    /// # struct Wedged;
+    /// # impl embedded_hal_async::i2c::ErrorType for Wedged { type Error = core::convert::Infallible; }
+    /// # impl I2c for Wedged {
+    /// #     async fn transaction(&mut self, _: u8, _: &mut [embedded_hal_async::i2c::Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         core::future::pending().await
+    /// #     }
+    /// # }
+    /// # let fut = core::pin::pin!(async {
+    /// // The device never completes the transaction
+    /// let mut device = Wedged;
+    /// let result = i2c::r#async::invoke_command_timeout(&mut device, 0x48, ReadSerial, &mut NoDelay, 1_000).await;
+    /// assert!(matches!(result, Err(TimeoutError::Elapsed)));
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_command_timeout<D, A, T, C>(
+        device: &mut D,
+        device_addr: A,
+        cmd: C,
+        delay: &mut T,
+        timeout_us: u32,
+    ) -> Result<
+        C::ResponseParameters,
+        TimeoutError<
+            CommandError<
+                D::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        >,
+    >
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        T: embedded_hal_async::delay::DelayNs,
+        C: Command,
+    {
+        with_timeout(delay, timeout_us, invoke_command(device, device_addr, cmd)).await
+    }
+
+    /// Read a register value from an I2C device once the device signals an interrupt.
+    ///
+    /// This function awaits the given edge or level on `pin`, such as the rising edge of a data
+    /// ready pin, then reads the register as [`read_register`] does.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `pin` - The interrupt pin of the device
+    /// * `edge` - The edge or level of `pin` to await before reading the register
+    ///
+    /// # Errors
+    /// * `InterruptReadError::PinError` - Waiting on the interrupt pin failed
+    /// * `InterruptReadError::BusError` - Communication with the device failed
+    /// * `InterruptReadError::DeserializationError` - Failed to convert received bytes into register value
+    ///
+    /// # Example
+    /// ```
+    /// # use core::{cell::RefCell, convert::Infallible};
+    /// # use embedded_hal_async::{digital::{self, Wait}, i2c};
+    /// use regiface::{interrupt::Edge, register, i2c as regiface_i2c, FromByteArray, ReadableRegister};
+    /// # #[register(0x28u8)]
+    /// # #[derive(ReadableRegister, Debug, PartialEq)]
+    /// # struct Sample(u8);
+    /// # impl FromByteArray for Sample {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # struct Pin<'a>(&'a RefCell<Vec<&'static str>>);
+    /// # impl embedded_hal::digital::ErrorType for Pin<'_> { type Error = Infallible; }
+    /// # impl Wait for Pin<'_> {
+    /// #     async fn wait_for_high(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// #     async fn wait_for_low(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// #     async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+    /// #         self.0.borrow_mut().push("rising edge");
+    /// #         Ok(())
+    /// #     }
+    /// #     async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// #     async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// # }
+    /// # struct Bus<'a>(&'a RefCell<Vec<&'static str>>);
+    /// # impl i2c::ErrorType for Bus<'_> { type Error = Infallible; }
+    /// # impl i2c::I2c for Bus<'_> {
+    /// #     async fn transaction(&mut self, _: u8, ops: &mut [i2c::Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         self.0.borrow_mut().push("read");
+    /// #         if let [_, i2c::Operation::Read(buf)] = ops { buf[0] = self.0.borrow().len() as u8; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// let log = RefCell::new(Vec::new());
+    /// let (mut pin, mut device) = (Pin(&log), Bus(&log));
+    /// # let fut = core::pin::pin!(async {
+    /// let sample: Sample = regiface_i2c::r#async::read_register_on_interrupt(&mut device, 0x6A, &mut pin, Edge::Rising)
+    ///     .await
+    ///     .unwrap();
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    ///
+    /// // The register is only read once the interrupt is signaled
+    /// assert_eq!(*log.borrow(), ["rising edge", "read"]);
+    /// ```
+    pub async fn read_register_on_interrupt<D, A, P, R>(
+        device: &mut D,
+        device_addr: A,
+        pin: &mut P,
+        edge: Edge,
+    ) -> Result<R, InterruptReadError<P::Error, D::Error, R::Error>>
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy,
+        D: embedded_hal_async::i2c::I2c<A>,
+        P: embedded_hal_async::digital::Wait,
+        R: ReadableRegister,
+    {
+        wait_for(pin, edge)
+            .await
+            .map_err(InterruptReadError::PinError)?;
+
+        Ok(read_register(device, device_addr).await?)
+    }
+
+    /// Continuously read a register value from an I2C device each time the device signals an
+    /// interrupt.
+    ///
+    /// This function repeatedly awaits the given edge or level on `pin`, reads the register as
+    /// [`read_register`] does, and passes the value to `on_value`. Streaming continues until
+    /// `on_value` returns [`ControlFlow::Break`], whose value is returned, or an error occurs.
+    ///
+    /// When awaiting a level rather than an edge, the register read must clear the interrupt, as
+    /// the wait otherwise completes immediately and the register is read continuously.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `pin` - The interrupt pin of the device
+    /// * `edge` - The edge or level of `pin` to await before each register read
+    /// * `on_value` - Called with each register value read, returning whether to continue
+    ///
+    /// # Errors
+    /// * `InterruptReadError::PinError` - Waiting on the interrupt pin failed
+    /// * `InterruptReadError::BusError` - Communication with the device failed
+    /// * `InterruptReadError::DeserializationError` - Failed to convert received bytes into register value
+    ///
+    /// # Example
+    /// ```
+    /// # use core::{cell::RefCell, convert::Infallible, ops::ControlFlow};
+    /// # use embedded_hal_async::{digital::{self, Wait}, i2c};
+    /// use regiface::{interrupt::Edge, register, i2c as regiface_i2c, FromByteArray, ReadableRegister};
+    /// # #[register(0x28u8)]
+    /// # #[derive(ReadableRegister, Debug, PartialEq)]
+    /// # struct Sample(u8);
+    /// # impl FromByteArray for Sample {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # struct Pin<'a>(&'a RefCell<Vec<&'static str>>);
+    /// # impl embedded_hal::digital::ErrorType for Pin<'_> { type Error = Infallible; }
+    /// # impl Wait for Pin<'_> {
+    /// #     async fn wait_for_high(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// #     async fn wait_for_low(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// #     async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+    /// #         self.0.borrow_mut().push("rising edge");
+    /// #         Ok(())
+    /// #     }
+    /// #     async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// #     async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// # }
+    /// # struct Bus<'a>(&'a RefCell<Vec<&'static str>>);
+    /// # impl i2c::ErrorType for Bus<'_> { type Error = Infallible; }
+    /// # impl i2c::I2c for Bus<'_> {
+    /// #     async fn transaction(&mut self, _: u8, ops: &mut [i2c::Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         self.0.borrow_mut().push("read");
+    /// #         if let [_, i2c::Operation::Read(buf)] = ops { buf[0] = self.0.borrow().len() as u8; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// let log = RefCell::new(Vec::new());
+    /// let (mut pin, mut device) = (Pin(&log), Bus(&log));
+    /// # let fut = core::pin::pin!(async {
+    /// let mut samples = Vec::new();
+    /// let count = regiface_i2c::r#async::stream_register_on_interrupt(&mut device, 0x6A, &mut pin, Edge::Rising, |sample: Sample| {
+    ///     samples.push(sample.0);
+    ///     if samples.len() == 3 {
+    ///         ControlFlow::Break(samples.len())
+    ///     } else {
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// })
+    /// .await
+    /// .unwrap();
+    /// assert_eq!(count, 3);
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    ///
+    /// assert_eq!(
+    ///     *log.borrow(),
+    ///     ["rising edge", "read", "rising edge", "read", "rising edge", "read"]
+    /// );
+    /// ```
+    pub async fn stream_register_on_interrupt<D, A, P, R, T, F>(
+        device: &mut D,
+        device_addr: A,
+        pin: &mut P,
+        edge: Edge,
+        mut on_value: F,
+    ) -> Result<T, InterruptReadError<P::Error, D::Error, R::Error>>
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy,
+        D: embedded_hal_async::i2c::I2c<A>,
+        P: embedded_hal_async::digital::Wait,
+        R: ReadableRegister,
+        F: FnMut(R) -> ControlFlow<T>,
+    {
+        loop {
+            let register: R = read_register_on_interrupt(device, device_addr, pin, edge).await?;
+            if let ControlFlow::Break(value) = on_value(register) {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// Check whether a device acknowledges the given I2C address.
+    ///
+    /// A single byte is read from the address and discarded. A device that does not acknowledge
+    /// its address is reported as absent rather than as an error, allowing a bus to be scanned for
+    /// devices. Reading a byte may have side effects on some devices, such as advancing an internal
+    /// register pointer.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C bus to communicate over
+    /// * `device_addr` - The I2C address to probe
+    ///
+    /// # Errors
+    /// Returns the bus error if communication failed for any reason other than the address not
+    /// being acknowledged.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::i2c;
+    /// async fn count_devices<D: I2c<u8>>(bus: &mut D) -> usize {
+    ///     let mut found = 0;
+    ///     for addr in 0x08..0x78 {
+    ///         if let Ok(true) = i2c::r#async::probe(bus, addr).await {
+    ///             found += 1;
+    ///         }
+    ///     }
+    ///     found
+    /// }
+    /// ```
+    pub async fn probe<D, A>(device: &mut D, device_addr: A) -> Result<bool, D::Error>
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+    {
+        match device.read(device_addr, &mut [0]).await {
+            Ok(()) => Ok(true),
+            Err(e) => match embedded_hal::i2c::Error::kind(&e) {
+                embedded_hal::i2c::ErrorKind::NoAcknowledge(_) => Ok(false),
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Scan the 7-bit address space of an I2C bus for devices.
+    ///
+    /// Each address is checked with [`probe`], and those that acknowledge are collected into the
+    /// returned [`Addresses`]. The addresses reserved by the I2C specification, `0x00..=0x07` and
+    /// `0x78..=0x7F`, are skipped unless `include_reserved` is set.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C bus to scan
+    /// * `include_reserved` - Whether the reserved addresses are also probed
+    ///
+    /// # Errors
+    /// Returns the bus error if communication with any address failed for any reason other than
+    /// the address not being acknowledged, without probing the remaining addresses.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::i2c;
+    /// async fn has_rtc<D: I2c<u8>>(bus: &mut D) -> bool {
+    ///     let found = i2c::r#async::scan(bus, false).await.unwrap();
+    ///     found.contains(0x68)
+    /// }
+    /// ```
+    pub async fn scan<D>(device: &mut D, include_reserved: bool) -> Result<Addresses, D::Error>
+    where
+        D: embedded_hal_async::i2c::I2c,
+    {
+        let mut found = Addresses::default();
+        for addr in scan_range(include_reserved) {
+            if probe(device, addr).await? {
+                found.insert(addr);
+            }
+        }
+        Ok(found)
+    }
+
+    /// Verify the identity of an I2C device by reading a register and comparing it to an expected value.
+    ///
+    /// The register `R` is read as with [`read_register`], and compared to `expected`, returning
+    /// the value read if they are equal. This is equivalent to [`verify_whoami`], for registers
+    /// whose expected value is only known at runtime, such as drivers supporting several
+    /// revisions of a device.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `expected` - The value the register holds on the expected device
+    ///
+    /// # Errors
+    /// * `WhoAmIError::BusError` - Communication with the device failed
+    /// * `WhoAmIError::DeserializationError` - Failed to convert received bytes into register value
+    /// * `WhoAmIError::Mismatch` - The register did not hold the expected value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::{register, i2c, FromByteArray, ReadableRegister};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister, Debug, PartialEq)]
+    /// # struct ChipId(u8);
+    /// # impl FromByteArray for ChipId {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// async fn probe_revision<D: I2c<u8>>(device: &mut D) -> bool {
+    ///     i2c::r#async::verify_device_id(device, 0x6A, ChipId(0x6A)).await.is_ok()
+    /// }
+    /// ```
+    pub async fn verify_device_id<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        expected: R,
+    ) -> Result<R, WhoAmIError<D::Error, R::Error, R>>
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        R: ReadableRegister + PartialEq,
+    {
+        let found: R = read_register(device, device_addr).await?;
+        if found != expected {
+            return Err(WhoAmIError::Mismatch { expected, found });
+        }
+        Ok(found)
+    }
+
+    /// Verify the identity of an I2C device by reading its [`WhoAmI`] register.
+    ///
+    /// The register `R` is read as with [`read_register`], and compared to [`WhoAmI::expected`].
+    /// This is typically performed once during driver initialization, to detect a missing device
+    /// or a different device than the one the driver was written for.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    ///
+    /// # Errors
+    /// * `WhoAmIError::BusError` - Communication with the device failed
+    /// * `WhoAmIError::DeserializationError` - Failed to convert received bytes into register value
+    /// * `WhoAmIError::Mismatch` - The register did not hold the expected value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::{register, i2c, FromByteArray, ReadableRegister, WhoAmI};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister, Debug, PartialEq)]
+    /// # struct ChipId(u8);
+    /// # impl FromByteArray for ChipId {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # impl WhoAmI for ChipId {
+    /// #     fn expected() -> Self { Self(0x6A) }
+    /// # }
+    /// async fn probe<D: I2c<u8>>(device: &mut D) -> bool {
+    ///     i2c::r#async::verify_whoami::<_, _, ChipId>(device, 0x6A).await.is_ok()
+    /// }
+    /// ```
+    pub async fn verify_whoami<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+    ) -> Result<R, WhoAmIError<D::Error, R::Error, R>>
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        R: WhoAmI,
+    {
+        verify_device_id(device, device_addr, R::expected()).await
+    }
+
+    /// Write a register value to an I2C device.
+    ///
+    /// This function performs a write I2C transaction, sending both the register ID
+    /// and the serialized register value. The operation is atomic, using the device's
+    /// transaction capability to ensure both writes occur without interruption.
+    ///
+    /// The register value is serialized with [`WritableRegister::to_register_bytes`], clearing any
+    /// reserved bits and setting any required bits before transmission.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `register` - The register value to write
+    ///
+    /// # Errors
+    /// * `WriteRegisterError::BusError` - Communication with the device failed
+    /// * `WriteRegisterError::SerializationError` - Failed to convert register value to bytes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::{register, i2c, WritableRegister, ToByteArray};
+    /// #[register(42u8)]
+    /// #[derive(WritableRegister)]
+    /// # struct ConfigRegister{};
+    /// # impl ToByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([0]) }
+    /// # }
+    /// async fn configure<D: I2c<u8>>(device: &mut D) {
+    ///     i2c::r#async::write_register(device, 0x48, ConfigRegister{ /* ... */}).await.unwrap();
+    /// }
+    /// ```
+    pub async fn write_register<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        R: WritableRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = R::writeable_id().to_id_bytes();
+
+        device
+            .transaction(
+                device_addr,
+                &mut write_operations(reg_id.as_ref(), buf.as_ref()),
+            )
+            .await
+            .map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "i2c: write register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        Ok(())
+    }
+
+    /// Write a number of samples to a FIFO data register of an I2C device.
+    ///
+    /// FIFO data registers, such as the input FIFO of a DAC or the data register of a bootloader,
+    /// are written by repeatedly addressing the same register. This function writes the first
+    /// `count` samples of `data`, each the length of the register's [`Array`](ToByteArray::Array),
+    /// without serializing them. The samples are split across as few transactions as possible,
+    /// each consisting of the register ID followed by as many samples as fit within
+    /// `max_transaction_len` bytes.
+    ///
+    /// Writing a `count` of zero performs no bus transactions.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `data` - The buffer holding the samples to write
+    /// * `count` - The number of samples to write
+    /// * `max_transaction_len` - The maximum number of bytes written in a single transaction,
+    ///   including the register ID
+    ///
+    /// # Errors
+    /// * `FifoError::BufferTooSmall` - `data` does not hold `count` samples
+    /// * `FifoError::TransactionTooSmall` - `max_transaction_len` can not hold the register ID and
+    ///   a single sample
+    /// * `FifoError::BusError` - Communication with the device failed, after committing the number
+    ///   of samples it holds, from which the write may be resumed
+    ///
+    /// # Returns
+    /// The number of samples written, which is always `count`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::{register, i2c, ToByteArray, WritableRegister};
+    /// # #[register(0x0Du8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct Waveform;
+    /// # impl ToByteArray for Waveform {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([0; 2]) }
+    /// # }
+    /// async fn load<D: I2c<u8>>(device: &mut D, samples: &[u8]) {
+    ///     i2c::r#async::write_fifo::<_, _, Waveform>(device, 0x4C, samples, samples.len() / 2, 32)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn write_fifo<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        data: &[u8],
+        count: usize,
+        max_transaction_len: usize,
+    ) -> Result<usize, FifoError<D::Error>>
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy,
+        D: embedded_hal_async::i2c::I2c<A>,
+        R: WritableRegister,
+    {
+        let required = crate::fifo_len::<<R as ToByteArray>::Array, _>(count, data.len())?;
+        let len = <R as ToByteArray>::Array::LEN;
+
+        let reg_id = R::writeable_id().to_id_bytes();
+        let per_transaction =
+            crate::fifo_samples_per_transaction(reg_id.as_ref().len(), len, max_transaction_len)?;
+
+        if required == 0 {
+            return Ok(0);
+        }
+        let data = &data[..required];
+
+        for (index, samples) in data.chunks(per_transaction * len).enumerate() {
+            device
+                .transaction(device_addr, &mut write_operations(reg_id.as_ref(), samples))
+                .await
+                .map_err(|error| FifoError::BusError {
+                    error,
+                    samples: index * per_transaction,
+                })?;
+        }
+
+        trace!(
+            "i2c: write fifo: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            data
+        );
+
+        Ok(count)
+    }
+
+    /// Write an instance of an indexed register value to an I2C device.
+    ///
+    /// This function behaves exactly like [`write_register`], except that the register ID is
+    /// computed from the provided index using [`IndexedRegister::writeable_indexed_id`].
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `index` - The index of the register instance to write
+    /// * `register` - The register value to write
+    ///
+    /// # Errors
+    /// * `WriteRegisterError::BusError` - Communication with the device failed
+    /// * `WriteRegisterError::SerializationError` - Failed to convert register value to bytes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::{register, i2c, IndexedRegister, WritableRegister, ToByteArray};
+    /// # #[register(0x06u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct LedOnOff { on: u16, off: u16 }
+    /// # impl IndexedRegister for LedOnOff {
+    /// #     type Index = u8;
+    /// #     fn indexed_id(channel: Self::Index) -> Self::IdType { Self::ID + 4 * channel }
+    /// # }
+    /// # impl ToByteArray for LedOnOff {
+    /// #     type Array = [u8; 4];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    /// #         let [on_l, on_h] = self.on.to_le_bytes();
+    /// #         let [off_l, off_h] = self.off.to_le_bytes();
+    /// #         Ok([on_l, on_h, off_l, off_h])
+    /// #     }
+    /// # }
+    /// async fn set_all_channels<D: I2c>(device: &mut D, off: u16) {
+    ///     for channel in 0..16 {
+    ///         i2c::r#async::write_register_at(device, 0x40, channel, LedOnOff { on: 0, off }).await.unwrap();
+    ///     }
+    /// }
+    /// ```
+    pub async fn write_register_at<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        index: R::Index,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        R: WritableRegister + IndexedRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = R::writeable_indexed_id(index).to_id_bytes();
+
+        device
+            .transaction(
+                device_addr,
+                &mut write_operations(reg_id.as_ref(), buf.as_ref()),
+            )
+            .await
+            .map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "i2c: write register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        Ok(())
+    }
+
+    /// Write a register value to an SMBus device using Packet Error Checking.
+    ///
+    /// This function performs the same transaction as [`write_register`], followed by a PEC byte
+    /// computed as a CRC-8 over the entire transaction, including the address byte, as required by
+    /// the SMBus specification. A device that detects a PEC mismatch will NACK the PEC byte, which
+    /// is reported as a bus error. Only 7-bit addresses are supported, as the address is part of
+    /// the PEC.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The 7-bit I2C address of the target device
+    /// * `register` - The register value to write
+    ///
+    /// # Errors
+    /// * `WriteRegisterError::BusError` - Communication with the device failed
+    /// * `WriteRegisterError::SerializationError` - Failed to convert register value to bytes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::{register, i2c, WritableRegister, ToByteArray};
+    /// # #[register(0x03u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct BatteryMode(u16);
+    /// # impl ToByteArray for BatteryMode {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok(self.0.to_le_bytes()) }
+    /// # }
+    /// async fn configure<D: I2c<u8>>(device: &mut D) {
+    ///     i2c::r#async::write_register_pec(device, 0x0B, BatteryMode(0x6000)).await.unwrap();
+    /// }
+    /// ```
+    pub async fn write_register_pec<D, R>(
+        device: &mut D,
+        device_addr: u8,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal_async::i2c::I2c<u8>,
+        R: WritableRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = R::writeable_id().to_id_bytes();
+        let pec = [pec(&[&[device_addr << 1], reg_id.as_ref(), buf.as_ref()])];
+
+        device
+            .transaction(
+                device_addr,
+                &mut [
+                    Operation::Write(reg_id.as_ref()),
+                    Operation::Write(buf.as_ref()),
+                    Operation::Write(&pec),
+                ],
+            )
+            .await
+            .map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "i2c: write register: id={:02X?} bytes={:02X?} pec={:02X}",
+            reg_id.as_ref(),
+            buf.as_ref(),
+            pec[0]
+        );
+
+        Ok(())
+    }
+
+    /// Write a contiguous block of registers to an I2C device in a single transaction.
+    ///
+    /// Many devices automatically increment their internal register pointer after each byte
+    /// is written, allowing a run of consecutive registers to be written with a single write of
+    /// `[start_id, b0, b1, ...]`. This function performs exactly that write I2C transaction, where
+    /// `R` is a register type whose ID is the address of the first register in the block and whose
+    /// [`Array`](ToByteArray::Array) spans the entire block. Register IDs wider than one byte are
+    /// sent in full, in the byte order produced by their [`ToByteArray`] implementation, before the
+    /// payload.
+    ///
+    /// This behaves identically to [`write_register`], but the dedicated name makes the reliance on
+    /// the device's auto-increment behavior explicit in driver code. Devices that do not
+    /// auto-increment their register pointer must not be written with this function, as every
+    /// payload byte would be written to the starting register.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `register` - The register block value to write
+    ///
+    /// # Errors
+    /// * `WriteRegisterError::BusError` - Communication with the device failed
+    /// * `WriteRegisterError::SerializationError` - Failed to convert register value to bytes
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal_async::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::{register, i2c, WritableRegister, ToByteArray};
+    /// // CTRL_REG1 through CTRL_REG4
+    /// #[register(0x20u8)]
+    /// #[derive(WritableRegister)]
+    /// struct ControlBlock {
+    ///     ctrl: [u8; 4],
+    /// }
+    ///
+    /// impl ToByteArray for ControlBlock {
+    ///     type Array = [u8; 4];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    ///         Ok(self.ctrl)
+    ///     }
+    /// }
+    ///
+    /// // A block of filter coefficients on a device with 16-bit register IDs
+    /// #[register(0x1040u16)]
+    /// #[derive(WritableRegister)]
+    /// struct Coefficients {
+    ///     taps: [u8; 3],
+    /// }
+    ///
+    /// impl ToByteArray for Coefficients {
+    ///     type Array = [u8; 3];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    ///         Ok(self.taps)
+    ///     }
+    /// }
+    ///
+    /// # struct Device(&'static [u8]);
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c for Device {
+    /// #     async fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         // Consecutive writes within a transaction are sent as a single write
+    /// #         let [Operation::Write(id), Operation::Write(payload)] = ops else { panic!() };
+    /// #         assert_eq!([*id, *payload].concat(), self.0);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let fut = core::pin::pin!(async {
+    /// // A single write of [0x20, 0x57, 0x00, 0x40, 0x88]
+    /// # let mut device = Device(&[0x20, 0x57, 0x00, 0x40, 0x88]);
+    /// let block = ControlBlock { ctrl: [0x57, 0x00, 0x40, 0x88] };
+    /// i2c::r#async::write_registers_burst(&mut device, 0x19, block).await.unwrap();
+    ///
+    /// // Register IDs wider than one byte are sent in full, most significant byte first, thus
+    /// // this is a single write of [0x10, 0x40, 0x01, 0x02, 0x03]
+    /// # let mut device = Device(&[0x10, 0x40, 0x01, 0x02, 0x03]);
+    /// let coefficients = Coefficients { taps: [0x01, 0x02, 0x03] };
+    /// i2c::r#async::write_registers_burst(&mut device, 0x19, coefficients).await.unwrap();
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    /// ```
+    pub async fn write_registers_burst<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        R: WritableRegister,
+    {
+        write_register(device, device_addr, register).await
+    }
+
+    /// Write a block of bytes to consecutive registers of an I2C device in a single transaction.
+    ///
+    /// This function performs a single write I2C transaction, sending the starting register ID once
+    /// followed by the entire `payload`. This is useful for loading tables of consecutive
+    /// registers, such as filter coefficients, where defining a register type for the whole block
+    /// is impractical. See [`write_registers_burst`] for a typed alternative.
+    ///
+    /// Some devices only auto-increment their register pointer when a flag bit is set in the
+    /// register address. If `auto_increment` is `Some(mask)`, the mask is OR'd into the first byte
+    /// of the serialized starting register ID. Devices that do not auto-increment their register
+    /// pointer must not be written with this function.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `start_id` - The ID of the first register to be written
+    /// * `payload` - The bytes to write, starting at `start_id`
+    /// * `auto_increment` - An optional flag to set in the first byte of `start_id`
+    ///
+    /// # Errors
+    /// * `D::Error` - Communication with the device failed
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::i2c;
+    /// async fn load_coefficients<D: I2c<u8>>(device: &mut D, coefficients: &[u8]) {
+    ///     i2c::r#async::write_registers_burst_raw(device, 0x1D, 0x30u8, coefficients, Some(0x80)).await.unwrap();
+    /// }
+    /// ```
+    pub async fn write_registers_burst_raw<D, A, I>(
+        device: &mut D,
+        device_addr: A,
+        start_id: I,
+        payload: &[u8],
+        auto_increment: Option<u8>,
+    ) -> Result<(), D::Error>
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        I: id::Id,
+    {
+        let reg_id = id::to_bytes_with_flag(start_id, auto_increment);
+
+        device
+            .transaction(device_addr, &mut write_operations(reg_id.as_ref(), payload))
+            .await?;
+
+        trace!(
+            "i2c: write registers burst: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            payload
+        );
+
+        Ok(())
+    }
+
+    /// Perform an SMBus block read from an I2C device.
+    ///
+    /// This function performs a single I2C transaction, sending the command code, then reading the
+    /// length byte reported by the device followed by the block itself. As the length is not known
+    /// in advance, as many bytes as fit in `buf` are read, and the first `length` of them are
+    /// returned.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `command` - The SMBus command code
+    /// * `buf` - The buffer to read the block into, which bounds the length of the block
+    ///
+    /// # Errors
+    /// * `BlockError::BusError` - Communication with the device failed
+    /// * `BlockError::LengthExceeded` - The device reported a block longer than `buf`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::i2c;
+    /// async fn manufacturer_name<D: I2c<u8>>(device: &mut D) {
+    ///     let mut buf = [0; 32];
+    ///     let name = i2c::r#async::block_read(device, 0x0B, 0x20, &mut buf).await.unwrap();
+    ///     // ...
+    /// }
+    /// ```
+    pub async fn block_read<'a, D, A>(
+        device: &mut D,
+        device_addr: A,
+        command: u8,
+        buf: &'a mut [u8],
+    ) -> Result<&'a mut [u8], BlockError<D::Error>>
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+    {
+        let mut length = [0];
+
+        device
+            .transaction(
+                device_addr,
+                &mut [
+                    Operation::Write(&[command]),
+                    Operation::Read(&mut length),
+                    Operation::Read(buf),
+                ],
+            )
+            .await
+            .map_err(BlockError::BusError)?;
+
+        let length = usize::from(length[0]);
+        if length > buf.len() {
+            return Err(BlockError::LengthExceeded {
+                length,
+                capacity: buf.len(),
+            });
+        }
+
+        trace!(
+            "i2c: block read: command={:02X} bytes={:02X?}",
+            command,
+            &buf[..length]
+        );
+
+        Ok(&mut buf[..length])
+    }
+
+    /// Perform an SMBus block write to an I2C device.
+    ///
+    /// This function performs a single I2C transaction, sending the command code, then the length
+    /// of `data` as a single byte, followed by `data` itself.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `command` - The SMBus command code
+    /// * `data` - The block to write
+    ///
+    /// # Errors
+    /// * `BlockError::BusError` - Communication with the device failed
+    /// * `BlockError::LengthExceeded` - `data` is longer than 255 bytes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::i2c;
+    /// async fn authenticate<D: I2c<u8>>(device: &mut D, challenge: &[u8; 20]) {
+    ///     i2c::r#async::block_write(device, 0x0B, 0x2F, challenge).await.unwrap();
+    /// }
+    /// ```
+    pub async fn block_write<D, A>(
+        device: &mut D,
+        device_addr: A,
+        command: u8,
+        data: &[u8],
+    ) -> Result<(), BlockError<D::Error>>
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+    {
+        let length = u8::try_from(data.len()).map_err(|_| BlockError::LengthExceeded {
+            length: data.len(),
+            capacity: usize::from(u8::MAX),
+        })?;
+
+        device
+            .transaction(
+                device_addr,
+                &mut [Operation::Write(&[command, length]), Operation::Write(data)],
+            )
+            .await
+            .map_err(BlockError::BusError)?;
+
+        trace!(
+            "i2c: block write: command={:02X} bytes={:02X?}",
+            command,
+            data
+        );
+
+        Ok(())
+    }
+
+    /// Write a register value to an I2C device and verify it by reading the register back.
+    ///
+    /// This function performs a write I2C transaction identical to [`write_register`], followed by
+    /// a write-read I2C transaction reading the register back. The bytes read back are compared
+    /// against the serialized bytes that were written, which avoids requiring the register type to
+    /// implement [`PartialEq`]. This is useful for registers that may silently ignore writes, such
+    /// as write-protected, EEPROM-backed, or OTP configuration registers.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `register` - The register value to write
+    ///
+    /// # Errors
+    /// * `WriteVerifyError::BusError` - Communication with the device failed
+    /// * `WriteVerifyError::SerializationError` - Failed to convert register value to bytes
+    /// * `WriteVerifyError::VerificationFailed` - The bytes read back differ from the bytes written
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::{register, i2c, ReadableRegister, WritableRegister, FromByteArray, ToByteArray};
+    /// #[register(42u8)]
+    /// #[derive(ReadableRegister, WritableRegister)]
+    /// # struct ConfigRegister{};
+    /// # impl FromByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { Ok(Self{}) }
+    /// # }
+    /// # impl ToByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([0]) }
+    /// # }
+    /// async fn configure<D: I2c<u8>>(device: &mut D) {
+    ///     i2c::r#async::write_register_verify(device, 0x48, ConfigRegister{ /* ... */}).await.unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn write_register_verify<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        register: R,
+    ) -> Result<(), WriteVerifyError<D::Error, <R as ToByteArray>::Error, <R as ToByteArray>::Array>>
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy,
+        D: embedded_hal_async::i2c::I2c<A>,
+        R: WritableRegister + ReadableRegister,
+    {
+        let written = register
+            .to_register_bytes()
+            .map_err(WriteVerifyError::SerializationError)?;
+        let mut read_back = <R as ToByteArray>::Array::new();
+
+        let write_id = R::writeable_id().to_id_bytes();
+        let read_id = R::readable_id().to_id_bytes();
+
+        device
+            .transaction(
+                device_addr,
+                &mut write_operations(write_id.as_ref(), written.as_ref()),
+            )
+            .await
+            .map_err(WriteVerifyError::BusError)?;
+
+        device
+            .write_read(device_addr, read_id.as_ref(), read_back.as_mut())
+            .await
+            .map_err(WriteVerifyError::BusError)?;
+
+        trace!(
+            "i2c: write register verify: id={:02X?} bytes={:02X?} read_back={:02X?}",
+            write_id.as_ref(),
+            written.as_ref(),
+            read_back.as_ref()
+        );
+
+        if written.as_ref() == read_back.as_ref() {
+            Ok(())
+        } else {
+            Err(WriteVerifyError::VerificationFailed { written, read_back })
+        }
+    }
+
+    /// Write a register value to an I2C device and verify it by reading the register back.
+    ///
+    /// This function writes the register using [`write_register`], then reads it back using
+    /// [`read_register`] and compares the deserialized value against the value that was written
+    /// using [`PartialEq`]. Unlike [`write_register_verify`], the comparison is performed on
+    /// register values rather than raw bytes, allowing a [`PartialEq`] implementation to ignore
+    /// volatile or reserved bits.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `register` - The register value to write
+    ///
+    /// # Errors
+    /// * `VerifyError::BusError` - Communication with the device failed
+    /// * `VerifyError::SerializationError` - Failed to convert register value to bytes
+    /// * `VerifyError::DeserializationError` - Failed to convert received bytes into register value
+    /// * `VerifyError::Mismatch` - The register value read back differs from the value written
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::{register, i2c, ReadableRegister, WritableRegister, FromByteArray, ToByteArray};
+    /// #[register(42u8)]
+    /// #[derive(ReadableRegister, WritableRegister, Clone, PartialEq, Debug)]
+    /// # struct ConfigRegister { value: u8 }
+    /// # impl FromByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self { value: bytes[0] }) }
+    /// # }
+    /// # impl ToByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.value]) }
+    /// # }
+    /// async fn configure<D: I2c<u8>>(device: &mut D) {
+    ///     i2c::r#async::write_register_verified(device, 0x48, ConfigRegister { value: 0x0F }).await.unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn write_register_verified<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        register: R,
+    ) -> Result<(), VerifyError<D::Error, <R as ToByteArray>::Error, <R as FromByteArray>::Error, R>>
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy,
+        D: embedded_hal_async::i2c::I2c<A>,
+        R: WritableRegister + ReadableRegister + Clone + PartialEq,
+    {
+        write_register(device, device_addr, register.clone()).await?;
+        let read: R = read_register(device, device_addr).await?;
+
+        if read == register {
+            Ok(())
+        } else {
+            Err(VerifyError::Mismatch {
+                wrote: register,
+                read,
+            })
+        }
+    }
+
+    /// Invoke a command on an I2C device and receive its response.
+    ///
+    /// This function performs a complete command transaction:
+    /// 1. Sends the command ID
+    /// 2. Sends the serialized command parameters
+    /// 3. Reads the status byte, if [`Command::STATUS_BYTE`] is set, followed by the command response
+    ///
+    /// Command parameters or responses with a length of zero, such as [`NoParameters`], are omitted
+    /// from the transaction.
+    ///
+    /// The entire operation is atomic, using the device's transaction capability to
+    /// ensure all steps occur without interruption.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `cmd` - The command to invoke
+    ///
+    /// # Errors
+    /// * `CommandError::BusError` - Communication with the device failed
+    /// * `CommandError::SerializationError` - Failed to convert command parameters to bytes
+    /// * `CommandError::DeserializationError` - Failed to convert received bytes into response parameters
+    /// * `CommandError::ValidationError` - The response was rejected by [`Command::validate_response`]
+    /// * `CommandError::DeviceError` - The device responded with a nonzero status byte
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal_async::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::{i2c, Command, FromByteArray, ToByteArray};
+    /// # struct SelfTestCommand{ mode: u8 };
+    /// # struct SelfTestResponse;
+    /// # impl Command for SelfTestCommand {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = u8;
+    /// #     type ResponseParameters = SelfTestResponse;
+    /// #     fn id() -> Self::IdType { 0xF0 }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { self.mode }
+    /// # }
+    /// # impl FromByteArray for SelfTestResponse {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { Ok(Self) }
+    /// # }
+    /// async fn perform_self_test<D: I2c<u8>>(device: &mut D) {
+    ///     let result: SelfTestResponse = i2c::r#async::invoke_command(device, 0x48, SelfTestCommand{ mode: 0x01 }).await.unwrap();
+    /// }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     async fn transaction(&mut self, address: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         assert_eq!(address, 0x48);
+    /// #         assert!(matches!(ops, [Operation::Write([0xF0]), Operation::Write([0x01]), Operation::Read([_])]));
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// # let fut = core::pin::pin!(perform_self_test(&mut device));
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_command<D, A, C>(
+        device: &mut D,
+        device_addr: A,
+        cmd: C,
+    ) -> Result<
+        C::ResponseParameters,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        C: Command,
+    {
+        check_response::<C>();
+
+        let mut cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+
+        let mut status = [0; 1];
+        let status_buf: &mut [u8] = if C::STATUS_BYTE { &mut status } else { &mut [] };
+
+        let mut reg_id = C::id().to_id_bytes();
+        let (id, parameters) = merge_id::<C>(reg_id.as_mut(), cmd_buf.as_mut());
+        let (mut operations, len) = command_operations(
+            C::preamble(),
+            id,
+            C::sub_id(),
+            parameters,
+            C::postamble(),
+            status_buf,
+            resp_buf.as_mut(),
+        );
+
+        device
+            .transaction(device_addr, &mut operations[..len])
+            .await
+            .map_err(CommandError::BusError)?;
+
+        trace!(
+            "i2c: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            reg_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
+        if status[0] != 0 {
+            return Err(CommandError::DeviceError(status[0]));
+        }
+
+        C::validate_response(resp_buf.as_ref()).map_err(CommandError::ValidationError)?;
+        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
+    }
+
+    /// Invoke a command on every device on an I2C bus using the general call address.
+    ///
+    /// The command is serialized and written as by [`invoke_command`], addressed to the general
+    /// call address `0x00`, to which devices supporting it respond, such as to perform a reset or
+    /// to synchronize a conversion. As a general call has no response, only commands with a
+    /// response of [`NoParameters`], and without a [`STATUS_BYTE`](Command::STATUS_BYTE), may be
+    /// broadcast, the latter being checked at compile time.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C bus to broadcast the command on
+    /// * `cmd` - The command to broadcast
+    ///
+    /// # Errors
+    /// * `CommandError::BusError` - Communication over the bus failed
+    /// * `CommandError::SerializationError` - Failed to convert command parameters to bytes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// use regiface::{errors::DeviceError, i2c, Command, NoParameters};
+    ///
+    /// struct SoftwareReset;
+    ///
+    /// impl Command for SoftwareReset {
+    ///     type IdType = u8;
+    ///     type CommandParameters = NoParameters;
+    ///     type ResponseParameters = NoParameters;
+    ///
+    ///     fn id() -> Self::IdType {
+    ///         0x06
+    ///     }
+    ///
+    ///     fn invoking_parameters(self) -> Self::CommandParameters {
+    ///         NoParameters::default()
+    ///     }
+    /// }
+    ///
+    /// async fn reset_all<D: I2c>(bus: &mut D) -> Result<(), DeviceError<D::Error>> {
+    ///     Ok(i2c::r#async::general_call(bus, SoftwareReset).await?)
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn general_call<D, C>(
+        device: &mut D,
+        cmd: C,
+    ) -> Result<(), CommandError<D::Error, <C::CommandParameters as ToByteArray>::Error, Infallible>>
+    where
+        D: embedded_hal_async::i2c::I2c<u8>,
+        C: Command<ResponseParameters = NoParameters>,
+    {
+        const {
+            assert!(
+                !C::STATUS_BYTE,
+                "commands broadcast with a general call can not have a status byte"
+            );
+        }
+
+        invoke_command(device, GENERAL_CALL_ADDRESS, cmd).await?;
+        Ok(())
+    }
+
+    /// Invoke a command on an I2C device and map its response into another type.
+    ///
+    /// This function invokes the command using [`invoke_command`], then transforms the parsed
+    /// response using the provided closure. This allows the response to be converted into a domain
+    /// type without defining a separate wrapper type.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `cmd` - The command to invoke
+    /// * `f` - The closure applied to the parsed response
+    ///
+    /// # Errors
+    /// * `CommandError::BusError` - Communication with the device failed
+    /// * `CommandError::SerializationError` - Failed to convert command parameters to bytes
+    /// * `CommandError::DeserializationError` - Failed to convert received bytes into response parameters
+    /// * `CommandError::ValidationError` - The response was rejected by [`Command::validate_response`]
+    /// * `CommandError::DeviceError` - The device responded with a nonzero status byte
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::{i2c, Command, FromByteArray, NoParameters};
+    /// # struct ReadTemperature;
+    /// # impl Command for ReadTemperature {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = u16;
+    /// #     fn id() -> Self::IdType { 0xE3 }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// async fn temperature<D: I2c>(device: &mut D) -> f32 {
+    ///     i2c::r#async::invoke_command_map(device, 0x40, ReadTemperature, |raw| {
+    ///         -46.85 + 175.72 * f32::from(raw) / 65536.0
+    ///     })
+    ///     .await
+    ///     .unwrap()
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_command_map<D, A, C, T, F>(
+        device: &mut D,
+        device_addr: A,
+        cmd: C,
+        f: F,
+    ) -> Result<
+        T,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        C: Command,
+        F: FnOnce(C::ResponseParameters) -> T,
+    {
+        invoke_command(device, device_addr, cmd).await.map(f)
+    }
+
+    /// Invoke a command on an I2C device, waiting for the command's response delay before
+    /// reading its response.
+    ///
+    /// Some devices require time to process a command, such as to perform a measurement, before
+    /// the response may be read. This function performs the command invocation as two transactions:
+    /// 1. Sends the command ID and the serialized command parameters
+    /// 2. Waits [`Command::RESPONSE_DELAY_US`] microseconds
+    /// 3. Reads the status byte, if [`Command::STATUS_BYTE`] is set, followed by the command response
+    ///
+    /// If [`Command::RESPONSE_DELAY_US`] is zero, this function behaves exactly like
+    /// [`invoke_command`], performing a single transaction.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `delay` - The delay provider used to wait for the response
+    /// * `cmd` - The command to invoke
+    ///
+    /// # Errors
+    /// * `CommandError::BusError` - Communication with the device failed
+    /// * `CommandError::SerializationError` - Failed to convert command parameters to bytes
+    /// * `CommandError::DeserializationError` - Failed to convert received bytes into response parameters
+    /// * `CommandError::ValidationError` - The response was rejected by [`Command::validate_response`]
+    /// * `CommandError::DeviceError` - The device responded with a nonzero status byte
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+    /// # use regiface::{NoParameters, i2c, Command, FromByteArray};
+    /// # struct MeasureHighPrecision;
+    /// # struct Measurement;
+    /// # impl Command for MeasureHighPrecision {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = Measurement;
+    /// #     const RESPONSE_DELAY_US: u32 = 8_300;
+    /// #     fn id() -> Self::IdType { 0xFD }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// # impl FromByteArray for Measurement {
+    /// #     type Array = [u8; 6];
+    /// #     type Error = ();
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { Ok(Self) }
+    /// # }
+    /// async fn measure<D: I2c<u8>, T: DelayNs>(device: &mut D, delay: &mut T) {
+    ///     let result: Measurement = i2c::r#async::invoke_command_delayed(device, 0x44, delay, MeasureHighPrecision).await.unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_command_delayed<D, A, T, C>(
+        device: &mut D,
+        device_addr: A,
+        delay: &mut T,
+        cmd: C,
+    ) -> Result<
+        C::ResponseParameters,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        A: embedded_hal_async::i2c::AddressMode + Copy,
+        D: embedded_hal_async::i2c::I2c<A>,
+        T: embedded_hal_async::delay::DelayNs,
+        C: Command,
+    {
+        check_response::<C>();
+
+        if C::RESPONSE_DELAY_US == 0 {
+            return invoke_command(device, device_addr, cmd).await;
+        }
+
+        let mut cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+
+        let mut status = [0; 1];
+        let status_buf: &mut [u8] = if C::STATUS_BYTE { &mut status } else { &mut [] };
+
+        let mut reg_id = C::id().to_id_bytes();
+        let (id, parameters) = merge_id::<C>(reg_id.as_mut(), cmd_buf.as_mut());
+        let (mut operations, len) = command_operations(
+            C::preamble(),
+            id,
+            C::sub_id(),
+            parameters,
+            C::postamble(),
+            &mut [],
+            &mut [],
+        );
+
+        device
+            .transaction(device_addr, &mut operations[..len])
+            .await
+            .map_err(CommandError::BusError)?;
+
+        delay.delay_us(C::RESPONSE_DELAY_US).await;
+
+        let (mut operations, len) = read_operations(status_buf, resp_buf.as_mut());
+        if len > 0 {
+            device
+                .transaction(device_addr, &mut operations[..len])
+                .await
+                .map_err(CommandError::BusError)?;
+        }
+
+        trace!(
+            "i2c: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            reg_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
+        if status[0] != 0 {
+            return Err(CommandError::DeviceError(status[0]));
+        }
+
+        C::validate_response(resp_buf.as_ref()).map_err(CommandError::ValidationError)?;
+        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
+    }
+
+    /// Invoke a [`DynamicCommand`] on an I2C device and receive its response.
+    ///
+    /// This function behaves exactly like [`invoke_command`], except that the command ID is
+    /// obtained from the command instance via [`DynamicCommand::id`] rather than from the command
+    /// type.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the device
+    /// * `cmd` - The command to invoke
+    ///
+    /// # Errors
+    /// * `CommandError::BusError` - Communication with the device failed
+    /// * `CommandError::SerializationError` - Failed to convert command parameters to bytes
+    /// * `CommandError::DeserializationError` - Failed to convert received bytes into response parameters
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::i2c::I2c;
+    /// # use regiface::{i2c, DynamicCommand, NoParameters};
+    /// # struct SetChannel { channel: u8, value: u16 }
+    /// # impl DynamicCommand for SetChannel {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = u16;
+    /// #     type ResponseParameters = NoParameters;
+    /// #     fn id(&self) -> Self::IdType { 0x30 | self.channel }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { self.value }
+    /// # }
+    /// async fn set_all_channels<D: I2c>(device: &mut D, value: u16) {
+    ///     for channel in 0..4 {
+    ///         i2c::r#async::invoke_dynamic_command(device, 0x60, SetChannel { channel, value }).await.unwrap();
+    ///     }
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_dynamic_command<D, A, C>(
+        device: &mut D,
+        device_addr: A,
+        cmd: C,
+    ) -> Result<
+        C::ResponseParameters,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        A: embedded_hal_async::i2c::AddressMode,
+        D: embedded_hal_async::i2c::I2c<A>,
+        C: DynamicCommand,
+    {
+        let reg_id = cmd.id().to_id_bytes();
+        let cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+
+        let (mut operations, len) = command_operations(
+            &[],
+            reg_id.as_ref(),
+            &[],
+            cmd_buf.as_ref(),
+            &[],
+            &mut [],
+            resp_buf.as_mut(),
+        );
+
+        device
+            .transaction(device_addr, &mut operations[..len])
+            .await
+            .map_err(CommandError::BusError)?;
+
+        trace!(
+            "i2c: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            reg_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
+        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
+    }
+}
+
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use super::*;
+    use crate::retry::{retry_policy_blocking, Policy};
+    use embedded_hal::i2c::Operation;
+
+    /// Builds the operations for writing a value: the register or command ID followed by the value
+    fn write_operations<'a>(id: &'a [u8], value: &'a [u8]) -> [Operation<'a>; 2] {
+        [Operation::Write(id), Operation::Write(value)]
+    }
+
+    /// Builds the operations for invoking a command: the preamble, the command ID, the sub-ID, the
+    /// command parameters, and the postamble, followed by a read of the status byte and command
+    /// response
+    ///
+    /// Empty preambles, command IDs, sub-IDs, command parameters, postambles, status bytes, and
+    /// responses are omitted, thus only the first `len` of the returned operations are to be
+    /// performed.
+    fn command_operations<'a>(
+        preamble: &'a [u8],
+        id: &'a [u8],
+        sub_id: &'a [u8],
+        parameters: &'a [u8],
+        postamble: &'a [u8],
+        status: &'a mut [u8],
+        response: &'a mut [u8],
+    ) -> ([Operation<'a>; 7], usize) {
+        let mut operations = [
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+        ];
+        let mut len = 0;
+
+        for write in [preamble, id, sub_id, parameters, postamble] {
+            if !write.is_empty() {
+                operations[len] = Operation::Write(write);
+                len += 1;
+            }
+        }
+
+        let (reads, reads_len) = read_operations(status, response);
+        for read in reads.into_iter().take(reads_len) {
+            operations[len] = read;
+            len += 1;
+        }
+
+        (operations, len)
+    }
+
+    /// Builds the operations for reading a command response: a read of the status byte, followed
+    /// by a read of the command response
+    ///
+    /// Empty status bytes and responses are omitted, thus only the first `len` of the returned
+    /// operations are to be performed.
+    fn read_operations<'a>(
+        status: &'a mut [u8],
+        response: &'a mut [u8],
+    ) -> ([Operation<'a>; 2], usize) {
+        let mut operations = [Operation::Write(&[]), Operation::Write(&[])];
+        let mut len = 0;
+
+        if !status.is_empty() {
+            operations[len] = Operation::Read(status);
+            len += 1;
+        }
+
+        if !response.is_empty() {
+            operations[len] = Operation::Read(response);
+            len += 1;
+        }
+
+        (operations, len)
+    }
+
+    /// Read a register value from an I2C device.
+    ///
+    /// Blocking variant of `r#async::read_register`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::i2c::I2c;
+    /// # use regiface::{register, i2c, ReadableRegister, FromByteArray};
+    /// # #[register(42u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct TemperatureRegister;
+    /// # impl FromByteArray for TemperatureRegister {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = ();
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> {todo!()}
+    /// # }
+    /// fn read_temp<D: I2c<u8>>(device: &mut D) {
+    ///     let temp: TemperatureRegister = i2c::blocking::read_register(device, 0x48).unwrap();
+    /// }
+    /// ```
+    pub fn read_register<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+        R: ReadableRegister,
+    {
+        let mut buf = <R as FromByteArray>::Array::new();
+
+        let reg_id = R::readable_id().to_id_bytes();
+
+        device
+            .write_read(device_addr, reg_id.as_ref(), buf.as_mut())
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "i2c: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from an I2C device, ending the register ID write with a stop
+    /// condition.
+    ///
+    /// Blocking variant of `r#async::read_register_stop`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::{register, i2c, ReadableRegister, FromByteArray};
+    /// # #[register(0x05u8)]
+    /// # #[derive(ReadableRegister, Debug, PartialEq)]
+    /// # struct Temperature(u8);
+    /// # impl FromByteArray for Temperature {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # struct Device { transactions: usize }
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         self.transactions += 1;
+    /// #         match ops {
+    /// #             [Operation::Write([0x05])] => {}
+    /// #             [Operation::Read(buf)] => buf[0] = 0x19,
+    /// #             _ => panic!("expected separate write and read transactions"),
+    /// #         }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device { transactions: 0 };
+    /// let temp: Temperature = i2c::blocking::read_register_stop(&mut device, 0x48).unwrap();
+    /// assert_eq!(temp, Temperature(0x19));
+    /// // The register ID and value are transferred in separate transactions
+    /// assert_eq!(device.transactions, 2);
+    /// ```
+    pub fn read_register_stop<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        A: embedded_hal::i2c::AddressMode + Copy,
+        D: embedded_hal::i2c::I2c<A>,
+        R: ReadableRegister,
+    {
+        let mut buf = <R as FromByteArray>::Array::new();
+
+        let reg_id = R::readable_id().to_id_bytes();
+
+        device
+            .write(device_addr, reg_id.as_ref())
+            .map_err(ReadRegisterError::BusError)?;
+        device
+            .read(device_addr, buf.as_mut())
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "i2c: read register (stop): id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from an I2C device, reading a fixed number of bytes regardless of
+    /// the size of the register.
+    ///
+    /// Blocking variant of `r#async::read_register_sized`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::{register, i2c, ReadableRegister, FromByteArray};
+    /// # #[register(0x10u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct Status(u16);
+    /// # impl FromByteArray for Status {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(u16::from_be_bytes(bytes))) }
+    /// # }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         let [Operation::Write([0x10]), Operation::Read(buf @ [_, _, _, _])] = ops else {
+    /// #             panic!("unexpected operations");
+    /// #         };
+    /// #         buf.copy_from_slice(&[0x12, 0x34, 0xAA, 0xBB]);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// // Reads [0x12, 0x34, 0xAA, 0xBB], deserializing the register from [0x12, 0x34]
+    /// let status = i2c::blocking::read_register_sized::<_, _, Status, 4>(&mut device, 0x2C).unwrap();
+    /// assert_eq!(status.0, 0x1234);
+    /// ```
+    ///
+    /// Reading fewer bytes than the register's [`Array`](FromByteArray::Array) fails to compile
+    /// ```compile_fail
+    /// # use embedded_hal::i2c::I2c;
+    /// # use regiface::{register, i2c, ReadableRegister, FromByteArray};
+    /// # #[register(0x10u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct Status(u16);
+    /// # impl FromByteArray for Status {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(u16::from_be_bytes(bytes))) }
+    /// # }
+    /// fn read_status<D: I2c>(device: &mut D) {
+    ///     let status = i2c::blocking::read_register_sized::<_, _, Status, 1>(device, 0x2C);
+    /// }
+    /// # fn main() { read_status(&mut Bus) }
+    /// # struct Bus;
+    /// # impl embedded_hal::i2c::ErrorType for Bus { type Error = core::convert::Infallible; }
+    /// # impl I2c for Bus {
+    /// #     fn transaction(&mut self, _: u8, _: &mut [embedded_hal::i2c::Operation<'_>]) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// ```
+    pub fn read_register_sized<D, A, R, const READ_LEN: usize>(
+        device: &mut D,
+        device_addr: A,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+        R: ReadableRegister,
+    {
+        const {
+            assert!(
+                READ_LEN >= <R as FromByteArray>::Array::LEN,
+                "READ_LEN must be at least the length of the register's FromByteArray::Array"
+            )
+        };
+
+        let mut buf = [0; READ_LEN];
+
+        let reg_id = R::readable_id().to_id_bytes();
+
+        device
+            .write_read(device_addr, reg_id.as_ref(), &mut buf)
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "i2c: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            &buf[..]
+        );
+
+        // Only the leading bytes of the oversized read make up the register value
+        let mut bytes = <R as FromByteArray>::Array::new();
+        bytes
+            .as_mut()
+            .copy_from_slice(&buf[..<R as FromByteArray>::Array::LEN]);
+
+        R::from_bytes(bytes).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from an SMBus device using Packet Error Checking.
+    ///
+    /// Blocking variant of `r#async::read_register_pec`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::{register, errors::ReadRegisterError, i2c::{self, PecError}, ReadableRegister, FromByteArray};
+    /// # #[register(0x09u8)]
+    /// # #[derive(ReadableRegister, Debug)]
+    /// # struct Voltage(u16);
+    /// # impl FromByteArray for Voltage {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(u16::from_le_bytes(bytes))) }
+    /// # }
+    /// # struct Device { pec: u8 }
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         let [Operation::Write([0x09]), Operation::Read(value), Operation::Read(pec)] = ops else { panic!() };
+    /// #         value.copy_from_slice(&[0xE0, 0x2E]);
+    /// #         pec[0] = self.pec;
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// // A smart battery at address 0x0B reports a voltage of 12000mV, followed by the PEC computed
+    /// // over the frame [0x16, 0x09, 0x17, 0xE0, 0x2E]
+    /// # let mut device = Device { pec: 0xE2 };
+    /// let voltage: Voltage = i2c::blocking::read_register_pec(&mut device, 0x0B).unwrap();
+    /// assert_eq!(voltage.0, 12000);
+    ///
+    /// // A corrupted PEC is reported as a mismatch
+    /// # let mut device = Device { pec: 0xE3 };
+    /// let err = i2c::blocking::read_register_pec::<_, Voltage>(&mut device, 0x0B).unwrap_err();
+    /// assert!(matches!(
+    ///     err,
+    ///     ReadRegisterError::BusError(PecError::PecMismatch { expected: 0xE2, received: 0xE3 })
+    /// ));
+    /// ```
+    pub fn read_register_pec<D, R>(
+        device: &mut D,
+        device_addr: u8,
+    ) -> Result<R, ReadRegisterError<PecError<D::Error>, R::Error>>
+    where
+        D: embedded_hal::i2c::I2c<u8>,
+        R: ReadableRegister,
+    {
+        let mut buf = <R as FromByteArray>::Array::new();
+        let mut received = [0];
+
+        let reg_id = R::readable_id().to_id_bytes();
+
+        device
+            .transaction(
+                device_addr,
+                &mut [
+                    Operation::Write(reg_id.as_ref()),
+                    Operation::Read(buf.as_mut()),
+                    Operation::Read(&mut received),
+                ],
+            )
+            .map_err(|e| ReadRegisterError::BusError(PecError::BusError(e)))?;
+
+        trace!(
+            "i2c: read register: id={:02X?} bytes={:02X?} pec={:02X}",
+            reg_id.as_ref(),
+            buf.as_ref(),
+            received[0]
+        );
+
+        let expected = pec(&[
+            &[device_addr << 1],
+            reg_id.as_ref(),
+            &[(device_addr << 1) | 1],
+            buf.as_ref(),
+        ]);
+        if received[0] != expected {
+            return Err(ReadRegisterError::BusError(PecError::PecMismatch {
+                expected,
+                received: received[0],
+            }));
+        }
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read an instance of an indexed register value from an I2C device.
+    ///
+    /// Blocking variant of `r#async::read_register_at`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::i2c::I2c;
+    /// # use regiface::{register, i2c, IndexedRegister, ReadableRegister, FromByteArray};
+    /// # #[register(0x06u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct LedOnOff { on: u16, off: u16 }
+    /// # impl IndexedRegister for LedOnOff {
+    /// #     type Index = u8;
+    /// #     fn indexed_id(channel: Self::Index) -> Self::IdType { Self::ID + 4 * channel }
+    /// # }
+    /// # impl FromByteArray for LedOnOff {
+    /// #     type Array = [u8; 4];
+    /// #     type Error = ();
+    /// #     fn from_bytes(b: Self::Array) -> Result<Self, Self::Error> {
+    /// #         Ok(Self { on: u16::from_le_bytes([b[0], b[1]]), off: u16::from_le_bytes([b[2], b[3]]) })
+    /// #     }
+    /// # }
+    /// fn read_channel<D: I2c>(device: &mut D, channel: u8) -> u16 {
+    ///     let led: LedOnOff = i2c::blocking::read_register_at(device, 0x40, channel).unwrap();
+    ///     led.off
+    /// }
+    /// ```
+    pub fn read_register_at<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        index: R::Index,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+        R: ReadableRegister + IndexedRegister,
+    {
+        let mut buf = <R as FromByteArray>::Array::new();
+
+        let reg_id = R::readable_indexed_id(index).to_id_bytes();
+
+        device
+            .write_read(device_addr, reg_id.as_ref(), buf.as_mut())
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "i2c: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from an I2C device, along with the number of bytes read.
+    ///
+    /// Blocking variant of `r#async::read_register_counted`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::i2c::I2c;
+    /// # use regiface::{register, i2c, ReadableRegister, FromByteArray};
+    /// # #[register(42u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct FifoData;
+    /// # impl FromByteArray for FifoData {
+    /// #     type Array = [u8; 32];
+    /// #     type Error = ();
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { todo!() }
+    /// # }
+    /// fn read_fifo<D: I2c<u8>>(device: &mut D) {
+    ///     let (data, count): (FifoData, usize) = i2c::blocking::read_register_counted(device, 0x48).unwrap();
+    /// }
+    /// ```
+    pub fn read_register_counted<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+    ) -> Result<(R, usize), ReadRegisterError<D::Error, R::Error>>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+        R: ReadableRegister,
+    {
+        let count = <R as FromByteArray>::Array::new().as_ref().len();
+        let register = read_register(device, device_addr)?;
+
+        Ok((register, count))
+    }
+
+    /// Read a contiguous block of registers from an I2C device in a single transaction.
+    ///
+    /// Blocking variant of `r#async::read_registers_burst`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::{register, i2c, ReadableRegister, FromByteArray};
+    /// // OUT_X_L, OUT_X_H, OUT_Y_L, OUT_Y_H, OUT_Z_L, OUT_Z_H
+    /// #[register(0x28u8)]
+    /// #[derive(ReadableRegister)]
+    /// struct AccelSample {
+    ///     x: i16,
+    ///     y: i16,
+    ///     z: i16,
+    /// }
+    ///
+    /// impl FromByteArray for AccelSample {
+    ///     type Array = [u8; 6];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+    ///         Ok(Self {
+    ///             x: i16::from_le_bytes([bytes[0], bytes[1]]),
+    ///             y: i16::from_le_bytes([bytes[2], bytes[3]]),
+    ///             z: i16::from_le_bytes([bytes[4], bytes[5]]),
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         let [Operation::Write([0x28]), Operation::Read(buf @ [_, _, _, _, _, _])] = ops else {
+    /// #             panic!("expected a single write of 0x28 followed by a 6 byte read");
+    /// #         };
+    /// #         buf.copy_from_slice(&[0x01, 0x00, 0x02, 0x00, 0xFD, 0xFF]);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// // A single transaction writing 0x28 followed by a 6 byte read
+    /// let sample: AccelSample = i2c::blocking::read_registers_burst(&mut device, 0x19).unwrap();
+    /// assert_eq!((sample.x, sample.y, sample.z), (1, 2, -3));
+    /// ```
+    pub fn read_registers_burst<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+        R: ReadableRegister,
+    {
+        read_register(device, device_addr)
+    }
+
+    /// Read a number of samples from a FIFO data register of an I2C device.
+    ///
+    /// Blocking variant of `r#async::read_fifo`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorKind, ErrorType, I2c, Operation};
+    /// use regiface::{errors::FifoError, register, i2c, FifoMode, FromByteArray, ReadableRegister};
+    ///
+    /// // The receive holding register of a UART bridge
+    /// #[register(0x00u8)]
+    /// #[derive(ReadableRegister)]
+    /// struct Rhr(u8);
+    ///
+    /// impl FromByteArray for Rhr {
+    ///     type Array = [u8; 1];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+    ///         Ok(Self(bytes[0]))
+    ///     }
+    /// }
+    /// # struct Bridge { transactions: usize }
+    /// # impl ErrorType for Bridge { type Error = ErrorKind; }
+    /// # impl I2c for Bridge {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         self.transactions += 1;
+    /// #         if self.transactions == 3 { return Err(ErrorKind::Bus); }
+    /// #         let [Operation::Write([0x00]), Operation::Read(buf)] = ops else { panic!() };
+    /// #         for (i, byte) in buf.iter_mut().enumerate() { *byte = b'a' + i as u8; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// let mut device = Bridge { transactions: 0 };
+    /// let mut buf = [0; 8];
+    ///
+    /// // Read four bytes in a single transaction
+    /// let read = i2c::blocking::read_fifo::<_, _, Rhr>(&mut device, 0x4D, &mut buf, 4, FifoMode::Burst).unwrap();
+    /// assert_eq!(read, 4);
+    /// assert_eq!(&buf[..4], b"abcd");
+    ///
+    /// // Read one byte per transaction, the second of which fails
+    /// let err = i2c::blocking::read_fifo::<_, _, Rhr>(&mut device, 0x4D, &mut buf, 4, FifoMode::PerSample).unwrap_err();
+    /// assert_eq!(err, FifoError::BusError { error: ErrorKind::Bus, samples: 1 });
+    ///
+    /// // The buffer can not hold the requested samples
+    /// let err = i2c::blocking::read_fifo::<_, _, Rhr>(&mut device, 0x4D, &mut buf, 9, FifoMode::Burst).unwrap_err();
+    /// assert_eq!(err, FifoError::BufferTooSmall { required: 9, available: 8 });
+    /// ```
+    pub fn read_fifo<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        out: &mut [u8],
+        count: usize,
+        mode: FifoMode,
+    ) -> Result<usize, FifoError<D::Error>>
+    where
+        A: embedded_hal::i2c::AddressMode + Copy,
+        D: embedded_hal::i2c::I2c<A>,
+        R: ReadableRegister,
+    {
+        let required = crate::fifo_len::<<R as FromByteArray>::Array, _>(count, out.len())?;
+        if required == 0 {
+            return Ok(0);
+        }
+        let out = &mut out[..required];
+        let len = <R as FromByteArray>::Array::LEN;
+
+        let reg_id = R::readable_id().to_id_bytes();
+
+        match mode {
+            FifoMode::Burst => device
+                .write_read(device_addr, reg_id.as_ref(), out)
+                .map_err(|error| FifoError::BusError { error, samples: 0 })?,
+            FifoMode::PerSample => {
+                for (samples, sample) in out.chunks_exact_mut(len).enumerate() {
+                    device
+                        .write_read(device_addr, reg_id.as_ref(), sample)
+                        .map_err(|error| FifoError::BusError { error, samples })?;
+                }
+            }
+        }
+
+        trace!(
+            "i2c: read fifo: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            out
+        );
+
+        Ok(count)
+    }
+
+    /// Repeatedly read a register from an I2C device until its value satisfies a condition.
+    ///
+    /// Blocking variant of `r#async::poll_register`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::{delay::DelayNs, i2c::I2c};
+    /// # use regiface::{register, i2c, ReadableRegister, FromByteArray};
+    /// # #[register(0x27u8)]
+    /// # #[derive(ReadableRegister, Debug)]
+    /// # struct Status { ready: bool }
+    /// # impl FromByteArray for Status {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self { ready: bytes[0] & 0x01 != 0 }) }
+    /// # }
+    /// fn wait_until_ready<D: I2c<u8>, T: DelayNs>(device: &mut D, delay: &mut T) {
+    ///     let status: Status = i2c::blocking::poll_register(device, 0x48, delay, 1_000, 10, |s: &Status| s.ready).unwrap();
+    /// }
+    /// # struct Device { reads: usize }
+    /// # impl embedded_hal::i2c::ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [embedded_hal::i2c::Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         self.reads += 1;
+    /// #         if let [_, embedded_hal::i2c::Operation::Read(buf)] = ops {
+    /// #             buf[0] = if self.reads < 3 { 0x00 } else { 0x01 };
+    /// #         }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # struct NoDelay;
+    /// # impl DelayNs for NoDelay { fn delay_ns(&mut self, _: u32) {} }
+    /// # let mut device = Device { reads: 0 };
+    /// # wait_until_ready(&mut device, &mut NoDelay);
+    /// // The device reports not-ready twice before becoming ready
+    /// assert_eq!(device.reads, 3);
+    /// ```
+    pub fn poll_register<D, A, T, R, F>(
+        device: &mut D,
+        device_addr: A,
+        delay: &mut T,
+        interval_us: u32,
+        max_attempts: usize,
+        mut condition: F,
+    ) -> Result<R, PollError<D::Error, R::Error, R>>
+    where
+        A: embedded_hal::i2c::AddressMode + Copy,
+        D: embedded_hal::i2c::I2c<A>,
+        T: embedded_hal::delay::DelayNs,
+        R: ReadableRegister,
+        F: FnMut(&R) -> bool,
+    {
+        let mut last = None;
+
+        for attempt in 0..max_attempts {
+            if attempt > 0 {
+                delay.delay_us(interval_us);
+            }
+
+            let register: R = read_register(device, device_addr)?;
+            if condition(&register) {
+                return Ok(register);
+            }
+            last = Some(register);
+        }
+
+        Err(PollError::Timeout { last })
+    }
+
+    /// Periodically read a register value from an I2C device as an [`Iterator`].
+    ///
+    /// The blocking counterpart of the async `poll_stream`, available with the `stream` feature.
+    /// The register is read by the first call to [`Iterator::next`], and each subsequent call
+    /// waits `period_us` microseconds before reading it again. Errors are yielded like any other
+    /// item rather than ending the iterator, which never ends on its own.
+    ///
+    /// # Parameters
+    /// * `device` - The I2C device to communicate with
+    /// * `device_addr` - The I2C address of the target device
+    /// * `delay` - The delay provider used to wait between reads
+    /// * `period_us` - The number of microseconds to wait between reads
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::ErrorKind;
+    /// # use embedded_hal::delay::DelayNs;
+    /// use regiface::{register, i2c, FromByteArray, ReadableRegister};
+    /// # #[register(0x05u8)]
+    /// # #[derive(ReadableRegister, Debug)]
+    /// # struct Temperature(u8);
+    /// # impl FromByteArray for Temperature {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # struct NoDelay;
+    /// # impl DelayNs for NoDelay { fn delay_ns(&mut self, _: u32) {} }
+    /// # struct Sensor(u8);
+    /// # impl embedded_hal::i2c::ErrorType for Sensor { type Error = ErrorKind; }
+    /// # impl embedded_hal::i2c::I2c for Sensor {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [embedded_hal::i2c::Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         self.0 += 1;
+    /// #         if self.0 == 2 { return Err(ErrorKind::ArbitrationLoss); }
+    /// #         if let [_, embedded_hal::i2c::Operation::Read(buf)] = ops { buf[0] = 20 + self.0; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let (mut device, mut delay) = (Sensor(0), NoDelay);
+    /// let mut samples = i2c::blocking::poll_iter::<_, _, _, Temperature>(&mut device, 0x48, &mut delay, 1_000_000);
+    ///
+    /// // A failed read is yielded, and sampling continues
+    /// assert_eq!(samples.next().unwrap().unwrap().0, 21);
+    /// assert!(samples.next().unwrap().is_err());
+    /// assert_eq!(samples.next().unwrap().unwrap().0, 23);
+    /// ```
+    pub fn poll_iter<'a, D, A, T, R>(
+        device: &'a mut D,
+        device_addr: A,
+        delay: &'a mut T,
+        period_us: u32,
+    ) -> impl Iterator<Item = Result<R, ReadRegisterError<D::Error, R::Error>>> + 'a
+    where
+        A: embedded_hal::i2c::AddressMode + Copy + 'a,
+        D: embedded_hal::i2c::I2c<A>,
+        T: embedded_hal::delay::DelayNs,
+        R: ReadableRegister + 'a,
+    {
+        let mut first = true;
+        core::iter::from_fn(move || {
+            if !core::mem::take(&mut first) {
+                delay.delay_us(period_us);
+            }
+            Some(read_register(device, device_addr))
+        })
+    }
+
+    /// Read a register value from an I2C device, retrying according to a [`Policy`].
+    ///
+    /// Blocking variant of `r#async::read_register_retry`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::{delay::DelayNs, i2c::{ErrorKind, ErrorType, I2c, NoAcknowledgeSource, Operation}};
+    /// use regiface::{errors::{ReadRegisterError, RetryError}, register, i2c, retry::Policy, FromByteArray, ReadableRegister};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister, Debug, PartialEq)]
+    /// # struct ChipId(u8);
+    /// # impl FromByteArray for ChipId {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # struct NoDelay;
+    /// # impl DelayNs for NoDelay { fn delay_ns(&mut self, _: u32) {} }
+    /// # struct Device { nacks: u8, attempts: u8 }
+    /// # impl ErrorType for Device { type Error = ErrorKind; }
+    /// # impl I2c for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         self.attempts += 1;
+    /// #         if self.nacks > 0 {
+    /// #             self.nacks -= 1;
+    /// #             return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+    /// #         }
+    /// #         if let [_, Operation::Read(buf)] = ops { buf[0] = 0x6A; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// let policy = Policy::exponential(3, 100, 2);
+    ///
+    /// // The first two attempts fail, the third succeeds
+    /// let mut device = Device { nacks: 2, attempts: 0 };
+    /// let id: ChipId = i2c::blocking::read_register_retry(&mut device, 0x48, &mut NoDelay, &policy).unwrap();
+    /// assert_eq!(id, ChipId(0x6A));
+    /// assert_eq!(device.attempts, 3);
+    ///
+    /// // Every attempt fails, thus the last error is returned
+    /// let mut device = Device { nacks: 3, attempts: 0 };
+    /// assert_eq!(
+    ///     i2c::blocking::read_register_retry::<_, _, _, ChipId>(&mut device, 0x48, &mut NoDelay, &policy),
+    ///     Err(RetryError { error: ReadRegisterError::BusError(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)), attempts: 3 })
+    /// );
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn read_register_retry<D, A, T, R>(
+        device: &mut D,
+        device_addr: A,
+        delay: &mut T,
+        policy: &Policy,
+    ) -> Result<R, RetryError<ReadRegisterError<D::Error, R::Error>>>
+    where
+        A: embedded_hal::i2c::AddressMode + Copy,
+        D: embedded_hal::i2c::I2c<A>,
+        T: embedded_hal::delay::DelayNs,
+        R: ReadableRegister,
+    {
+        retry_policy_blocking(policy, delay, || read_register(device, device_addr))
+    }
+
+    /// Write a register value to an I2C device, retrying according to a [`Policy`].
+    ///
+    /// Blocking variant of `r#async::write_register_retry`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::{delay::DelayNs, i2c::{ErrorKind, ErrorType, I2c, NoAcknowledgeSource, Operation}};
+    /// use regiface::{register, i2c, retry::Policy, ToByteArray, WritableRegister};
+    /// # #[register(0x20u8)]
+    /// # #[derive(WritableRegister, Clone)]
+    /// # struct Control(u8);
+    /// # impl ToByteArray for Control {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.0]) }
+    /// # }
+    /// # struct NoDelay;
+    /// # impl DelayNs for NoDelay { fn delay_ns(&mut self, _: u32) {} }
+    /// # struct Device { nacks: u8, attempts: u8 }
+    /// # impl ErrorType for Device { type Error = ErrorKind; }
+    /// # impl I2c for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         self.attempts += 1;
+    /// #         if self.nacks > 0 {
+    /// #             self.nacks -= 1;
+    /// #             return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+    /// #         }
+    /// #         if let [_, Operation::Read(buf)] = ops { buf[0] = 0x6A; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// let policy = Policy::fixed(3, 1_000);
+    ///
+    /// // The device does not acknowledge the first attempt
+    /// let mut device = Device { nacks: 1, attempts: 0 };
+    /// i2c::blocking::write_register_retry(&mut device, 0x48, Control(0x01), &mut NoDelay, &policy).unwrap();
+    /// assert_eq!(device.attempts, 2);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn write_register_retry<D, A, T, R>(
+        device: &mut D,
+        device_addr: A,
+        register: R,
+        delay: &mut T,
+        policy: &Policy,
+    ) -> Result<(), RetryError<WriteRegisterError<D::Error, R::Error>>>
+    where
+        A: embedded_hal::i2c::AddressMode + Copy,
+        D: embedded_hal::i2c::I2c<A>,
+        T: embedded_hal::delay::DelayNs,
+        R: WritableRegister + Clone,
+    {
+        retry_policy_blocking(policy, delay, || {
+            write_register(device, device_addr, register.clone())
+        })
+    }
+
+    /// Invoke a command on an I2C device, retrying according to a [`Policy`].
+    ///
+    /// Blocking variant of `r#async::invoke_command_retry`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::{delay::DelayNs, i2c::I2c};
+    /// # use regiface::{i2c, retry::Policy, Command, NoParameters};
+    /// # #[derive(Clone)]
+    /// # struct ReadSerial;
+    /// # impl Command for ReadSerial {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = u8;
+    /// #     fn id() -> Self::IdType { 0x36 }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// fn serial<D: I2c<u8>, T: DelayNs>(device: &mut D, delay: &mut T) -> u8 {
+    ///     let policy = Policy::exponential(3, 1_000, 4);
+    ///     i2c::blocking::invoke_command_retry(device, 0x48, ReadSerial, delay, &policy).unwrap()
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn invoke_command_retry<D, A, T, C>(
+        device: &mut D,
+        device_addr: A,
+        cmd: C,
+        delay: &mut T,
+        policy: &Policy,
+    ) -> Result<
+        C::ResponseParameters,
+        RetryError<
+            CommandError<
+                D::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        >,
+    >
+    where
+        A: embedded_hal::i2c::AddressMode + Copy,
+        D: embedded_hal::i2c::I2c<A>,
+        T: embedded_hal::delay::DelayNs,
+        C: Command + Clone,
+    {
+        retry_policy_blocking(policy, delay, || {
+            invoke_command(device, device_addr, cmd.clone())
+        })
+    }
+
+    /// Check whether a device acknowledges the given I2C address.
+    ///
+    /// Blocking variant of `r#async::probe`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorKind, I2c, NoAcknowledgeSource, Operation};
+    /// # use regiface::i2c;
+    /// # struct Bus;
+    /// # impl embedded_hal::i2c::ErrorType for Bus { type Error = ErrorKind; }
+    /// # impl I2c for Bus {
+    /// #     fn transaction(&mut self, addr: u8, _: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         match addr {
+    /// #             0x48 => Ok(()),
+    /// #             0x7F => Err(ErrorKind::ArbitrationLoss),
+    /// #             _ => Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+    /// #         }
+    /// #     }
+    /// # }
+    /// # let mut bus = Bus;
+    /// assert_eq!(i2c::blocking::probe(&mut bus, 0x48), Ok(true));
+    /// assert_eq!(i2c::blocking::probe(&mut bus, 0x49), Ok(false));
+    /// assert_eq!(i2c::blocking::probe(&mut bus, 0x7F), Err(ErrorKind::ArbitrationLoss));
+    /// ```
+    pub fn probe<D, A>(device: &mut D, device_addr: A) -> Result<bool, D::Error>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+    {
+        match device.read(device_addr, &mut [0]) {
+            Ok(()) => Ok(true),
+            Err(e) => match embedded_hal::i2c::Error::kind(&e) {
+                embedded_hal::i2c::ErrorKind::NoAcknowledge(_) => Ok(false),
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Scan the 7-bit address space of an I2C bus for devices.
+    ///
+    /// Blocking variant of `r#async::scan`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorKind, I2c, NoAcknowledgeSource, Operation};
+    /// # use regiface::i2c;
+    /// # struct Bus;
+    /// # impl embedded_hal::i2c::ErrorType for Bus { type Error = ErrorKind; }
+    /// # impl I2c for Bus {
+    /// #     fn transaction(&mut self, addr: u8, _: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         match addr {
+    /// #             0x00 | 0x1E | 0x68 => Ok(()),
+    /// #             _ => Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+    /// #         }
+    /// #     }
+    /// # }
+    /// # let mut bus = Bus;
+    /// let found = i2c::blocking::scan(&mut bus, false).unwrap();
+    /// assert!(found.iter().eq([0x1E, 0x68]));
+    ///
+    /// // The general call address is only probed when reserved addresses are included
+    /// let found = i2c::blocking::scan(&mut bus, true).unwrap();
+    /// assert!(found.iter().eq([0x00, 0x1E, 0x68]));
+    /// ```
+    pub fn scan<D>(device: &mut D, include_reserved: bool) -> Result<Addresses, D::Error>
+    where
+        D: embedded_hal::i2c::I2c,
+    {
+        let mut found = Addresses::default();
+        for addr in scan_range(include_reserved) {
+            if probe(device, addr)? {
+                found.insert(addr);
+            }
+        }
+        Ok(found)
+    }
+
+    /// Verify the identity of an I2C device by reading a register and comparing it to an expected value.
+    ///
+    /// Blocking variant of `r#async::verify_device_id`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{I2c, Operation};
+    /// # use regiface::{errors::WhoAmIError, register, i2c, FromByteArray, ReadableRegister};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister, Debug, PartialEq)]
+    /// # struct ChipId(u8);
+    /// # impl FromByteArray for ChipId {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # struct Bus(u8);
+    /// # impl embedded_hal::i2c::ErrorType for Bus { type Error = core::convert::Infallible; }
+    /// # impl I2c for Bus {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         if let [_, Operation::Read(buf)] = ops { buf[0] = self.0; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// let mut device = Bus(0x69);
+    /// assert_eq!(
+    ///     i2c::blocking::verify_device_id(&mut device, 0x6A, ChipId(0x6A)),
+    ///     Err(WhoAmIError::Mismatch { expected: ChipId(0x6A), found: ChipId(0x69) })
+    /// );
+    /// ```
+    pub fn verify_device_id<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        expected: R,
+    ) -> Result<R, WhoAmIError<D::Error, R::Error, R>>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+        R: ReadableRegister + PartialEq,
+    {
+        let found: R = read_register(device, device_addr)?;
+        if found != expected {
+            return Err(WhoAmIError::Mismatch { expected, found });
+        }
+        Ok(found)
+    }
+
+    /// Verify the identity of an I2C device by reading its [`WhoAmI`] register.
+    ///
+    /// Blocking variant of `r#async::verify_whoami`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{I2c, Operation};
+    /// # use regiface::{errors::WhoAmIError, register, i2c, FromByteArray, ReadableRegister, WhoAmI};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister, Debug, PartialEq)]
+    /// # struct ChipId(u8);
+    /// # impl FromByteArray for ChipId {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # impl WhoAmI for ChipId {
+    /// #     fn expected() -> Self { Self(0x6A) }
+    /// # }
+    /// # struct Bus(u8);
+    /// # impl embedded_hal::i2c::ErrorType for Bus { type Error = core::convert::Infallible; }
+    /// # impl I2c for Bus {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         if let [_, Operation::Read(buf)] = ops { buf[0] = self.0; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    ///
+    /// let mut device = Bus(0x6A);
+    /// assert_eq!(i2c::blocking::verify_whoami::<_, _, ChipId>(&mut device, 0x6A), Ok(ChipId(0x6A)));
+    ///
+    /// // A different device is present at the address
+    /// let mut device = Bus(0x69);
+    /// assert_eq!(
+    ///     i2c::blocking::verify_whoami::<_, _, ChipId>(&mut device, 0x6A),
+    ///     Err(WhoAmIError::Mismatch { expected: ChipId(0x6A), found: ChipId(0x69) })
+    /// );
+    /// ```
+    pub fn verify_whoami<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+    ) -> Result<R, WhoAmIError<D::Error, R::Error, R>>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+        R: WhoAmI,
+    {
+        verify_device_id(device, device_addr, R::expected())
+    }
+
+    /// Write a register value to an I2C device.
+    ///
+    /// Blocking variant of `r#async::write_register`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::i2c::I2c;
+    /// # use regiface::{register, i2c, WritableRegister, ToByteArray};
+    /// #[register(42u8)]
+    /// #[derive(WritableRegister)]
+    /// # struct ConfigRegister;
+    /// # impl ToByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([0]) }
+    /// # }
+    /// fn configure<D: I2c<u8>>(device: &mut D) {
+    ///     i2c::blocking::write_register(device, 0x48, ConfigRegister{ /* ... */}).unwrap();
+    /// }
+    /// ```
+    pub fn write_register<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+        R: WritableRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = R::writeable_id().to_id_bytes();
+
+        device
+            .transaction(
+                device_addr,
+                &mut write_operations(reg_id.as_ref(), buf.as_ref()),
+            )
+            .map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "i2c: write register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        Ok(())
+    }
+
+    /// Write a number of samples to a FIFO data register of an I2C device.
+    ///
+    /// Blocking variant of `r#async::write_fifo`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorKind, ErrorType, I2c, Operation};
+    /// use regiface::{errors::FifoError, register, i2c, ToByteArray, WritableRegister};
+    ///
+    /// // The input FIFO of a DAC, holding 16-bit samples
+    /// #[register(0x0Du8)]
+    /// #[derive(WritableRegister)]
+    /// struct Fifo(u16);
+    ///
+    /// impl ToByteArray for Fifo {
+    ///     type Array = [u8; 2];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    ///         Ok(self.0.to_be_bytes())
+    ///     }
+    /// }
+    /// # struct Dac { transactions: usize }
+    /// # impl ErrorType for Dac { type Error = ErrorKind; }
+    /// # impl I2c for Dac {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         self.transactions += 1;
+    /// #         match (self.transactions, &*ops) {
+    /// #             (1, [Operation::Write([0x0D]), Operation::Write([1, 2, 3, 4])]) => Ok(()),
+    /// #             (2, [Operation::Write([0x0D]), Operation::Write([5, 6])]) => Ok(()),
+    /// #             (3, [Operation::Write([0x0D]), Operation::Write([1, 2, 3, 4])]) => Ok(()),
+    /// #             (4, _) => Err(ErrorKind::Bus),
+    /// #             _ => panic!(),
+    /// #         }
+    /// #     }
+    /// # }
+    /// let mut device = Dac { transactions: 0 };
+    /// let samples = [1, 2, 3, 4, 5, 6];
+    ///
+    /// // Write three samples, at most two per transaction of the register ID and samples
+    /// let written = i2c::blocking::write_fifo::<_, _, Fifo>(&mut device, 0x4C, &samples, 3, 5).unwrap();
+    /// assert_eq!(written, 3);
+    ///
+    /// // The second transaction fails, after the first two samples were committed
+    /// let err = i2c::blocking::write_fifo::<_, _, Fifo>(&mut device, 0x4C, &samples, 3, 5).unwrap_err();
+    /// assert_eq!(err, FifoError::BusError { error: ErrorKind::Bus, samples: 2 });
+    ///
+    /// // A transaction can not hold the register ID and a sample
+    /// let err = i2c::blocking::write_fifo::<_, _, Fifo>(&mut device, 0x4C, &samples, 3, 2).unwrap_err();
+    /// assert_eq!(err, FifoError::TransactionTooSmall { required: 3, maximum: 2 });
+    /// ```
+    pub fn write_fifo<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        data: &[u8],
+        count: usize,
+        max_transaction_len: usize,
+    ) -> Result<usize, FifoError<D::Error>>
+    where
+        A: embedded_hal::i2c::AddressMode + Copy,
+        D: embedded_hal::i2c::I2c<A>,
+        R: WritableRegister,
+    {
+        let required = crate::fifo_len::<<R as ToByteArray>::Array, _>(count, data.len())?;
+        let len = <R as ToByteArray>::Array::LEN;
+
+        let reg_id = R::writeable_id().to_id_bytes();
+        let per_transaction =
+            crate::fifo_samples_per_transaction(reg_id.as_ref().len(), len, max_transaction_len)?;
+
+        if required == 0 {
+            return Ok(0);
+        }
+        let data = &data[..required];
+
+        for (index, samples) in data.chunks(per_transaction * len).enumerate() {
+            device
+                .transaction(device_addr, &mut write_operations(reg_id.as_ref(), samples))
+                .map_err(|error| FifoError::BusError {
+                    error,
+                    samples: index * per_transaction,
+                })?;
+        }
+
+        trace!(
+            "i2c: write fifo: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            data
+        );
+
+        Ok(count)
+    }
+
+    /// Write an instance of an indexed register value to an I2C device.
+    ///
+    /// Blocking variant of `r#async::write_register_at`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::{register, i2c, IndexedRegister, WritableRegister, ToByteArray};
+    /// # #[register(0x06u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct LedOnOff { on: u16, off: u16 }
+    /// # impl IndexedRegister for LedOnOff {
+    /// #     type Index = u8;
+    /// #     fn indexed_id(channel: Self::Index) -> Self::IdType { Self::ID + 4 * channel }
+    /// # }
+    /// # impl ToByteArray for LedOnOff {
+    /// #     type Array = [u8; 4];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    /// #         let [on_l, on_h] = self.on.to_le_bytes();
+    /// #         let [off_l, off_h] = self.off.to_le_bytes();
+    /// #         Ok([on_l, on_h, off_l, off_h])
+    /// #     }
+    /// # }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         assert!(matches!(ops, [Operation::Write([0x0E]), Operation::Write([0x00, 0x00, 0x00, 0x08])]));
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// # let device = &mut device;
+    /// // Channel 2 is located at LED2_ON_L (0x0E)
+    /// i2c::blocking::write_register_at(device, 0x40, 2, LedOnOff { on: 0, off: 0x0800 }).unwrap();
+    /// ```
+    pub fn write_register_at<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        index: R::Index,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+        R: WritableRegister + IndexedRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = R::writeable_indexed_id(index).to_id_bytes();
+
+        device
+            .transaction(
+                device_addr,
+                &mut write_operations(reg_id.as_ref(), buf.as_ref()),
+            )
+            .map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "i2c: write register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        Ok(())
+    }
+
+    /// Write a register value to an SMBus device using Packet Error Checking.
+    ///
+    /// Blocking variant of `r#async::write_register_pec`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::{register, i2c, WritableRegister, ToByteArray};
+    /// # #[register(0x03u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct BatteryMode(u16);
+    /// # impl ToByteArray for BatteryMode {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok(self.0.to_le_bytes()) }
+    /// # }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         assert!(matches!(ops, [Operation::Write([0x03]), Operation::Write([0x00, 0x60]), Operation::Write([0x89])]));
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// // Transmits the PEC computed over the frame [0x16, 0x03, 0x00, 0x60]
+    /// i2c::blocking::write_register_pec(&mut device, 0x0B, BatteryMode(0x6000)).unwrap();
+    /// ```
+    pub fn write_register_pec<D, R>(
+        device: &mut D,
+        device_addr: u8,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal::i2c::I2c<u8>,
+        R: WritableRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = R::writeable_id().to_id_bytes();
+        let pec = [pec(&[&[device_addr << 1], reg_id.as_ref(), buf.as_ref()])];
+
+        device
+            .transaction(
+                device_addr,
+                &mut [
+                    Operation::Write(reg_id.as_ref()),
+                    Operation::Write(buf.as_ref()),
+                    Operation::Write(&pec),
+                ],
+            )
+            .map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "i2c: write register: id={:02X?} bytes={:02X?} pec={:02X}",
+            reg_id.as_ref(),
+            buf.as_ref(),
+            pec[0]
+        );
+
+        Ok(())
+    }
+
+    /// Write a contiguous block of registers to an I2C device in a single transaction.
+    ///
+    /// Blocking variant of `r#async::write_registers_burst`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::{register, i2c, WritableRegister, ToByteArray};
+    /// // CTRL_REG1 through CTRL_REG4
+    /// #[register(0x20u8)]
+    /// #[derive(WritableRegister)]
+    /// struct ControlBlock {
+    ///     ctrl: [u8; 4],
+    /// }
+    ///
+    /// impl ToByteArray for ControlBlock {
+    ///     type Array = [u8; 4];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    ///         Ok(self.ctrl)
+    ///     }
+    /// }
+    ///
+    /// // A block of filter coefficients on a device with 16-bit register IDs
+    /// #[register(0x1040u16)]
+    /// #[derive(WritableRegister)]
+    /// struct Coefficients {
+    ///     taps: [u8; 3],
+    /// }
+    ///
+    /// impl ToByteArray for Coefficients {
+    ///     type Array = [u8; 3];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    ///         Ok(self.taps)
+    ///     }
+    /// }
+    ///
+    /// # struct Device(&'static [u8]);
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         // Consecutive writes within a transaction are sent as a single write
+    /// #         let [Operation::Write(id), Operation::Write(payload)] = ops else { panic!() };
+    /// #         assert_eq!([*id, *payload].concat(), self.0);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// // A single write of [0x20, 0x57, 0x00, 0x40, 0x88]
+    /// # let mut device = Device(&[0x20, 0x57, 0x00, 0x40, 0x88]);
+    /// let block = ControlBlock { ctrl: [0x57, 0x00, 0x40, 0x88] };
+    /// i2c::blocking::write_registers_burst(&mut device, 0x19, block).unwrap();
+    ///
+    /// // Register IDs wider than one byte are sent in full, most significant byte first, thus
+    /// // this is a single write of [0x10, 0x40, 0x01, 0x02, 0x03]
+    /// # let mut device = Device(&[0x10, 0x40, 0x01, 0x02, 0x03]);
+    /// let coefficients = Coefficients { taps: [0x01, 0x02, 0x03] };
+    /// i2c::blocking::write_registers_burst(&mut device, 0x19, coefficients).unwrap();
+    /// ```
+    pub fn write_registers_burst<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+        R: WritableRegister,
+    {
+        write_register(device, device_addr, register)
+    }
+
+    /// Write a block of bytes to consecutive registers of an I2C device in a single transaction.
+    ///
+    /// Blocking variant of `r#async::write_registers_burst_raw`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::i2c::I2c;
+    /// # use regiface::i2c;
+    /// fn load_coefficients<D: I2c<u8>>(device: &mut D, coefficients: &[u8]) {
+    ///     i2c::blocking::write_registers_burst_raw(device, 0x1D, 0x30u8, coefficients, Some(0x80)).unwrap();
+    /// }
+    /// ```
+    pub fn write_registers_burst_raw<D, A, I>(
+        device: &mut D,
+        device_addr: A,
+        start_id: I,
+        payload: &[u8],
+        auto_increment: Option<u8>,
+    ) -> Result<(), D::Error>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+        I: id::Id,
+    {
+        let reg_id = id::to_bytes_with_flag(start_id, auto_increment);
+
+        device.transaction(device_addr, &mut write_operations(reg_id.as_ref(), payload))?;
+
+        trace!(
+            "i2c: write registers burst: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            payload
+        );
+
+        Ok(())
+    }
+
+    /// Perform an SMBus block read from an I2C device.
+    ///
+    /// Blocking variant of `r#async::block_read`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::i2c::{self, BlockError};
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         let [Operation::Write([0x20]), Operation::Read(length), Operation::Read(buf)] = ops else { panic!() };
+    /// #         length[0] = 4;
+    /// #         let len = buf.len().min(4);
+    /// #         buf[..len].copy_from_slice(&b"ACME"[..len]);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// // The device reports a 4 byte block
+    /// let mut buf = [0; 32];
+    /// let name = i2c::blocking::block_read(&mut device, 0x0B, 0x20, &mut buf).unwrap();
+    /// assert_eq!(name, b"ACME");
+    ///
+    /// // A buffer too small for the block is reported rather than truncated
+    /// let mut buf = [0; 2];
+    /// let err = i2c::blocking::block_read(&mut device, 0x0B, 0x20, &mut buf).unwrap_err();
+    /// assert_eq!(err, BlockError::LengthExceeded { length: 4, capacity: 2 });
+    /// ```
+    pub fn block_read<'a, D, A>(
+        device: &mut D,
+        device_addr: A,
+        command: u8,
+        buf: &'a mut [u8],
+    ) -> Result<&'a mut [u8], BlockError<D::Error>>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+    {
+        let mut length = [0];
+
+        device
+            .transaction(
+                device_addr,
+                &mut [
+                    Operation::Write(&[command]),
+                    Operation::Read(&mut length),
+                    Operation::Read(buf),
+                ],
+            )
+            .map_err(BlockError::BusError)?;
+
+        let length = usize::from(length[0]);
+        if length > buf.len() {
+            return Err(BlockError::LengthExceeded {
+                length,
+                capacity: buf.len(),
+            });
+        }
+
+        trace!(
+            "i2c: block read: command={:02X} bytes={:02X?}",
+            command,
+            &buf[..length]
+        );
+
+        Ok(&mut buf[..length])
+    }
+
+    /// Perform an SMBus block write to an I2C device.
+    ///
+    /// Blocking variant of `r#async::block_write`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::i2c;
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         assert!(matches!(ops, [Operation::Write([0x2F, 0x03]), Operation::Write([0x01, 0x02, 0x03])]));
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// // Transmits [0x2F, 0x03, 0x01, 0x02, 0x03]
+    /// i2c::blocking::block_write(&mut device, 0x0B, 0x2F, &[0x01, 0x02, 0x03]).unwrap();
+    /// ```
+    pub fn block_write<D, A>(
+        device: &mut D,
+        device_addr: A,
+        command: u8,
+        data: &[u8],
+    ) -> Result<(), BlockError<D::Error>>
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+    {
+        let length = u8::try_from(data.len()).map_err(|_| BlockError::LengthExceeded {
+            length: data.len(),
+            capacity: usize::from(u8::MAX),
+        })?;
+
+        device
+            .transaction(
+                device_addr,
+                &mut [Operation::Write(&[command, length]), Operation::Write(data)],
+            )
+            .map_err(BlockError::BusError)?;
+
+        trace!(
+            "i2c: block write: command={:02X} bytes={:02X?}",
+            command,
+            data
+        );
+
+        Ok(())
+    }
+
+    /// Write a register value to an I2C device and verify it by reading the register back.
+    ///
+    /// Blocking variant of `r#async::write_register_verify`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::i2c::I2c;
+    /// # use regiface::{register, i2c, ReadableRegister, WritableRegister, FromByteArray, ToByteArray};
+    /// #[register(42u8)]
+    /// #[derive(ReadableRegister, WritableRegister)]
+    /// # struct ConfigRegister;
+    /// # impl FromByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { Ok(Self) }
+    /// # }
+    /// # impl ToByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([0]) }
+    /// # }
+    /// fn configure<D: I2c<u8>>(device: &mut D) {
+    ///     i2c::blocking::write_register_verify(device, 0x48, ConfigRegister{ /* ... */}).unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn write_register_verify<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        register: R,
+    ) -> Result<(), WriteVerifyError<D::Error, <R as ToByteArray>::Error, <R as ToByteArray>::Array>>
+    where
+        A: embedded_hal::i2c::AddressMode + Copy,
+        D: embedded_hal::i2c::I2c<A>,
+        R: WritableRegister + ReadableRegister,
+    {
+        let written = register
+            .to_register_bytes()
+            .map_err(WriteVerifyError::SerializationError)?;
+        let mut read_back = <R as ToByteArray>::Array::new();
+
+        let write_id = R::writeable_id().to_id_bytes();
+        let read_id = R::readable_id().to_id_bytes();
+
+        device
+            .transaction(
+                device_addr,
+                &mut write_operations(write_id.as_ref(), written.as_ref()),
+            )
+            .map_err(WriteVerifyError::BusError)?;
+
+        device
+            .write_read(device_addr, read_id.as_ref(), read_back.as_mut())
+            .map_err(WriteVerifyError::BusError)?;
+
+        trace!(
+            "i2c: write register verify: id={:02X?} bytes={:02X?} read_back={:02X?}",
+            write_id.as_ref(),
+            written.as_ref(),
+            read_back.as_ref()
+        );
+
+        if written.as_ref() == read_back.as_ref() {
+            Ok(())
+        } else {
+            Err(WriteVerifyError::VerificationFailed { written, read_back })
+        }
+    }
+
+    /// Write a register value to an I2C device and verify it by reading the register back.
+    ///
+    /// Blocking variant of `r#async::write_register_verified`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::i2c::I2c;
+    /// # use regiface::{register, i2c, ReadableRegister, WritableRegister, FromByteArray, ToByteArray};
+    /// #[register(42u8)]
+    /// #[derive(ReadableRegister, WritableRegister, Clone, PartialEq, Debug)]
+    /// # struct ConfigRegister { value: u8 }
+    /// # impl FromByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self { value: bytes[0] }) }
+    /// # }
+    /// # impl ToByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.value]) }
+    /// # }
+    /// fn configure<D: I2c<u8>>(device: &mut D) {
+    ///     i2c::blocking::write_register_verified(device, 0x48, ConfigRegister { value: 0x0F }).unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn write_register_verified<D, A, R>(
+        device: &mut D,
+        device_addr: A,
+        register: R,
+    ) -> Result<(), VerifyError<D::Error, <R as ToByteArray>::Error, <R as FromByteArray>::Error, R>>
+    where
+        A: embedded_hal::i2c::AddressMode + Copy,
+        D: embedded_hal::i2c::I2c<A>,
+        R: WritableRegister + ReadableRegister + Clone + PartialEq,
+    {
+        write_register(device, device_addr, register.clone())?;
+        let read: R = read_register(device, device_addr)?;
+
+        if read == register {
+            Ok(())
+        } else {
+            Err(VerifyError::Mismatch {
+                wrote: register,
+                read,
+            })
+        }
+    }
+
+    /// Invoke a command on an I2C device and receive its response.
+    ///
+    /// Blocking variant of `r#async::invoke_command`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::{i2c, Command, FromByteArray, ToByteArray};
+    /// # struct SelfTestCommand{ mode: u8 };
+    /// # struct SelfTestResponse;
+    /// # impl Command for SelfTestCommand {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = u8;
+    /// #     type ResponseParameters = SelfTestResponse;
     /// #     fn id() -> Self::IdType { 0xF0 }
-    /// #     fn invoking_parameters(self) -> Self::CommandParameters { todo!() }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { self.mode }
     /// # }
     /// # impl FromByteArray for SelfTestResponse {
     /// #     type Array = [u8; 1];
@@ -329,8 +4719,65 @@ pub mod blocking {
     /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { Ok(Self) }
     /// # }
     /// fn perform_self_test<D: I2c<u8>>(device: &mut D) {
-    ///     let result: SelfTestResponse = i2c::blocking::invoke_command(device, 0x48, SelfTestCommand).unwrap();
+    ///     let result: SelfTestResponse = i2c::blocking::invoke_command(device, 0x48, SelfTestCommand{ mode: 0x01 }).unwrap();
     /// }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     fn transaction(&mut self, address: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         assert_eq!(address, 0x48);
+    /// #         assert!(matches!(ops, [Operation::Write([0xF0]), Operation::Write([0x01]), Operation::Read([_])]));
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # perform_self_test(&mut Device);
+    /// ```
+    ///
+    /// Command parameters or responses with a length of zero are omitted from the transaction:
+    ///
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::{i2c, Command, NoParameters};
+    /// # macro_rules! command {
+    /// #     ($name:ident, $id:literal, $params:ty, $resp:ty) => {
+    /// #         struct $name($params);
+    /// #         impl Command for $name {
+    /// #             type IdType = u8;
+    /// #             type CommandParameters = $params;
+    /// #             type ResponseParameters = $resp;
+    /// #             fn id() -> Self::IdType { $id }
+    /// #             fn invoking_parameters(self) -> Self::CommandParameters { self.0 }
+    /// #         }
+    /// #     };
+    /// # }
+    /// # command!(Reset, 0x01, NoParameters, NoParameters);
+    /// # command!(SetMode, 0x02, u8, NoParameters);
+    /// # command!(GetStatus, 0x03, NoParameters, u8);
+    /// # command!(Exchange, 0x04, u8, u8);
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         match ops {
+    /// #             [Operation::Write([0x01])] => {}
+    /// #             [Operation::Write([0x02]), Operation::Write([0x0A])] => {}
+    /// #             [Operation::Write([0x03]), Operation::Read([_])] => {}
+    /// #             [Operation::Write([0x04]), Operation::Write([0x0B]), Operation::Read([_])] => {}
+    /// #             _ => panic!("unexpected operations"),
+    /// #         }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// # let device = &mut device;
+    /// // [Write([0x01])]
+    /// i2c::blocking::invoke_command(device, 0x48, Reset(NoParameters::default())).unwrap();
+    /// // [Write([0x02]), Write([0x0A])]
+    /// i2c::blocking::invoke_command(device, 0x48, SetMode(0x0A)).unwrap();
+    /// // [Write([0x03]), Read([_])]
+    /// i2c::blocking::invoke_command(device, 0x48, GetStatus(NoParameters::default())).unwrap();
+    /// // [Write([0x04]), Write([0x0B]), Read([_])]
+    /// i2c::blocking::invoke_command(device, 0x48, Exchange(0x0B)).unwrap();
     /// ```
     #[allow(clippy::type_complexity)]
     pub fn invoke_command<D, A, C>(
@@ -350,26 +4797,364 @@ pub mod blocking {
         D: embedded_hal::i2c::I2c<A>,
         C: Command,
     {
+        check_response::<C>();
+
+        let mut cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+
+        let mut status = [0; 1];
+        let status_buf: &mut [u8] = if C::STATUS_BYTE { &mut status } else { &mut [] };
+
+        let mut reg_id = C::id().to_id_bytes();
+        let (id, parameters) = merge_id::<C>(reg_id.as_mut(), cmd_buf.as_mut());
+        let (mut operations, len) = command_operations(
+            C::preamble(),
+            id,
+            C::sub_id(),
+            parameters,
+            C::postamble(),
+            status_buf,
+            resp_buf.as_mut(),
+        );
+
+        device
+            .transaction(device_addr, &mut operations[..len])
+            .map_err(CommandError::BusError)?;
+
+        trace!(
+            "i2c: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            reg_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
+        if status[0] != 0 {
+            return Err(CommandError::DeviceError(status[0]));
+        }
+
+        C::validate_response(resp_buf.as_ref()).map_err(CommandError::ValidationError)?;
+        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
+    }
+
+    /// Blocking variant of `r#async::general_call`. See the async function
+    /// documentation for detailed behavior description.
+    ///
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// use regiface::{i2c, Command, NoParameters};
+    ///
+    /// struct SoftwareReset;
+    ///
+    /// impl Command for SoftwareReset {
+    ///     type IdType = u8;
+    ///     type CommandParameters = NoParameters;
+    ///     type ResponseParameters = NoParameters;
+    ///
+    ///     fn id() -> Self::IdType {
+    ///         0x06
+    ///     }
+    ///
+    ///     fn invoking_parameters(self) -> Self::CommandParameters {
+    ///         NoParameters::default()
+    ///     }
+    /// }
+    /// # struct Bus;
+    /// # impl ErrorType for Bus { type Error = core::convert::Infallible; }
+    /// # impl I2c for Bus {
+    /// #     fn transaction(&mut self, address: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         assert_eq!(address, 0x00);
+    /// #         assert!(matches!(ops, [Operation::Write([0x06])]));
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut bus = Bus;
+    ///
+    /// // Writes the reset opcode 0x06 to the general call address
+    /// i2c::blocking::general_call(&mut bus, SoftwareReset).unwrap();
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn general_call<D, C>(
+        device: &mut D,
+        cmd: C,
+    ) -> Result<(), CommandError<D::Error, <C::CommandParameters as ToByteArray>::Error, Infallible>>
+    where
+        D: embedded_hal::i2c::I2c<u8>,
+        C: Command<ResponseParameters = NoParameters>,
+    {
+        const {
+            assert!(
+                !C::STATUS_BYTE,
+                "commands broadcast with a general call can not have a status byte"
+            );
+        }
+
+        invoke_command(device, GENERAL_CALL_ADDRESS, cmd)?;
+        Ok(())
+    }
+
+    /// Invoke a command on an I2C device and map its response into another type.
+    ///
+    /// Blocking variant of `r#async::invoke_command_map`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::{i2c, Command, FromByteArray, NoParameters};
+    /// # struct ReadTemperature;
+    /// # impl Command for ReadTemperature {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = u16;
+    /// #     fn id() -> Self::IdType { 0xE3 }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         if let [Operation::Write([0xE3]), Operation::Read(buf)] = ops {
+    /// #             buf.copy_from_slice(&[0x01, 0xF4]);
+    /// #         }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// // The device responds with the raw value 500, in tenths of a degree
+    /// let celsius = i2c::blocking::invoke_command_map(&mut device, 0x40, ReadTemperature, |raw| {
+    ///     f32::from(raw) / 10.0
+    /// })
+    /// .unwrap();
+    /// assert_eq!(celsius, 50.0);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn invoke_command_map<D, A, C, T, F>(
+        device: &mut D,
+        device_addr: A,
+        cmd: C,
+        f: F,
+    ) -> Result<
+        T,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+        C: Command,
+        F: FnOnce(C::ResponseParameters) -> T,
+    {
+        invoke_command(device, device_addr, cmd).map(f)
+    }
+
+    /// Invoke a command on an I2C device, waiting for the command's response delay before
+    /// reading its response.
+    ///
+    /// Blocking variant of `r#async::invoke_command_delayed`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::{delay::DelayNs, i2c::{ErrorType, I2c, Operation}};
+    /// # use regiface::{NoParameters, i2c, Command, FromByteArray};
+    /// # struct MeasureHighPrecision;
+    /// # struct Measurement;
+    /// # impl Command for MeasureHighPrecision {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = Measurement;
+    /// #     const RESPONSE_DELAY_US: u32 = 8_300;
+    /// #     fn id() -> Self::IdType { 0xFD }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// # impl FromByteArray for Measurement {
+    /// #     type Array = [u8; 6];
+    /// #     type Error = ();
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { Ok(Self) }
+    /// # }
+    /// # #[derive(Default)]
+    /// # struct Device { transactions: usize }
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         match (self.transactions, ops) {
+    /// #             (0, [Operation::Write([0xFD])]) => {}
+    /// #             (1, [Operation::Read([_, _, _, _, _, _])]) => {}
+    /// #             _ => panic!("unexpected operations"),
+    /// #         }
+    /// #         self.transactions += 1;
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # #[derive(Default)]
+    /// # struct Delay { ns: u32 }
+    /// # impl DelayNs for Delay { fn delay_ns(&mut self, ns: u32) { self.ns += ns; } }
+    /// fn measure<D: I2c<u8>, T: DelayNs>(device: &mut D, delay: &mut T) {
+    ///     let result: Measurement = i2c::blocking::invoke_command_delayed(device, 0x44, delay, MeasureHighPrecision).unwrap();
+    /// }
+    /// # let mut device = Device::default();
+    /// # let mut delay = Delay::default();
+    /// # measure(&mut device, &mut delay);
+    /// # assert_eq!(device.transactions, 2);
+    /// # assert_eq!(delay.ns, 8_300_000);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn invoke_command_delayed<D, A, T, C>(
+        device: &mut D,
+        device_addr: A,
+        delay: &mut T,
+        cmd: C,
+    ) -> Result<
+        C::ResponseParameters,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        A: embedded_hal::i2c::AddressMode + Copy,
+        D: embedded_hal::i2c::I2c<A>,
+        T: embedded_hal::delay::DelayNs,
+        C: Command,
+    {
+        check_response::<C>();
+
+        if C::RESPONSE_DELAY_US == 0 {
+            return invoke_command(device, device_addr, cmd);
+        }
+
+        let mut cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+
+        let mut status = [0; 1];
+        let status_buf: &mut [u8] = if C::STATUS_BYTE { &mut status } else { &mut [] };
+
+        let mut reg_id = C::id().to_id_bytes();
+        let (id, parameters) = merge_id::<C>(reg_id.as_mut(), cmd_buf.as_mut());
+        let (mut operations, len) = command_operations(
+            C::preamble(),
+            id,
+            C::sub_id(),
+            parameters,
+            C::postamble(),
+            &mut [],
+            &mut [],
+        );
+
+        device
+            .transaction(device_addr, &mut operations[..len])
+            .map_err(CommandError::BusError)?;
+
+        delay.delay_us(C::RESPONSE_DELAY_US);
+
+        let (mut operations, len) = read_operations(status_buf, resp_buf.as_mut());
+        if len > 0 {
+            device
+                .transaction(device_addr, &mut operations[..len])
+                .map_err(CommandError::BusError)?;
+        }
+
+        trace!(
+            "i2c: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            reg_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
+        if status[0] != 0 {
+            return Err(CommandError::DeviceError(status[0]));
+        }
+
+        C::validate_response(resp_buf.as_ref()).map_err(CommandError::ValidationError)?;
+        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
+    }
+
+    /// Invoke a [`DynamicCommand`] on an I2C device and receive its response.
+    ///
+    /// Blocking variant of `r#async::invoke_dynamic_command`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// # use regiface::{i2c, DynamicCommand, NoParameters};
+    /// # struct SetChannel { channel: u8, value: u16 }
+    /// # impl DynamicCommand for SetChannel {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = u16;
+    /// #     type ResponseParameters = NoParameters;
+    /// #     fn id(&self) -> Self::IdType { 0x30 | self.channel }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { self.value }
+    /// # }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         assert!(matches!(ops, [Operation::Write([0x32]), Operation::Write([0x0F, 0xFF])]));
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// # let device = &mut device;
+    /// // [Write([0x32]), Write([0x0F, 0xFF])]
+    /// i2c::blocking::invoke_dynamic_command(device, 0x60, SetChannel { channel: 2, value: 0x0FFF }).unwrap();
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn invoke_dynamic_command<D, A, C>(
+        device: &mut D,
+        device_addr: A,
+        cmd: C,
+    ) -> Result<
+        C::ResponseParameters,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        A: embedded_hal::i2c::AddressMode,
+        D: embedded_hal::i2c::I2c<A>,
+        C: DynamicCommand,
+    {
+        let reg_id = cmd.id().to_id_bytes();
         let cmd_buf = cmd
             .invoking_parameters()
             .to_bytes()
             .map_err(CommandError::SerializationError)?;
         let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
 
-        // Register ID types have compiler enforced infallible byte conversions, thus this unwrap is safe
-        let reg_id = unsafe { C::id().to_bytes().unwrap_unchecked() };
+        let (mut operations, len) = command_operations(
+            &[],
+            reg_id.as_ref(),
+            &[],
+            cmd_buf.as_ref(),
+            &[],
+            &mut [],
+            resp_buf.as_mut(),
+        );
 
         device
-            .transaction(
-                device_addr,
-                &mut [
-                    embedded_hal::i2c::Operation::Write(reg_id.as_ref()),
-                    embedded_hal::i2c::Operation::Write(cmd_buf.as_ref()),
-                    embedded_hal::i2c::Operation::Read(resp_buf.as_mut()),
-                ],
-            )
+            .transaction(device_addr, &mut operations[..len])
             .map_err(CommandError::BusError)?;
 
+        trace!(
+            "i2c: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            reg_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
         C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
     }
 }
@@ -1,11 +1,121 @@
-use core::convert::Infallible;
+use crate::ByteArray;
 
-use crate::ToByteArray;
+/// A trait to be implemented by any type that can be used as a register or command ID
+///
+/// Unlike [`ToByteArray`](crate::ToByteArray), serializing an ID can not fail. Default
+/// implementations are provided for [`u8`], [`u16`], [`u32`], [`u64`], and [`u128`], all of which
+/// are serialized in big-endian byte order. The [`LeId`] and [`BeId`] wrappers may be used to
+/// explicitly specify the byte order of an ID.
+pub trait Id {
+    /// The array of bytes that this ID is serialized into
+    ///
+    /// This value must be a byte array of a specified length, for example `[u8; 2]` or `[u8; 1]`
+    type Array: ByteArray;
 
-pub trait Id: ToByteArray<Error = Infallible> {}
+    /// Serialize the ID into an array of bytes
+    fn to_id_bytes(self) -> Self::Array;
+}
 
-impl Id for u8 {}
-impl Id for u16 {}
-impl Id for u32 {}
-impl Id for u64 {}
-impl Id for u128 {}
+impl Id for u8 {
+    type Array = [u8; 1];
+
+    #[inline]
+    fn to_id_bytes(self) -> Self::Array {
+        [self]
+    }
+}
+
+impl Id for u16 {
+    type Array = [u8; 2];
+
+    #[inline]
+    fn to_id_bytes(self) -> Self::Array {
+        self.to_be_bytes()
+    }
+}
+
+impl Id for u32 {
+    type Array = [u8; 4];
+
+    #[inline]
+    fn to_id_bytes(self) -> Self::Array {
+        self.to_be_bytes()
+    }
+}
+
+impl Id for u64 {
+    type Array = [u8; 8];
+
+    #[inline]
+    fn to_id_bytes(self) -> Self::Array {
+        self.to_be_bytes()
+    }
+}
+
+impl Id for u128 {
+    type Array = [u8; 16];
+
+    #[inline]
+    fn to_id_bytes(self) -> Self::Array {
+        self.to_be_bytes()
+    }
+}
+
+/// An ID that is serialized in little-endian byte order
+///
+/// ```
+/// use regiface::id::{Id, LeId};
+///
+/// assert_eq!(LeId(0x1234u16).to_id_bytes(), [0x34, 0x12]);
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct LeId<T>(pub T);
+
+/// An ID that is serialized in big-endian byte order
+///
+/// This is the byte order used by the [`Id`] implementations of the unsigned primitives, but may be
+/// used to make the byte order of an ID explicit.
+///
+/// ```
+/// use regiface::id::{BeId, Id};
+///
+/// assert_eq!(BeId(0x1234u16).to_id_bytes(), [0x12, 0x34]);
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct BeId<T>(pub T);
+
+macro_rules! impl_endian_id {
+    ($($ty:ty => $len:literal),*) => {
+        $(
+            impl Id for LeId<$ty> {
+                type Array = [u8; $len];
+
+                #[inline]
+                fn to_id_bytes(self) -> Self::Array {
+                    self.0.to_le_bytes()
+                }
+            }
+
+            impl Id for BeId<$ty> {
+                type Array = [u8; $len];
+
+                #[inline]
+                fn to_id_bytes(self) -> Self::Array {
+                    self.0.to_be_bytes()
+                }
+            }
+        )*
+    };
+}
+
+impl_endian_id!(u8 => 1, u16 => 2, u32 => 4, u64 => 8, u128 => 16);
+
+/// Serializes a register ID, optionally setting an auto-increment flag in its first byte
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) fn to_bytes_with_flag<I: Id>(id: I, flag: Option<u8>) -> I::Array {
+    let mut bytes = id.to_id_bytes();
+    if let (Some(flag), Some(first)) = (flag, bytes.as_mut().first_mut()) {
+        *first |= flag;
+    }
+    bytes
+}
@@ -0,0 +1,42 @@
+//! Utilities for reading registers when a device signals an interrupt
+//!
+//! Many devices assert an interrupt pin, such as a data ready (`DRDY`) pin, once a new sample is
+//! available. Rather than polling a status register, a driver may await the pin through the
+//! [`Wait`](embedded_hal_async::digital::Wait) trait and read the sample once it is signaled. The
+//! `read_register_on_interrupt` and `stream_register_on_interrupt` functions of the async
+//! [`i2c`](crate::i2c::async) and [`spi`](crate::spi::async) modules combine both steps, reporting
+//! pin and register read failures through a single
+//! [`InterruptReadError`](crate::errors::InterruptReadError).
+
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+
+/// The pin transition or level awaited before a register is read
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum Edge {
+    /// The pin transitions from low to high
+    Rising,
+    /// The pin transitions from high to low
+    Falling,
+    /// The pin transitions in either direction
+    Any,
+    /// The pin is high, completing immediately if it already is
+    High,
+    /// The pin is low, completing immediately if it already is
+    Low,
+}
+
+/// Waits for the given edge or level on a pin
+pub(crate) async fn wait_for<P>(pin: &mut P, edge: Edge) -> Result<(), P::Error>
+where
+    P: embedded_hal_async::digital::Wait,
+{
+    match edge {
+        Edge::Rising => pin.wait_for_rising_edge().await,
+        Edge::Falling => pin.wait_for_falling_edge().await,
+        Edge::Any => pin.wait_for_any_edge().await,
+        Edge::High => pin.wait_for_high().await,
+        Edge::Low => pin.wait_for_low().await,
+    }
+}
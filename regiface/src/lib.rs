@@ -4,7 +4,8 @@
 //! This crate provides a handful of utility types for writing abstractions for interfacing with
 //! register based devices. Most commonly, this would be utilized when writing drivers for
 //! external peripherals within an embedded environment. As such, some utility functions
-//! are provided for reading and writing registers on devices across I2C or SPI buses.
+//! are provided for reading and writing registers on devices across I2C or SPI buses, as well
+//! as invoking commands on devices across QSPI buses.
 //!
 //! This crate provides two core traits:
 //! - [`Register`] for types that represent a value stored within an addressable register
@@ -117,20 +118,406 @@
 //!     }
 //! }
 //! ```
+//!
+//! ### Features
+//!
+//! The `blocking` submodules, such as `i2c::blocking`, are only available when the `blocking`
+//! feature is enabled, while the `async` submodules and the `timeout` module are only available
+//! when the `async` feature is enabled. Both features are enabled by default, and a build without
+//! the `async` feature does not depend on `embedded-hal-async` or `embedded-io-async`.
+//!
+//! The `stream` feature enables the `poll_stream` functions of the async [`i2c`] and [`spi`]
+//! modules, which sample a register periodically as a
+//! [`Stream`](https://docs.rs/futures-core/0.3/futures_core/stream/trait.Stream.html). It enables
+//! the `async` feature.
+//!
+//! The `defmt-03` feature implements [`defmt::Format`](https://docs.rs/defmt/0.3/defmt/trait.Format.html)
+//! for the error types of the [`errors`] module, [`NoParameters`], and [`Zeros`]. As with
+//! `embedded-hal`, the feature is named for the major version of `defmt` it supports.
+//!
+//! The `std` feature links the standard library, enabling `errors::DynError`, which retains the
+//! original error of a failed operation as a boxed trait object rather than discarding it as the
+//! simplified [`errors::Error`] does. It is enabled by the `linux` and `test-utils` features.
+//!
+//! The `test-utils` feature enables the `mock` module, which builds the expectations of the
+//! [`embedded-hal-mock`](https://crates.io/crates/embedded-hal-mock) I2C and SPI mocks for register
+//! reads, register writes, and command invocations, along with a simulated register device, for
+//! testing drivers built on this crate. It is intended to be enabled only for the
+//! `dev-dependencies` of a driver.
+//!
+//! The `alloc` feature implements [`FromByteSlice`] for `Vec<u8>` and `String`, allowing responses
+//! of variable length, such as those read by `serial::blocking::invoke_command_until`, to be
+//! received without declaring a type for them. [`FromByteArray`] and [`ToByteArray`] remain limited
+//! to fixed length arrays, as their length determines the number of bytes read from a device. It is
+//! enabled by the `std` feature.
 
 pub use byte_array::*;
 pub use command::*;
 pub use regiface_macros::*;
 pub use register::*;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "defmt-03")]
+use defmt_03 as defmt;
+
+/// Emits a trace level log event when the `log` feature is enabled, otherwise expands to nothing
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "log")]
+        log::trace!($($arg)*);
+    };
+}
+
+pub mod addressed;
 pub mod byte_array;
+pub mod cache;
 mod command;
+pub mod device;
 pub mod errors;
 pub mod i2c;
 pub mod id;
+#[cfg(feature = "async")]
+pub mod interrupt;
+#[cfg(feature = "linux")]
+pub mod linux;
+pub mod mmio;
+#[cfg(feature = "test-utils")]
+pub mod mock;
+pub mod prelude;
+pub mod qspi;
 mod register;
+pub mod retry;
+pub mod sequence;
+pub mod serial;
 pub mod spi;
+#[cfg(feature = "stream")]
+mod stream;
+#[cfg(feature = "async")]
+pub mod timeout;
+pub mod transaction;
+pub mod util;
 
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Default)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 pub struct NoParameters {}
+
+/// How the samples of a FIFO data register are read by the `read_fifo` functions of the
+/// [`i2c`] and [`spi`] modules
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum FifoMode {
+    /// The register ID is sent once, then every sample is read in the same transaction
+    ///
+    /// This requires a device that does not advance its register pointer while reading its FIFO
+    /// data register, as is common for FIFOs.
+    #[default]
+    Burst,
+    /// Each sample is read in its own transaction, sending the register ID every time
+    PerSample,
+}
+
+/// Returns the number of bytes of `count` samples of a FIFO data register serialized as `A`,
+/// checking that they fit in a buffer of `available` bytes
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) fn fifo_len<A: ByteArray, B>(
+    count: usize,
+    available: usize,
+) -> Result<usize, errors::FifoError<B>> {
+    const {
+        assert!(
+            A::LEN > 0,
+            "FIFO data registers must be at least one byte long"
+        );
+    }
+
+    let required = count.saturating_mul(A::LEN);
+    if required > available {
+        return Err(errors::FifoError::BufferTooSmall {
+            required,
+            available,
+        });
+    }
+    Ok(required)
+}
+
+/// Returns the number of samples of `sample_len` bytes that fit in a FIFO write transaction of at
+/// most `max_transaction_len` bytes, following a register ID of `id_len` bytes
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) fn fifo_samples_per_transaction<B>(
+    id_len: usize,
+    sample_len: usize,
+    max_transaction_len: usize,
+) -> Result<usize, errors::FifoError<B>> {
+    match max_transaction_len.saturating_sub(id_len) / sample_len {
+        0 => Err(errors::FifoError::TransactionTooSmall {
+            required: id_len + sample_len,
+            maximum: max_transaction_len,
+        }),
+        samples => Ok(samples),
+    }
+}
+
+/// A payload consisting of `N` zero bytes
+///
+/// This type may be used as the parameters of a [`Command`] that expects a fixed number of zero
+/// bytes, or as a response whose contents are irrelevant. When used as a response, the received
+/// bytes are accepted regardless of their value.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Zeros<const N: usize>;
+
+/// A payload prefixed by a single byte containing its serialized length
+///
+/// Some devices use self-describing frames, in which the parameters of a command are preceded by
+/// their length. This type wraps any [`ToByteArray`] type, serializing it as `[len, payload..]`.
+///
+/// As the length of the array can not be computed from `T` on stable Rust, `LEN` must be given
+/// explicitly as one more than the length of `T`'s [`Array`](ToByteArray::Array), which is
+/// enforced at compile time along with the payload length fitting in a single byte.
+///
+/// # Example
+/// ```
+/// use regiface::{LengthPrefixed, ToByteArray};
+///
+/// let bytes = LengthPrefixed::<_, 5>(0x1234_5678u32).to_bytes().unwrap();
+/// assert_eq!(bytes, [0x04, 0x12, 0x34, 0x56, 0x78]);
+/// assert_eq!(usize::from(bytes[0]), bytes.len() - 1);
+/// ```
+///
+/// A `LEN` not matching the length of the payload fails to compile
+///
+/// ```compile_fail
+/// use regiface::{LengthPrefixed, ToByteArray};
+///
+/// let bytes = LengthPrefixed::<_, 4>(0x1234_5678u32).to_bytes();
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Default)]
+pub struct LengthPrefixed<T, const LEN: usize>(pub T);
+
+/// A reference to a [`Copy`] register or payload, which may be written in place of the value itself
+///
+/// Serializing a value with [`ToByteArray::to_bytes`] consumes it, thus writing a register that is
+/// kept around, such as a cached configuration, would otherwise require a copy at each call site.
+/// This type implements [`WritableRegister`] and [`ToByteArray`] by copying the referenced value.
+///
+/// # Example
+#[cfg_attr(feature = "blocking", doc = "```")]
+#[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+/// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+/// use regiface::{i2c, register, ByRef, ToByteArray, WritableRegister};
+///
+/// #[register(0x01u8)]
+/// #[derive(WritableRegister, Clone, Copy)]
+/// pub struct Config(u8);
+///
+/// impl ToByteArray for Config {
+///     type Error = core::convert::Infallible;
+///     type Array = [u8; 1];
+///
+///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+///         Ok([self.0])
+///     }
+/// }
+/// # struct Device;
+/// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+/// # impl I2c<u8> for Device {
+/// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+/// #         assert!(matches!(ops, [Operation::Write([0x01]), Operation::Write([0x42])]));
+/// #         Ok(())
+/// #     }
+/// # }
+/// # let mut device = Device;
+///
+/// let config = Config(0x42);
+///
+/// i2c::blocking::write_register(&mut device, 0x48, ByRef(&config)).unwrap();
+/// i2c::blocking::write_register(&mut device, 0x48, ByRef(&config)).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ByRef<'a, T>(pub &'a T);
+
+/// A 24-bit unsigned integer, serialized as three big-endian bytes
+///
+/// Many precision ADCs and sensors report 24-bit values, for which no primitive integer type
+/// exists. This type may be used directly as a register value or command response, or within the
+/// [`FromByteArray`] and [`ToByteArray`] implementations of a register.
+///
+/// ```
+/// use regiface::{FromByteArray, ToByteArray, U24};
+///
+/// let value = U24::from_bytes([0x12, 0x34, 0x56]).unwrap();
+/// assert_eq!(value.get(), 0x12_3456);
+/// assert_eq!(value.to_bytes().unwrap(), [0x12, 0x34, 0x56]);
+///
+/// assert_eq!(U24::new(0x100_0000), None);
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct U24(u32);
+
+impl U24 {
+    /// The smallest value that can be represented by a [`U24`]
+    pub const MIN: Self = Self(0);
+    /// The largest value that can be represented by a [`U24`]
+    pub const MAX: Self = Self((1 << 24) - 1);
+
+    /// Create a new [`U24`], returning `None` if the value does not fit in 24 bits
+    pub const fn new(value: u32) -> Option<Self> {
+        if value <= Self::MAX.0 {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a [`u32`]
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+}
+
+impl From<U24> for u32 {
+    fn from(value: U24) -> Self {
+        value.0
+    }
+}
+
+/// A 24-bit signed integer, serialized as three big-endian bytes in two's complement
+///
+/// When deserialized, the value is sign-extended into an [`i32`].
+///
+#[cfg_attr(feature = "blocking", doc = "```")]
+#[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+/// use embedded_hal::i2c::{ErrorType, I2c, Operation};
+/// use regiface::{i2c, register, FromByteArray, ReadableRegister, I24};
+///
+/// #[register(0xF7u8)]
+/// #[derive(ReadableRegister)]
+/// struct Pressure(I24);
+///
+/// impl FromByteArray for Pressure {
+///     type Array = [u8; 3];
+///     type Error = core::convert::Infallible;
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         I24::from_bytes(bytes).map(Self)
+///     }
+/// }
+/// # struct Device;
+/// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+/// # impl I2c for Device {
+/// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+/// #         let [Operation::Write([0xF7]), Operation::Read(buf)] = ops else { panic!() };
+/// #         buf.copy_from_slice(&[0xFF, 0xFF, 0xFE]);
+/// #         Ok(())
+/// #     }
+/// # }
+/// # let mut device = Device;
+///
+/// // The device reports 0xFFFFFE, which is -2 in 24-bit two's complement
+/// let pressure: Pressure = i2c::blocking::read_register(&mut device, 0x76).unwrap();
+/// assert_eq!(pressure.0.get(), -2);
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct I24(i32);
+
+impl I24 {
+    /// The smallest value that can be represented by an [`I24`]
+    pub const MIN: Self = Self(-(1 << 23));
+    /// The largest value that can be represented by an [`I24`]
+    pub const MAX: Self = Self((1 << 23) - 1);
+
+    /// Create a new [`I24`], returning `None` if the value does not fit in 24 bits
+    pub const fn new(value: i32) -> Option<Self> {
+        if value >= Self::MIN.0 && value <= Self::MAX.0 {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as an [`i32`]
+    pub const fn get(self) -> i32 {
+        self.0
+    }
+}
+
+impl From<I24> for i32 {
+    fn from(value: I24) -> Self {
+        value.0
+    }
+}
+
+/// A raw register value with a linear transfer function to a physical quantity
+///
+/// Datasheets commonly specify the conversion of a raw reading into physical units as a scale and
+/// offset, such as `celsius = raw * 0.0625`. This type encodes that conversion in the type of a
+/// register field, as the value `raw * NUM / DEN + OFFSET`. Serialization and deserialization are
+/// delegated to `Raw` unchanged, and the conversion is only applied by [`Scaled::to_f32`].
+///
+/// # Example
+/// ```
+/// use regiface::{FromByteArray, Scaled};
+///
+/// // A temperature sensor reporting sixteenths of a degree above zero
+/// type Celsius = Scaled<u16, 1, 16, 0>;
+///
+/// let temp = Celsius::from_bytes([0x01, 0x90]).unwrap();
+/// assert_eq!(temp.0, 400);
+/// assert_eq!(temp.to_f32(), 25.0);
+///
+/// // A humidity sensor whose temperature spans -45 to 130 degrees over the full 16-bit range
+/// type ShtCelsius = Scaled<u16, 175, 65535, -45>;
+///
+/// assert_eq!(ShtCelsius::from_bytes([0x00, 0x00]).unwrap().to_f32(), -45.0);
+/// assert_eq!(ShtCelsius::from_bytes([0xFF, 0xFF]).unwrap().to_f32(), 130.0);
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Scaled<Raw, const NUM: i32, const DEN: i32, const OFFSET: i32>(pub Raw);
+
+impl<Raw, const NUM: i32, const DEN: i32, const OFFSET: i32> Scaled<Raw, NUM, DEN, OFFSET>
+where
+    Raw: Copy + Into<f64>,
+{
+    /// Returns the value converted into physical units, as `raw * NUM / DEN + OFFSET`
+    pub fn to_f32(self) -> f32 {
+        const { assert!(DEN != 0, "DEN must be nonzero") }
+
+        let raw: f64 = self.0.into();
+        (raw * f64::from(NUM) / f64::from(DEN) + f64::from(OFFSET)) as f32
+    }
+}
+
+/// A payload serialized with [`postcard`](https://crates.io/crates/postcard) into a buffer of `N` bytes
+///
+/// This type bridges any `serde` compatible type into a [`ToByteArray`] and [`FromByteArray`]
+/// implementation, allowing serde-derived structs to be used as command parameters and responses.
+/// When serialized, any bytes of the buffer not used by the encoded value are left as zero. When
+/// deserialized, any trailing bytes beyond the encoded value are ignored.
+///
+/// This type is only available when the `postcard` feature is enabled.
+///
+/// # Example
+/// ```
+/// use regiface::{FromByteArray, Postcard, ToByteArray};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct SetRate {
+///     channel: u8,
+///     rate_hz: u16,
+/// }
+///
+/// let bytes = Postcard::<_, 8>(SetRate { channel: 2, rate_hz: 100 }).to_bytes().unwrap();
+/// assert_eq!(bytes, [0x02, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+///
+/// let Postcard(decoded) = Postcard::<SetRate, 8>::from_bytes(bytes).unwrap();
+/// assert_eq!(decoded, SetRate { channel: 2, rate_hz: 100 });
+/// ```
+#[cfg(feature = "postcard")]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Default)]
+pub struct Postcard<T, const N: usize>(pub T);
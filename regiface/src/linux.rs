@@ -0,0 +1,249 @@
+//! Convenience wrappers for accessing registers through Linux `i2cdev` and `spidev` devices
+//!
+//! This module builds on [`linux-embedded-hal`](https://crates.io/crates/linux-embedded-hal) to
+//! allow drivers to be prototyped against real hardware from a desktop binary, such as on a
+//! Raspberry Pi, before moving to a microcontroller. [`open_i2c`] and [`open_spi`] open a bus and
+//! return a handle whose methods delegate to the functions of the [`i2c::blocking`] and
+//! [`spi::blocking`] modules, with any bus error converted into a [`std::io::Error`].
+//!
+//! This module is only available when the `linux` feature is enabled.
+//!
+//! # Example
+//! ```no_run
+//! use regiface::{linux, register, FromByteArray, ReadableRegister};
+//!
+//! #[register(0x0Fu8)]
+//! #[derive(ReadableRegister)]
+//! struct WhoAmI(u8);
+//!
+//! impl FromByteArray for WhoAmI {
+//!     type Array = [u8; 1];
+//!     type Error = core::convert::Infallible;
+//!
+//!     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+//!         Ok(Self(bytes[0]))
+//!     }
+//! }
+//!
+//! let mut device = linux::open_i2c("/dev/i2c-1", 0x19).unwrap();
+//! let id: WhoAmI = device.read().unwrap();
+//! println!("WHO_AM_I: {:#04X}", id.0);
+//! ```
+//!
+//! The underlying bus may also be wrapped in an [`I2cInterface`](crate::device::I2cInterface) for
+//! use with the helpers of the [`util`](crate::util) module, such as to dump the register file of
+//! an RTC:
+//! ```no_run
+//! use linux_embedded_hal::I2cdev;
+//! use regiface::{device::I2cInterface, register, util, FromByteArray, ReadableRegister};
+//!
+//! #[register(0x00u8)]
+//! #[derive(ReadableRegister)]
+//! struct Time([u8; 7]);
+//!
+//! #[register(0x07u8)]
+//! #[derive(ReadableRegister)]
+//! struct Alarm([u8; 7]);
+//!
+//! #[register(0x0Eu8)]
+//! #[derive(ReadableRegister)]
+//! struct Control([u8; 2]);
+//! # impl FromByteArray for Time {
+//! #     type Array = [u8; 7];
+//! #     type Error = core::convert::Infallible;
+//! #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes)) }
+//! # }
+//! # impl FromByteArray for Alarm {
+//! #     type Array = [u8; 7];
+//! #     type Error = core::convert::Infallible;
+//! #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes)) }
+//! # }
+//! # impl FromByteArray for Control {
+//! #     type Array = [u8; 2];
+//! #     type Error = core::convert::Infallible;
+//! #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes)) }
+//! # }
+//!
+//! let mut rtc = I2cInterface::new(I2cdev::new("/dev/i2c-1").unwrap(), 0x68u8);
+//! util::blocking::dump_registers::<(Time, Alarm, Control), _, _>(&mut rtc, |id, bytes| {
+//!     match bytes {
+//!         Ok(bytes) => println!("{id}: {bytes:02X?}"),
+//!         Err(e) => println!("{id}: {e}"),
+//!     }
+//! });
+//! ```
+
+use std::{io, path::Path};
+
+use linux_embedded_hal::{I2CError, I2cdev, SPIError, SpidevDevice};
+
+use crate::{
+    errors::{CommandError, CommandResult, ReadRegisterError, WriteRegisterError},
+    i2c, spi, Command, ReadableRegister, WritableRegister,
+};
+
+/// A handle to a device on a Linux I2C bus, created with [`open_i2c`]
+pub struct LinuxI2cRegisterDevice {
+    bus: I2cdev,
+    address: u8,
+}
+
+/// A handle to a device on a Linux SPI bus, created with [`open_spi`]
+pub struct LinuxSpiRegisterDevice {
+    device: SpidevDevice,
+}
+
+/// Open the I2C bus at `path`, such as `/dev/i2c-1`, for communicating with the device at `address`
+///
+/// # Errors
+/// Returns an error if the bus could not be opened
+pub fn open_i2c(path: impl AsRef<Path>, address: u8) -> io::Result<LinuxI2cRegisterDevice> {
+    let bus = I2cdev::new(path)?;
+    Ok(LinuxI2cRegisterDevice { bus, address })
+}
+
+/// Open the SPI device at `path`, such as `/dev/spidev0.0`
+///
+/// The device is opened with the default configuration of the kernel driver. Use
+/// [`LinuxSpiRegisterDevice::device_mut`] to configure the mode and clock speed before use.
+///
+/// # Errors
+/// Returns an error if the device could not be opened
+pub fn open_spi(path: impl AsRef<Path>) -> io::Result<LinuxSpiRegisterDevice> {
+    let device = SpidevDevice::open(path).map_err(spi_error)?;
+    Ok(LinuxSpiRegisterDevice { device })
+}
+
+impl LinuxI2cRegisterDevice {
+    /// Read a register value from the device
+    ///
+    /// See [`i2c::blocking::read_register`] for details.
+    pub fn read<R: ReadableRegister>(
+        &mut self,
+    ) -> Result<R, ReadRegisterError<io::Error, R::Error>> {
+        i2c::blocking::read_register(&mut self.bus, self.address).map_err(|err| match err {
+            ReadRegisterError::BusError(e) => ReadRegisterError::BusError(i2c_error(e)),
+            ReadRegisterError::DeserializationError(e) => {
+                ReadRegisterError::DeserializationError(e)
+            }
+        })
+    }
+
+    /// Write a register value to the device
+    ///
+    /// See [`i2c::blocking::write_register`] for details.
+    pub fn write<R: WritableRegister>(
+        &mut self,
+        register: R,
+    ) -> Result<(), WriteRegisterError<io::Error, R::Error>> {
+        i2c::blocking::write_register(&mut self.bus, self.address, register).map_err(
+            |err| match err {
+                WriteRegisterError::BusError(e) => WriteRegisterError::BusError(i2c_error(e)),
+                WriteRegisterError::SerializationError(e) => {
+                    WriteRegisterError::SerializationError(e)
+                }
+            },
+        )
+    }
+
+    /// Invoke a command on the device and receive its response
+    ///
+    /// See [`i2c::blocking::invoke_command`] for details.
+    pub fn invoke<C: Command>(&mut self, cmd: C) -> CommandResult<C, io::Error> {
+        i2c::blocking::invoke_command(&mut self.bus, self.address, cmd)
+            .map_err(|err| command_error(err, i2c_error))
+    }
+
+    /// Returns the address of the device
+    pub fn address(&self) -> u8 {
+        self.address
+    }
+
+    /// Returns a mutable reference to the underlying bus
+    pub fn bus_mut(&mut self) -> &mut I2cdev {
+        &mut self.bus
+    }
+
+    /// Consumes the handle, returning the underlying bus
+    pub fn release(self) -> I2cdev {
+        self.bus
+    }
+}
+
+impl LinuxSpiRegisterDevice {
+    /// Read a register value from the device
+    ///
+    /// See [`spi::blocking::read_register`] for details.
+    pub fn read<R: ReadableRegister>(
+        &mut self,
+    ) -> Result<R, ReadRegisterError<io::Error, R::Error>> {
+        spi::blocking::read_register(&mut self.device).map_err(|err| match err {
+            ReadRegisterError::BusError(e) => ReadRegisterError::BusError(spi_error(e)),
+            ReadRegisterError::DeserializationError(e) => {
+                ReadRegisterError::DeserializationError(e)
+            }
+        })
+    }
+
+    /// Write a register value to the device
+    ///
+    /// See [`spi::blocking::write_register`] for details.
+    pub fn write<R: WritableRegister>(
+        &mut self,
+        register: R,
+    ) -> Result<(), WriteRegisterError<io::Error, R::Error>> {
+        spi::blocking::write_register(&mut self.device, register).map_err(|err| match err {
+            WriteRegisterError::BusError(e) => WriteRegisterError::BusError(spi_error(e)),
+            WriteRegisterError::SerializationError(e) => WriteRegisterError::SerializationError(e),
+        })
+    }
+
+    /// Invoke a command on the device and receive its response
+    ///
+    /// See [`spi::blocking::invoke_command`] for details.
+    pub fn invoke<C: Command>(&mut self, cmd: C) -> CommandResult<C, io::Error> {
+        spi::blocking::invoke_command(&mut self.device, cmd)
+            .map_err(|err| command_error(err, spi_error))
+    }
+
+    /// Returns a mutable reference to the underlying SPI device, such as for configuring its mode
+    /// and clock speed
+    pub fn device_mut(&mut self) -> &mut SpidevDevice {
+        &mut self.device
+    }
+
+    /// Consumes the handle, returning the underlying SPI device
+    pub fn release(self) -> SpidevDevice {
+        self.device
+    }
+}
+
+/// Converts an I2C bus error into an [`io::Error`], preserving its kind
+fn i2c_error(err: I2CError) -> io::Error {
+    let kind = match err.inner() {
+        linux_embedded_hal::i2cdev::linux::LinuxI2CError::Errno(errno) => {
+            io::Error::from_raw_os_error(*errno).kind()
+        }
+        linux_embedded_hal::i2cdev::linux::LinuxI2CError::Io(e) => e.kind(),
+    };
+    io::Error::new(kind, err)
+}
+
+/// Converts a SPI bus error into an [`io::Error`], preserving its kind
+fn spi_error(err: SPIError) -> io::Error {
+    io::Error::new(err.inner().kind(), err)
+}
+
+/// Converts the bus error of a [`CommandError`] into an [`io::Error`]
+fn command_error<B, S, D>(
+    err: CommandError<B, S, D>,
+    bus_error: impl FnOnce(B) -> io::Error,
+) -> CommandError<io::Error, S, D> {
+    match err {
+        CommandError::BusError(e) => CommandError::BusError(bus_error(e)),
+        CommandError::SerializationError(e) => CommandError::SerializationError(e),
+        CommandError::DeserializationError(e) => CommandError::DeserializationError(e),
+        CommandError::DeviceError(status) => CommandError::DeviceError(status),
+        CommandError::ValidationError(e) => CommandError::ValidationError(e),
+    }
+}
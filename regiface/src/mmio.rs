@@ -0,0 +1,281 @@
+//! A collection of utility functions for interfacing with memory-mapped registers
+//!
+//! Not all register based devices are accessed over a bus. Peripherals of a SoC, or IP blocks
+//! within an FPGA, commonly expose their registers as a bank of memory. This module provides
+//! register read/write operations for such devices, in which the ID of a register is interpreted as
+//! its byte offset from the base address of the register bank. As with the bus operations, register
+//! values are serialized with [`ToByteArray`] and deserialized with [`FromByteArray`], thus the byte
+//! order of a register is determined by its implementation of those traits.
+//!
+//! The [`read_register`] and [`write_register`] functions operate directly on a base pointer and are
+//! `unsafe`, while the [`MmioBlock`] wrapper provides safe access by checking each register against
+//! the size of the register bank.
+//!
+//! # Memory ordering
+//!
+//! Registers are accessed with [`read_volatile`](core::ptr::read_volatile) and
+//! [`write_volatile`](core::ptr::write_volatile) of the register's byte array. Volatile accesses are
+//! never elided or reordered relative to other volatile accesses, however they provide no ordering
+//! guarantees relative to non-volatile memory accesses, other cores, or DMA. Where such ordering is
+//! required, such as when handing a buffer to a peripheral, an appropriate fence or barrier must be
+//! issued by the caller.
+//!
+//! The width of the accesses performed is not guaranteed. The compiler may perform a single access
+//! of the register's full width, or multiple narrower accesses. Peripherals that require accesses
+//! of a specific width should instead be accessed through a dedicated PAC.
+
+use core::convert::Infallible;
+
+use crate::{
+    byte_array::ByteArray as _,
+    errors::{ReadRegisterError, WriteRegisterError},
+    id::Id,
+    FromByteArray, ReadableRegister, ToByteArray, WritableRegister,
+};
+
+/// Error returned by [`MmioBlock`] when a register does not lie entirely within the register bank
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub struct OutOfBoundsError;
+
+/// Interprets a register ID as a big-endian byte offset, returning `None` if it does not fit in a
+/// [`usize`]
+fn offset<I: Id>(id: I) -> Option<usize> {
+    id.to_id_bytes()
+        .as_ref()
+        .iter()
+        .try_fold(0usize, |offset, byte| {
+            offset.checked_mul(256)?.checked_add(usize::from(*byte))
+        })
+}
+
+/// Read a register value from a memory-mapped register bank.
+///
+/// The register is read from `base` offset by the register ID in bytes, then deserialized into the
+/// specified register type.
+///
+/// # Parameters
+/// * `base` - The base address of the register bank
+///
+/// # Errors
+/// * `ReadRegisterError::DeserializationError` - Failed to convert the read bytes into register value
+///
+/// # Panics
+/// Panics if the register ID does not fit in a [`usize`]
+///
+/// # Safety
+/// `base` offset by the register ID must be valid for volatile reads of the length of the register's
+/// [`Array`](FromByteArray::Array).
+pub unsafe fn read_register<R>(base: *mut u8) -> Result<R, ReadRegisterError<Infallible, R::Error>>
+where
+    R: ReadableRegister,
+{
+    let offset = offset(R::readable_id()).expect("register ID does not fit in a usize");
+
+    let buf = unsafe {
+        core::ptr::read_volatile(
+            base.add(offset)
+                .cast::<<R as FromByteArray>::Array>()
+                .cast_const(),
+        )
+    };
+
+    trace!(
+        "mmio: read register: offset={:#X} bytes={:02X?}",
+        offset,
+        buf.as_ref()
+    );
+
+    R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+}
+
+/// Write a register value to a memory-mapped register bank.
+///
+/// The register value is serialized, then written to `base` offset by the register ID in bytes.
+///
+/// # Parameters
+/// * `base` - The base address of the register bank
+/// * `register` - The register value to write
+///
+/// # Errors
+/// * `WriteRegisterError::SerializationError` - Failed to convert register value to bytes
+///
+/// # Panics
+/// Panics if the register ID does not fit in a [`usize`]
+///
+/// # Safety
+/// `base` offset by the register ID must be valid for volatile writes of the length of the
+/// register's [`Array`](ToByteArray::Array).
+pub unsafe fn write_register<R>(
+    base: *mut u8,
+    register: R,
+) -> Result<(), WriteRegisterError<Infallible, R::Error>>
+where
+    R: WritableRegister,
+{
+    let offset = offset(R::writeable_id()).expect("register ID does not fit in a usize");
+
+    let buf = register
+        .to_register_bytes()
+        .map_err(WriteRegisterError::SerializationError)?;
+
+    trace!(
+        "mmio: write register: offset={:#X} bytes={:02X?}",
+        offset,
+        buf.as_ref()
+    );
+
+    unsafe {
+        core::ptr::write_volatile(base.add(offset).cast::<<R as ToByteArray>::Array>(), buf);
+    }
+
+    Ok(())
+}
+
+/// A memory-mapped register bank of a known size
+///
+/// Every register access is checked to lie entirely within the bank, returning an
+/// [`OutOfBoundsError`] rather than accessing memory outside of it.
+///
+/// # Example
+/// ```
+/// use regiface::{
+///     errors::ReadRegisterError, mmio::{MmioBlock, OutOfBoundsError}, register, FromByteArray,
+///     ReadableRegister, ToByteArray, WritableRegister,
+/// };
+///
+/// #[register(0x04u8)]
+/// #[derive(ReadableRegister, WritableRegister, Debug, PartialEq)]
+/// struct Control(u32);
+///
+/// impl FromByteArray for Control {
+///     type Array = [u8; 4];
+///     type Error = core::convert::Infallible;
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         Ok(Self(u32::from_le_bytes(bytes)))
+///     }
+/// }
+///
+/// impl ToByteArray for Control {
+///     type Array = [u8; 4];
+///     type Error = core::convert::Infallible;
+///
+///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+///         Ok(self.0.to_le_bytes())
+///     }
+/// }
+///
+/// #[register(0x10u8)]
+/// #[derive(ReadableRegister, Debug)]
+/// struct Status(u32);
+///
+/// impl FromByteArray for Status {
+///     type Array = [u8; 4];
+///     type Error = core::convert::Infallible;
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         Ok(Self(u32::from_le_bytes(bytes)))
+///     }
+/// }
+///
+/// // A fake register bank of 16 bytes
+/// let mut memory = Box::new([0u8; 16]);
+///
+/// // SAFETY: the memory is valid for the lifetime of the block and is not otherwise accessed
+/// let mut block = unsafe { MmioBlock::new(memory.as_mut_ptr(), memory.len()) };
+///
+/// block.write_register(Control(0x1234_5678)).unwrap();
+/// assert_eq!(block.read_register::<Control>().unwrap(), Control(0x1234_5678));
+///
+/// // The status register lies beyond the end of the bank
+/// assert!(matches!(
+///     block.read_register::<Status>(),
+///     Err(ReadRegisterError::BusError(OutOfBoundsError))
+/// ));
+///
+/// drop(block);
+/// assert_eq!(memory[4..8], [0x78, 0x56, 0x34, 0x12]);
+/// ```
+#[derive(Debug)]
+pub struct MmioBlock {
+    base: *mut u8,
+    size: usize,
+}
+
+impl MmioBlock {
+    /// Create a new [`MmioBlock`] from the base address and size in bytes of a register bank
+    ///
+    /// # Safety
+    /// `base` must be valid for volatile reads and writes of `size` bytes for the lifetime of the
+    /// returned [`MmioBlock`], and the register bank must not be accessed other than through the
+    /// returned [`MmioBlock`] for its lifetime.
+    pub const unsafe fn new(base: *mut u8, size: usize) -> Self {
+        Self { base, size }
+    }
+
+    /// Returns the base address of the register bank
+    pub fn base(&self) -> *mut u8 {
+        self.base
+    }
+
+    /// Returns the size of the register bank in bytes
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns whether a register of `len` bytes with the given ID lies entirely within the bank
+    fn contains<I: Id>(&self, id: I, len: usize) -> bool {
+        offset(id)
+            .and_then(|offset| offset.checked_add(len))
+            .is_some_and(|end| end <= self.size)
+    }
+
+    /// Read a register value from the register bank.
+    ///
+    /// See [`read_register`] for details.
+    ///
+    /// # Errors
+    /// * `ReadRegisterError::BusError` - The register does not lie entirely within the bank
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert the read bytes into register value
+    pub fn read_register<R>(&mut self) -> Result<R, ReadRegisterError<OutOfBoundsError, R::Error>>
+    where
+        R: ReadableRegister,
+    {
+        if !self.contains(R::readable_id(), <R as FromByteArray>::Array::LEN) {
+            return Err(ReadRegisterError::BusError(OutOfBoundsError));
+        }
+
+        // SAFETY: the register lies within the bank, which is valid for volatile reads
+        unsafe { read_register(self.base) }.map_err(|err| match err {
+            ReadRegisterError::BusError(never) => match never {},
+            ReadRegisterError::DeserializationError(e) => {
+                ReadRegisterError::DeserializationError(e)
+            }
+        })
+    }
+
+    /// Write a register value to the register bank.
+    ///
+    /// See [`write_register`] for details.
+    ///
+    /// # Errors
+    /// * `WriteRegisterError::BusError` - The register does not lie entirely within the bank
+    /// * `WriteRegisterError::SerializationError` - Failed to convert register value to bytes
+    pub fn write_register<R>(
+        &mut self,
+        register: R,
+    ) -> Result<(), WriteRegisterError<OutOfBoundsError, R::Error>>
+    where
+        R: WritableRegister,
+    {
+        if !self.contains(R::writeable_id(), <R as ToByteArray>::Array::LEN) {
+            return Err(WriteRegisterError::BusError(OutOfBoundsError));
+        }
+
+        // SAFETY: the register lies within the bank, which is valid for volatile writes
+        unsafe { write_register(self.base, register) }.map_err(|err| match err {
+            WriteRegisterError::BusError(never) => match never {},
+            WriteRegisterError::SerializationError(e) => WriteRegisterError::SerializationError(e),
+        })
+    }
+}
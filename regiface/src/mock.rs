@@ -0,0 +1,875 @@
+//! Utilities for testing drivers on the host, either against the mocks of
+//! [`embedded-hal-mock`](https://crates.io/crates/embedded-hal-mock) or against a simulated device
+//!
+//! The functions of the [`i2c`] and [`spi`] submodules build the transactions expected by the
+//! `embedded-hal-mock` I2C and SPI mocks when a register is read, a register is written, or a
+//! command is invoked. The expectations match the operations performed by the
+//! [`crate::i2c`] and [`crate::spi`] modules exactly, including the grouping of operations into
+//! transactions and the serialization of register and command IDs, thus a driver may be tested
+//! without restating the wire format of each register. The expectations of consecutive operations
+//! are combined by concatenating their lists.
+//!
+//! Where a transcript of the expected operations is too rigid, [`FakeRegisterDevice`] simulates the
+//! register file of a device instead, allowing a driver to be tested by its effect on the registers
+//! rather than by the operations it performs.
+//!
+//! This module is only available when the `test-utils` feature is enabled.
+//!
+//! # Example
+//! ```
+//! use embedded_hal_mock::eh1::i2c::Mock;
+//! use regiface::{i2c, mock, register, FromByteArray, ReadableRegister, ToByteArray, WritableRegister};
+//!
+//! #[register(0x20u8)]
+//! #[derive(ReadableRegister, WritableRegister, Debug, PartialEq)]
+//! struct Ctrl(u8);
+//!
+//! impl FromByteArray for Ctrl {
+//!     type Array = [u8; 1];
+//!     type Error = core::convert::Infallible;
+//!
+//!     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+//!         Ok(Self(bytes[0]))
+//!     }
+//! }
+//!
+//! impl ToByteArray for Ctrl {
+//!     type Array = [u8; 1];
+//!     type Error = core::convert::Infallible;
+//!
+//!     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+//!         Ok([self.0])
+//!     }
+//! }
+//!
+//! /// Enables the device, as a driver might
+//! fn enable(device: &mut Mock) {
+//!     let Ctrl(ctrl) = i2c::blocking::read_register(device, 0x19).unwrap();
+//!     i2c::blocking::write_register(device, 0x19, Ctrl(ctrl | 0x80)).unwrap();
+//! }
+//!
+//! let mut expectations = mock::i2c::expect_read_register::<Ctrl>(0x19, [0x07]);
+//! expectations.extend(mock::i2c::expect_write_register::<Ctrl>(0x19, [0x87]));
+//!
+//! let mut device = Mock::new(&expectations);
+//! enable(&mut device);
+//! device.done();
+//! ```
+
+use std::{boxed::Box, collections::BTreeMap, vec::Vec};
+
+use crate::{
+    command::merge_id, id::Id as _, ByteArray as _, Command, FromByteArray, ReadableRegister,
+    ToByteArray, WritableRegister,
+};
+
+/// Returns the writes performed when invoking the command `C` with the given serialized
+/// parameters: the preamble, the command ID, the sub-ID, the command parameters, and the
+/// postamble, omitting any that are empty
+fn command_writes<C: Command>(
+    parameters: <C::CommandParameters as ToByteArray>::Array,
+) -> Vec<Vec<u8>> {
+    let mut parameters = parameters;
+    let mut reg_id = C::id().to_id_bytes();
+    let (id, parameters) = merge_id::<C>(reg_id.as_mut(), parameters.as_mut());
+
+    [C::preamble(), id, C::sub_id(), parameters, C::postamble()]
+        .into_iter()
+        .filter(|write| !write.is_empty())
+        .map(<[u8]>::to_vec)
+        .collect()
+}
+
+/// Returns the reads performed when invoking the command `C`: a successful status byte, if
+/// [`Command::STATUS_BYTE`] is set, followed by the given response, omitting any that are empty
+fn command_reads<C: Command>(
+    response: <C::ResponseParameters as FromByteArray>::Array,
+) -> Vec<Vec<u8>> {
+    let status: &[u8] = if C::STATUS_BYTE { &[0] } else { &[] };
+
+    [status, response.as_ref()]
+        .into_iter()
+        .filter(|read| !read.is_empty())
+        .map(<[u8]>::to_vec)
+        .collect()
+}
+
+/// Expectations for the [`embedded_hal_mock::eh1::i2c::Mock`]
+pub mod i2c {
+    use super::*;
+    use embedded_hal_mock::eh1::i2c::Transaction;
+
+    /// Returns the transactions expected when reading the register `R` from the device at
+    /// `device_addr`, such as with [`crate::i2c::blocking::read_register`]
+    ///
+    /// # Parameters
+    /// * `device_addr` - The I2C address of the target device
+    /// * `response` - The bytes of the register value returned by the device
+    ///
+    /// # Example
+    /// ```
+    /// use embedded_hal_mock::eh1::i2c::Mock;
+    /// use regiface::{i2c, mock, register, FromByteArray, ReadableRegister};
+    ///
+    /// #[register(0x0Fu8)]
+    /// #[derive(ReadableRegister, Debug, PartialEq)]
+    /// struct WhoAmI(u16);
+    ///
+    /// impl FromByteArray for WhoAmI {
+    ///     type Array = [u8; 2];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+    ///         Ok(Self(u16::from_be_bytes(bytes)))
+    ///     }
+    /// }
+    ///
+    /// let expectations = mock::i2c::expect_read_register::<WhoAmI>(0x19, [0x12, 0x34]);
+    ///
+    /// let mut device = Mock::new(&expectations);
+    /// let id: WhoAmI = i2c::blocking::read_register(&mut device, 0x19).unwrap();
+    /// assert_eq!(id, WhoAmI(0x1234));
+    /// device.done();
+    ///
+    /// let mut device = Mock::new(&expectations);
+    /// # {
+    /// # let fut = core::pin::pin!(async {
+    /// let id: WhoAmI = i2c::r#async::read_register(&mut device, 0x19).await.unwrap();
+    /// assert_eq!(id, WhoAmI(0x1234));
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    /// # }
+    /// device.done();
+    /// ```
+    pub fn expect_read_register<R: ReadableRegister>(
+        device_addr: u8,
+        response: <R as FromByteArray>::Array,
+    ) -> Vec<Transaction> {
+        let reg_id = R::readable_id().to_id_bytes();
+
+        std::vec![Transaction::write_read(
+            device_addr,
+            reg_id.as_ref().to_vec(),
+            response.as_ref().to_vec(),
+        )]
+    }
+
+    /// Returns the transactions expected when writing the register `R` to the device at
+    /// `device_addr`, such as with [`crate::i2c::blocking::write_register`]
+    ///
+    /// # Parameters
+    /// * `device_addr` - The I2C address of the target device
+    /// * `expected` - The bytes of the register value expected to be written
+    ///
+    /// # Example
+    /// ```
+    /// use embedded_hal_mock::eh1::i2c::Mock;
+    /// use regiface::{i2c, mock, register, ToByteArray, WritableRegister};
+    ///
+    /// #[register(0x2Du8)]
+    /// #[derive(WritableRegister)]
+    /// struct Threshold(u16);
+    ///
+    /// impl ToByteArray for Threshold {
+    ///     type Array = [u8; 2];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    ///         Ok(self.0.to_be_bytes())
+    ///     }
+    /// }
+    ///
+    /// let expectations = mock::i2c::expect_write_register::<Threshold>(0x19, [0x01, 0xF4]);
+    ///
+    /// let mut device = Mock::new(&expectations);
+    /// i2c::blocking::write_register(&mut device, 0x19, Threshold(500)).unwrap();
+    /// device.done();
+    ///
+    /// let mut device = Mock::new(&expectations);
+    /// # {
+    /// # let fut = core::pin::pin!(async {
+    /// i2c::r#async::write_register(&mut device, 0x19, Threshold(500)).await.unwrap();
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    /// # }
+    /// device.done();
+    /// ```
+    pub fn expect_write_register<R: WritableRegister>(
+        device_addr: u8,
+        expected: <R as ToByteArray>::Array,
+    ) -> Vec<Transaction> {
+        let reg_id = R::writeable_id().to_id_bytes();
+
+        std::vec![
+            Transaction::transaction_start(device_addr),
+            Transaction::write(device_addr, reg_id.as_ref().to_vec()),
+            Transaction::write(device_addr, expected.as_ref().to_vec()),
+            Transaction::transaction_end(device_addr),
+        ]
+    }
+
+    /// Returns the transactions expected when invoking the command `C` on the device at
+    /// `device_addr`, such as with [`crate::i2c::blocking::invoke_command`]
+    ///
+    /// The command ID is merged into the parameters when [`Command::MERGED_ID_SHIFT`] is set, and
+    /// the device is expected to report success if [`Command::STATUS_BYTE`] is set.
+    ///
+    /// # Parameters
+    /// * `device_addr` - The I2C address of the target device
+    /// * `expected_params` - The bytes of the command parameters expected to be written
+    /// * `response` - The bytes of the command response returned by the device
+    ///
+    /// # Example
+    /// ```
+    /// use embedded_hal_mock::eh1::i2c::Mock;
+    /// use regiface::{i2c, mock, Command};
+    ///
+    /// struct ReadAdc(u8);
+    ///
+    /// impl Command for ReadAdc {
+    ///     type IdType = u8;
+    ///     type CommandParameters = u8;
+    ///     type ResponseParameters = u16;
+    ///
+    ///     const STATUS_BYTE: bool = true;
+    ///
+    ///     fn id() -> Self::IdType {
+    ///         0x40
+    ///     }
+    ///
+    ///     fn sub_id() -> &'static [u8] {
+    ///         &[0x01]
+    ///     }
+    ///
+    ///     fn invoking_parameters(self) -> Self::CommandParameters {
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// let expectations = mock::i2c::expect_command::<ReadAdc>(0x48, [0x03], [0x0F, 0xA0]);
+    ///
+    /// let mut device = Mock::new(&expectations);
+    /// let sample = i2c::blocking::invoke_command(&mut device, 0x48, ReadAdc(3)).unwrap();
+    /// assert_eq!(sample, 0x0FA0);
+    /// device.done();
+    ///
+    /// let mut device = Mock::new(&expectations);
+    /// # {
+    /// # let fut = core::pin::pin!(async {
+    /// let sample = i2c::r#async::invoke_command(&mut device, 0x48, ReadAdc(3)).await.unwrap();
+    /// assert_eq!(sample, 0x0FA0);
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    /// # }
+    /// device.done();
+    /// ```
+    pub fn expect_command<C: Command>(
+        device_addr: u8,
+        expected_params: <C::CommandParameters as ToByteArray>::Array,
+        response: <C::ResponseParameters as FromByteArray>::Array,
+    ) -> Vec<Transaction> {
+        let writes = command_writes::<C>(expected_params)
+            .into_iter()
+            .map(|write| Transaction::write(device_addr, write));
+        let reads = command_reads::<C>(response)
+            .into_iter()
+            .map(|read| Transaction::read(device_addr, read));
+
+        core::iter::once(Transaction::transaction_start(device_addr))
+            .chain(writes)
+            .chain(reads)
+            .chain(core::iter::once(Transaction::transaction_end(device_addr)))
+            .collect()
+    }
+}
+
+/// Expectations for the [`embedded_hal_mock::eh1::spi::Mock`]
+pub mod spi {
+    use super::*;
+    use crate::spi::DummyMode;
+    use embedded_hal_mock::eh1::spi::Transaction;
+
+    /// Returns the transactions expected when reading the register `R`, such as with
+    /// [`crate::spi::blocking::read_register`]
+    ///
+    /// Any [`ReadableRegister::dummy_bytes`] are expected to be clocked between the register ID
+    /// and the register value, as configured by [`ReadableRegister::dummy_mode`].
+    ///
+    /// # Parameters
+    /// * `response` - The bytes of the register value returned by the device
+    ///
+    /// # Panics
+    /// Panics if [`ReadableRegister::dummy_bytes`] returns more than
+    /// [`MAX_DUMMY_BYTES`](crate::spi::MAX_DUMMY_BYTES)
+    ///
+    /// # Example
+    /// ```
+    /// use embedded_hal_mock::eh1::spi::Mock;
+    /// use regiface::{mock, register, spi, FromByteArray, ReadableRegister};
+    ///
+    /// #[register(0x8Fu8)]
+    /// #[derive(Debug, PartialEq)]
+    /// struct Status(u8);
+    ///
+    /// impl ReadableRegister for Status {
+    ///     fn dummy_bytes() -> usize {
+    ///         1
+    ///     }
+    /// }
+    ///
+    /// impl FromByteArray for Status {
+    ///     type Array = [u8; 1];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+    ///         Ok(Self(bytes[0]))
+    ///     }
+    /// }
+    ///
+    /// let expectations = mock::spi::expect_read_register::<Status>([0x42]);
+    ///
+    /// let mut device = Mock::new(&expectations);
+    /// let status: Status = spi::blocking::read_register(&mut device).unwrap();
+    /// assert_eq!(status, Status(0x42));
+    /// device.done();
+    ///
+    /// let mut device = Mock::new(&expectations);
+    /// # {
+    /// # let fut = core::pin::pin!(async {
+    /// let status: Status = spi::r#async::read_register(&mut device).await.unwrap();
+    /// assert_eq!(status, Status(0x42));
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    /// # }
+    /// device.done();
+    /// ```
+    pub fn expect_read_register<R: ReadableRegister>(
+        response: <R as FromByteArray>::Array,
+    ) -> Vec<Transaction<u8>> {
+        let reg_id = R::readable_id().to_id_bytes();
+
+        let mut transactions = std::vec![
+            Transaction::transaction_start(),
+            Transaction::write_vec(reg_id.as_ref().to_vec()),
+        ];
+        let dummy = std::vec![0; crate::spi::dummy_count::<R>()];
+        if !dummy.is_empty() {
+            transactions.push(match R::dummy_mode() {
+                DummyMode::Write => Transaction::write_vec(dummy),
+                DummyMode::Read => Transaction::read_vec(dummy),
+            });
+        }
+        transactions.push(Transaction::read_vec(response.as_ref().to_vec()));
+        transactions.push(Transaction::transaction_end());
+
+        transactions
+    }
+
+    /// Returns the transactions expected when writing the register `R`, such as with
+    /// [`crate::spi::blocking::write_register`]
+    ///
+    /// # Parameters
+    /// * `expected` - The bytes of the register value expected to be written
+    ///
+    /// # Example
+    /// ```
+    /// use embedded_hal_mock::eh1::spi::Mock;
+    /// use regiface::{mock, register, spi, ToByteArray, WritableRegister};
+    ///
+    /// #[register(0x20u8)]
+    /// #[derive(WritableRegister)]
+    /// struct Ctrl(u8);
+    ///
+    /// impl ToByteArray for Ctrl {
+    ///     type Array = [u8; 1];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    ///         Ok([self.0])
+    ///     }
+    /// }
+    ///
+    /// let expectations = mock::spi::expect_write_register::<Ctrl>([0x67]);
+    ///
+    /// let mut device = Mock::new(&expectations);
+    /// spi::blocking::write_register(&mut device, Ctrl(0x67)).unwrap();
+    /// device.done();
+    ///
+    /// let mut device = Mock::new(&expectations);
+    /// # {
+    /// # let fut = core::pin::pin!(async {
+    /// spi::r#async::write_register(&mut device, Ctrl(0x67)).await.unwrap();
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    /// # }
+    /// device.done();
+    /// ```
+    pub fn expect_write_register<R: WritableRegister>(
+        expected: <R as ToByteArray>::Array,
+    ) -> Vec<Transaction<u8>> {
+        let reg_id = R::writeable_id().to_id_bytes();
+
+        std::vec![
+            Transaction::transaction_start(),
+            Transaction::write_vec(reg_id.as_ref().to_vec()),
+            Transaction::write_vec(expected.as_ref().to_vec()),
+            Transaction::transaction_end(),
+        ]
+    }
+
+    /// Returns the transactions expected when invoking the command `C`, such as with
+    /// [`crate::spi::blocking::invoke_command`]
+    ///
+    /// The command ID is merged into the parameters when [`Command::MERGED_ID_SHIFT`] is set, and
+    /// the device is expected to report success if [`Command::STATUS_BYTE`] is set.
+    ///
+    /// # Parameters
+    /// * `expected_params` - The bytes of the command parameters expected to be written
+    /// * `response` - The bytes of the command response returned by the device
+    ///
+    /// # Example
+    /// ```
+    /// use embedded_hal_mock::eh1::spi::Mock;
+    /// use regiface::{mock, spi, Command, NoParameters};
+    ///
+    /// struct SetChannel(u8);
+    ///
+    /// impl Command for SetChannel {
+    ///     type IdType = u8;
+    ///     type CommandParameters = u8;
+    ///     type ResponseParameters = NoParameters;
+    ///
+    ///     const MERGED_ID_SHIFT: Option<u32> = Some(4);
+    ///
+    ///     fn id() -> Self::IdType {
+    ///         0x3
+    ///     }
+    ///
+    ///     fn preamble() -> &'static [u8] {
+    ///         &[0xA5]
+    ///     }
+    ///
+    ///     fn invoking_parameters(self) -> Self::CommandParameters {
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// let expectations = mock::spi::expect_command::<SetChannel>([0x05], []);
+    ///
+    /// let mut device = Mock::new(&expectations);
+    /// spi::blocking::invoke_command(&mut device, SetChannel(5)).unwrap();
+    /// device.done();
+    ///
+    /// let mut device = Mock::new(&expectations);
+    /// # {
+    /// # let fut = core::pin::pin!(async {
+    /// spi::r#async::invoke_command(&mut device, SetChannel(5)).await.unwrap();
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    /// # }
+    /// device.done();
+    /// ```
+    pub fn expect_command<C: Command>(
+        expected_params: <C::CommandParameters as ToByteArray>::Array,
+        response: <C::ResponseParameters as FromByteArray>::Array,
+    ) -> Vec<Transaction<u8>> {
+        let writes = command_writes::<C>(expected_params)
+            .into_iter()
+            .map(Transaction::write_vec);
+        let reads = command_reads::<C>(response)
+            .into_iter()
+            .map(Transaction::read_vec);
+
+        core::iter::once(Transaction::transaction_start())
+            .chain(writes)
+            .chain(reads)
+            .chain(core::iter::once(Transaction::transaction_end()))
+            .collect()
+    }
+}
+
+/// A simulated device whose registers are held in memory, implementing the I2C and SPI traits of
+/// `embedded-hal`, and of `embedded-hal-async` when the `async` feature is enabled
+///
+/// The device behaves as a typical pointer-register device. The first bytes written in a
+/// transaction, as many as the length of a register ID, set the register pointer. Any further
+/// bytes written are stored starting at the pointer, while bytes read are returned starting at the
+/// pointer, which is incremented after every byte and wraps around at the end of the address
+/// space. The pointer is retained between transactions, thus a read that does not write a
+/// register ID continues from where the previous transaction left off. Register IDs are
+/// interpreted as big-endian addresses, and bytes that were never written read as zero.
+///
+/// On the I2C bus, the device responds to every address, and each write following a read within a
+/// transaction sets the pointer anew, as it follows a repeated start condition. On the SPI bus, the
+/// whole transaction is a single frame, thus writes following a read are stored as data. Dummy
+/// bytes written between a register ID and a read, such as those of
+/// [`ReadableRegister::dummy_bytes`], are likewise stored, while full-duplex transfers are not
+/// supported and fail with [`spi::ErrorKind::Other`](embedded_hal::spi::ErrorKind::Other).
+///
+/// Failures are injected with [`nack_next`](FakeRegisterDevice::nack_next), which causes the next
+/// transactions to fail without affecting the device, and
+/// [`set_read_hook`](FakeRegisterDevice::set_read_hook), which may alter the bytes read.
+///
+/// # Example
+/// ```
+/// use regiface::{errors::Error, i2c, mock::FakeRegisterDevice, register};
+/// use regiface::{FromByteArray, ReadableRegister, ToByteArray, WritableRegister};
+///
+/// #[register(0x20u8)]
+/// #[derive(ReadableRegister, WritableRegister)]
+/// struct Ctrl(u8);
+///
+/// impl FromByteArray for Ctrl {
+///     type Array = [u8; 1];
+///     type Error = core::convert::Infallible;
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         Ok(Self(bytes[0]))
+///     }
+/// }
+///
+/// impl ToByteArray for Ctrl {
+///     type Array = [u8; 1];
+///     type Error = core::convert::Infallible;
+///
+///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+///         Ok([self.0])
+///     }
+/// }
+///
+/// /// Enables the device, as a driver might
+/// fn enable(device: &mut FakeRegisterDevice) -> Result<(), Error> {
+///     let Ctrl(ctrl) = i2c::blocking::read_register(device, 0x19)?;
+///     i2c::blocking::write_register(device, 0x19, Ctrl(ctrl | 0x80))?;
+///     Ok(())
+/// }
+///
+/// let mut device = FakeRegisterDevice::new();
+/// device.set_register(&[0x20], &[0x07]);
+///
+/// enable(&mut device).unwrap();
+/// assert_eq!(device.get_register(&[0x20]), [0x87]);
+///
+/// // The device does not acknowledge the next transaction
+/// device.nack_next(1);
+/// assert!(matches!(enable(&mut device), Err(Error::BusError(_))));
+/// assert_eq!(device.get_register(&[0x20]), [0x87]);
+/// ```
+pub struct FakeRegisterDevice {
+    id_len: usize,
+    memory: BTreeMap<u32, u8>,
+    pointer: u32,
+    address: u32,
+    address_remaining: usize,
+    nacks: usize,
+    read_hook: Option<Box<dyn FnMut(u32, u8) -> u8>>,
+}
+
+impl Default for FakeRegisterDevice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeRegisterDevice {
+    /// Creates a device with one byte register IDs, whose registers all read as zero
+    pub fn new() -> Self {
+        Self::with_id_len(1)
+    }
+
+    /// Creates a device with register IDs of `id_len` bytes, whose registers all read as zero
+    ///
+    /// # Panics
+    /// Panics if `id_len` is zero or greater than four
+    ///
+    /// # Example
+    /// ```
+    /// use regiface::{mock::FakeRegisterDevice, register, spi, FromByteArray, ReadableRegister, ToByteArray, WritableRegister};
+    ///
+    /// #[register(0x0102u16)]
+    /// #[derive(ReadableRegister, WritableRegister, Debug, PartialEq)]
+    /// struct Threshold(u16);
+    ///
+    /// impl FromByteArray for Threshold {
+    ///     type Array = [u8; 2];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+    ///         Ok(Self(u16::from_be_bytes(bytes)))
+    ///     }
+    /// }
+    ///
+    /// impl ToByteArray for Threshold {
+    ///     type Array = [u8; 2];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    ///         Ok(self.0.to_be_bytes())
+    ///     }
+    /// }
+    ///
+    /// let mut device = FakeRegisterDevice::with_id_len(2);
+    ///
+    /// spi::blocking::write_register(&mut device, Threshold(500)).unwrap();
+    /// assert_eq!(device.get_register(&[0x01, 0x02]), [0x01, 0xF4]);
+    ///
+    /// # let fut = core::pin::pin!(async {
+    /// let threshold: Threshold = spi::r#async::read_register(&mut device).await.unwrap();
+    /// assert_eq!(threshold, Threshold(500));
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    /// ```
+    pub fn with_id_len(id_len: usize) -> Self {
+        assert!(
+            (1..=4).contains(&id_len),
+            "register IDs must be between one and four bytes long"
+        );
+
+        Self {
+            id_len,
+            memory: BTreeMap::new(),
+            pointer: 0,
+            address: 0,
+            address_remaining: 0,
+            nacks: 0,
+            read_hook: None,
+        }
+    }
+
+    /// Stores `bytes` starting at the register `id`, as if they were written by the bus
+    ///
+    /// # Panics
+    /// Panics if `id` is not as long as the register IDs of the device
+    pub fn set_register(&mut self, id: &[u8], bytes: &[u8]) {
+        let mut address = self.address_of(id);
+        for &byte in bytes {
+            self.memory.insert(address, byte);
+            address = self.next_address(address);
+        }
+    }
+
+    /// Returns the `N` bytes starting at the register `id`, bypassing any read hook
+    ///
+    /// # Panics
+    /// Panics if `id` is not as long as the register IDs of the device
+    pub fn get_register<const N: usize>(&self, id: &[u8]) -> [u8; N] {
+        let mut address = self.address_of(id);
+        let mut bytes = [0; N];
+        for byte in &mut bytes {
+            *byte = self.memory.get(&address).copied().unwrap_or(0);
+            address = self.next_address(address);
+        }
+        bytes
+    }
+
+    /// Causes the next `count` transactions to fail without affecting the device
+    ///
+    /// I2C transactions fail with a
+    /// [`NoAcknowledge`](embedded_hal::i2c::ErrorKind::NoAcknowledge) of the address, and SPI
+    /// transactions fail with [`Other`](embedded_hal::spi::ErrorKind::Other).
+    pub fn nack_next(&mut self, count: usize) {
+        self.nacks = count;
+    }
+
+    /// Passes every byte read over the bus through `hook`, along with the address it was read
+    /// from, returning the byte received by the driver
+    ///
+    /// The stored bytes are unaffected, thus this may be used to simulate corruption in transit.
+    ///
+    /// ```
+    /// use embedded_hal::i2c::I2c;
+    /// use regiface::mock::FakeRegisterDevice;
+    ///
+    /// let mut device = FakeRegisterDevice::new();
+    /// device.set_register(&[0x10], &[0x12, 0x34]);
+    ///
+    /// // Flip the lowest bit of the second byte
+    /// device.set_read_hook(|address, byte| if address == 0x11 { byte ^ 0x01 } else { byte });
+    ///
+    /// let mut buf = [0; 2];
+    /// device.write_read(0x19, &[0x10], &mut buf).unwrap();
+    /// assert_eq!(buf, [0x12, 0x35]);
+    /// assert_eq!(device.get_register(&[0x10]), [0x12, 0x34]);
+    /// ```
+    pub fn set_read_hook(&mut self, hook: impl FnMut(u32, u8) -> u8 + 'static) {
+        self.read_hook = Some(Box::new(hook));
+    }
+
+    /// Removes the hook set with [`set_read_hook`](FakeRegisterDevice::set_read_hook)
+    pub fn clear_read_hook(&mut self) {
+        self.read_hook = None;
+    }
+
+    /// Returns the address of the register `id`
+    fn address_of(&self, id: &[u8]) -> u32 {
+        assert_eq!(
+            id.len(),
+            self.id_len,
+            "register IDs of this device are {} bytes long",
+            self.id_len
+        );
+        id.iter()
+            .fold(0, |address, &byte| (address << 8) | u32::from(byte))
+    }
+
+    /// Returns the address following `address`, wrapping around at the end of the address space
+    fn next_address(&self, address: u32) -> u32 {
+        match self.id_len {
+            4 => address.wrapping_add(1),
+            len => (address + 1) & ((1 << (8 * len)) - 1),
+        }
+    }
+
+    /// Returns whether the next transaction is to fail, consuming one injected failure
+    fn take_nack(&mut self) -> bool {
+        let nack = self.nacks > 0;
+        self.nacks = self.nacks.saturating_sub(1);
+        nack
+    }
+
+    /// Prepares for the register pointer to be set by the next bytes written
+    fn start(&mut self) {
+        self.address = 0;
+        self.address_remaining = self.id_len;
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            if self.address_remaining > 0 {
+                self.address = (self.address << 8) | u32::from(byte);
+                self.address_remaining -= 1;
+                if self.address_remaining == 0 {
+                    self.pointer = self.address;
+                }
+            } else {
+                self.memory.insert(self.pointer, byte);
+                self.pointer = self.next_address(self.pointer);
+            }
+        }
+    }
+
+    fn read(&mut self, buf: &mut [u8]) {
+        for byte in buf {
+            let value = self.memory.get(&self.pointer).copied().unwrap_or(0);
+            *byte = match &mut self.read_hook {
+                Some(hook) => hook(self.pointer, value),
+                None => value,
+            };
+            self.pointer = self.next_address(self.pointer);
+        }
+    }
+
+    fn i2c_transaction(
+        &mut self,
+        operations: &mut [embedded_hal::i2c::Operation<'_>],
+    ) -> Result<(), embedded_hal::i2c::ErrorKind> {
+        use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource, Operation};
+
+        if self.take_nack() {
+            return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+        }
+
+        let mut reading = true;
+        for operation in operations {
+            match operation {
+                Operation::Write(bytes) => {
+                    if reading {
+                        self.start();
+                        reading = false;
+                    }
+                    self.write(bytes);
+                }
+                Operation::Read(buf) => {
+                    self.read(buf);
+                    reading = true;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn spi_transaction(
+        &mut self,
+        operations: &mut [embedded_hal::spi::Operation<'_, u8>],
+    ) -> Result<(), embedded_hal::spi::ErrorKind> {
+        use embedded_hal::spi::{ErrorKind, Operation};
+
+        let duplex = operations.iter().any(|operation| {
+            matches!(
+                operation,
+                Operation::Transfer(..) | Operation::TransferInPlace(_)
+            )
+        });
+        if self.take_nack() || duplex {
+            return Err(ErrorKind::Other);
+        }
+
+        self.start();
+        for operation in operations {
+            match operation {
+                Operation::Write(bytes) => self.write(bytes),
+                Operation::Read(buf) => self.read(buf),
+                Operation::Transfer(..) | Operation::TransferInPlace(_) | Operation::DelayNs(_) => {
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl embedded_hal::i2c::ErrorType for FakeRegisterDevice {
+    type Error = embedded_hal::i2c::ErrorKind;
+}
+
+impl embedded_hal::i2c::I2c for FakeRegisterDevice {
+    fn transaction(
+        &mut self,
+        _address: u8,
+        operations: &mut [embedded_hal::i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.i2c_transaction(operations)
+    }
+}
+
+impl embedded_hal::spi::ErrorType for FakeRegisterDevice {
+    type Error = embedded_hal::spi::ErrorKind;
+}
+
+impl embedded_hal::spi::SpiDevice for FakeRegisterDevice {
+    fn transaction(
+        &mut self,
+        operations: &mut [embedded_hal::spi::Operation<'_, u8>],
+    ) -> Result<(), Self::Error> {
+        self.spi_transaction(operations)
+    }
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::i2c::I2c for FakeRegisterDevice {
+    async fn transaction(
+        &mut self,
+        _address: u8,
+        operations: &mut [embedded_hal_async::i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.i2c_transaction(operations)
+    }
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::spi::SpiDevice for FakeRegisterDevice {
+    async fn transaction(
+        &mut self,
+        operations: &mut [embedded_hal_async::spi::Operation<'_, u8>],
+    ) -> Result<(), Self::Error> {
+        self.spi_transaction(operations)
+    }
+}
@@ -0,0 +1,37 @@
+//! A convenience module re-exporting the most commonly used traits, derives, and types
+//!
+//! Bus specific items, such as those in the [`i2c`](crate::i2c) and [`spi`](crate::spi) modules,
+//! are intentionally not included.
+//!
+//! ```
+//! use regiface::prelude::*;
+//!
+//! #[register(42u8)]
+//! #[derive(ReadableRegister, WritableRegister, Debug)]
+//! pub struct MyRegister {
+//!     value: u8
+//! }
+//!
+//! impl FromByteArray for MyRegister {
+//!     type Error = core::convert::Infallible;
+//!     type Array = [u8; 1];
+//!
+//!     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+//!         Ok(Self { value: bytes[0] })
+//!     }
+//! }
+//!
+//! impl ToByteArray for MyRegister {
+//!     type Error = core::convert::Infallible;
+//!     type Array = [u8; 1];
+//!
+//!     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+//!         Ok([self.value])
+//!     }
+//! }
+//! ```
+
+pub use crate::{
+    register, Command, DynamicCommand, FromByteArray, FromByteSlice, IndexedRegister, NoParameters,
+    ReadableRegister, Register, ToByteArray, WritableRegister, Zeros, I24, U24,
+};
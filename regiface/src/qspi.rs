@@ -0,0 +1,249 @@
+//! A collection of utility functions for invoking commands across a QSPI (Quad SPI) bus
+//!
+//! QSPI buses extend SPI with additional data lines, allowing each phase of a transaction to be
+//! clocked out over one, two, or four lines. As no QSPI abstraction is provided by `embedded-hal`,
+//! this module defines a minimal `blocking::QspiDevice` and `async::QspiDevice` trait that HAL
+//! implementations or driver authors can implement for their QSPI peripheral.
+//!
+//! The number of lines used for each phase of a [`Command`] is taken from
+//! [`Command::id_width`], [`Command::parameters_width`], and [`Command::response_width`], all of
+//! which default to [`LineWidth::Single`].
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+use crate::command::check_response;
+#[cfg(any(feature = "blocking", feature = "async", doc))]
+use crate::{
+    byte_array::ByteArray as _, errors::CommandError, id::Id as _, Command, FromByteArray,
+    ToByteArray,
+};
+
+/// The number of data lines used to transfer a phase of a QSPI transaction
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub enum LineWidth {
+    /// A single data line, equivalent to standard SPI
+    #[default]
+    Single,
+    /// Two data lines
+    Dual,
+    /// Four data lines
+    Quad,
+}
+
+/// A single phase of a QSPI transaction
+#[derive(Debug, PartialEq, Eq)]
+pub enum Operation<'a> {
+    /// Write the provided bytes using the given number of data lines
+    Write(LineWidth, &'a [u8]),
+    /// Read bytes into the provided buffer using the given number of data lines
+    Read(LineWidth, &'a mut [u8]),
+}
+
+#[cfg(feature = "async")]
+pub mod r#async {
+    use super::*;
+
+    /// A QSPI device capable of performing multi-phase transactions
+    ///
+    /// Implementations must keep the device selected for the duration of the transaction.
+    #[allow(async_fn_in_trait)]
+    pub trait QspiDevice {
+        /// The type of error that may occur while communicating with the device
+        type Error: core::fmt::Debug;
+
+        /// Perform a transaction consisting of the provided operations, in order
+        async fn transaction(
+            &mut self,
+            operations: &mut [Operation<'_>],
+        ) -> Result<(), Self::Error>;
+    }
+
+    /// Invoke a command on a QSPI device and receive its response.
+    ///
+    /// This function performs a complete command transaction:
+    /// 1. Sends the command ID using [`Command::id_width`] lines
+    /// 2. Sends the serialized command parameters using [`Command::parameters_width`] lines
+    /// 3. Reads the status byte, if [`Command::STATUS_BYTE`] is set, followed by the command
+    ///    response using [`Command::response_width`] lines
+    ///
+    /// The entire operation is atomic, using the device's transaction capability to
+    /// ensure all steps occur without interruption.
+    ///
+    /// # Parameters
+    /// * `device` - The QSPI device to communicate with
+    /// * `cmd` - The command to invoke
+    ///
+    /// # Errors
+    /// * `CommandError::BusError` - Communication with the device failed
+    /// * `CommandError::SerializationError` - Failed to convert command parameters to bytes
+    /// * `CommandError::DeserializationError` - Failed to convert received bytes into response parameters
+    /// * `CommandError::ValidationError` - The response was rejected by [`Command::validate_response`]
+    /// * `CommandError::DeviceError` - The device responded with a nonzero status byte
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use regiface::{qspi::{self, LineWidth, r#async::QspiDevice}, Command, FromByteArray};
+    /// # struct FastReadQuad { address: u32 }
+    /// # struct Page([u8; 256]);
+    /// # impl Command for FastReadQuad {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = u32;
+    /// #     type ResponseParameters = Page;
+    /// #     fn id() -> Self::IdType { 0xEC }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { self.address }
+    /// #     fn parameters_width() -> LineWidth { LineWidth::Quad }
+    /// #     fn response_width() -> LineWidth { LineWidth::Quad }
+    /// # }
+    /// # impl FromByteArray for Page {
+    /// #     type Array = [u8; 256];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes)) }
+    /// # }
+    /// async fn read_page<D: QspiDevice>(device: &mut D) {
+    ///     let page: Page = qspi::r#async::invoke_command(device, FastReadQuad { address: 0x100 }).await.unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_command<D, C>(
+        device: &mut D,
+        cmd: C,
+    ) -> Result<
+        C::ResponseParameters,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        D: QspiDevice,
+        C: Command,
+    {
+        check_response::<C>();
+
+        let cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+
+        let mut status = [0; 1];
+
+        let reg_id = C::id().to_id_bytes();
+        let id_op = Operation::Write(C::id_width(), reg_id.as_ref());
+        let params_op = Operation::Write(C::parameters_width(), cmd_buf.as_ref());
+        let resp_op = Operation::Read(C::response_width(), resp_buf.as_mut());
+
+        let operations: &mut [Operation<'_>] = if C::STATUS_BYTE {
+            let status_op = Operation::Read(C::response_width(), &mut status);
+            &mut [id_op, params_op, status_op, resp_op]
+        } else {
+            &mut [id_op, params_op, resp_op]
+        };
+
+        device
+            .transaction(operations)
+            .await
+            .map_err(CommandError::BusError)?;
+
+        if status[0] != 0 {
+            return Err(CommandError::DeviceError(status[0]));
+        }
+
+        C::validate_response(resp_buf.as_ref()).map_err(CommandError::ValidationError)?;
+        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
+    }
+}
+
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use super::*;
+
+    /// A QSPI device capable of performing multi-phase transactions
+    ///
+    /// Implementations must keep the device selected for the duration of the transaction.
+    pub trait QspiDevice {
+        /// The type of error that may occur while communicating with the device
+        type Error: core::fmt::Debug;
+
+        /// Perform a transaction consisting of the provided operations, in order
+        fn transaction(&mut self, operations: &mut [Operation<'_>]) -> Result<(), Self::Error>;
+    }
+
+    /// Invoke a command on a QSPI device and receive its response.
+    ///
+    /// Blocking variant of `r#async::invoke_command`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use regiface::{qspi::{self, LineWidth, blocking::QspiDevice}, Command, FromByteArray};
+    /// # struct FastReadQuad { address: u32 }
+    /// # struct Page([u8; 256]);
+    /// # impl Command for FastReadQuad {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = u32;
+    /// #     type ResponseParameters = Page;
+    /// #     fn id() -> Self::IdType { 0xEC }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { self.address }
+    /// #     fn parameters_width() -> LineWidth { LineWidth::Quad }
+    /// #     fn response_width() -> LineWidth { LineWidth::Quad }
+    /// # }
+    /// # impl FromByteArray for Page {
+    /// #     type Array = [u8; 256];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes)) }
+    /// # }
+    /// fn read_page<D: QspiDevice>(device: &mut D) {
+    ///     let page: Page = qspi::blocking::invoke_command(device, FastReadQuad { address: 0x100 }).unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn invoke_command<D, C>(
+        device: &mut D,
+        cmd: C,
+    ) -> Result<
+        C::ResponseParameters,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        D: QspiDevice,
+        C: Command,
+    {
+        check_response::<C>();
+
+        let cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+
+        let mut status = [0; 1];
+
+        let reg_id = C::id().to_id_bytes();
+        let id_op = Operation::Write(C::id_width(), reg_id.as_ref());
+        let params_op = Operation::Write(C::parameters_width(), cmd_buf.as_ref());
+        let resp_op = Operation::Read(C::response_width(), resp_buf.as_mut());
+
+        let operations: &mut [Operation<'_>] = if C::STATUS_BYTE {
+            let status_op = Operation::Read(C::response_width(), &mut status);
+            &mut [id_op, params_op, status_op, resp_op]
+        } else {
+            &mut [id_op, params_op, resp_op]
+        };
+
+        device
+            .transaction(operations)
+            .map_err(CommandError::BusError)?;
+
+        if status[0] != 0 {
+            return Err(CommandError::DeviceError(status[0]));
+        }
+
+        C::validate_response(resp_buf.as_ref()).map_err(CommandError::ValidationError)?;
+        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
+    }
+}
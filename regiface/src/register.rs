@@ -1,9 +1,33 @@
-use crate::{id, FromByteArray, ToByteArray};
+use crate::{byte_array::ByteArray as _, id, ByRef, FromByteArray, ToByteArray};
 
 /// The core trait to be implemented for all types that represent readable or writable register values
 ///
 /// This trait provides minimal value on its own, but is a building block to be combined with either [`ReadableRegister`]
 /// or [`WritableRegister`].
+///
+/// This trait may be implemented with the [`register`](crate::register) attribute macro, which additionally
+/// emits an `ID` associated constant for use in const contexts and patterns, and appends a line such as
+/// "Register ID: `0x42` (`u8`)" to the documentation of the type
+///
+/// ```
+/// use regiface::{register, Register};
+///
+/// #[register(0x42u8)]
+/// pub struct Status;
+///
+/// #[register(0x43u8)]
+/// pub struct Control;
+///
+/// fn describe(addr: u8) -> &'static str {
+///     match addr {
+///         Status::ID => "status",
+///         Control::ID => "control",
+///         _ => "unknown",
+///     }
+/// }
+///
+/// assert_eq!(describe(Status::id()), "status");
+/// ```
 pub trait Register {
     /// The type used to represent the register's ID.
     ///
@@ -11,6 +35,19 @@ pub trait Register {
     /// trait provides default implementations for [`u8`], [`u16`], [`u32`], [`u64`], and [`u128`].
     type IdType: id::Id;
 
+    /// A mask of bits within the register that are reserved and must always be written as zero
+    ///
+    /// The mask is applied to the serialized register value as a big-endian integer, such that bit 0
+    /// of the mask corresponds to bit 0 of the last byte. Defaults to no reserved bits. See
+    /// [`WritableRegister::to_register_bytes`] for how this mask is enforced.
+    const RESERVED_MASK: u128 = 0;
+
+    /// A mask of bits within the register that are required and must always be written as one
+    ///
+    /// This mask is aligned in the same manner as [`Register::RESERVED_MASK`] and defaults to no
+    /// required bits.
+    const REQUIRED_MASK: u128 = 0;
+
     /// A method that returns the ID of the register for the associated type
     fn id() -> Self::IdType;
 }
@@ -46,6 +83,147 @@ pub trait ReadableRegister: Register + FromByteArray {
     fn readable_id() -> Self::IdType {
         Self::id()
     }
+
+    /// The number of dummy bytes to be clocked out between sending the register ID and reading the
+    /// register value.
+    ///
+    /// Some SPI devices require a number of dummy clock cycles between the address and data phases
+    /// of a read. Override this function to specify the number of dummy bytes required. This is
+    /// only used by the [`spi`](crate::spi) module and defaults to zero.
+    ///
+    #[cfg_attr(feature = "blocking", doc = "```")]
+    #[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+    /// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+    /// use regiface::{register, spi, ReadableRegister, FromByteArray};
+    ///
+    /// #[register(0x0Bu8)]
+    /// #[derive(Debug)]
+    /// pub struct FastRead {
+    ///     value: u8
+    /// }
+    ///
+    /// impl ReadableRegister for FastRead {
+    ///     fn dummy_bytes() -> usize {
+    ///         2
+    ///     }
+    /// }
+    ///
+    /// impl FromByteArray for FastRead {
+    ///     type Error = core::convert::Infallible;
+    ///     type Array = [u8; 1];
+    ///
+    ///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+    ///         Ok(Self { value: bytes[0] })
+    ///     }
+    /// }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         assert!(matches!(ops, [Operation::Write([0x0B]), Operation::Write([0, 0]), Operation::Read([_])]));
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    ///
+    /// // Clocks out [0x0B, 0x00, 0x00] before reading the register value
+    /// let reg: FastRead = spi::blocking::read_register(&mut device).unwrap();
+    /// ```
+    #[inline]
+    fn dummy_bytes() -> usize {
+        0
+    }
+
+    /// How the dummy bytes specified by [`ReadableRegister::dummy_bytes`] are clocked.
+    ///
+    /// By default dummy bytes are clocked by writing zeros. Override this function to instead
+    /// clock them by reading into a scratch buffer, for devices that require the data line to be
+    /// left undriven. Either way, the dummy bytes are never included in the register value. This is
+    /// only used by the [`spi`](crate::spi) module.
+    ///
+    #[cfg_attr(feature = "blocking", doc = "```")]
+    #[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+    /// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+    /// use regiface::{register, spi::{self, DummyMode}, ReadableRegister, FromByteArray};
+    ///
+    /// #[register(0x03u8)]
+    /// #[derive(Debug)]
+    /// pub struct Sample(u16);
+    ///
+    /// impl ReadableRegister for Sample {
+    ///     fn dummy_bytes() -> usize {
+    ///         1
+    ///     }
+    ///
+    ///     fn dummy_mode() -> DummyMode {
+    ///         DummyMode::Read
+    ///     }
+    /// }
+    ///
+    /// impl FromByteArray for Sample {
+    ///     type Error = core::convert::Infallible;
+    ///     type Array = [u8; 2];
+    ///
+    ///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+    ///         Ok(Self(u16::from_be_bytes(bytes)))
+    ///     }
+    /// }
+    /// # // A device that clocks out a turnaround byte followed by the register value
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         let [Operation::Write([0x03]), Operation::Read(dummy), Operation::Read(data)] = ops else {
+    /// #             panic!("unexpected operations");
+    /// #         };
+    /// #         let mut wire = [0xFF, 0x12, 0x34].into_iter();
+    /// #         dummy.iter_mut().chain(data.iter_mut()).for_each(|b| *b = wire.next().unwrap());
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    ///
+    /// // The turnaround byte is read into a scratch buffer and discarded
+    /// let sample: Sample = spi::blocking::read_register(&mut device).unwrap();
+    /// assert_eq!(sample.0, 0x1234);
+    /// ```
+    #[inline]
+    fn dummy_mode() -> crate::spi::DummyMode {
+        crate::spi::DummyMode::Write
+    }
+}
+
+/// A readable register holding a fixed value that identifies the device, such as a `WHO_AM_I` or
+/// chip ID register
+///
+/// Reading the register and comparing it to [`WhoAmI::expected`] verifies that the expected device
+/// is present, as performed by `verify_whoami` functions such as `i2c::blocking::verify_whoami`.
+///
+/// ```
+/// use regiface::{register, FromByteArray, ReadableRegister, WhoAmI};
+///
+/// #[register(0x0Fu8)]
+/// #[derive(ReadableRegister, Debug, PartialEq)]
+/// pub struct ChipId(u8);
+///
+/// impl FromByteArray for ChipId {
+///     type Error = core::convert::Infallible;
+///     type Array = [u8; 1];
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         Ok(Self(bytes[0]))
+///     }
+/// }
+///
+/// impl WhoAmI for ChipId {
+///     fn expected() -> Self {
+///         Self(0x6A)
+///     }
+/// }
+/// ```
+pub trait WhoAmI: ReadableRegister + PartialEq {
+    /// The value the register holds on the expected device
+    fn expected() -> Self;
 }
 
 /// A marker trait that represents a type that can be written into a register
@@ -79,4 +257,159 @@ pub trait WritableRegister: Register + ToByteArray {
     fn writeable_id() -> Self::IdType {
         Self::id()
     }
+
+    /// Serialize the register value into the bytes to be written to the device
+    ///
+    /// This serializes the value with [`ToByteArray::to_bytes`], then clears any bits set in
+    /// [`Register::RESERVED_MASK`] and sets any bits set in [`Register::REQUIRED_MASK`]. All
+    /// register writes performed by this crate use this method, ensuring a value with reserved bits
+    /// set is sanitized before being transmitted.
+    ///
+    /// The masks may be declared with the [`register`](crate::register) attribute macro
+    ///
+    #[cfg_attr(feature = "blocking", doc = "```")]
+    #[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+    /// # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+    /// use regiface::{i2c, register, ToByteArray, WritableRegister};
+    ///
+    /// #[register(0x01u8, reserved = 0b0000_1100, required = 0b1000_0000)]
+    /// #[derive(WritableRegister)]
+    /// pub struct Config(u8);
+    ///
+    /// impl ToByteArray for Config {
+    ///     type Error = core::convert::Infallible;
+    ///     type Array = [u8; 1];
+    ///
+    ///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    ///         Ok([self.0])
+    ///     }
+    /// }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl I2c<u8> for Device {
+    /// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+    /// #         assert!(matches!(ops, [Operation::Write([0x01]), Operation::Write([0b1111_0011])]));
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    ///
+    /// assert_eq!(Config(0xFF).to_register_bytes().unwrap(), [0b1111_0011]);
+    /// assert_eq!(Config(0x00).to_register_bytes().unwrap(), [0b1000_0000]);
+    ///
+    /// // The reserved bits are cleared before the value is transmitted
+    /// i2c::blocking::write_register(&mut device, 0x48, Config(0xFF)).unwrap();
+    /// ```
+    fn to_register_bytes(self) -> Result<Self::Array, Self::Error>
+    where
+        Self: Sized,
+    {
+        let mut bytes = self.to_bytes()?;
+        let reserved = Self::RESERVED_MASK.to_be_bytes();
+        let required = Self::REQUIRED_MASK.to_be_bytes();
+
+        for ((byte, reserved), required) in bytes
+            .as_mut()
+            .iter_mut()
+            .rev()
+            .zip(reserved.iter().rev())
+            .zip(required.iter().rev())
+        {
+            *byte = (*byte & !reserved) | required;
+        }
+
+        Ok(bytes)
+    }
+}
+
+impl<R: Register> Register for ByRef<'_, R> {
+    type IdType = R::IdType;
+
+    const RESERVED_MASK: u128 = R::RESERVED_MASK;
+    const REQUIRED_MASK: u128 = R::REQUIRED_MASK;
+
+    #[inline]
+    fn id() -> Self::IdType {
+        R::id()
+    }
+}
+
+impl<R: WritableRegister + Copy> WritableRegister for ByRef<'_, R> {
+    #[inline]
+    fn writeable_id() -> Self::IdType {
+        R::writeable_id()
+    }
+}
+
+/// A trait to be implemented for registers that exist at multiple IDs, such as per-channel registers
+///
+/// Devices with a number of identical channels, such as ADC inputs or LED drivers, commonly expose
+/// one instance of a register per channel with the register ID computed from the channel index.
+/// Rather than defining a distinct type per channel, a single type may implement this trait and be
+/// used with the `read_register_at` and `write_register_at` functions, such as
+/// `i2c::blocking::read_register_at`.
+///
+/// The `_at` functions use [`IndexedRegister::readable_indexed_id`] and
+/// [`IndexedRegister::writeable_indexed_id`] as the register ID, and do not use any overrides of
+/// [`ReadableRegister::readable_id`] or [`WritableRegister::writeable_id`]. If reading and writing
+/// a register require different IDs, override the indexed variants instead.
+///
+/// ```
+/// use regiface::{register, IndexedRegister, ToByteArray, WritableRegister};
+///
+/// // The PCA9685 exposes a 4 byte ON/OFF register block per channel, starting at LED0_ON_L
+/// #[register(0x06u8)]
+/// #[derive(WritableRegister)]
+/// pub struct LedOnOff {
+///     on: u16,
+///     off: u16,
+/// }
+///
+/// impl IndexedRegister for LedOnOff {
+///     type Index = u8;
+///
+///     fn indexed_id(channel: Self::Index) -> Self::IdType {
+///         Self::ID + 4 * channel
+///     }
+/// }
+///
+/// impl ToByteArray for LedOnOff {
+///     type Error = core::convert::Infallible;
+///     type Array = [u8; 4];
+///
+///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+///         let [on_l, on_h] = self.on.to_le_bytes();
+///         let [off_l, off_h] = self.off.to_le_bytes();
+///         Ok([on_l, on_h, off_l, off_h])
+///     }
+/// }
+///
+/// assert_eq!(LedOnOff::indexed_id(15), 0x42);
+/// ```
+pub trait IndexedRegister: Register {
+    /// The type used to select an instance of the register, such as a channel number
+    type Index;
+
+    /// A method that returns the ID of the register instance at the given index
+    fn indexed_id(index: Self::Index) -> Self::IdType;
+
+    /// Some implementations may specify a different register ID to be used when reading the register
+    /// instance at the given index.
+    ///
+    /// Override the function if you need to specify an ID value different than that specified by
+    /// [`IndexedRegister::indexed_id`] for the purpose of reading from the register
+    #[inline]
+    fn readable_indexed_id(index: Self::Index) -> Self::IdType {
+        Self::indexed_id(index)
+    }
+
+    /// Some implementations may specify a different register ID to be used when writing the register
+    /// instance at the given index.
+    ///
+    /// Override the function if you need to specify an ID value different than that specified by
+    /// [`IndexedRegister::indexed_id`] for the purpose of writing to the register
+    #[inline]
+    fn writeable_indexed_id(index: Self::Index) -> Self::IdType {
+        Self::indexed_id(index)
+    }
 }
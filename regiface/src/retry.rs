@@ -0,0 +1,397 @@
+//! Utilities for retrying register operations that fail with a transient error
+//!
+//! Devices may fail an operation for reasons that resolve on their own, such as an EEPROM not
+//! acknowledging its address while an internal write cycle completes, or arbitration being lost
+//! to another controller on a shared bus. The functions of this module re-run an operation,
+//! waiting between attempts, until it succeeds, fails with an error that is not
+//! [transient](ErrorClass::is_transient), or the allowed number of attempts is exhausted.
+//!
+//! `retry_blocking` is only available when the `blocking` feature is enabled, and `retry_async`
+//! when the `async` feature is enabled.
+//!
+//! A [`Policy`] additionally allows the wait between attempts to grow with each attempt. The
+//! `retry_policy_blocking` and `retry_policy_async` functions, and the `*_retry` wrappers of the
+//! [`i2c`](crate::i2c) and [`spi`](crate::spi) modules, follow a policy and report the number of
+//! attempts made in a [`RetryError`].
+
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+#[cfg(any(feature = "blocking", feature = "async", doc))]
+use crate::errors::{ErrorClass, RetryError};
+
+/// How the wait between attempts changes as an operation is retried
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum Backoff {
+    /// Every wait is the base delay of the policy
+    #[default]
+    Fixed,
+    /// Each wait is `factor` times longer than the previous one, starting at the base delay
+    Exponential {
+        /// The factor by which the wait grows after each attempt
+        factor: u32,
+    },
+}
+
+/// A policy describing how often, and how patiently, an operation is retried
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Policy {
+    /// The maximum number of times to perform the operation. Zero is treated as one.
+    pub max_attempts: u32,
+    /// The number of microseconds to wait before the first retry
+    pub base_delay_us: u32,
+    /// How the wait changes for subsequent retries
+    pub backoff: Backoff,
+}
+
+impl Policy {
+    /// Create a policy waiting `delay_us` microseconds between each of up to `max_attempts`
+    /// attempts
+    pub const fn fixed(max_attempts: u32, delay_us: u32) -> Self {
+        Self {
+            max_attempts,
+            base_delay_us: delay_us,
+            backoff: Backoff::Fixed,
+        }
+    }
+
+    /// Create a policy waiting `base_delay_us` microseconds before the first retry, multiplying
+    /// the wait by `factor` before each subsequent retry
+    pub const fn exponential(max_attempts: u32, base_delay_us: u32, factor: u32) -> Self {
+        Self {
+            max_attempts,
+            base_delay_us,
+            backoff: Backoff::Exponential { factor },
+        }
+    }
+
+    /// Returns the number of microseconds to wait after the given attempt, counting from one
+    ///
+    /// The wait saturates at [`u32::MAX`] microseconds.
+    ///
+    /// ```
+    /// use regiface::retry::Policy;
+    ///
+    /// let policy = Policy::exponential(5, 100, 2);
+    /// assert_eq!(policy.delay_us(1), 100);
+    /// assert_eq!(policy.delay_us(2), 200);
+    /// assert_eq!(policy.delay_us(4), 800);
+    /// assert_eq!(Policy::fixed(5, 100).delay_us(4), 100);
+    ///
+    /// // A base delay of zero never waits, however many attempts have been made
+    /// assert_eq!(Policy::exponential(40, 0, 2).delay_us(34), 0);
+    /// ```
+    pub fn delay_us(&self, attempt: u32) -> u32 {
+        match self.backoff {
+            Backoff::Fixed => self.base_delay_us,
+            Backoff::Exponential { factor } => self
+                .base_delay_us
+                .saturating_mul(factor.saturating_pow(attempt.saturating_sub(1))),
+        }
+    }
+}
+
+/// Perform an operation, retrying it while it fails with a transient error.
+///
+/// The operation is performed up to `attempts` times, waiting `interval_us` microseconds between
+/// attempts. The result of the first attempt to succeed, or to fail with an error that is not
+/// transient, is returned. If every attempt fails with a transient error, the error of the last
+/// attempt is returned. An `attempts` of zero is treated as one.
+///
+/// # Parameters
+/// * `attempts` - The maximum number of times to perform the operation
+/// * `delay` - The delay used to wait between attempts
+/// * `interval_us` - The number of microseconds to wait between attempts
+/// * `operation` - The operation to perform
+///
+/// # Errors
+/// Returns the error of the last attempt if no attempt succeeded
+///
+/// # Example
+/// ```
+/// # use embedded_hal::{delay::DelayNs, i2c::{ErrorKind, ErrorType, I2c, NoAcknowledgeSource, Operation}};
+/// use regiface::{i2c, register, retry::retry_blocking, FromByteArray, ReadableRegister};
+///
+/// #[register(0x0Fu8)]
+/// #[derive(ReadableRegister, Debug)]
+/// struct WhoAmI(u8);
+///
+/// impl FromByteArray for WhoAmI {
+///     type Array = [u8; 1];
+///     type Error = core::convert::Infallible;
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         Ok(Self(bytes[0]))
+///     }
+/// }
+/// # struct NoDelay;
+/// # impl DelayNs for NoDelay { fn delay_ns(&mut self, _: u32) {} }
+/// # struct Device { nacks: u8, attempts: u8 }
+/// # impl ErrorType for Device { type Error = ErrorKind; }
+/// # impl I2c for Device {
+/// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+/// #         self.attempts += 1;
+/// #         if self.nacks > 0 {
+/// #             self.nacks -= 1;
+/// #             return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+/// #         }
+/// #         let [Operation::Write([0x0F]), Operation::Read(buf)] = ops else { panic!() };
+/// #         buf.copy_from_slice(&[0x33]);
+/// #         Ok(())
+/// #     }
+/// # }
+/// # let mut delay = NoDelay;
+///
+/// // The device does not acknowledge the first two attempts
+/// # let mut device = Device { nacks: 2, attempts: 0 };
+/// let id: WhoAmI = retry_blocking(5, &mut delay, 1_000, || {
+///     i2c::blocking::read_register(&mut device, 0x19)
+/// })
+/// .unwrap();
+/// assert_eq!(id.0, 0x33);
+/// assert_eq!(device.attempts, 3);
+///
+/// // Once the attempts are exhausted, the last error is returned
+/// # let mut device = Device { nacks: 5, attempts: 0 };
+/// let result = retry_blocking(3, &mut delay, 1_000, || {
+///     i2c::blocking::read_register::<_, _, WhoAmI>(&mut device, 0x19)
+/// });
+/// assert!(result.is_err());
+/// assert_eq!(device.attempts, 3);
+/// ```
+#[cfg(feature = "blocking")]
+pub fn retry_blocking<T, E, D, F>(
+    attempts: u32,
+    delay: &mut D,
+    interval_us: u32,
+    operation: F,
+) -> Result<T, E>
+where
+    E: ErrorClass,
+    D: embedded_hal::delay::DelayNs,
+    F: FnMut() -> Result<T, E>,
+{
+    retry_policy_blocking(&Policy::fixed(attempts, interval_us), delay, operation)
+        .map_err(|e| e.error)
+}
+
+/// Perform an async operation, retrying it while it fails with a transient error.
+///
+/// See `retry_blocking` for a detailed description of behavior.
+///
+/// # Example
+/// ```
+/// # use embedded_hal_async::{delay::DelayNs, i2c::{ErrorKind, ErrorType, I2c, NoAcknowledgeSource, Operation}};
+/// use regiface::{i2c, register, retry::retry_async, FromByteArray, ReadableRegister};
+///
+/// #[register(0x0Fu8)]
+/// #[derive(ReadableRegister, Debug)]
+/// struct WhoAmI(u8);
+///
+/// impl FromByteArray for WhoAmI {
+///     type Array = [u8; 1];
+///     type Error = core::convert::Infallible;
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         Ok(Self(bytes[0]))
+///     }
+/// }
+/// # struct NoDelay;
+/// # impl DelayNs for NoDelay { async fn delay_ns(&mut self, _: u32) {} }
+/// # struct Device { nacks: u8, attempts: u8 }
+/// # impl ErrorType for Device { type Error = ErrorKind; }
+/// # impl I2c for Device {
+/// #     async fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+/// #         self.attempts += 1;
+/// #         if self.nacks > 0 {
+/// #             self.nacks -= 1;
+/// #             return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+/// #         }
+/// #         let [Operation::Write([0x0F]), Operation::Read(buf)] = ops else { panic!() };
+/// #         buf.copy_from_slice(&[0x33]);
+/// #         Ok(())
+/// #     }
+/// # }
+/// # let mut delay = NoDelay;
+/// # let mut device = Device { nacks: 2, attempts: 0 };
+/// # let fut = core::pin::pin!(async {
+/// // The device does not acknowledge the first two attempts
+/// let id: WhoAmI = retry_async(5, &mut delay, 1_000, async || {
+///     i2c::r#async::read_register(&mut device, 0x19).await
+/// })
+/// .await
+/// .unwrap();
+/// assert_eq!(id.0, 0x33);
+/// assert_eq!(device.attempts, 3);
+/// # });
+/// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+/// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+/// ```
+#[cfg(feature = "async")]
+pub async fn retry_async<T, E, D, F>(
+    attempts: u32,
+    delay: &mut D,
+    interval_us: u32,
+    operation: F,
+) -> Result<T, E>
+where
+    E: ErrorClass,
+    D: embedded_hal_async::delay::DelayNs,
+    F: AsyncFnMut() -> Result<T, E>,
+{
+    retry_policy_async(&Policy::fixed(attempts, interval_us), delay, operation)
+        .await
+        .map_err(|e| e.error)
+}
+
+/// Perform an operation, retrying it according to a [`Policy`] while it fails with a transient
+/// error.
+///
+/// The operation is performed up to [`Policy::max_attempts`] times, waiting
+/// [`Policy::delay_us`] microseconds after each failed attempt. The result of the first attempt to
+/// succeed is returned.
+///
+/// # Parameters
+/// * `policy` - The policy determining the number of attempts and the waits between them
+/// * `delay` - The delay used to wait between attempts
+/// * `operation` - The operation to perform
+///
+/// # Errors
+/// Returns the error of the first attempt to fail with an error that is not transient, or of the
+/// last attempt if every attempt failed with a transient error, along with the number of attempts
+/// made.
+///
+/// # Example
+/// ```
+/// # use embedded_hal::{delay::DelayNs, i2c::{ErrorKind, ErrorType, I2c, NoAcknowledgeSource, Operation}};
+/// use regiface::{
+///     i2c, register,
+///     retry::{retry_policy_blocking, Policy},
+///     FromByteArray, ReadableRegister,
+/// };
+///
+/// #[register(0x0Fu8)]
+/// #[derive(ReadableRegister, Debug)]
+/// struct WhoAmI(u8);
+///
+/// impl FromByteArray for WhoAmI {
+///     type Array = [u8; 1];
+///     type Error = core::convert::Infallible;
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         Ok(Self(bytes[0]))
+///     }
+/// }
+/// # struct Delay(u32);
+/// # impl DelayNs for Delay { fn delay_ns(&mut self, ns: u32) { self.0 += ns / 1_000; } }
+/// # struct Device { nacks: u8 }
+/// # impl ErrorType for Device { type Error = ErrorKind; }
+/// # impl I2c for Device {
+/// #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+/// #         if self.nacks > 0 {
+/// #             self.nacks -= 1;
+/// #             return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+/// #         }
+/// #         let [Operation::Write([0x0F]), Operation::Read(buf)] = ops else { panic!() };
+/// #         buf.copy_from_slice(&[0x33]);
+/// #         Ok(())
+/// #     }
+/// # }
+/// # let mut device = Device { nacks: 3 };
+/// # let mut delay = Delay(0);
+/// let policy = Policy::exponential(5, 100, 2);
+///
+/// // The device does not acknowledge the first three attempts
+/// let id: WhoAmI = retry_policy_blocking(&policy, &mut delay, || {
+///     i2c::blocking::read_register(&mut device, 0x19)
+/// })
+/// .unwrap();
+/// assert_eq!(id.0, 0x33);
+/// // Waited 100us, 200us, and 400us
+/// assert_eq!(delay.0, 700);
+/// ```
+#[cfg(feature = "blocking")]
+pub fn retry_policy_blocking<T, E, D, F>(
+    policy: &Policy,
+    delay: &mut D,
+    mut operation: F,
+) -> Result<T, RetryError<E>>
+where
+    E: ErrorClass,
+    D: embedded_hal::delay::DelayNs,
+    F: FnMut() -> Result<T, E>,
+{
+    let max_attempts = policy.max_attempts.max(1);
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        match operation() {
+            Ok(value) => return Ok(value),
+            Err(e) if e.is_transient() && attempts < max_attempts => {
+                trace!(
+                    "retry: transient error, {} attempts remaining",
+                    max_attempts - attempts
+                );
+                delay.delay_us(policy.delay_us(attempts));
+            }
+            Err(error) => return Err(RetryError { error, attempts }),
+        }
+    }
+}
+
+/// Perform an async operation, retrying it according to a [`Policy`] while it fails with a
+/// transient error.
+///
+/// See `retry_policy_blocking` for a detailed description of behavior.
+///
+/// # Example
+/// ```no_run
+/// # use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+/// # use regiface::{i2c, register, retry::{retry_policy_async, Policy}, FromByteArray, ReadableRegister};
+/// # #[register(0x0Fu8)]
+/// # #[derive(ReadableRegister, Debug)]
+/// # struct WhoAmI(u8);
+/// # impl FromByteArray for WhoAmI {
+/// #     type Array = [u8; 1];
+/// #     type Error = core::convert::Infallible;
+/// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+/// # }
+/// async fn identify<D: I2c<u8>, T: DelayNs>(device: &mut D, delay: &mut T) -> u8 {
+///     let policy = Policy::exponential(5, 100, 2);
+///     let id: WhoAmI = retry_policy_async(&policy, delay, async || {
+///         i2c::r#async::read_register(device, 0x19).await
+///     })
+///     .await
+///     .unwrap();
+///     id.0
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn retry_policy_async<T, E, D, F>(
+    policy: &Policy,
+    delay: &mut D,
+    mut operation: F,
+) -> Result<T, RetryError<E>>
+where
+    E: ErrorClass,
+    D: embedded_hal_async::delay::DelayNs,
+    F: AsyncFnMut() -> Result<T, E>,
+{
+    let max_attempts = policy.max_attempts.max(1);
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(e) if e.is_transient() && attempts < max_attempts => {
+                trace!(
+                    "retry: transient error, {} attempts remaining",
+                    max_attempts - attempts
+                );
+                delay.delay_us(policy.delay_us(attempts)).await;
+            }
+            Err(error) => return Err(RetryError { error, attempts }),
+        }
+    }
+}
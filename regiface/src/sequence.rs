@@ -0,0 +1,436 @@
+//! Declarative initialization sequences of raw register writes, delays, and status polls
+//!
+//! Bringing up a device commonly consists of writing a handful of registers, waiting for the
+//! device to settle between some of them, and polling a status register until the device reports
+//! that it is ready. Rather than expressing this as a long imperative function, an
+//! [`InitSequence`] describes it as a table of [`InitStep`]s, which may be held in `const` data
+//! and shared between blocking and async drivers.
+//!
+//! A sequence is performed by the `run` functions of the `blocking` and `async` modules over any
+//! `RawBus`, such as a [`Device`] on an I2C or SPI bus. Register IDs and values are transmitted
+//! exactly as provided, thus any read or write flags of the device must be included in the IDs of
+//! the sequence.
+//!
+//! Steps are performed in order, and the sequence stops at the first step that fails, reporting
+//! the index of that step in the returned [`SequenceError`].
+//!
+//! # Example
+#![cfg_attr(feature = "blocking", doc = "```")]
+#![cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+//! # use embedded_hal::{delay::DelayNs, i2c::{ErrorType, I2c, Operation}};
+//! use regiface::{
+//!     device::I2cInterface,
+//!     sequence::{blocking::run, InitSequence, InitStep, SequenceError, StepError},
+//! };
+//!
+//! const BRING_UP: InitSequence = InitSequence::new(&[
+//!     // Soft reset, then allow the device to restart
+//!     InitStep::WriteRaw { id: &[0x7E], bytes: &[0xB6] },
+//!     InitStep::DelayUs(10_000),
+//!     // Configure the output data rate and range
+//!     InitStep::WriteRaw { id: &[0x40], bytes: &[0x28, 0x03] },
+//!     // Wait for the data ready bit of the status register
+//!     InitStep::PollFlag { id: &[0x03], mask: 0x80, value: 0x80, max_attempts: 5, interval_us: 1_000 },
+//! ]);
+//! # struct Bus { status: u8 }
+//! # impl ErrorType for Bus { type Error = core::convert::Infallible; }
+//! # impl I2c for Bus {
+//! #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+//! #         if let [Operation::Write(_), Operation::Read(buf)] = ops { buf[0] = self.status; }
+//! #         Ok(())
+//! #     }
+//! # }
+//! # struct NoDelay;
+//! # impl DelayNs for NoDelay { fn delay_ns(&mut self, _: u32) {} }
+//! # let mut delay = NoDelay;
+//!
+//! let mut device = I2cInterface::new(Bus { status: 0x80 }, 0x68);
+//! assert_eq!(run(&mut device, &mut delay, BRING_UP), Ok(()));
+//!
+//! // A device that never becomes ready fails the final step
+//! # device.bus_mut().status = 0x00;
+//! assert_eq!(
+//!     run(&mut device, &mut delay, BRING_UP),
+//!     Err(SequenceError { step: 3, kind: StepError::NotReady })
+//! );
+//! ```
+
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+#[cfg(any(feature = "blocking", feature = "async", doc))]
+use crate::device::{Device, NoAddress};
+
+/// A single step of an [`InitSequence`]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum InitStep<'a> {
+    /// Write the raw bytes of a register
+    WriteRaw {
+        /// The register ID, transmitted before the value
+        id: &'a [u8],
+        /// The value to write
+        bytes: &'a [u8],
+    },
+    /// Wait for the given number of microseconds
+    DelayUs(u32),
+    /// Repeatedly read a single byte register until the bits of `mask` equal those of `value`
+    PollFlag {
+        /// The register ID, transmitted before the register is read
+        id: &'a [u8],
+        /// The bits of the register to compare
+        mask: u8,
+        /// The expected value of the masked bits
+        value: u8,
+        /// The maximum number of times to read the register
+        max_attempts: u32,
+        /// The number of microseconds to wait between reads of the register
+        interval_us: u32,
+    },
+}
+
+/// An ordered sequence of [`InitStep`]s
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct InitSequence<'a> {
+    steps: &'a [InitStep<'a>],
+}
+
+impl<'a> InitSequence<'a> {
+    /// Create a new [`InitSequence`] performing the given steps in order
+    pub const fn new(steps: &'a [InitStep<'a>]) -> Self {
+        Self { steps }
+    }
+
+    /// Returns the steps of the sequence
+    pub const fn steps(&self) -> &'a [InitStep<'a>] {
+        self.steps
+    }
+}
+
+/// Error that can occur while performing a single step of an [`InitSequence`]
+///
+/// Generic over the bus error type `B`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum StepError<B> {
+    /// An error occurred while communicating over the bus
+    BusError(B),
+    /// The polled register did not hold the expected value within the maximum number of attempts
+    NotReady,
+}
+
+/// Error that can occur when performing an [`InitSequence`], identifying the step that failed
+///
+/// Generic over the bus error type `B`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct SequenceError<B> {
+    /// The index of the failed step within the sequence
+    pub step: usize,
+    /// The error that occurred while performing the step
+    pub kind: StepError<B>,
+}
+
+#[cfg(feature = "async")]
+pub mod r#async {
+    use embedded_hal_async::{
+        delay::DelayNs,
+        i2c::{self, I2c},
+        spi::{self, SpiDevice},
+    };
+
+    use super::*;
+
+    /// A handle capable of writing and reading raw register bytes on a single device
+    #[allow(async_fn_in_trait)]
+    pub trait RawBus {
+        /// The type of error that may occur while communicating over the bus
+        type Error;
+
+        /// Write the raw bytes of the register with the given ID
+        async fn write_raw(&mut self, id: &[u8], bytes: &[u8]) -> Result<(), Self::Error>;
+
+        /// Read the raw bytes of the register with the given ID into `buf`
+        async fn read_raw(&mut self, id: &[u8], buf: &mut [u8]) -> Result<(), Self::Error>;
+    }
+
+    impl<B, A> RawBus for Device<B, A>
+    where
+        A: i2c::AddressMode + Copy,
+        B: I2c<A>,
+    {
+        type Error = B::Error;
+
+        async fn write_raw(&mut self, id: &[u8], bytes: &[u8]) -> Result<(), Self::Error> {
+            let address = *self.address();
+            self.bus_mut()
+                .transaction(
+                    address,
+                    &mut [i2c::Operation::Write(id), i2c::Operation::Write(bytes)],
+                )
+                .await
+        }
+
+        async fn read_raw(&mut self, id: &[u8], buf: &mut [u8]) -> Result<(), Self::Error> {
+            let address = *self.address();
+            self.bus_mut()
+                .transaction(
+                    address,
+                    &mut [i2c::Operation::Write(id), i2c::Operation::Read(buf)],
+                )
+                .await
+        }
+    }
+
+    impl<B: SpiDevice> RawBus for Device<B, NoAddress> {
+        type Error = B::Error;
+
+        async fn write_raw(&mut self, id: &[u8], bytes: &[u8]) -> Result<(), Self::Error> {
+            self.bus_mut()
+                .transaction(&mut [spi::Operation::Write(id), spi::Operation::Write(bytes)])
+                .await
+        }
+
+        async fn read_raw(&mut self, id: &[u8], buf: &mut [u8]) -> Result<(), Self::Error> {
+            self.bus_mut()
+                .transaction(&mut [spi::Operation::Write(id), spi::Operation::Read(buf)])
+                .await
+        }
+    }
+
+    /// Perform a single step of a sequence
+    async fn run_step<B: RawBus, T: DelayNs>(
+        bus: &mut B,
+        delay: &mut T,
+        step: &InitStep<'_>,
+    ) -> Result<(), StepError<B::Error>> {
+        match *step {
+            InitStep::WriteRaw { id, bytes } => {
+                bus.write_raw(id, bytes)
+                    .await
+                    .map_err(StepError::BusError)?;
+                trace!("sequence: write: id={:02X?} bytes={:02X?}", id, bytes);
+            }
+            InitStep::DelayUs(us) => delay.delay_us(us).await,
+            InitStep::PollFlag {
+                id,
+                mask,
+                value,
+                max_attempts,
+                interval_us,
+            } => {
+                for attempt in 0..max_attempts {
+                    if attempt > 0 {
+                        delay.delay_us(interval_us).await;
+                    }
+
+                    let mut buf = [0];
+                    bus.read_raw(id, &mut buf)
+                        .await
+                        .map_err(StepError::BusError)?;
+                    trace!("sequence: poll: id={:02X?} value={:02X}", id, buf[0]);
+
+                    if buf[0] & mask == value & mask {
+                        return Ok(());
+                    }
+                }
+                return Err(StepError::NotReady);
+            }
+        }
+        Ok(())
+    }
+
+    /// Perform each step of an [`InitSequence`] in order.
+    ///
+    /// Register writes and polls are performed over `bus`, each as its own bus transaction, while
+    /// delays, including those between polls, are performed with `delay`. The sequence stops at
+    /// the first step that fails.
+    ///
+    /// # Parameters
+    /// * `bus` - The raw register interface of the device
+    /// * `delay` - The delay used for delay steps and between polls
+    /// * `sequence` - The sequence of steps to perform
+    ///
+    /// # Errors
+    /// Returns a [`SequenceError`] holding the index of the failed step and one of:
+    /// * `StepError::BusError` - Communication with the device failed
+    /// * `StepError::NotReady` - A polled register did not hold the expected value within the
+    ///   maximum number of attempts
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+    /// use regiface::{
+    ///     device::I2cInterface,
+    ///     sequence::{self, InitSequence, InitStep},
+    /// };
+    ///
+    /// const BRING_UP: InitSequence = InitSequence::new(&[
+    ///     InitStep::WriteRaw { id: &[0x7E], bytes: &[0xB6] },
+    ///     InitStep::DelayUs(10_000),
+    ///     InitStep::WriteRaw { id: &[0x40], bytes: &[0x28] },
+    /// ]);
+    ///
+    /// async fn bring_up<D: I2c, T: DelayNs>(bus: D, delay: &mut T) -> Option<I2cInterface<D>> {
+    ///     let mut device = I2cInterface::new(bus, 0x68);
+    ///     sequence::r#async::run(&mut device, delay, BRING_UP).await.ok()?;
+    ///     Some(device)
+    /// }
+    /// ```
+    pub async fn run<B: RawBus, T: DelayNs>(
+        bus: &mut B,
+        delay: &mut T,
+        sequence: InitSequence<'_>,
+    ) -> Result<(), SequenceError<B::Error>> {
+        for (step, init_step) in sequence.steps().iter().enumerate() {
+            run_step(bus, delay, init_step)
+                .await
+                .map_err(|kind| SequenceError { step, kind })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use embedded_hal::{
+        delay::DelayNs,
+        i2c::{self, I2c},
+        spi::{self, SpiDevice},
+    };
+
+    use super::*;
+
+    /// A handle capable of writing and reading raw register bytes on a single device
+    pub trait RawBus {
+        /// The type of error that may occur while communicating over the bus
+        type Error;
+
+        /// Write the raw bytes of the register with the given ID
+        fn write_raw(&mut self, id: &[u8], bytes: &[u8]) -> Result<(), Self::Error>;
+
+        /// Read the raw bytes of the register with the given ID into `buf`
+        fn read_raw(&mut self, id: &[u8], buf: &mut [u8]) -> Result<(), Self::Error>;
+    }
+
+    impl<B, A> RawBus for Device<B, A>
+    where
+        A: i2c::AddressMode + Copy,
+        B: I2c<A>,
+    {
+        type Error = B::Error;
+
+        fn write_raw(&mut self, id: &[u8], bytes: &[u8]) -> Result<(), Self::Error> {
+            let address = *self.address();
+            self.bus_mut().transaction(
+                address,
+                &mut [i2c::Operation::Write(id), i2c::Operation::Write(bytes)],
+            )
+        }
+
+        fn read_raw(&mut self, id: &[u8], buf: &mut [u8]) -> Result<(), Self::Error> {
+            let address = *self.address();
+            self.bus_mut().transaction(
+                address,
+                &mut [i2c::Operation::Write(id), i2c::Operation::Read(buf)],
+            )
+        }
+    }
+
+    impl<B: SpiDevice> RawBus for Device<B, NoAddress> {
+        type Error = B::Error;
+
+        fn write_raw(&mut self, id: &[u8], bytes: &[u8]) -> Result<(), Self::Error> {
+            self.bus_mut()
+                .transaction(&mut [spi::Operation::Write(id), spi::Operation::Write(bytes)])
+        }
+
+        fn read_raw(&mut self, id: &[u8], buf: &mut [u8]) -> Result<(), Self::Error> {
+            self.bus_mut()
+                .transaction(&mut [spi::Operation::Write(id), spi::Operation::Read(buf)])
+        }
+    }
+
+    /// Perform a single step of a sequence
+    fn run_step<B: RawBus, T: DelayNs>(
+        bus: &mut B,
+        delay: &mut T,
+        step: &InitStep<'_>,
+    ) -> Result<(), StepError<B::Error>> {
+        match *step {
+            InitStep::WriteRaw { id, bytes } => {
+                bus.write_raw(id, bytes).map_err(StepError::BusError)?;
+                trace!("sequence: write: id={:02X?} bytes={:02X?}", id, bytes);
+            }
+            InitStep::DelayUs(us) => delay.delay_us(us),
+            InitStep::PollFlag {
+                id,
+                mask,
+                value,
+                max_attempts,
+                interval_us,
+            } => {
+                for attempt in 0..max_attempts {
+                    if attempt > 0 {
+                        delay.delay_us(interval_us);
+                    }
+
+                    let mut buf = [0];
+                    bus.read_raw(id, &mut buf).map_err(StepError::BusError)?;
+                    trace!("sequence: poll: id={:02X?} value={:02X}", id, buf[0]);
+
+                    if buf[0] & mask == value & mask {
+                        return Ok(());
+                    }
+                }
+                return Err(StepError::NotReady);
+            }
+        }
+        Ok(())
+    }
+
+    /// Blocking variant of `r#async::run`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::{delay::DelayNs, spi::{ErrorType, Operation, SpiDevice}};
+    /// use regiface::{
+    ///     device::SpiInterface,
+    ///     sequence::{self, InitSequence, InitStep},
+    /// };
+    ///
+    /// const BRING_UP: InitSequence = InitSequence::new(&[
+    ///     InitStep::WriteRaw { id: &[0x0B], bytes: &[0x02] },
+    ///     InitStep::DelayUs(500),
+    ///     InitStep::WriteRaw { id: &[0x2D], bytes: &[0x08] },
+    /// ]);
+    /// # #[derive(Default)]
+    /// # struct Bus(Vec<u8>);
+    /// # impl ErrorType for Bus { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Bus {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         let Operation::Write(id) = &ops[0] else { unreachable!() };
+    /// #         self.0.push(id[0]);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # struct NoDelay;
+    /// # impl DelayNs for NoDelay { fn delay_ns(&mut self, _: u32) {} }
+    /// # let mut delay = NoDelay;
+    ///
+    /// let mut device = SpiInterface::new_unaddressed(Bus::default());
+    /// sequence::blocking::run(&mut device, &mut delay, BRING_UP).unwrap();
+    /// # assert_eq!(device.bus_mut().0, [0x0B, 0x2D]);
+    /// ```
+    pub fn run<B: RawBus, T: DelayNs>(
+        bus: &mut B,
+        delay: &mut T,
+        sequence: InitSequence<'_>,
+    ) -> Result<(), SequenceError<B::Error>> {
+        for (step, init_step) in sequence.steps().iter().enumerate() {
+            run_step(bus, delay, init_step).map_err(|kind| SequenceError { step, kind })?;
+        }
+        Ok(())
+    }
+}
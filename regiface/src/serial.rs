@@ -0,0 +1,1201 @@
+//! A collection of utility functions for interfacing with registers across a serial (UART) link
+//!
+//! Some devices, such as GPS modules, motor controllers, and fingerprint sensors, expose a register
+//! or command protocol over a UART rather than a bus. Such protocols typically wrap each request
+//! and response within a frame consisting of a sync byte, the register or command ID, the length
+//! of the payload, the payload itself, and a checksum:
+//!
+//! ```text
+//! [SYNC, ID..., LEN, PAYLOAD..., CHECKSUM]
+//! ```
+//!
+//! Commands with a [`Command::sub_id`] write it immediately after the command ID, ahead of the
+//! length byte, such that it is covered by the checksum but not by the length.
+//!
+//! The layout of a frame is described by a [`FrameFormat`], and responses are expected to use the
+//! same layout as requests, echoing the ID of the register or command. This module provides both
+//! blocking and async variants of register read/write operations and command invocation, built on
+//! the [`embedded-io`](https://crates.io/crates/embedded-io) and
+//! [`embedded-io-async`](https://crates.io/crates/embedded-io-async) traits.
+//!
+//! Line-oriented protocols, in which responses are of variable length and end with a terminator
+//! byte rather than being framed, are supported by `invoke_command_until`, which deserializes the
+//! response with [`FromByteSlice`].
+//!
+//! No timeout is applied while waiting for a response. Blocking implementations should configure a
+//! read timeout on the underlying serial port, while async operations may be wrapped with
+//! `with_timeout`.
+//!
+//! When the `log` feature is enabled, each operation emits a trace level log event containing the
+//! register or command ID and the bytes transferred.
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+use crate::command::check_response;
+#[cfg(any(feature = "blocking", feature = "async", doc))]
+use crate::{
+    byte_array::ByteArray as _,
+    errors::{CommandError, ReadRegisterError, WriteRegisterError},
+    id::Id as _,
+    Command, FromByteArray, FromByteSlice, ReadableRegister, ToByteArray, WritableRegister,
+};
+
+/// The checksum appended to each frame, computed over every preceding byte of the frame including
+/// the sync byte
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Checksum {
+    /// Frames do not include a checksum
+    None,
+    /// The wrapping sum of all bytes
+    Sum8,
+    /// The XOR of all bytes
+    Xor8,
+    /// A CRC-8 of all bytes, computed MSB first with the given polynomial and initial value, and
+    /// without reflection or a final XOR
+    Crc8 {
+        /// The generator polynomial, excluding the implicit leading bit
+        polynomial: u8,
+        /// The initial value of the CRC
+        init: u8,
+    },
+}
+
+impl Checksum {
+    /// The CRC-8 with polynomial `0x07` and initial value `0x00`, as used by SMBus
+    pub const CRC8: Self = Self::Crc8 {
+        polynomial: 0x07,
+        init: 0x00,
+    };
+
+    /// Computes the checksum of the given bytes
+    ///
+    /// ```
+    /// use regiface::serial::Checksum;
+    ///
+    /// assert_eq!(Checksum::CRC8.compute(b"123456789"), 0xF4);
+    /// assert_eq!(Checksum::Sum8.compute(&[0xF0, 0x20]), 0x10);
+    /// assert_eq!(Checksum::Xor8.compute(&[0xF0, 0x30]), 0xC0);
+    /// ```
+    pub fn compute(self, bytes: &[u8]) -> u8 {
+        self.update(self.init(), bytes)
+    }
+
+    /// Returns the initial state of the checksum
+    pub(crate) fn init(self) -> u8 {
+        match self {
+            Self::Crc8 { init, .. } => init,
+            Self::None | Self::Sum8 | Self::Xor8 => 0,
+        }
+    }
+
+    /// Updates the state of the checksum with the given bytes
+    pub(crate) fn update(self, state: u8, bytes: &[u8]) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Sum8 => bytes
+                .iter()
+                .fold(state, |sum, byte| sum.wrapping_add(*byte)),
+            Self::Xor8 => bytes.iter().fold(state, |xor, byte| xor ^ byte),
+            Self::Crc8 { polynomial, .. } => bytes.iter().fold(state, |crc, byte| {
+                (0..8).fold(crc ^ byte, |crc, _| {
+                    if crc & 0x80 != 0 {
+                        (crc << 1) ^ polynomial
+                    } else {
+                        crc << 1
+                    }
+                })
+            }),
+        }
+    }
+}
+
+/// The layout of the frames exchanged with a serial device
+///
+/// ```
+/// use regiface::serial::{Checksum, FrameFormat};
+///
+/// // Frames start with 0xAA, include a length byte, and end with a CRC-8
+/// const FORMAT: FrameFormat = FrameFormat::new(0xAA);
+///
+/// // Frames start with 0x55, omit the length byte, and end with an 8-bit sum
+/// const OTHER_FORMAT: FrameFormat = FrameFormat {
+///     sync: 0x55,
+///     include_length: false,
+///     checksum: Checksum::Sum8,
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct FrameFormat {
+    /// The byte starting every frame
+    pub sync: u8,
+    /// Whether frames include the length of the payload as a single byte following the ID
+    pub include_length: bool,
+    /// The checksum ending every frame
+    pub checksum: Checksum,
+}
+
+impl FrameFormat {
+    /// Create a new [`FrameFormat`] with the given sync byte, a length byte, and a
+    /// [`CRC8`](Checksum::CRC8) checksum
+    pub const fn new(sync: u8) -> Self {
+        Self {
+            sync,
+            include_length: true,
+            checksum: Checksum::CRC8,
+        }
+    }
+}
+
+/// Error that can occur when exchanging frames with a serial device
+///
+/// Generic over the serial port error type `E`. When a malformed response is received, the
+/// remainder of the response is left unread, thus the caller may need to resynchronize with the
+/// device, such as by discarding any pending bytes, before further use.
+///
+#[cfg_attr(feature = "blocking", doc = "```")]
+#[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+/// # use embedded_io::{ErrorType, Read, Write};
+/// # use regiface::{register, serial::{self, Checksum, FrameError, FrameFormat}, errors::ReadRegisterError, FromByteArray, ReadableRegister};
+/// # #[register(0x10u8)]
+/// # #[derive(ReadableRegister, Debug)]
+/// # struct Temperature(u16);
+/// # impl FromByteArray for Temperature {
+/// #     type Array = [u8; 2];
+/// #     type Error = core::convert::Infallible;
+/// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(u16::from_be_bytes(bytes))) }
+/// # }
+/// # struct Pipe<'a> { rx: &'a [u8], tx: Vec<u8> }
+/// # impl ErrorType for Pipe<'_> { type Error = core::convert::Infallible; }
+/// # impl Read for Pipe<'_> {
+/// #     fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> { self.rx.read(buf) }
+/// # }
+/// # impl Write for Pipe<'_> {
+/// #     fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> { self.tx.extend_from_slice(buf); Ok(buf.len()) }
+/// #     fn flush(&mut self) -> Result<(), Self::Error> { Ok(()) }
+/// # }
+/// const FORMAT: FrameFormat = FrameFormat::new(0xAA);
+///
+/// // A response whose checksum has been corrupted in transit
+/// let mut response = vec![0xAA, 0x10, 0x02, 0x01, 0x90];
+/// response.push(Checksum::CRC8.compute(&response) ^ 0xFF);
+/// # let mut device = Pipe { rx: &response, tx: Vec::new() };
+///
+/// let err = serial::blocking::read_register::<_, Temperature>(&mut device, FORMAT).unwrap_err();
+/// assert!(matches!(
+///     err,
+///     ReadRegisterError::BusError(FrameError::Checksum { .. })
+/// ));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameError<E> {
+    /// An error occurred while communicating over the serial port
+    Io(E),
+    /// The serial port reached the end of its input before a complete response was received
+    UnexpectedEof,
+    /// The response did not start with the sync byte
+    Sync {
+        /// The byte received in place of the sync byte
+        received: u8,
+    },
+    /// The response echoed a different ID than the one requested
+    Id,
+    /// The length of the response payload did not match the length expected
+    Length {
+        /// The length expected
+        expected: usize,
+        /// The length received
+        received: usize,
+    },
+    /// The checksum of the response did not match the checksum computed over its contents
+    Checksum {
+        /// The checksum computed over the received frame
+        expected: u8,
+        /// The checksum received
+        received: u8,
+    },
+    /// The payload of a request is too long for its length to be represented by a single byte
+    Oversized,
+    /// The response did not contain the terminator byte within the capacity of the buffer provided
+    /// for it
+    FrameTooLong {
+        /// The number of bytes that could be held before the terminator
+        capacity: usize,
+    },
+}
+
+impl<E> From<embedded_io::ReadExactError<E>> for FrameError<E> {
+    fn from(value: embedded_io::ReadExactError<E>) -> Self {
+        match value {
+            embedded_io::ReadExactError::UnexpectedEof => Self::UnexpectedEof,
+            embedded_io::ReadExactError::Other(e) => Self::Io(e),
+        }
+    }
+}
+
+/// Returns the length byte of a frame with the given payload, if the format includes one
+#[cfg(any(feature = "blocking", feature = "async"))]
+fn length_byte<E>(format: FrameFormat, payload: &[u8]) -> Result<Option<u8>, FrameError<E>> {
+    if !format.include_length {
+        return Ok(None);
+    }
+    u8::try_from(payload.len())
+        .map(Some)
+        .map_err(|_| FrameError::Oversized)
+}
+
+#[cfg(feature = "async")]
+pub mod r#async {
+    use super::*;
+    use embedded_io_async::{Read, Write};
+
+    /// Writes a frame containing the given ID, sub-ID, and payload
+    async fn write_frame<D: Write>(
+        device: &mut D,
+        format: FrameFormat,
+        id: &[u8],
+        sub_id: &[u8],
+        payload: &[u8],
+    ) -> Result<(), FrameError<D::Error>> {
+        let length = length_byte(format, payload)?;
+        let length = length.as_slice();
+
+        let mut checksum = format.checksum.init();
+        for part in [&[format.sync][..], id, sub_id, length, payload] {
+            device.write_all(part).await.map_err(FrameError::Io)?;
+            checksum = format.checksum.update(checksum, part);
+        }
+
+        if format.checksum != Checksum::None {
+            device
+                .write_all(&[checksum])
+                .await
+                .map_err(FrameError::Io)?;
+        }
+
+        device.flush().await.map_err(FrameError::Io)
+    }
+
+    /// Reads a frame echoing the given ID, whose payload consists of the status byte, if any,
+    /// followed by the response
+    async fn read_frame<D: Read>(
+        device: &mut D,
+        format: FrameFormat,
+        id: &[u8],
+        status: &mut [u8],
+        response: &mut [u8],
+    ) -> Result<(), FrameError<D::Error>> {
+        let mut byte = [0];
+        let mut checksum = format.checksum.init();
+
+        device.read_exact(&mut byte).await?;
+        checksum = format.checksum.update(checksum, &byte);
+        if byte[0] != format.sync {
+            return Err(FrameError::Sync { received: byte[0] });
+        }
+
+        for expected in id {
+            device.read_exact(&mut byte).await?;
+            checksum = format.checksum.update(checksum, &byte);
+            if byte[0] != *expected {
+                return Err(FrameError::Id);
+            }
+        }
+
+        if format.include_length {
+            device.read_exact(&mut byte).await?;
+            checksum = format.checksum.update(checksum, &byte);
+            let expected = status.len() + response.len();
+            if usize::from(byte[0]) != expected {
+                return Err(FrameError::Length {
+                    expected,
+                    received: usize::from(byte[0]),
+                });
+            }
+        }
+
+        for part in [status, response] {
+            device.read_exact(part).await?;
+            checksum = format.checksum.update(checksum, part);
+        }
+
+        if format.checksum != Checksum::None {
+            device.read_exact(&mut byte).await?;
+            if byte[0] != checksum {
+                return Err(FrameError::Checksum {
+                    expected: checksum,
+                    received: byte[0],
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads bytes into `buf` until the terminator is received, returning the number of bytes read
+    /// excluding the terminator
+    async fn read_until<D: Read>(
+        device: &mut D,
+        terminator: u8,
+        buf: &mut [u8],
+    ) -> Result<usize, FrameError<D::Error>> {
+        let mut byte = [0];
+        let mut len = 0;
+
+        loop {
+            device.read_exact(&mut byte).await?;
+            if byte[0] == terminator {
+                return Ok(len);
+            }
+
+            let Some(slot) = buf.get_mut(len) else {
+                return Err(FrameError::FrameTooLong {
+                    capacity: buf.len(),
+                });
+            };
+            *slot = byte[0];
+            len += 1;
+        }
+    }
+
+    /// Read a register value from a serial device.
+    ///
+    /// This function sends a frame containing the register ID and an empty payload, then receives
+    /// a frame echoing the register ID whose payload contains the register value. The received
+    /// bytes are deserialized into the specified register type.
+    ///
+    /// # Parameters
+    /// * `device` - The serial port connected to the device
+    /// * `format` - The layout of the frames exchanged with the device
+    ///
+    /// # Errors
+    /// * `ReadRegisterError::BusError` - Communication with the device failed, or the response was malformed
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_io_async::{Read, Write};
+    /// # use regiface::{register, serial::{self, FrameFormat}, ReadableRegister, FromByteArray};
+    /// # #[register(0x10u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct Temperature(u16);
+    /// # impl FromByteArray for Temperature {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(u16::from_be_bytes(bytes))) }
+    /// # }
+    /// const FORMAT: FrameFormat = FrameFormat::new(0xAA);
+    ///
+    /// async fn read_temp<D: Read + Write>(device: &mut D) -> u16 {
+    ///     let temp: Temperature = serial::r#async::read_register(device, FORMAT).await.unwrap();
+    ///     temp.0
+    /// }
+    /// ```
+    pub async fn read_register<D, R>(
+        device: &mut D,
+        format: FrameFormat,
+    ) -> Result<R, ReadRegisterError<FrameError<D::Error>, R::Error>>
+    where
+        D: Read + Write,
+        R: ReadableRegister,
+    {
+        let mut buf = <R as FromByteArray>::Array::new();
+
+        let reg_id = R::readable_id().to_id_bytes();
+
+        write_frame(device, format, reg_id.as_ref(), &[], &[])
+            .await
+            .map_err(ReadRegisterError::BusError)?;
+        read_frame(device, format, reg_id.as_ref(), &mut [], buf.as_mut())
+            .await
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "serial: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Write a register value to a serial device.
+    ///
+    /// This function serializes the register value, then sends a frame containing the register ID
+    /// and the register value as its payload. No response is read.
+    ///
+    /// # Parameters
+    /// * `device` - The serial port connected to the device
+    /// * `format` - The layout of the frames exchanged with the device
+    /// * `register` - The register value to write
+    ///
+    /// # Errors
+    /// * `WriteRegisterError::BusError` - Communication with the device failed
+    /// * `WriteRegisterError::SerializationError` - Failed to convert register value to bytes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_io_async::{Read, Write};
+    /// # use regiface::{register, serial::{self, FrameFormat}, WritableRegister, ToByteArray};
+    /// # #[register(0x20u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct Speed(u16);
+    /// # impl ToByteArray for Speed {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok(self.0.to_be_bytes()) }
+    /// # }
+    /// const FORMAT: FrameFormat = FrameFormat::new(0xAA);
+    ///
+    /// async fn set_speed<D: Read + Write>(device: &mut D) {
+    ///     serial::r#async::write_register(device, FORMAT, Speed(1500)).await.unwrap();
+    /// }
+    /// ```
+    pub async fn write_register<D, R>(
+        device: &mut D,
+        format: FrameFormat,
+        register: R,
+    ) -> Result<(), WriteRegisterError<FrameError<D::Error>, R::Error>>
+    where
+        D: Read + Write,
+        R: WritableRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = R::writeable_id().to_id_bytes();
+
+        write_frame(device, format, reg_id.as_ref(), &[], buf.as_ref())
+            .await
+            .map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "serial: write register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        Ok(())
+    }
+
+    /// Invoke a command on a serial device and receive its response.
+    ///
+    /// This function sends a frame containing the command ID and the serialized command
+    /// parameters, then receives a frame echoing the command ID whose payload contains the
+    /// command response. If [`Command::STATUS_BYTE`] is set, the response payload is expected to
+    /// begin with the status byte. Any [`Command::preamble`] and [`Command::postamble`] are written
+    /// immediately before and after the request frame.
+    ///
+    /// # Parameters
+    /// * `device` - The serial port connected to the device
+    /// * `format` - The layout of the frames exchanged with the device
+    /// * `cmd` - The command to invoke
+    ///
+    /// # Errors
+    /// * `CommandError::BusError` - Communication with the device failed, or the response was malformed
+    /// * `CommandError::SerializationError` - Failed to serialize command parameters
+    /// * `CommandError::DeserializationError` - Failed to deserialize command response
+    /// * `CommandError::ValidationError` - The response was rejected by [`Command::validate_response`]
+    /// * `CommandError::DeviceError` - The device responded with a nonzero status byte
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_io_async::{Read, Write};
+    /// # use regiface::{serial::{self, FrameFormat}, Command, NoParameters};
+    /// # struct GetVersion;
+    /// # impl Command for GetVersion {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = u16;
+    /// #     fn id() -> Self::IdType { 0x01 }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// const FORMAT: FrameFormat = FrameFormat::new(0xAA);
+    ///
+    /// async fn version<D: Read + Write>(device: &mut D) -> u16 {
+    ///     serial::r#async::invoke_command(device, FORMAT, GetVersion).await.unwrap()
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_command<D, C>(
+        device: &mut D,
+        format: FrameFormat,
+        cmd: C,
+    ) -> Result<
+        C::ResponseParameters,
+        CommandError<
+            FrameError<D::Error>,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        D: Read + Write,
+        C: Command,
+    {
+        check_response::<C>();
+
+        let cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+
+        let mut status = [0; 1];
+        let status_buf: &mut [u8] = if C::STATUS_BYTE { &mut status } else { &mut [] };
+
+        let cmd_id = C::id().to_id_bytes();
+
+        device
+            .write_all(C::preamble())
+            .await
+            .map_err(|e| CommandError::BusError(FrameError::Io(e)))?;
+        write_frame(
+            device,
+            format,
+            cmd_id.as_ref(),
+            C::sub_id(),
+            cmd_buf.as_ref(),
+        )
+        .await
+        .map_err(CommandError::BusError)?;
+        device
+            .write_all(C::postamble())
+            .await
+            .map_err(|e| CommandError::BusError(FrameError::Io(e)))?;
+        device
+            .flush()
+            .await
+            .map_err(|e| CommandError::BusError(FrameError::Io(e)))?;
+        read_frame(
+            device,
+            format,
+            cmd_id.as_ref(),
+            status_buf,
+            resp_buf.as_mut(),
+        )
+        .await
+        .map_err(CommandError::BusError)?;
+
+        trace!(
+            "serial: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            cmd_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
+        if status[0] != 0 {
+            return Err(CommandError::DeviceError(status[0]));
+        }
+
+        C::validate_response(resp_buf.as_ref()).map_err(CommandError::ValidationError)?;
+        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
+    }
+
+    /// Invoke a command on a serial device and receive a response delimited by a terminator byte.
+    ///
+    /// This function is intended for line-oriented protocols, in which requests are not framed and
+    /// responses are of variable length. The command ID and the serialized command parameters are
+    /// written as-is, between any [`Command::preamble`] and [`Command::postamble`], then the
+    /// response is read one byte at a time until `terminator` is received. The bytes preceding the
+    /// terminator are deserialized into `T` with [`FromByteSlice::from_slice`], in place of
+    /// [`Command::ResponseParameters`]. If [`Command::STATUS_BYTE`] is set, the first byte of the
+    /// response is the status byte.
+    ///
+    /// `buf` bounds the length of the response, excluding the terminator, such that a device that
+    /// never sends the terminator cannot cause an unbounded read.
+    ///
+    /// # Parameters
+    /// * `device` - The serial port connected to the device
+    /// * `cmd` - The command to invoke
+    /// * `terminator` - The byte marking the end of the response
+    /// * `buf` - The buffer to receive the response into
+    ///
+    /// # Errors
+    /// * `CommandError::BusError` - Communication with the device failed, or the response did not
+    ///   fit in `buf`
+    /// * `CommandError::SerializationError` - Failed to serialize command parameters
+    /// * `CommandError::DeserializationError` - Failed to deserialize command response
+    /// * `CommandError::ValidationError` - The response was rejected by [`Command::validate_response`]
+    /// * `CommandError::DeviceError` - The device responded with a nonzero status byte
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_io_async::{Read, Write};
+    /// # use regiface::{serial, Command, FromByteSlice, NoParameters};
+    /// # struct Version;
+    /// # impl Command for Version {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = NoParameters;
+    /// #     fn id() -> Self::IdType { b'V' }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// # struct Line;
+    /// # impl FromByteSlice for Line {
+    /// #     type Error = ();
+    /// #     fn from_slice(_: &[u8]) -> Result<Self, Self::Error> { Ok(Self) }
+    /// # }
+    /// async fn version<D: Read + Write>(device: &mut D) -> Line {
+    ///     let mut buf = [0; 64];
+    ///     serial::r#async::invoke_command_until(device, Version, b'\n', &mut buf)
+    ///         .await
+    ///         .unwrap()
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_command_until<D, C, T>(
+        device: &mut D,
+        cmd: C,
+        terminator: u8,
+        buf: &mut [u8],
+    ) -> Result<
+        T,
+        CommandError<FrameError<D::Error>, <C::CommandParameters as ToByteArray>::Error, T::Error>,
+    >
+    where
+        D: Read + Write,
+        C: Command,
+        T: FromByteSlice,
+    {
+        let cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+
+        let cmd_id = C::id().to_id_bytes();
+
+        for part in [
+            C::preamble(),
+            cmd_id.as_ref(),
+            C::sub_id(),
+            cmd_buf.as_ref(),
+            C::postamble(),
+        ] {
+            device
+                .write_all(part)
+                .await
+                .map_err(|e| CommandError::BusError(FrameError::Io(e)))?;
+        }
+        device
+            .flush()
+            .await
+            .map_err(|e| CommandError::BusError(FrameError::Io(e)))?;
+
+        let len = read_until(device, terminator, buf)
+            .await
+            .map_err(CommandError::BusError)?;
+        let response = &buf[..len];
+
+        trace!(
+            "serial: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            cmd_id.as_ref(),
+            cmd_buf.as_ref(),
+            response
+        );
+
+        let response = if C::STATUS_BYTE {
+            match response {
+                [0, response @ ..] => response,
+                [status, ..] => return Err(CommandError::DeviceError(*status)),
+                [] => {
+                    return Err(CommandError::BusError(FrameError::Length {
+                        expected: 1,
+                        received: 0,
+                    }))
+                }
+            }
+        } else {
+            response
+        };
+
+        C::validate_response(response).map_err(CommandError::ValidationError)?;
+        T::from_slice(response).map_err(CommandError::DeserializationError)
+    }
+}
+
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use super::*;
+    use embedded_io::{Read, Write};
+
+    /// Writes a frame containing the given ID, sub-ID, and payload
+    fn write_frame<D: Write>(
+        device: &mut D,
+        format: FrameFormat,
+        id: &[u8],
+        sub_id: &[u8],
+        payload: &[u8],
+    ) -> Result<(), FrameError<D::Error>> {
+        let length = length_byte(format, payload)?;
+        let length = length.as_slice();
+
+        let mut checksum = format.checksum.init();
+        for part in [&[format.sync][..], id, sub_id, length, payload] {
+            device.write_all(part).map_err(FrameError::Io)?;
+            checksum = format.checksum.update(checksum, part);
+        }
+
+        if format.checksum != Checksum::None {
+            device.write_all(&[checksum]).map_err(FrameError::Io)?;
+        }
+
+        device.flush().map_err(FrameError::Io)
+    }
+
+    /// Reads a frame echoing the given ID, whose payload consists of the status byte, if any,
+    /// followed by the response
+    fn read_frame<D: Read>(
+        device: &mut D,
+        format: FrameFormat,
+        id: &[u8],
+        status: &mut [u8],
+        response: &mut [u8],
+    ) -> Result<(), FrameError<D::Error>> {
+        let mut byte = [0];
+        let mut checksum = format.checksum.init();
+
+        device.read_exact(&mut byte)?;
+        checksum = format.checksum.update(checksum, &byte);
+        if byte[0] != format.sync {
+            return Err(FrameError::Sync { received: byte[0] });
+        }
+
+        for expected in id {
+            device.read_exact(&mut byte)?;
+            checksum = format.checksum.update(checksum, &byte);
+            if byte[0] != *expected {
+                return Err(FrameError::Id);
+            }
+        }
+
+        if format.include_length {
+            device.read_exact(&mut byte)?;
+            checksum = format.checksum.update(checksum, &byte);
+            let expected = status.len() + response.len();
+            if usize::from(byte[0]) != expected {
+                return Err(FrameError::Length {
+                    expected,
+                    received: usize::from(byte[0]),
+                });
+            }
+        }
+
+        for part in [status, response] {
+            device.read_exact(part)?;
+            checksum = format.checksum.update(checksum, part);
+        }
+
+        if format.checksum != Checksum::None {
+            device.read_exact(&mut byte)?;
+            if byte[0] != checksum {
+                return Err(FrameError::Checksum {
+                    expected: checksum,
+                    received: byte[0],
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads bytes into `buf` until the terminator is received, returning the number of bytes read
+    /// excluding the terminator
+    fn read_until<D: Read>(
+        device: &mut D,
+        terminator: u8,
+        buf: &mut [u8],
+    ) -> Result<usize, FrameError<D::Error>> {
+        let mut byte = [0];
+        let mut len = 0;
+
+        loop {
+            device.read_exact(&mut byte)?;
+            if byte[0] == terminator {
+                return Ok(len);
+            }
+
+            let Some(slot) = buf.get_mut(len) else {
+                return Err(FrameError::FrameTooLong {
+                    capacity: buf.len(),
+                });
+            };
+            *slot = byte[0];
+            len += 1;
+        }
+    }
+
+    /// Read a register value from a serial device.
+    ///
+    /// Blocking variant of `r#async::read_register`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_io::{ErrorType, Read, Write};
+    /// # use regiface::{register, serial::{self, Checksum, FrameFormat}, ReadableRegister, FromByteArray};
+    /// # #[register(0x10u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct Temperature(u16);
+    /// # impl FromByteArray for Temperature {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(u16::from_be_bytes(bytes))) }
+    /// # }
+    /// # struct Pipe<'a> { rx: &'a [u8], tx: Vec<u8> }
+    /// # impl ErrorType for Pipe<'_> { type Error = core::convert::Infallible; }
+    /// # impl Read for Pipe<'_> {
+    /// #     fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> { self.rx.read(buf) }
+    /// # }
+    /// # impl Write for Pipe<'_> {
+    /// #     fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> { self.tx.extend_from_slice(buf); Ok(buf.len()) }
+    /// #     fn flush(&mut self) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// const FORMAT: FrameFormat = FrameFormat::new(0xAA);
+    ///
+    /// // The device responds with a temperature of 400
+    /// let mut response = vec![0xAA, 0x10, 0x02, 0x01, 0x90];
+    /// response.push(Checksum::CRC8.compute(&response));
+    /// # let mut device = Pipe { rx: &response, tx: Vec::new() };
+    ///
+    /// let temp: Temperature = serial::blocking::read_register(&mut device, FORMAT).unwrap();
+    /// assert_eq!(temp.0, 400);
+    ///
+    /// // The request contained the register ID and an empty payload
+    /// let mut request = vec![0xAA, 0x10, 0x00];
+    /// request.push(Checksum::CRC8.compute(&request));
+    /// assert_eq!(device.tx, request);
+    /// ```
+    pub fn read_register<D, R>(
+        device: &mut D,
+        format: FrameFormat,
+    ) -> Result<R, ReadRegisterError<FrameError<D::Error>, R::Error>>
+    where
+        D: Read + Write,
+        R: ReadableRegister,
+    {
+        let mut buf = <R as FromByteArray>::Array::new();
+
+        let reg_id = R::readable_id().to_id_bytes();
+
+        write_frame(device, format, reg_id.as_ref(), &[], &[])
+            .map_err(ReadRegisterError::BusError)?;
+        read_frame(device, format, reg_id.as_ref(), &mut [], buf.as_mut())
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "serial: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Write a register value to a serial device.
+    ///
+    /// Blocking variant of `r#async::write_register`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_io::{ErrorType, Read, Write};
+    /// # use regiface::{register, serial::{self, Checksum, FrameFormat}, WritableRegister, ToByteArray};
+    /// # #[register(0x20u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct Speed(u16);
+    /// # impl ToByteArray for Speed {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok(self.0.to_be_bytes()) }
+    /// # }
+    /// # struct Pipe { tx: Vec<u8> }
+    /// # impl ErrorType for Pipe { type Error = core::convert::Infallible; }
+    /// # impl Read for Pipe {
+    /// #     fn read(&mut self, _: &mut [u8]) -> Result<usize, Self::Error> { Ok(0) }
+    /// # }
+    /// # impl Write for Pipe {
+    /// #     fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> { self.tx.extend_from_slice(buf); Ok(buf.len()) }
+    /// #     fn flush(&mut self) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # let mut device = Pipe { tx: Vec::new() };
+    /// const FORMAT: FrameFormat = FrameFormat::new(0xAA);
+    ///
+    /// serial::blocking::write_register(&mut device, FORMAT, Speed(1500)).unwrap();
+    ///
+    /// let mut request = vec![0xAA, 0x20, 0x02, 0x05, 0xDC];
+    /// request.push(Checksum::CRC8.compute(&request));
+    /// assert_eq!(device.tx, request);
+    /// ```
+    pub fn write_register<D, R>(
+        device: &mut D,
+        format: FrameFormat,
+        register: R,
+    ) -> Result<(), WriteRegisterError<FrameError<D::Error>, R::Error>>
+    where
+        D: Read + Write,
+        R: WritableRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = R::writeable_id().to_id_bytes();
+
+        write_frame(device, format, reg_id.as_ref(), &[], buf.as_ref())
+            .map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "serial: write register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        Ok(())
+    }
+
+    /// Invoke a command on a serial device and receive its response.
+    ///
+    /// Blocking variant of `r#async::invoke_command`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_io::{ErrorType, Read, Write};
+    /// # use regiface::{errors::CommandError, serial::{self, Checksum, FrameFormat}, Command, NoParameters};
+    /// # struct Pipe<'a> { rx: &'a [u8], tx: Vec<u8> }
+    /// # impl ErrorType for Pipe<'_> { type Error = core::convert::Infallible; }
+    /// # impl Read for Pipe<'_> {
+    /// #     fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> { self.rx.read(buf) }
+    /// # }
+    /// # impl Write for Pipe<'_> {
+    /// #     fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> { self.tx.extend_from_slice(buf); Ok(buf.len()) }
+    /// #     fn flush(&mut self) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// struct Enroll(u8);
+    ///
+    /// impl Command for Enroll {
+    ///     type IdType = u8;
+    ///     type CommandParameters = u8;
+    ///     type ResponseParameters = NoParameters;
+    ///
+    ///     const STATUS_BYTE: bool = true;
+    ///
+    ///     fn id() -> Self::IdType {
+    ///         0x31
+    ///     }
+    ///
+    ///     fn invoking_parameters(self) -> Self::CommandParameters {
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// const FORMAT: FrameFormat = FrameFormat::new(0xAA);
+    ///
+    /// // The device responds with a status of 0x02, indicating the command failed
+    /// let mut response = vec![0xAA, 0x31, 0x01, 0x02];
+    /// response.push(Checksum::CRC8.compute(&response));
+    /// # let mut device = Pipe { rx: &response, tx: Vec::new() };
+    ///
+    /// let err = serial::blocking::invoke_command(&mut device, FORMAT, Enroll(3)).unwrap_err();
+    /// assert!(matches!(err, CommandError::DeviceError(0x02)));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn invoke_command<D, C>(
+        device: &mut D,
+        format: FrameFormat,
+        cmd: C,
+    ) -> Result<
+        C::ResponseParameters,
+        CommandError<
+            FrameError<D::Error>,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        D: Read + Write,
+        C: Command,
+    {
+        check_response::<C>();
+
+        let cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+
+        let mut status = [0; 1];
+        let status_buf: &mut [u8] = if C::STATUS_BYTE { &mut status } else { &mut [] };
+
+        let cmd_id = C::id().to_id_bytes();
+
+        device
+            .write_all(C::preamble())
+            .map_err(|e| CommandError::BusError(FrameError::Io(e)))?;
+        write_frame(
+            device,
+            format,
+            cmd_id.as_ref(),
+            C::sub_id(),
+            cmd_buf.as_ref(),
+        )
+        .map_err(CommandError::BusError)?;
+        device
+            .write_all(C::postamble())
+            .map_err(|e| CommandError::BusError(FrameError::Io(e)))?;
+        device
+            .flush()
+            .map_err(|e| CommandError::BusError(FrameError::Io(e)))?;
+        read_frame(
+            device,
+            format,
+            cmd_id.as_ref(),
+            status_buf,
+            resp_buf.as_mut(),
+        )
+        .map_err(CommandError::BusError)?;
+
+        trace!(
+            "serial: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            cmd_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
+        if status[0] != 0 {
+            return Err(CommandError::DeviceError(status[0]));
+        }
+
+        C::validate_response(resp_buf.as_ref()).map_err(CommandError::ValidationError)?;
+        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
+    }
+
+    /// Invoke a command on a serial device and receive a response delimited by a terminator byte.
+    ///
+    /// Blocking variant of `r#async::invoke_command_until`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_io::{ErrorType, Read, Write};
+    /// # use regiface::{errors::CommandError, serial::{self, FrameError}, Command, FromByteSlice, NoParameters};
+    /// # struct Pipe<'a> { rx: &'a [u8], tx: Vec<u8> }
+    /// # impl ErrorType for Pipe<'_> { type Error = core::convert::Infallible; }
+    /// # impl Read for Pipe<'_> {
+    /// #     fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> { self.rx.read(buf) }
+    /// # }
+    /// # impl Write for Pipe<'_> {
+    /// #     fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> { self.tx.extend_from_slice(buf); Ok(buf.len()) }
+    /// #     fn flush(&mut self) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// struct Version;
+    ///
+    /// impl Command for Version {
+    ///     type IdType = u8;
+    ///     type CommandParameters = NoParameters;
+    ///     type ResponseParameters = NoParameters;
+    ///
+    ///     fn id() -> Self::IdType {
+    ///         b'V'
+    ///     }
+    ///
+    ///     fn invoking_parameters(self) -> Self::CommandParameters {
+    ///         NoParameters::default()
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Line([u8; 8], usize);
+    ///
+    /// impl FromByteSlice for Line {
+    ///     type Error = ();
+    ///
+    ///     fn from_slice(bytes: &[u8]) -> Result<Self, Self::Error> {
+    ///         let mut line = [0; 8];
+    ///         line.get_mut(..bytes.len()).ok_or(())?.copy_from_slice(bytes);
+    ///         Ok(Self(line, bytes.len()))
+    ///     }
+    /// }
+    ///
+    /// let mut buf = [0; 8];
+    ///
+    /// # let mut device = Pipe { rx: b"v1.2.0\n", tx: Vec::new() };
+    /// // The device responds with `v1.2.0\n`
+    /// let line: Line = serial::blocking::invoke_command_until(&mut device, Version, b'\n', &mut buf).unwrap();
+    /// assert_eq!(&line.0[..line.1], b"v1.2.0");
+    ///
+    /// # let mut device = Pipe { rx: b"v1.2.0-rc1\n", tx: Vec::new() };
+    /// // A response that does not fit in the buffer is abandoned
+    /// let err = serial::blocking::invoke_command_until::<_, _, Line>(&mut device, Version, b'\n', &mut buf)
+    ///     .unwrap_err();
+    /// assert!(matches!(
+    ///     err,
+    ///     CommandError::BusError(FrameError::FrameTooLong { capacity: 8 })
+    /// ));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn invoke_command_until<D, C, T>(
+        device: &mut D,
+        cmd: C,
+        terminator: u8,
+        buf: &mut [u8],
+    ) -> Result<
+        T,
+        CommandError<FrameError<D::Error>, <C::CommandParameters as ToByteArray>::Error, T::Error>,
+    >
+    where
+        D: Read + Write,
+        C: Command,
+        T: FromByteSlice,
+    {
+        let cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+
+        let cmd_id = C::id().to_id_bytes();
+
+        for part in [
+            C::preamble(),
+            cmd_id.as_ref(),
+            C::sub_id(),
+            cmd_buf.as_ref(),
+            C::postamble(),
+        ] {
+            device
+                .write_all(part)
+                .map_err(|e| CommandError::BusError(FrameError::Io(e)))?;
+        }
+        device
+            .flush()
+            .map_err(|e| CommandError::BusError(FrameError::Io(e)))?;
+
+        let len = read_until(device, terminator, buf).map_err(CommandError::BusError)?;
+        let response = &buf[..len];
+
+        trace!(
+            "serial: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            cmd_id.as_ref(),
+            cmd_buf.as_ref(),
+            response
+        );
+
+        let response = if C::STATUS_BYTE {
+            match response {
+                [0, response @ ..] => response,
+                [status, ..] => return Err(CommandError::DeviceError(*status)),
+                [] => {
+                    return Err(CommandError::BusError(FrameError::Length {
+                        expected: 1,
+                        received: 0,
+                    }))
+                }
+            }
+        } else {
+            response
+        };
+
+        C::validate_response(response).map_err(CommandError::ValidationError)?;
+        T::from_slice(response).map_err(CommandError::DeserializationError)
+    }
+}
@@ -3,22 +3,601 @@
 //! This module provides both blocking and async variants of register read/write operations
 //! and command invocation for SPI devices. All operations handle proper byte
 //! serialization/deserialization of register values.
+//!
+//! When the `log` feature is enabled, each operation emits a trace level log event containing the
+//! register or command ID and the bytes transferred.
 
+#[cfg(any(feature = "blocking", feature = "async"))]
+use crate::command::{check_response, merge_id};
+#[cfg(any(feature = "blocking", feature = "async", doc))]
 use crate::{
     byte_array::ByteArray as _,
     errors::CommandError,
-    errors::{ReadRegisterError, WriteRegisterError},
-    Command, FromByteArray, ReadableRegister, ToByteArray, WritableRegister,
+    errors::{
+        FifoError, PollError, ReadRegisterError, RetryError, VerifyError, WhoAmIError,
+        WriteRegisterError, WriteVerifyError,
+    },
+    id::{self, Id as _},
+    Command, DynamicCommand, FifoMode, FromByteArray, IndexedRegister, ReadableRegister,
+    ToByteArray, WhoAmI, WritableRegister,
 };
 
+/// The maximum number of dummy bytes that may be requested by
+/// [`ReadableRegister::dummy_bytes`] for SPI register reads
+pub const MAX_DUMMY_BYTES: usize = 32;
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+static DUMMY_BYTES: [u8; MAX_DUMMY_BYTES] = [0; MAX_DUMMY_BYTES];
+
+/// How the dummy bytes between the register ID and the register value of a SPI read are clocked
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub enum DummyMode {
+    /// Dummy bytes are clocked by writing zeros
+    #[default]
+    Write,
+    /// Dummy bytes are clocked by reading into a scratch buffer, which is then discarded
+    Read,
+}
+
+/// The maximum combined length of the register ID and register value that may be read using a
+/// full-duplex transfer, such as with `blocking::read_register_transfer`, and of the command ID and
+/// command parameters, or the status byte and response, of a command invoked with
+/// `blocking::invoke_command_duplex`
+pub const MAX_TRANSFER_BYTES: usize = 64;
+
+/// Returns the number of dummy bytes to be clocked between the register ID and the register value
+///
+/// # Panics
+/// Panics if the register requests more than [`MAX_DUMMY_BYTES`] dummy bytes
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub(crate) fn dummy_count<R: ReadableRegister>() -> usize {
+    let count = R::dummy_bytes();
+    assert!(
+        count <= MAX_DUMMY_BYTES,
+        "register requested more than MAX_DUMMY_BYTES dummy bytes"
+    );
+    count
+}
+
+/// Returns the operation clocking the dummy bytes between the register ID and the register value,
+/// if any dummy bytes are required
+///
+/// # Panics
+/// Panics if the register requests more than [`MAX_DUMMY_BYTES`] dummy bytes
+#[cfg(any(feature = "blocking", feature = "async"))]
+fn dummy_operation<R: ReadableRegister>(
+    scratch: &mut [u8; MAX_DUMMY_BYTES],
+) -> Option<embedded_hal::spi::Operation<'_, u8>> {
+    let count = dummy_count::<R>();
+    if count == 0 {
+        return None;
+    }
+
+    Some(match R::dummy_mode() {
+        DummyMode::Write => embedded_hal::spi::Operation::Write(&DUMMY_BYTES[..count]),
+        DummyMode::Read => embedded_hal::spi::Operation::Read(&mut scratch[..count]),
+    })
+}
+
+/// A modification applied to the first byte of a serialized register ID before it is transmitted
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub enum AddressModifier {
+    /// The register ID is transmitted unmodified
+    #[default]
+    None,
+    /// The bits of the mask are set (OR-ed) in the first byte of the register ID
+    Set(u8),
+    /// The bits of the mask are cleared (AND-NOT-ed) in the first byte of the register ID
+    Clear(u8),
+}
+
+impl AddressModifier {
+    /// Serializes the register ID, applying the modifier to its first byte
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    fn apply<I: id::Id>(self, id: I) -> I::Array {
+        let mut bytes = id.to_id_bytes();
+        if let Some(first) = bytes.as_mut().first_mut() {
+            match self {
+                Self::None => {}
+                Self::Set(mask) => *first |= mask,
+                Self::Clear(mask) => *first &= !mask,
+            }
+        }
+        bytes
+    }
+}
+
+/// The register addressing convention used by a SPI device
+///
+/// Many SPI devices distinguish reads from writes by a flag within the first byte of the register
+/// ID, such as the most significant bit being set for reads on many ST parts. Rather than
+/// overriding [`ReadableRegister::readable_id`] and [`WritableRegister::writeable_id`] for every
+/// register, a [`SpiAddressing`] may be provided to the `_with` functions, such as
+/// `blocking::read_register_with`, to apply the convention across an entire register map.
+///
+/// ```
+/// use regiface::spi::{AddressModifier, SpiAddressing};
+///
+/// // Reads set the MSB of the register ID, writes clear it
+/// const ST_ADDRESSING: SpiAddressing = SpiAddressing::read_flag(0x80);
+///
+/// // Reads set bit 6, writes are unmodified
+/// const OTHER_ADDRESSING: SpiAddressing = SpiAddressing {
+///     read: AddressModifier::Set(0x40),
+///     write: AddressModifier::None,
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub struct SpiAddressing {
+    /// The modifier applied to register IDs when reading
+    pub read: AddressModifier,
+    /// The modifier applied to register IDs when writing
+    pub write: AddressModifier,
+}
+
+impl SpiAddressing {
+    /// Register IDs are transmitted unmodified for both reads and writes
+    pub const NONE: Self = Self {
+        read: AddressModifier::None,
+        write: AddressModifier::None,
+    };
+
+    /// Reads set the bits of `mask` in the register ID, while writes clear them
+    pub const fn read_flag(mask: u8) -> Self {
+        Self {
+            read: AddressModifier::Set(mask),
+            write: AddressModifier::Clear(mask),
+        }
+    }
+}
+
+/// The bit layout of a SPI device using 16-bit words, where the register ID and register value
+/// share a single frame
+///
+/// Many DACs, motor drivers, and position sensors use 16-bit SPI frames in which some bits carry
+/// the register ID, some carry the register value, and others flag whether the frame is a read or a
+/// write. A [`WordLayout`] describes where each field sits within the frame, and is provided to the
+/// `_word` functions, such as `blocking::read_register_word`.
+///
+/// Register IDs are serialized with [`Id`](crate::id::Id) and interpreted as a big-endian integer,
+/// and register values are likewise interpreted as a big-endian integer, thus registers used with
+/// a [`WordLayout`] should serialize into at most two bytes. Both fields are truncated to the
+/// number of bits specified by the layout.
+///
+/// ```
+/// use regiface::spi::WordLayout;
+///
+/// // The register ID occupies bits 15..12, the register value occupies bits 11..0
+/// const DAC_LAYOUT: WordLayout = WordLayout::new(12, 4, 0, 12);
+///
+/// // Bit 15 flags a read, the register ID occupies bits 14..11, and the register value occupies
+/// // bits 10..0
+/// const DRIVER_LAYOUT: WordLayout = WordLayout::new(11, 4, 0, 11).with_read_flag(0x8000);
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub struct WordLayout {
+    /// The bit position of the least significant bit of the register ID
+    pub id_shift: u32,
+    /// The number of bits occupied by the register ID
+    pub id_bits: u32,
+    /// The bit position of the least significant bit of the register value
+    pub data_shift: u32,
+    /// The number of bits occupied by the register value
+    pub data_bits: u32,
+    /// Bits set in every frame reading a register
+    pub read_flag: u16,
+    /// Bits set in every frame writing a register
+    pub write_flag: u16,
+}
+
+impl WordLayout {
+    /// Create a new [`WordLayout`] from the position and width of the register ID and register
+    /// value fields, with no read or write flags
+    pub const fn new(id_shift: u32, id_bits: u32, data_shift: u32, data_bits: u32) -> Self {
+        Self {
+            id_shift,
+            id_bits,
+            data_shift,
+            data_bits,
+            read_flag: 0,
+            write_flag: 0,
+        }
+    }
+
+    /// Returns the layout with the bits of `flag` set in every frame reading a register
+    pub const fn with_read_flag(self, flag: u16) -> Self {
+        Self {
+            read_flag: flag,
+            ..self
+        }
+    }
+
+    /// Returns the layout with the bits of `flag` set in every frame writing a register
+    pub const fn with_write_flag(self, flag: u16) -> Self {
+        Self {
+            write_flag: flag,
+            ..self
+        }
+    }
+
+    /// Places `value` into the field at `shift`, truncated to `bits` bits
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    fn field(value: u16, shift: u32, bits: u32) -> u16 {
+        let mask = u16::MAX.checked_shr(16 - bits.min(16)).unwrap_or(0);
+        (value & mask).checked_shl(shift).unwrap_or(0)
+    }
+
+    /// Builds the frame requesting a read of the register with the given ID
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    fn read_frame(&self, id: &[u8]) -> u16 {
+        self.read_flag | Self::field(to_word(id), self.id_shift, self.id_bits)
+    }
+
+    /// Builds the frame writing the given value to the register with the given ID
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    fn write_frame(&self, id: &[u8], value: &[u8]) -> u16 {
+        self.write_flag
+            | Self::field(to_word(id), self.id_shift, self.id_bits)
+            | Self::field(to_word(value), self.data_shift, self.data_bits)
+    }
+
+    /// Extracts the register value from a received frame into `value` in big-endian byte order
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    fn read_value(&self, frame: u16, value: &mut [u8]) {
+        let data = Self::field(
+            frame.checked_shr(self.data_shift).unwrap_or(0),
+            0,
+            self.data_bits,
+        );
+        for (byte, data) in value
+            .iter_mut()
+            .rev()
+            .zip(data.to_be_bytes().into_iter().rev())
+        {
+            *byte = data;
+        }
+    }
+}
+
+/// Interprets bytes as a big-endian integer, keeping only the last two bytes
+#[cfg(any(feature = "blocking", feature = "async"))]
+fn to_word(bytes: &[u8]) -> u16 {
+    bytes
+        .iter()
+        .fold(0, |word, byte| word.wrapping_shl(8) | u16::from(*byte))
+}
+
+/// Error that can occur when communicating through a [`CsDevice`]
+///
+/// Generic over the bus error type `B` and chip select pin error type `P`.
+#[derive(Clone, Copy, Debug)]
+pub enum CsDeviceError<B, P> {
+    /// An error occurred while communicating over the bus
+    Spi(B),
+    /// An error occurred while asserting or releasing the chip select pin
+    ChipSelect(P),
+}
+
+impl<B, P> embedded_hal::spi::Error for CsDeviceError<B, P>
+where
+    B: embedded_hal::spi::Error,
+    P: core::fmt::Debug,
+{
+    fn kind(&self) -> embedded_hal::spi::ErrorKind {
+        match self {
+            Self::Spi(e) => e.kind(),
+            Self::ChipSelect(_) => embedded_hal::spi::ErrorKind::ChipSelectFault,
+        }
+    }
+}
+
+/// A SPI device built from a raw SPI bus and a manually controlled chip select pin
+///
+/// This adapter implements both the blocking and async
+/// [`SpiDevice`](embedded_hal::spi::SpiDevice) traits, allowing a bus with exclusive access and a
+/// GPIO chip select to be used with the functions of this module without an additional bus sharing
+/// layer. For each transaction, the chip select pin is driven low, the operations are performed on
+/// the bus, the bus is flushed, and the chip select pin is driven high. Errors from the chip select
+/// pin are reported as [`CsDeviceError::ChipSelect`], and thus appear in the bus error slot of the
+/// error types of this crate.
+///
+/// # Example
+#[cfg_attr(feature = "blocking", doc = "```")]
+#[cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+/// # use core::cell::RefCell;
+/// # use embedded_hal::{delay::DelayNs, digital::{ErrorType as PinErrorType, OutputPin}};
+/// # use embedded_hal::spi::{ErrorType, SpiBus};
+/// use regiface::{register, spi::{self, CsDevice}, ReadableRegister, FromByteArray};
+///
+/// #[register(0x0Fu8)]
+/// #[derive(ReadableRegister)]
+/// struct WhoAmI(u8);
+///
+/// impl FromByteArray for WhoAmI {
+///     type Array = [u8; 1];
+///     type Error = core::convert::Infallible;
+///
+///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+///         Ok(Self(bytes[0]))
+///     }
+/// }
+/// # struct Bus<'a>(&'a RefCell<Vec<&'static str>>);
+/// # impl ErrorType for Bus<'_> { type Error = core::convert::Infallible; }
+/// # impl SpiBus for Bus<'_> {
+/// #     fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+/// #         self.0.borrow_mut().push("read");
+/// #         words.fill(0x33);
+/// #         Ok(())
+/// #     }
+/// #     fn write(&mut self, _: &[u8]) -> Result<(), Self::Error> { self.0.borrow_mut().push("write"); Ok(()) }
+/// #     fn transfer(&mut self, _: &mut [u8], _: &[u8]) -> Result<(), Self::Error> { unimplemented!() }
+/// #     fn transfer_in_place(&mut self, _: &mut [u8]) -> Result<(), Self::Error> { unimplemented!() }
+/// #     fn flush(&mut self) -> Result<(), Self::Error> { self.0.borrow_mut().push("flush"); Ok(()) }
+/// # }
+/// # struct Pin<'a>(&'a RefCell<Vec<&'static str>>);
+/// # impl PinErrorType for Pin<'_> { type Error = core::convert::Infallible; }
+/// # impl OutputPin for Pin<'_> {
+/// #     fn set_low(&mut self) -> Result<(), Self::Error> { self.0.borrow_mut().push("cs low"); Ok(()) }
+/// #     fn set_high(&mut self) -> Result<(), Self::Error> { self.0.borrow_mut().push("cs high"); Ok(()) }
+/// # }
+/// # struct NoDelay;
+/// # impl DelayNs for NoDelay { fn delay_ns(&mut self, _: u32) {} }
+/// # let log = RefCell::new(Vec::new());
+/// # let (bus, cs, delay) = (Bus(&log), Pin(&log), NoDelay);
+///
+/// let mut device = CsDevice::new(bus, cs, delay);
+/// let id: WhoAmI = spi::blocking::read_register(&mut device).unwrap();
+///
+/// assert_eq!(id.0, 0x33);
+/// assert_eq!(*log.borrow(), ["cs low", "write", "read", "flush", "cs high"]);
+/// ```
+///
+/// The async flavor behaves identically
+#[cfg_attr(feature = "async", doc = "```")]
+#[cfg_attr(not(feature = "async"), doc = "```ignore")]
+/// # use core::cell::RefCell;
+/// # use embedded_hal::digital::{ErrorType as PinErrorType, OutputPin};
+/// # use embedded_hal_async::{delay::DelayNs, spi::{ErrorType, SpiBus}};
+/// # use regiface::{register, spi::{self, CsDevice}, WritableRegister, ToByteArray};
+/// # #[register(0x20u8)]
+/// # #[derive(WritableRegister)]
+/// # struct Ctrl(u8);
+/// # impl ToByteArray for Ctrl {
+/// #     type Array = [u8; 1];
+/// #     type Error = core::convert::Infallible;
+/// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.0]) }
+/// # }
+/// # struct Bus<'a>(&'a RefCell<Vec<&'static str>>);
+/// # impl ErrorType for Bus<'_> { type Error = core::convert::Infallible; }
+/// # impl SpiBus for Bus<'_> {
+/// #     async fn read(&mut self, _: &mut [u8]) -> Result<(), Self::Error> { unimplemented!() }
+/// #     async fn write(&mut self, _: &[u8]) -> Result<(), Self::Error> { self.0.borrow_mut().push("write"); Ok(()) }
+/// #     async fn transfer(&mut self, _: &mut [u8], _: &[u8]) -> Result<(), Self::Error> { unimplemented!() }
+/// #     async fn transfer_in_place(&mut self, _: &mut [u8]) -> Result<(), Self::Error> { unimplemented!() }
+/// #     async fn flush(&mut self) -> Result<(), Self::Error> { self.0.borrow_mut().push("flush"); Ok(()) }
+/// # }
+/// # struct Pin<'a>(&'a RefCell<Vec<&'static str>>);
+/// # impl PinErrorType for Pin<'_> { type Error = core::convert::Infallible; }
+/// # impl OutputPin for Pin<'_> {
+/// #     fn set_low(&mut self) -> Result<(), Self::Error> { self.0.borrow_mut().push("cs low"); Ok(()) }
+/// #     fn set_high(&mut self) -> Result<(), Self::Error> { self.0.borrow_mut().push("cs high"); Ok(()) }
+/// # }
+/// # struct NoDelay;
+/// # impl DelayNs for NoDelay { async fn delay_ns(&mut self, _: u32) {} }
+/// # let log = RefCell::new(Vec::new());
+/// # let (bus, cs, delay) = (Bus(&log), Pin(&log), NoDelay);
+/// let mut device = CsDevice::new(bus, cs, delay);
+/// # let fut = core::pin::pin!(async {
+/// spi::r#async::write_register(&mut device, Ctrl(0x67)).await.unwrap();
+/// # });
+/// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+/// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+///
+/// assert_eq!(*log.borrow(), ["cs low", "write", "write", "flush", "cs high"]);
+/// ```
+#[derive(Debug)]
+pub struct CsDevice<BUS, CS, D> {
+    bus: BUS,
+    cs: CS,
+    delay: D,
+}
+
+impl<BUS, CS, D> CsDevice<BUS, CS, D> {
+    /// Create a new [`CsDevice`] from a SPI bus, a chip select pin, and a delay provider
+    ///
+    /// The delay provider is used to perform any [`DelayNs`](embedded_hal::spi::Operation::DelayNs)
+    /// operations within a transaction. The chip select pin is not modified until the first
+    /// transaction, thus it should be driven high by the caller beforehand.
+    pub fn new(bus: BUS, cs: CS, delay: D) -> Self {
+        Self { bus, cs, delay }
+    }
+
+    /// Returns a mutable reference to the underlying SPI bus
+    pub fn bus_mut(&mut self) -> &mut BUS {
+        &mut self.bus
+    }
+
+    /// Consumes the [`CsDevice`], returning the underlying SPI bus, chip select pin, and delay provider
+    pub fn release(self) -> (BUS, CS, D) {
+        (self.bus, self.cs, self.delay)
+    }
+}
+
+impl<BUS, CS, D> embedded_hal::spi::ErrorType for CsDevice<BUS, CS, D>
+where
+    BUS: embedded_hal::spi::ErrorType,
+    CS: embedded_hal::digital::OutputPin,
+{
+    type Error = CsDeviceError<BUS::Error, CS::Error>;
+}
+
+#[cfg(feature = "blocking")]
+impl<BUS, CS, D> embedded_hal::spi::SpiDevice for CsDevice<BUS, CS, D>
+where
+    BUS: embedded_hal::spi::SpiBus,
+    CS: embedded_hal::digital::OutputPin,
+    D: embedded_hal::delay::DelayNs,
+{
+    fn transaction(
+        &mut self,
+        operations: &mut [embedded_hal::spi::Operation<'_, u8>],
+    ) -> Result<(), Self::Error> {
+        use embedded_hal::spi::Operation;
+
+        self.cs.set_low().map_err(CsDeviceError::ChipSelect)?;
+
+        let result = operations.iter_mut().try_for_each(|op| match op {
+            Operation::Read(buf) => self.bus.read(buf),
+            Operation::Write(buf) => self.bus.write(buf),
+            Operation::Transfer(read, write) => self.bus.transfer(read, write),
+            Operation::TransferInPlace(buf) => self.bus.transfer_in_place(buf),
+            Operation::DelayNs(ns) => {
+                self.bus.flush()?;
+                self.delay.delay_ns(*ns);
+                Ok(())
+            }
+        });
+
+        // Always release the chip select, even if the bus operations failed
+        let flush = self.bus.flush();
+        let release = self.cs.set_high();
+
+        result.map_err(CsDeviceError::Spi)?;
+        flush.map_err(CsDeviceError::Spi)?;
+        release.map_err(CsDeviceError::ChipSelect)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<BUS, CS, D> embedded_hal_async::spi::SpiDevice for CsDevice<BUS, CS, D>
+where
+    BUS: embedded_hal_async::spi::SpiBus,
+    CS: embedded_hal::digital::OutputPin,
+    D: embedded_hal_async::delay::DelayNs,
+{
+    async fn transaction(
+        &mut self,
+        operations: &mut [embedded_hal_async::spi::Operation<'_, u8>],
+    ) -> Result<(), Self::Error> {
+        use embedded_hal_async::spi::Operation;
+
+        self.cs.set_low().map_err(CsDeviceError::ChipSelect)?;
+
+        let mut result = Ok(());
+        for op in operations {
+            result = match op {
+                Operation::Read(buf) => self.bus.read(buf).await,
+                Operation::Write(buf) => self.bus.write(buf).await,
+                Operation::Transfer(read, write) => self.bus.transfer(read, write).await,
+                Operation::TransferInPlace(buf) => self.bus.transfer_in_place(buf).await,
+                Operation::DelayNs(ns) => match self.bus.flush().await {
+                    Ok(()) => {
+                        self.delay.delay_ns(*ns).await;
+                        Ok(())
+                    }
+                    Err(e) => Err(e),
+                },
+            };
+            if result.is_err() {
+                break;
+            }
+        }
+
+        // Always release the chip select, even if the bus operations failed
+        let flush = self.bus.flush().await;
+        let release = self.cs.set_high();
+
+        result.map_err(CsDeviceError::Spi)?;
+        flush.map_err(CsDeviceError::Spi)?;
+        release.map_err(CsDeviceError::ChipSelect)
+    }
+}
+
+#[cfg(feature = "async")]
 pub mod r#async {
     use super::*;
+    use crate::{
+        errors::{InterruptReadError, TimeoutError},
+        interrupt::{wait_for, Edge},
+        retry::{retry_policy_async, Policy},
+        timeout::with_timeout,
+    };
+    use core::ops::ControlFlow;
+    use embedded_hal_async::spi::Operation;
+
+    /// Builds the operations for invoking a command: the preamble, the command ID, the sub-ID, the
+    /// command parameters, and the postamble, followed by a read of the status byte and command
+    /// response
+    ///
+    /// Empty preambles, command IDs, sub-IDs, command parameters, postambles, status bytes, and
+    /// responses are omitted, thus only the first `len` of the returned operations are to be
+    /// performed.
+    fn command_operations<'a>(
+        preamble: &'a [u8],
+        id: &'a [u8],
+        sub_id: &'a [u8],
+        parameters: &'a [u8],
+        postamble: &'a [u8],
+        status: &'a mut [u8],
+        response: &'a mut [u8],
+    ) -> ([Operation<'a, u8>; 7], usize) {
+        let mut operations = [
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+        ];
+        let mut len = 0;
+
+        for write in [preamble, id, sub_id, parameters, postamble] {
+            if !write.is_empty() {
+                operations[len] = Operation::Write(write);
+                len += 1;
+            }
+        }
+
+        let (reads, reads_len) = read_operations(status, response);
+        for read in reads.into_iter().take(reads_len) {
+            operations[len] = read;
+            len += 1;
+        }
+
+        (operations, len)
+    }
+
+    /// Builds the operations for reading a command response: a read of the status byte, followed
+    /// by a read of the command response
+    ///
+    /// Empty status bytes and responses are omitted, thus only the first `len` of the returned
+    /// operations are to be performed.
+    fn read_operations<'a>(
+        status: &'a mut [u8],
+        response: &'a mut [u8],
+    ) -> ([Operation<'a, u8>; 2], usize) {
+        let mut operations = [Operation::Write(&[]), Operation::Write(&[])];
+        let mut len = 0;
+
+        if !status.is_empty() {
+            operations[len] = Operation::Read(status);
+            len += 1;
+        }
+
+        if !response.is_empty() {
+            operations[len] = Operation::Read(response);
+            len += 1;
+        }
+
+        (operations, len)
+    }
 
     /// Read a register value from a SPI device.
     ///
-    /// This function performs a SPI transaction, first sending the register ID
-    /// then reading the register value. The received bytes are deserialized into the
-    /// specified register type.
+    /// This function performs a SPI transaction, first sending the register ID, followed by
+    /// the number of dummy bytes specified by [`ReadableRegister::dummy_bytes`], then reading
+    /// the register value. The received bytes are deserialized into the specified register type.
     ///
     /// # Parameters
     /// * `device` - The SPI device to communicate with
@@ -27,6 +606,9 @@ pub mod r#async {
     /// * `ReadRegisterError::BusError` - Communication with the device failed
     /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
     ///
+    /// # Panics
+    /// Panics if [`ReadableRegister::dummy_bytes`] returns more than [`MAX_DUMMY_BYTES`]
+    ///
     /// # Example
     /// ```no_run
     /// # use embedded_hal_async::spi::SpiDevice;
@@ -50,100 +632,4324 @@ pub mod r#async {
         D: embedded_hal_async::spi::SpiDevice,
         R: ReadableRegister,
     {
-        let mut buf = <R as FromByteArray>::Array::new();
+        let mut buf = <R as FromByteArray>::Array::new();
+
+        let reg_id = R::readable_id().to_id_bytes();
+
+        let mut scratch = [0; MAX_DUMMY_BYTES];
+        let operations: &mut [embedded_hal_async::spi::Operation<'_, u8>] =
+            match dummy_operation::<R>(&mut scratch) {
+                None => &mut [
+                    embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
+                    embedded_hal_async::spi::Operation::Read(buf.as_mut()),
+                ],
+                Some(dummy) => &mut [
+                    embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
+                    dummy,
+                    embedded_hal_async::spi::Operation::Read(buf.as_mut()),
+                ],
+            };
+
+        device
+            .transaction(operations)
+            .await
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "spi: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from a SPI device, reading a fixed number of bytes regardless of
+    /// the size of the register.
+    ///
+    /// This function behaves like [`read_register`], except that `READ_LEN` bytes are read rather
+    /// than the length of the register's [`Array`](FromByteArray::Array). This is useful for
+    /// devices that always clock out a fixed frame, or for HALs with a minimum transfer size. The
+    /// register value is deserialized from the first bytes of the frame, and any trailing bytes are
+    /// discarded.
+    ///
+    /// `READ_LEN` must be at least the length of the register's [`Array`](FromByteArray::Array),
+    /// which is enforced at compile time.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    ///
+    /// # Errors
+    /// * `ReadRegisterError::BusError` - Communication with the device failed
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
+    ///
+    /// # Panics
+    /// Panics if [`ReadableRegister::dummy_bytes`] returns more than [`MAX_DUMMY_BYTES`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi, ReadableRegister, FromByteArray};
+    /// # #[register(0x10u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct Status(u8);
+    /// # impl FromByteArray for Status {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// async fn read_status<D: SpiDevice>(device: &mut D) -> u8 {
+    ///     // The device always clocks out a 4 byte frame
+    ///     let status: Status = spi::r#async::read_register_sized::<_, Status, 4>(device).await.unwrap();
+    ///     status.0
+    /// }
+    /// ```
+    pub async fn read_register_sized<D, R, const READ_LEN: usize>(
+        device: &mut D,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: ReadableRegister,
+    {
+        const {
+            assert!(
+                READ_LEN >= <R as FromByteArray>::Array::LEN,
+                "READ_LEN must be at least the length of the register's FromByteArray::Array"
+            )
+        };
+
+        let mut buf = [0; READ_LEN];
+
+        let reg_id = R::readable_id().to_id_bytes();
+
+        let mut scratch = [0; MAX_DUMMY_BYTES];
+        let operations: &mut [embedded_hal_async::spi::Operation<'_, u8>] =
+            match dummy_operation::<R>(&mut scratch) {
+                None => &mut [
+                    embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
+                    embedded_hal_async::spi::Operation::Read(&mut buf),
+                ],
+                Some(dummy) => &mut [
+                    embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
+                    dummy,
+                    embedded_hal_async::spi::Operation::Read(&mut buf),
+                ],
+            };
+
+        device
+            .transaction(operations)
+            .await
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "spi: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            &buf[..]
+        );
+
+        // Only the leading bytes of the oversized read make up the register value
+        let mut bytes = <R as FromByteArray>::Array::new();
+        bytes
+            .as_mut()
+            .copy_from_slice(&buf[..<R as FromByteArray>::Array::LEN]);
+
+        R::from_bytes(bytes).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from a SPI device using a single full-duplex transfer.
+    ///
+    /// Rather than writing the register ID and then reading the register value, this function
+    /// performs a single [`Transfer`](embedded_hal_async::spi::Operation::Transfer) that clocks out
+    /// the register ID followed by zero padding, while simultaneously capturing the received bytes.
+    /// The register value is deserialized from the tail of the received bytes, skipping the bytes
+    /// received while the register ID and any [`ReadableRegister::dummy_bytes`] were clocked out.
+    /// This is required by devices that stream data while the register ID is clocked, which a
+    /// separate write and read would de-synchronize.
+    ///
+    /// The combined length of the register ID and register value must not exceed
+    /// [`MAX_TRANSFER_BYTES`], which is enforced at compile time.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    ///
+    /// # Errors
+    /// * `ReadRegisterError::BusError` - Communication with the device failed
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
+    ///
+    /// # Panics
+    /// Panics if [`ReadableRegister::dummy_bytes`] returns more than [`MAX_DUMMY_BYTES`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi, ReadableRegister, FromByteArray};
+    /// # #[register(0x00u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct Thermocouple(u32);
+    /// # impl FromByteArray for Thermocouple {
+    /// #     type Array = [u8; 4];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(u32::from_be_bytes(bytes))) }
+    /// # }
+    /// async fn read_thermocouple<D: SpiDevice>(device: &mut D) -> u32 {
+    ///     let reading: Thermocouple = spi::r#async::read_register_transfer(device).await.unwrap();
+    ///     reading.0
+    /// }
+    /// ```
+    pub async fn read_register_transfer<D, R>(
+        device: &mut D,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: ReadableRegister,
+    {
+        const {
+            assert!(
+                <<R as crate::Register>::IdType as id::Id>::Array::LEN
+                    + <R as FromByteArray>::Array::LEN
+                    <= MAX_TRANSFER_BYTES,
+                "the register ID and value exceed MAX_TRANSFER_BYTES"
+            )
+        };
+
+        let reg_id = R::readable_id().to_id_bytes();
+        let id_len = reg_id.as_ref().len();
+        let data_start = id_len + dummy_count::<R>();
+        let len = data_start + <R as FromByteArray>::Array::LEN;
+
+        let mut tx = [0; MAX_TRANSFER_BYTES + MAX_DUMMY_BYTES];
+        let mut rx = [0; MAX_TRANSFER_BYTES + MAX_DUMMY_BYTES];
+        tx[..id_len].copy_from_slice(reg_id.as_ref());
+
+        device
+            .transaction(&mut [embedded_hal_async::spi::Operation::Transfer(
+                &mut rx[..len],
+                &tx[..len],
+            )])
+            .await
+            .map_err(ReadRegisterError::BusError)?;
+
+        let mut buf = <R as FromByteArray>::Array::new();
+        buf.as_mut().copy_from_slice(&rx[data_start..len]);
+
+        trace!(
+            "spi: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from a SPI device using the given addressing convention.
+    ///
+    /// This function behaves exactly like [`read_register`], except that the
+    /// [`SpiAddressing::read`] modifier is applied to the first byte of the register ID before it
+    /// is transmitted.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `addressing` - The addressing convention used by the device
+    ///
+    /// # Errors
+    /// * `ReadRegisterError::BusError` - Communication with the device failed
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
+    ///
+    /// # Panics
+    /// Panics if [`ReadableRegister::dummy_bytes`] returns more than [`MAX_DUMMY_BYTES`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi::{self, SpiAddressing}, ReadableRegister, FromByteArray};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct WhoAmI(u8);
+    /// # impl FromByteArray for WhoAmI {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// const ADDRESSING: SpiAddressing = SpiAddressing::read_flag(0x80);
+    ///
+    /// async fn who_am_i<D: SpiDevice>(device: &mut D) -> u8 {
+    ///     // Transmits 0x8F as the register ID
+    ///     let id: WhoAmI = spi::r#async::read_register_with(device, ADDRESSING).await.unwrap();
+    ///     id.0
+    /// }
+    /// ```
+    pub async fn read_register_with<D, R>(
+        device: &mut D,
+        addressing: SpiAddressing,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: ReadableRegister,
+    {
+        let mut buf = <R as FromByteArray>::Array::new();
+
+        let reg_id = addressing.read.apply(R::readable_id());
+
+        let mut scratch = [0; MAX_DUMMY_BYTES];
+        let operations: &mut [embedded_hal_async::spi::Operation<'_, u8>] =
+            match dummy_operation::<R>(&mut scratch) {
+                None => &mut [
+                    embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
+                    embedded_hal_async::spi::Operation::Read(buf.as_mut()),
+                ],
+                Some(dummy) => &mut [
+                    embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
+                    dummy,
+                    embedded_hal_async::spi::Operation::Read(buf.as_mut()),
+                ],
+            };
+
+        device
+            .transaction(operations)
+            .await
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "spi: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from a 3-wire (half-duplex) SPI device using the given addressing
+    /// convention.
+    ///
+    /// Devices in 3-wire mode share a single bidirectional data line (SDIO), which the device only
+    /// drives once the register ID has been received. This function performs a SPI transaction
+    /// consisting only of a write of the register ID, with the [`SpiAddressing::read`] modifier
+    /// applied to set the direction bit, followed by reads. Any dummy bytes requested by
+    /// [`ReadableRegister::dummy_bytes`] are always clocked by reading, regardless of
+    /// [`ReadableRegister::dummy_mode`], as the data line is no longer driven by the host. No
+    /// full-duplex [`Operation::Transfer`] is ever issued.
+    ///
+    /// The [`SpiDevice`](embedded_hal_async::spi::SpiDevice) implementation is responsible for
+    /// switching the direction of the data line between the write and read operations. Devices with
+    /// separate MOSI and MISO lines should continue to use [`read_register_with`], which is
+    /// unaffected.
+    ///
+    /// # Parameters
+    /// * `device` - The half-duplex SPI device to communicate with
+    /// * `addressing` - The addressing convention used by the device
+    ///
+    /// # Errors
+    /// * `ReadRegisterError::BusError` - Communication with the device failed
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
+    ///
+    /// # Panics
+    /// Panics if [`ReadableRegister::dummy_bytes`] returns more than [`MAX_DUMMY_BYTES`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi::{self, SpiAddressing}, ReadableRegister, FromByteArray};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct WhoAmI(u8);
+    /// # impl FromByteArray for WhoAmI {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// const ADDRESSING: SpiAddressing = SpiAddressing::read_flag(0x80);
+    ///
+    /// async fn who_am_i<D: SpiDevice>(device: &mut D) -> u8 {
+    ///     // Transmits 0x8F as the register ID, then releases SDIO to the device
+    ///     let id: WhoAmI = spi::r#async::read_register_3wire(device, ADDRESSING).await.unwrap();
+    ///     id.0
+    /// }
+    /// ```
+    pub async fn read_register_3wire<D, R>(
+        device: &mut D,
+        addressing: SpiAddressing,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: ReadableRegister,
+    {
+        let mut buf = <R as FromByteArray>::Array::new();
+
+        let reg_id = addressing.read.apply(R::readable_id());
+
+        let mut scratch = [0; MAX_DUMMY_BYTES];
+        let dummy = dummy_count::<R>();
+        let operations: &mut [embedded_hal_async::spi::Operation<'_, u8>] = if dummy == 0 {
+            &mut [
+                embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
+                embedded_hal_async::spi::Operation::Read(buf.as_mut()),
+            ]
+        } else {
+            &mut [
+                embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
+                embedded_hal_async::spi::Operation::Read(&mut scratch[..dummy]),
+                embedded_hal_async::spi::Operation::Read(buf.as_mut()),
+            ]
+        };
+
+        device
+            .transaction(operations)
+            .await
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "spi: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from a SPI device using 16-bit words.
+    ///
+    /// This function performs a SPI transaction, first sending a single frame containing the
+    /// register ID and read flag placed according to `layout`, then reading a single frame. The
+    /// register value is extracted from the received frame according to `layout` and deserialized
+    /// into the specified register type.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `layout` - The bit layout of the frames used by the device
+    ///
+    /// # Errors
+    /// * `ReadRegisterError::BusError` - Communication with the device failed
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi::{self, WordLayout}, ReadableRegister, FromByteArray};
+    /// # #[register(0x2u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct Status(u16);
+    /// # impl FromByteArray for Status {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(u16::from_be_bytes(bytes))) }
+    /// # }
+    /// const LAYOUT: WordLayout = WordLayout::new(11, 4, 0, 11).with_read_flag(0x8000);
+    ///
+    /// async fn read_status<D: SpiDevice<u16>>(device: &mut D) -> u16 {
+    ///     // Transmits 0x9000 as the read frame
+    ///     let status: Status = spi::r#async::read_register_word(device, LAYOUT).await.unwrap();
+    ///     status.0
+    /// }
+    /// ```
+    pub async fn read_register_word<D, R>(
+        device: &mut D,
+        layout: WordLayout,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice<u16>,
+        R: ReadableRegister,
+    {
+        let mut buf = <R as FromByteArray>::Array::new();
+
+        let reg_id = R::readable_id().to_id_bytes();
+        let request = [layout.read_frame(reg_id.as_ref())];
+        let mut response = [0u16];
+
+        device
+            .transaction(&mut [
+                embedded_hal_async::spi::Operation::Write(&request),
+                embedded_hal_async::spi::Operation::Read(&mut response),
+            ])
+            .await
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "spi: read register: id={:02X?} frame={:04X?}",
+            reg_id.as_ref(),
+            response[0]
+        );
+
+        layout.read_value(response[0], buf.as_mut());
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read an instance of an indexed register value from a SPI device.
+    ///
+    /// This function behaves exactly like [`read_register`], except that the register ID is
+    /// computed from the provided index using [`IndexedRegister::readable_indexed_id`].
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `index` - The index of the register instance to read
+    ///
+    /// # Errors
+    /// * `ReadRegisterError::BusError` - Communication with the device failed
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
+    ///
+    /// # Panics
+    /// Panics if [`ReadableRegister::dummy_bytes`] returns more than [`MAX_DUMMY_BYTES`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi, IndexedRegister, ReadableRegister, FromByteArray};
+    /// # #[register(0x06u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct LedOnOff { on: u16, off: u16 }
+    /// # impl IndexedRegister for LedOnOff {
+    /// #     type Index = u8;
+    /// #     fn indexed_id(channel: Self::Index) -> Self::IdType { Self::ID + 4 * channel }
+    /// # }
+    /// # impl FromByteArray for LedOnOff {
+    /// #     type Array = [u8; 4];
+    /// #     type Error = ();
+    /// #     fn from_bytes(b: Self::Array) -> Result<Self, Self::Error> {
+    /// #         Ok(Self { on: u16::from_le_bytes([b[0], b[1]]), off: u16::from_le_bytes([b[2], b[3]]) })
+    /// #     }
+    /// # }
+    /// async fn read_channel<D: SpiDevice>(device: &mut D, channel: u8) -> u16 {
+    ///     let led: LedOnOff = spi::r#async::read_register_at(device, channel).await.unwrap();
+    ///     led.off
+    /// }
+    /// ```
+    pub async fn read_register_at<D, R>(
+        device: &mut D,
+        index: R::Index,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: ReadableRegister + IndexedRegister,
+    {
+        let mut buf = <R as FromByteArray>::Array::new();
+
+        let reg_id = R::readable_indexed_id(index).to_id_bytes();
+
+        let mut scratch = [0; MAX_DUMMY_BYTES];
+        let operations: &mut [embedded_hal_async::spi::Operation<'_, u8>] =
+            match dummy_operation::<R>(&mut scratch) {
+                None => &mut [
+                    embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
+                    embedded_hal_async::spi::Operation::Read(buf.as_mut()),
+                ],
+                Some(dummy) => &mut [
+                    embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
+                    dummy,
+                    embedded_hal_async::spi::Operation::Read(buf.as_mut()),
+                ],
+            };
+
+        device
+            .transaction(operations)
+            .await
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "spi: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from a SPI device, along with the number of bytes read.
+    ///
+    /// This function behaves identically to [`read_register`], but additionally returns the number
+    /// of bytes that the bus transfer placed into the register buffer. This allows the caller to
+    /// correctly interpret reads, such as FIFO or status reads, on buses capable of returning fewer
+    /// bytes than requested.
+    ///
+    /// The `embedded-hal` SPI traits require that every read fills the entire buffer, thus the
+    /// count returned is always equal to the length of the register's [`Array`](FromByteArray::Array).
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    ///
+    /// # Errors
+    /// * `ReadRegisterError::BusError` - Communication with the device failed
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi, ReadableRegister, FromByteArray};
+    /// # #[register(42u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct FifoData;
+    /// # impl FromByteArray for FifoData {
+    /// #     type Array = [u8; 32];
+    /// #     type Error = ();
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { todo!() }
+    /// # }
+    /// async fn read_fifo<D: SpiDevice>(device: &mut D) {
+    ///     let (data, count): (FifoData, usize) = spi::r#async::read_register_counted(device).await.unwrap();
+    /// }
+    /// ```
+    pub async fn read_register_counted<D, R>(
+        device: &mut D,
+    ) -> Result<(R, usize), ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: ReadableRegister,
+    {
+        let count = <R as FromByteArray>::Array::new().as_ref().len();
+        let register = read_register(device).await?;
+
+        Ok((register, count))
+    }
+
+    /// Read a contiguous block of registers from a SPI device in a single transaction.
+    ///
+    /// Many devices automatically increment their internal register pointer for each byte clocked
+    /// out while chip select remains asserted, allowing a run of consecutive registers to be read
+    /// by sending the starting register ID once and then reading the entire block. This function
+    /// performs exactly that SPI transaction, where `R` is a register type whose ID is the address
+    /// of the first register in the block and whose [`Array`](FromByteArray::Array) spans the entire
+    /// block.
+    ///
+    /// This behaves identically to [`read_register`], but the dedicated name makes the reliance on
+    /// the device's auto-increment behavior explicit in driver code. Devices that do not
+    /// auto-increment their register pointer must not be read with this function. Some devices
+    /// require an address bit to be set to enable auto-increment, which should be included in the
+    /// register ID.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    ///
+    /// # Errors
+    /// * `ReadRegisterError::BusError` - Communication with the device failed
+    /// * `ReadRegisterError::DeserializationError` - Failed to convert received bytes into register value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi, ReadableRegister, FromByteArray};
+    /// // OUTX_L_A, OUTX_H_A, OUTY_L_A, OUTY_H_A, OUTZ_L_A, OUTZ_H_A
+    /// #[register(0xA8u8)]
+    /// #[derive(ReadableRegister)]
+    /// struct AccelSample {
+    ///     x: i16,
+    ///     y: i16,
+    ///     z: i16,
+    /// }
+    ///
+    /// impl FromByteArray for AccelSample {
+    ///     type Array = [u8; 6];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+    ///         Ok(Self {
+    ///             x: i16::from_le_bytes([bytes[0], bytes[1]]),
+    ///             y: i16::from_le_bytes([bytes[2], bytes[3]]),
+    ///             z: i16::from_le_bytes([bytes[4], bytes[5]]),
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// async fn read_accel<D: SpiDevice>(device: &mut D) {
+    ///     let sample: AccelSample = spi::r#async::read_registers_burst(device).await.unwrap();
+    /// }
+    /// ```
+    pub async fn read_registers_burst<D, R>(
+        device: &mut D,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: ReadableRegister,
+    {
+        read_register(device).await
+    }
+
+    /// Read a number of samples from a FIFO data register of an SPI device.
+    ///
+    /// FIFO data registers, such as the FIFO output of an IMU or the receive holding register of a
+    /// UART bridge, are read by repeatedly addressing the same register. This function reads
+    /// `count` samples, each the length of the register's [`Array`](FromByteArray::Array), into
+    /// the start of `out`, without deserializing them. With [`FifoMode::Burst`], the register ID
+    /// is sent once and all samples are read in a single transaction. For SPI devices, chip select remains asserted for the whole burst. With
+    /// [`FifoMode::PerSample`], each sample is read in its own transaction, for devices that
+    /// require the register ID before every sample.
+    ///
+    /// Reading a `count` of zero performs no bus transactions.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `out` - The buffer to read the samples into
+    /// * `count` - The number of samples to read
+    /// * `mode` - Whether to read all samples in one transaction or one transaction per sample
+    ///
+    /// # Errors
+    /// * `FifoError::BufferTooSmall` - `out` can not hold `count` samples
+    /// * `FifoError::BusError` - Communication with the device failed, after reading the number of
+    ///   samples it holds
+    ///
+    /// # Returns
+    /// The number of samples read, which is always `count`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi, FifoMode, FromByteArray, ReadableRegister};
+    /// # #[register(0x3Fu8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct FifoData;
+    /// # impl FromByteArray for FifoData {
+    /// #     type Array = [u8; 6];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { Ok(Self) }
+    /// # }
+    /// async fn drain<D: SpiDevice>(device: &mut D, available: usize) {
+    ///     let mut samples = [0; 6 * 32];
+    ///     spi::r#async::read_fifo::<_, FifoData>(device, &mut samples, available.min(32), FifoMode::Burst)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn read_fifo<D, R>(
+        device: &mut D,
+        out: &mut [u8],
+        count: usize,
+        mode: FifoMode,
+    ) -> Result<usize, FifoError<D::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: ReadableRegister,
+    {
+        let required = crate::fifo_len::<<R as FromByteArray>::Array, _>(count, out.len())?;
+        if required == 0 {
+            return Ok(0);
+        }
+        let out = &mut out[..required];
+        let len = <R as FromByteArray>::Array::LEN;
+
+        let reg_id = R::readable_id().to_id_bytes();
+
+        let chunk = match mode {
+            FifoMode::Burst => required,
+            FifoMode::PerSample => len,
+        };
+        for (index, samples) in out.chunks_mut(chunk).enumerate() {
+            let mut scratch = [0; MAX_DUMMY_BYTES];
+            let operations: &mut [Operation<'_, u8>] = match dummy_operation::<R>(&mut scratch) {
+                None => &mut [Operation::Write(reg_id.as_ref()), Operation::Read(samples)],
+                Some(dummy) => &mut [
+                    Operation::Write(reg_id.as_ref()),
+                    dummy,
+                    Operation::Read(samples),
+                ],
+            };
+
+            device
+                .transaction(operations)
+                .await
+                .map_err(|error| FifoError::BusError {
+                    error,
+                    samples: index * chunk / len,
+                })?;
+        }
+
+        trace!(
+            "spi: read fifo: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            out
+        );
+
+        Ok(count)
+    }
+
+    /// Repeatedly read a register from a SPI device until its value satisfies a condition.
+    ///
+    /// The register is read using [`read_register`] up to `max_attempts` times, waiting
+    /// `interval_us` microseconds between each attempt. The first register value for which
+    /// `condition` returns `true` is returned.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `delay` - The delay provider used to wait between attempts
+    /// * `interval_us` - The number of microseconds to wait between attempts
+    /// * `max_attempts` - The maximum number of times the register will be read
+    /// * `condition` - A predicate that returns `true` once the register holds the desired value
+    ///
+    /// # Errors
+    /// * `PollError::BusError` - Communication with the device failed
+    /// * `PollError::DeserializationError` - Failed to convert received bytes into register value
+    /// * `PollError::Timeout` - No register value satisfied `condition` within `max_attempts` reads
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::{delay::DelayNs, spi::SpiDevice};
+    /// # use regiface::{register, spi, ReadableRegister, FromByteArray};
+    /// # #[register(0x27u8)]
+    /// # #[derive(ReadableRegister, Debug)]
+    /// # struct Status { ready: bool }
+    /// # impl FromByteArray for Status {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self { ready: bytes[0] & 0x01 != 0 }) }
+    /// # }
+    /// async fn wait_until_ready<D: SpiDevice, T: DelayNs>(device: &mut D, delay: &mut T) {
+    ///     let status: Status = spi::r#async::poll_register(device, delay, 1_000, 10, |s: &Status| s.ready).await.unwrap();
+    /// }
+    /// ```
+    pub async fn poll_register<D, T, R, F>(
+        device: &mut D,
+        delay: &mut T,
+        interval_us: u32,
+        max_attempts: usize,
+        mut condition: F,
+    ) -> Result<R, PollError<D::Error, R::Error, R>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        T: embedded_hal_async::delay::DelayNs,
+        R: ReadableRegister,
+        F: FnMut(&R) -> bool,
+    {
+        let mut last = None;
+
+        for attempt in 0..max_attempts {
+            if attempt > 0 {
+                delay.delay_us(interval_us).await;
+            }
+
+            let register: R = read_register(device).await?;
+            if condition(&register) {
+                return Ok(register);
+            }
+            last = Some(register);
+        }
+
+        Err(PollError::Timeout { last })
+    }
+
+    /// Reads a register, first waiting `wait_us` microseconds if nonzero, returning the bus and
+    /// delay along with the result for the next sample of a [`poll_stream`]
+    #[cfg(feature = "stream")]
+    #[allow(clippy::type_complexity)]
+    async fn sample<'a, D, T, R>(
+        device: &'a mut D,
+        delay: &'a mut T,
+        wait_us: u32,
+    ) -> (
+        Result<R, ReadRegisterError<D::Error, R::Error>>,
+        (&'a mut D, &'a mut T),
+    )
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        T: embedded_hal_async::delay::DelayNs,
+        R: ReadableRegister,
+    {
+        if wait_us > 0 {
+            delay.delay_us(wait_us).await;
+        }
+        (read_register(device).await, (device, delay))
+    }
+
+    /// Periodically read a register value from an SPI device as a
+    /// [`Stream`](futures_core::Stream).
+    ///
+    /// The register is read as soon as the stream is first polled, then again each time the stream
+    /// is polled after waiting `period_us` microseconds, thus the period is measured from the end
+    /// of one read to the start of the next. Errors are yielded like any other item rather than
+    /// ending the stream, leaving the consumer to decide whether to continue. The stream never ends
+    /// on its own, and stops reading once dropped.
+    ///
+    /// This function is only available when the `stream` feature is enabled.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `delay` - The delay provider used to wait between reads
+    /// * `period_us` - The number of microseconds to wait between reads
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::spi::ErrorKind;
+    /// # use embedded_hal_async::delay::DelayNs;
+    /// # use futures_core::Stream;
+    /// use regiface::{register, spi, FromByteArray, ReadableRegister};
+    /// # #[register(0x05u8)]
+    /// # #[derive(ReadableRegister, Debug)]
+    /// # struct Temperature(u8);
+    /// # impl FromByteArray for Temperature {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # struct NoDelay;
+    /// # impl DelayNs for NoDelay { async fn delay_ns(&mut self, _: u32) {} }
+    /// # struct Sensor(u8);
+    /// # impl embedded_hal_async::spi::ErrorType for Sensor { type Error = ErrorKind; }
+    /// # impl embedded_hal_async::spi::SpiDevice for Sensor {
+    /// #     async fn transaction(&mut self, ops: &mut [embedded_hal_async::spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         self.0 += 1;
+    /// #         if self.0 == 2 { return Err(ErrorKind::Overrun); }
+    /// #         if let [_, embedded_hal_async::spi::Operation::Read(buf)] = ops { buf[0] = 20 + self.0; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let (mut device, mut delay) = (Sensor(0), NoDelay);
+    /// # let fut = core::pin::pin!(async {
+    /// let mut samples = core::pin::pin!(spi::r#async::poll_stream::<_, _, Temperature>(&mut device, &mut delay, 1_000_000));
+    /// # let mut next = async || core::future::poll_fn(|cx| samples.as_mut().poll_next(cx)).await.unwrap();
+    ///
+    /// // A failed read is yielded, and sampling continues
+    /// assert_eq!(next().await.unwrap().0, 21);
+    /// assert!(next().await.is_err());
+    /// assert_eq!(next().await.unwrap().0, 23);
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    /// ```
+    #[cfg(feature = "stream")]
+    pub fn poll_stream<'a, D, T, R>(
+        device: &'a mut D,
+        delay: &'a mut T,
+        period_us: u32,
+    ) -> impl futures_core::Stream<Item = Result<R, ReadRegisterError<D::Error, R::Error>>> + 'a
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        T: embedded_hal_async::delay::DelayNs,
+        R: ReadableRegister + 'a,
+    {
+        crate::stream::Sampler::new(sample(device, delay, 0), move |(device, delay)| {
+            sample(device, delay, period_us)
+        })
+    }
+
+    /// Read a register value from an SPI device, retrying according to a [`Policy`].
+    ///
+    /// The register is read with [`read_register`] up to [`Policy::max_attempts`] times, waiting
+    /// [`Policy::delay_us`] microseconds after each failed attempt. Only errors that are
+    /// [transient](crate::errors::ErrorClass::is_transient) are retried, thus a register value that
+    /// could not be deserialized is returned immediately.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `delay` - The delay provider used to wait between attempts
+    /// * `policy` - The policy determining the number of attempts and the waits between them
+    ///
+    /// # Errors
+    /// Returns the error of the last attempt, along with the number of attempts made, if no attempt
+    /// succeeded.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::{delay::DelayNs, spi::SpiDevice};
+    /// # use regiface::{register, spi, retry::Policy, ReadableRegister, FromByteArray};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister, Debug)]
+    /// # struct ChipId(u8);
+    /// # impl FromByteArray for ChipId {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// async fn identify<D: SpiDevice, T: DelayNs>(device: &mut D, delay: &mut T) -> u8 {
+    ///     let policy = Policy::exponential(4, 500, 2);
+    ///     let id: ChipId = spi::r#async::read_register_retry(device, delay, &policy).await.unwrap();
+    ///     id.0
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn read_register_retry<D, T, R>(
+        device: &mut D,
+        delay: &mut T,
+        policy: &Policy,
+    ) -> Result<R, RetryError<ReadRegisterError<D::Error, R::Error>>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        T: embedded_hal_async::delay::DelayNs,
+        R: ReadableRegister,
+    {
+        retry_policy_async(policy, delay, async || read_register(device).await).await
+    }
+
+    /// Write a register value to an SPI device, retrying according to a [`Policy`].
+    ///
+    /// The register is written with [`write_register`] up to [`Policy::max_attempts`] times,
+    /// waiting [`Policy::delay_us`] microseconds after each failed attempt. Only errors that are
+    /// [transient](crate::errors::ErrorClass::is_transient) are retried, thus a register value that
+    /// could not be serialized is returned immediately. The register value is cloned for each
+    /// attempt.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `register` - The register value to write
+    /// * `delay` - The delay provider used to wait between attempts
+    /// * `policy` - The policy determining the number of attempts and the waits between them
+    ///
+    /// # Errors
+    /// Returns the error of the last attempt, along with the number of attempts made, if no attempt
+    /// succeeded.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::{delay::DelayNs, spi::SpiDevice};
+    /// # use regiface::{register, spi, retry::Policy, WritableRegister, ToByteArray};
+    /// # #[register(0x20u8)]
+    /// # #[derive(WritableRegister, Clone)]
+    /// # struct Control(u8);
+    /// # impl ToByteArray for Control {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.0]) }
+    /// # }
+    /// async fn power_up<D: SpiDevice, T: DelayNs>(device: &mut D, delay: &mut T) {
+    ///     let policy = Policy::fixed(3, 1_000);
+    ///     spi::r#async::write_register_retry(device, Control(0x01), delay, &policy).await.unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn write_register_retry<D, T, R>(
+        device: &mut D,
+        register: R,
+        delay: &mut T,
+        policy: &Policy,
+    ) -> Result<(), RetryError<WriteRegisterError<D::Error, R::Error>>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        T: embedded_hal_async::delay::DelayNs,
+        R: WritableRegister + Clone,
+    {
+        retry_policy_async(policy, delay, async || {
+            write_register(device, register.clone()).await
+        })
+        .await
+    }
+
+    /// Invoke a command on an SPI device, retrying according to a [`Policy`].
+    ///
+    /// The command is invoked with [`invoke_command`] up to [`Policy::max_attempts`] times,
+    /// waiting [`Policy::delay_us`] microseconds after each failed attempt. Only errors that are
+    /// [transient](crate::errors::ErrorClass::is_transient) are retried. The command is cloned for
+    /// each attempt, thus a command that is not idempotent may take effect more than once if an
+    /// attempt fails after the device received it.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `cmd` - The command to invoke
+    /// * `delay` - The delay provider used to wait between attempts
+    /// * `policy` - The policy determining the number of attempts and the waits between them
+    ///
+    /// # Errors
+    /// Returns the error of the last attempt, along with the number of attempts made, if no attempt
+    /// succeeded.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::{delay::DelayNs, spi::SpiDevice};
+    /// # use regiface::{spi, retry::Policy, Command, FromByteArray, NoParameters};
+    /// # #[derive(Clone)]
+    /// # struct ReadSerial;
+    /// # impl Command for ReadSerial {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = u32;
+    /// #     fn id() -> Self::IdType { 0x36 }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// async fn serial<D: SpiDevice, T: DelayNs>(device: &mut D, delay: &mut T) -> u32 {
+    ///     let policy = Policy::exponential(3, 1_000, 4);
+    ///     spi::r#async::invoke_command_retry(device, ReadSerial, delay, &policy).await.unwrap()
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_command_retry<D, T, C>(
+        device: &mut D,
+        cmd: C,
+        delay: &mut T,
+        policy: &Policy,
+    ) -> Result<
+        C::ResponseParameters,
+        RetryError<
+            CommandError<
+                D::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        >,
+    >
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        T: embedded_hal_async::delay::DelayNs,
+        C: Command + Clone,
+    {
+        retry_policy_async(policy, delay, async || {
+            invoke_command(device, cmd.clone()).await
+        })
+        .await
+    }
+
+    /// Read a register value from an SPI device, abandoning the read if it does not complete in
+    /// time.
+    ///
+    /// The register is read with [`read_register`], raced against a delay of `timeout_us`
+    /// microseconds. If the timeout elapses, the operation is abandoned, possibly in the middle of
+    /// a bus transaction, thus the state of the device and bus is unknown. See [`with_timeout`] for
+    /// details.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `delay` - The delay used to measure the timeout
+    /// * `timeout_us` - The maximum number of microseconds to wait for the read to complete
+    ///
+    /// # Errors
+    /// * `TimeoutError::Error` - The read failed with a [`ReadRegisterError`]
+    /// * `TimeoutError::Elapsed` - The read did not complete within `timeout_us` microseconds
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::{delay::DelayNs, spi::SpiDevice};
+    /// # use regiface::{register, spi, ReadableRegister, FromByteArray};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister, Debug)]
+    /// # struct ChipId(u8);
+    /// # impl FromByteArray for ChipId {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// async fn identify<D: SpiDevice, T: DelayNs>(device: &mut D, delay: &mut T) -> u8 {
+    ///     let id: ChipId = spi::r#async::read_register_timeout(device, delay, 10_000).await.unwrap();
+    ///     id.0
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn read_register_timeout<D, T, R>(
+        device: &mut D,
+        delay: &mut T,
+        timeout_us: u32,
+    ) -> Result<R, TimeoutError<ReadRegisterError<D::Error, R::Error>>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        T: embedded_hal_async::delay::DelayNs,
+        R: ReadableRegister,
+    {
+        with_timeout(delay, timeout_us, read_register(device)).await
+    }
+
+    /// Write a register value to an SPI device, abandoning the write if it does not complete in
+    /// time.
+    ///
+    /// The register is written with [`write_register`], raced against a delay of `timeout_us`
+    /// microseconds. If the timeout elapses, the operation is abandoned, possibly in the middle of
+    /// a bus transaction, thus the state of the device and bus is unknown. See [`with_timeout`] for
+    /// details.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `register` - The register value to write
+    /// * `delay` - The delay used to measure the timeout
+    /// * `timeout_us` - The maximum number of microseconds to wait for the write to complete
+    ///
+    /// # Errors
+    /// * `TimeoutError::Error` - The write failed with a [`WriteRegisterError`]
+    /// * `TimeoutError::Elapsed` - The write did not complete within `timeout_us` microseconds
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::{delay::DelayNs, spi::SpiDevice};
+    /// # use regiface::{register, spi, WritableRegister, ToByteArray};
+    /// # #[register(0x20u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct Control(u8);
+    /// # impl ToByteArray for Control {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.0]) }
+    /// # }
+    /// async fn power_up<D: SpiDevice, T: DelayNs>(device: &mut D, delay: &mut T) {
+    ///     spi::r#async::write_register_timeout(device, Control(0x01), delay, 10_000).await.unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn write_register_timeout<D, T, R>(
+        device: &mut D,
+        register: R,
+        delay: &mut T,
+        timeout_us: u32,
+    ) -> Result<(), TimeoutError<WriteRegisterError<D::Error, R::Error>>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        T: embedded_hal_async::delay::DelayNs,
+        R: WritableRegister,
+    {
+        with_timeout(delay, timeout_us, write_register(device, register)).await
+    }
+
+    /// Invoke a command on an SPI device, abandoning the command if it does not complete in
+    /// time.
+    ///
+    /// The command is invoked with [`invoke_command`], raced against a delay of `timeout_us`
+    /// microseconds. If the timeout elapses, the operation is abandoned, possibly in the middle of
+    /// a bus transaction, thus the state of the device and bus is unknown. See [`with_timeout`] for
+    /// details.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `cmd` - The command to invoke
+    /// * `delay` - The delay used to measure the timeout
+    /// * `timeout_us` - The maximum number of microseconds to wait for the command to complete
+    ///
+    /// # Errors
+    /// * `TimeoutError::Error` - The command failed with a [`CommandError`]
+    /// * `TimeoutError::Elapsed` - The command did not complete within `timeout_us` microseconds
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal_async::{delay::DelayNs, spi::SpiDevice};
+    /// use regiface::{errors::TimeoutError, spi, Command, NoParameters};
+    /// # struct ReadSerial;
+    /// # impl Command for ReadSerial {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = u8;
+    /// #     fn id() -> Self::IdType { 0x36 }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// # struct NoDelay;
+    /// # impl DelayNs for NoDelay { async fn delay_ns(&mut self, _: u32) {} }
+    /// # struct Wedged;
+    /// # impl embedded_hal_async::spi::ErrorType for Wedged { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Wedged {
+    /// #     async fn transaction(&mut self, _: &mut [embedded_hal_async::spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         core::future::pending().await
+    /// #     }
+    /// # }
+    /// # let fut = core::pin::pin!(async {
+    /// // The device never completes the transaction
+    /// let mut device = Wedged;
+    /// let result = spi::r#async::invoke_command_timeout(&mut device, ReadSerial, &mut NoDelay, 1_000).await;
+    /// assert!(matches!(result, Err(TimeoutError::Elapsed)));
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_command_timeout<D, T, C>(
+        device: &mut D,
+        cmd: C,
+        delay: &mut T,
+        timeout_us: u32,
+    ) -> Result<
+        C::ResponseParameters,
+        TimeoutError<
+            CommandError<
+                D::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        >,
+    >
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        T: embedded_hal_async::delay::DelayNs,
+        C: Command,
+    {
+        with_timeout(delay, timeout_us, invoke_command(device, cmd)).await
+    }
+
+    /// Read a register value from an SPI device once the device signals an interrupt.
+    ///
+    /// This function awaits the given edge or level on `pin`, such as the rising edge of a data
+    /// ready pin, then reads the register as [`read_register`] does.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `pin` - The interrupt pin of the device
+    /// * `edge` - The edge or level of `pin` to await before reading the register
+    ///
+    /// # Errors
+    /// * `InterruptReadError::PinError` - Waiting on the interrupt pin failed
+    /// * `InterruptReadError::BusError` - Communication with the device failed
+    /// * `InterruptReadError::DeserializationError` - Failed to convert received bytes into register value
+    ///
+    /// # Example
+    /// ```
+    /// # use core::{cell::RefCell, convert::Infallible};
+    /// # use embedded_hal_async::{digital::{self, Wait}, spi};
+    /// use regiface::{interrupt::Edge, register, spi as regiface_spi, FromByteArray, ReadableRegister};
+    /// # #[register(0x28u8)]
+    /// # #[derive(ReadableRegister, Debug, PartialEq)]
+    /// # struct Sample(u8);
+    /// # impl FromByteArray for Sample {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # struct Pin<'a>(&'a RefCell<Vec<&'static str>>);
+    /// # impl embedded_hal::digital::ErrorType for Pin<'_> { type Error = Infallible; }
+    /// # impl Wait for Pin<'_> {
+    /// #     async fn wait_for_high(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// #     async fn wait_for_low(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// #     async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+    /// #         self.0.borrow_mut().push("rising edge");
+    /// #         Ok(())
+    /// #     }
+    /// #     async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// #     async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// # }
+    /// # struct Bus<'a>(&'a RefCell<Vec<&'static str>>);
+    /// # impl spi::ErrorType for Bus<'_> { type Error = Infallible; }
+    /// # impl spi::SpiDevice for Bus<'_> {
+    /// #     async fn transaction(&mut self, ops: &mut [spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         self.0.borrow_mut().push("read");
+    /// #         if let [_, spi::Operation::Read(buf)] = ops { buf[0] = self.0.borrow().len() as u8; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// let log = RefCell::new(Vec::new());
+    /// let (mut pin, mut device) = (Pin(&log), Bus(&log));
+    /// # let fut = core::pin::pin!(async {
+    /// let sample: Sample = regiface_spi::r#async::read_register_on_interrupt(&mut device, &mut pin, Edge::Rising)
+    ///     .await
+    ///     .unwrap();
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    ///
+    /// // The register is only read once the interrupt is signaled
+    /// assert_eq!(*log.borrow(), ["rising edge", "read"]);
+    /// ```
+    pub async fn read_register_on_interrupt<D, P, R>(
+        device: &mut D,
+        pin: &mut P,
+        edge: Edge,
+    ) -> Result<R, InterruptReadError<P::Error, D::Error, R::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        P: embedded_hal_async::digital::Wait,
+        R: ReadableRegister,
+    {
+        wait_for(pin, edge)
+            .await
+            .map_err(InterruptReadError::PinError)?;
+
+        Ok(read_register(device).await?)
+    }
+
+    /// Continuously read a register value from an SPI device each time the device signals an
+    /// interrupt.
+    ///
+    /// This function repeatedly awaits the given edge or level on `pin`, reads the register as
+    /// [`read_register`] does, and passes the value to `on_value`. Streaming continues until
+    /// `on_value` returns [`ControlFlow::Break`], whose value is returned, or an error occurs.
+    ///
+    /// When awaiting a level rather than an edge, the register read must clear the interrupt, as
+    /// the wait otherwise completes immediately and the register is read continuously.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `pin` - The interrupt pin of the device
+    /// * `edge` - The edge or level of `pin` to await before each register read
+    /// * `on_value` - Called with each register value read, returning whether to continue
+    ///
+    /// # Errors
+    /// * `InterruptReadError::PinError` - Waiting on the interrupt pin failed
+    /// * `InterruptReadError::BusError` - Communication with the device failed
+    /// * `InterruptReadError::DeserializationError` - Failed to convert received bytes into register value
+    ///
+    /// # Example
+    /// ```
+    /// # use core::{cell::RefCell, convert::Infallible, ops::ControlFlow};
+    /// # use embedded_hal_async::{digital::{self, Wait}, spi};
+    /// use regiface::{interrupt::Edge, register, spi as regiface_spi, FromByteArray, ReadableRegister};
+    /// # #[register(0x28u8)]
+    /// # #[derive(ReadableRegister, Debug, PartialEq)]
+    /// # struct Sample(u8);
+    /// # impl FromByteArray for Sample {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # struct Pin<'a>(&'a RefCell<Vec<&'static str>>);
+    /// # impl embedded_hal::digital::ErrorType for Pin<'_> { type Error = Infallible; }
+    /// # impl Wait for Pin<'_> {
+    /// #     async fn wait_for_high(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// #     async fn wait_for_low(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// #     async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+    /// #         self.0.borrow_mut().push("rising edge");
+    /// #         Ok(())
+    /// #     }
+    /// #     async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// #     async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> { unimplemented!() }
+    /// # }
+    /// # struct Bus<'a>(&'a RefCell<Vec<&'static str>>);
+    /// # impl spi::ErrorType for Bus<'_> { type Error = Infallible; }
+    /// # impl spi::SpiDevice for Bus<'_> {
+    /// #     async fn transaction(&mut self, ops: &mut [spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         self.0.borrow_mut().push("read");
+    /// #         if let [_, spi::Operation::Read(buf)] = ops { buf[0] = self.0.borrow().len() as u8; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// let log = RefCell::new(Vec::new());
+    /// let (mut pin, mut device) = (Pin(&log), Bus(&log));
+    /// # let fut = core::pin::pin!(async {
+    /// let mut samples = Vec::new();
+    /// let count = regiface_spi::r#async::stream_register_on_interrupt(&mut device, &mut pin, Edge::Rising, |sample: Sample| {
+    ///     samples.push(sample.0);
+    ///     if samples.len() == 3 {
+    ///         ControlFlow::Break(samples.len())
+    ///     } else {
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// })
+    /// .await
+    /// .unwrap();
+    /// assert_eq!(count, 3);
+    /// # });
+    /// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+    /// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+    ///
+    /// assert_eq!(
+    ///     *log.borrow(),
+    ///     ["rising edge", "read", "rising edge", "read", "rising edge", "read"]
+    /// );
+    /// ```
+    pub async fn stream_register_on_interrupt<D, P, R, T, F>(
+        device: &mut D,
+        pin: &mut P,
+        edge: Edge,
+        mut on_value: F,
+    ) -> Result<T, InterruptReadError<P::Error, D::Error, R::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        P: embedded_hal_async::digital::Wait,
+        R: ReadableRegister,
+        F: FnMut(R) -> ControlFlow<T>,
+    {
+        loop {
+            let register: R = read_register_on_interrupt(device, pin, edge).await?;
+            if let ControlFlow::Break(value) = on_value(register) {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// Verify the identity of a SPI device by reading a register and comparing it to an expected value.
+    ///
+    /// The register `R` is read as with [`read_register`], and compared to `expected`, returning
+    /// the value read if they are equal. This is equivalent to [`verify_whoami`], for registers
+    /// whose expected value is only known at runtime, such as drivers supporting several
+    /// revisions of a device.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `expected` - The value the register holds on the expected device
+    ///
+    /// # Errors
+    /// * `WhoAmIError::BusError` - Communication with the device failed
+    /// * `WhoAmIError::DeserializationError` - Failed to convert received bytes into register value
+    /// * `WhoAmIError::Mismatch` - The register did not hold the expected value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi, FromByteArray, ReadableRegister};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister, Debug, PartialEq)]
+    /// # struct ChipId(u8);
+    /// # impl FromByteArray for ChipId {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// async fn probe_revision<D: SpiDevice>(device: &mut D) -> bool {
+    ///     spi::r#async::verify_device_id(device, ChipId(0x6A)).await.is_ok()
+    /// }
+    /// ```
+    pub async fn verify_device_id<D, R>(
+        device: &mut D,
+        expected: R,
+    ) -> Result<R, WhoAmIError<D::Error, R::Error, R>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: ReadableRegister + PartialEq,
+    {
+        let found: R = read_register(device).await?;
+        if found != expected {
+            return Err(WhoAmIError::Mismatch { expected, found });
+        }
+        Ok(found)
+    }
+
+    /// Verify the identity of a SPI device by reading its [`WhoAmI`] register.
+    ///
+    /// The register `R` is read as with [`read_register`], and compared to [`WhoAmI::expected`].
+    /// This is typically performed once during driver initialization, to detect a missing device
+    /// or a different device than the one the driver was written for.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    ///
+    /// # Errors
+    /// * `WhoAmIError::BusError` - Communication with the device failed
+    /// * `WhoAmIError::DeserializationError` - Failed to convert received bytes into register value
+    /// * `WhoAmIError::Mismatch` - The register did not hold the expected value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi, FromByteArray, ReadableRegister, WhoAmI};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister, Debug, PartialEq)]
+    /// # struct ChipId(u8);
+    /// # impl FromByteArray for ChipId {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # impl WhoAmI for ChipId {
+    /// #     fn expected() -> Self { Self(0x6A) }
+    /// # }
+    /// async fn probe<D: SpiDevice>(device: &mut D) -> bool {
+    ///     spi::r#async::verify_whoami::<_, ChipId>(device).await.is_ok()
+    /// }
+    /// ```
+    pub async fn verify_whoami<D, R>(
+        device: &mut D,
+    ) -> Result<R, WhoAmIError<D::Error, R::Error, R>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: WhoAmI,
+    {
+        verify_device_id(device, R::expected()).await
+    }
+
+    /// Write a register value to a SPI device.
+    ///
+    /// This function performs a SPI transaction, sending both the register ID
+    /// and the serialized register value. The operation is atomic, using the device's
+    /// transaction capability to ensure both writes occur without interruption.
+    ///
+    /// The register value is serialized with [`WritableRegister::to_register_bytes`], clearing any
+    /// reserved bits and setting any required bits before transmission.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `register` - The register value to write
+    ///
+    /// # Errors
+    /// * `WriteRegisterError::BusError` - Communication with the device failed
+    /// * `WriteRegisterError::SerializationError` - Failed to convert register value to bytes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi, WritableRegister, ToByteArray};
+    /// # #[register(1u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct ConfigRegister{};
+    /// # impl ToByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([0]) }
+    /// # }
+    /// async fn configure<D: SpiDevice>(device: &mut D) {
+    ///     spi::r#async::write_register(device, ConfigRegister{/* ... */}).await.unwrap();
+    /// }
+    /// ```
+    pub async fn write_register<D, R>(
+        device: &mut D,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: WritableRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = R::writeable_id().to_id_bytes();
+
+        device
+            .transaction(&mut [
+                embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
+                embedded_hal_async::spi::Operation::Write(buf.as_ref()),
+            ])
+            .await
+            .map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "spi: write register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        Ok(())
+    }
+
+    /// Write a number of samples to a FIFO data register of a SPI device.
+    ///
+    /// FIFO data registers, such as the input FIFO of a DAC or the data register of a bootloader,
+    /// are written by repeatedly addressing the same register. This function writes the first
+    /// `count` samples of `data`, each the length of the register's [`Array`](ToByteArray::Array),
+    /// without serializing them. The samples are split across as few transactions as possible,
+    /// each consisting of the register ID followed by as many samples as fit within
+    /// `max_transaction_len` bytes.
+    ///
+    /// Writing a `count` of zero performs no bus transactions.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `data` - The buffer holding the samples to write
+    /// * `count` - The number of samples to write
+    /// * `max_transaction_len` - The maximum number of bytes written in a single transaction,
+    ///   including the register ID
+    ///
+    /// # Errors
+    /// * `FifoError::BufferTooSmall` - `data` does not hold `count` samples
+    /// * `FifoError::TransactionTooSmall` - `max_transaction_len` can not hold the register ID and
+    ///   a single sample
+    /// * `FifoError::BusError` - Communication with the device failed, after committing the number
+    ///   of samples it holds, from which the write may be resumed
+    ///
+    /// # Returns
+    /// The number of samples written, which is always `count`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi, ToByteArray, WritableRegister};
+    /// # #[register(0x0Du8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct Waveform;
+    /// # impl ToByteArray for Waveform {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([0; 2]) }
+    /// # }
+    /// async fn load<D: SpiDevice>(device: &mut D, samples: &[u8]) {
+    ///     spi::r#async::write_fifo::<_, Waveform>(device, samples, samples.len() / 2, 32)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn write_fifo<D, R>(
+        device: &mut D,
+        data: &[u8],
+        count: usize,
+        max_transaction_len: usize,
+    ) -> Result<usize, FifoError<D::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: WritableRegister,
+    {
+        let required = crate::fifo_len::<<R as ToByteArray>::Array, _>(count, data.len())?;
+        let len = <R as ToByteArray>::Array::LEN;
+
+        let reg_id = R::writeable_id().to_id_bytes();
+        let per_transaction =
+            crate::fifo_samples_per_transaction(reg_id.as_ref().len(), len, max_transaction_len)?;
+
+        if required == 0 {
+            return Ok(0);
+        }
+        let data = &data[..required];
+
+        for (index, samples) in data.chunks(per_transaction * len).enumerate() {
+            device
+                .transaction(&mut [
+                    embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
+                    embedded_hal_async::spi::Operation::Write(samples),
+                ])
+                .await
+                .map_err(|error| FifoError::BusError {
+                    error,
+                    samples: index * per_transaction,
+                })?;
+        }
+
+        trace!(
+            "spi: write fifo: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            data
+        );
+
+        Ok(count)
+    }
+
+    /// Write a register value to a SPI device using the given addressing convention.
+    ///
+    /// This function behaves exactly like [`write_register`], except that the
+    /// [`SpiAddressing::write`] modifier is applied to the first byte of the register ID before it
+    /// is transmitted.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `addressing` - The addressing convention used by the device
+    /// * `register` - The register value to write
+    ///
+    /// # Errors
+    /// * `WriteRegisterError::BusError` - Communication with the device failed
+    /// * `WriteRegisterError::SerializationError` - Failed to convert register value to bytes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi::{self, SpiAddressing}, WritableRegister, ToByteArray};
+    /// # #[register(0x20u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct Ctrl1(u8);
+    /// # impl ToByteArray for Ctrl1 {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.0]) }
+    /// # }
+    /// const ADDRESSING: SpiAddressing = SpiAddressing::read_flag(0x80);
+    ///
+    /// async fn enable<D: SpiDevice>(device: &mut D) {
+    ///     spi::r#async::write_register_with(device, ADDRESSING, Ctrl1(0x67)).await.unwrap();
+    /// }
+    /// ```
+    pub async fn write_register_with<D, R>(
+        device: &mut D,
+        addressing: SpiAddressing,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: WritableRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = addressing.write.apply(R::writeable_id());
+
+        device
+            .transaction(&mut [
+                embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
+                embedded_hal_async::spi::Operation::Write(buf.as_ref()),
+            ])
+            .await
+            .map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "spi: write register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        Ok(())
+    }
+
+    /// Write a register value to a SPI device using 16-bit words.
+    ///
+    /// This function serializes the register value and transmits a single frame containing the
+    /// register ID, register value, and write flag placed according to `layout`.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `layout` - The bit layout of the frames used by the device
+    /// * `register` - The register value to write
+    ///
+    /// # Errors
+    /// * `WriteRegisterError::BusError` - Communication with the device failed
+    /// * `WriteRegisterError::SerializationError` - Failed to convert register value to bytes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi::{self, WordLayout}, WritableRegister, ToByteArray};
+    /// # #[register(0x3u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct DacOutput(u16);
+    /// # impl ToByteArray for DacOutput {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok(self.0.to_be_bytes()) }
+    /// # }
+    /// const LAYOUT: WordLayout = WordLayout::new(12, 4, 0, 12);
+    ///
+    /// async fn set_output<D: SpiDevice<u16>>(device: &mut D) {
+    ///     // Transmits 0x3800 as the write frame
+    ///     spi::r#async::write_register_word(device, LAYOUT, DacOutput(0x800)).await.unwrap();
+    /// }
+    /// ```
+    pub async fn write_register_word<D, R>(
+        device: &mut D,
+        layout: WordLayout,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice<u16>,
+        R: WritableRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = R::writeable_id().to_id_bytes();
+        let frame = [layout.write_frame(reg_id.as_ref(), buf.as_ref())];
+
+        device
+            .write(&frame)
+            .await
+            .map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "spi: write register: id={:02X?} frame={:04X?}",
+            reg_id.as_ref(),
+            frame[0]
+        );
+
+        Ok(())
+    }
+
+    /// Write an instance of an indexed register value to a SPI device.
+    ///
+    /// This function behaves exactly like [`write_register`], except that the register ID is
+    /// computed from the provided index using [`IndexedRegister::writeable_indexed_id`].
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `index` - The index of the register instance to write
+    /// * `register` - The register value to write
+    ///
+    /// # Errors
+    /// * `WriteRegisterError::BusError` - Communication with the device failed
+    /// * `WriteRegisterError::SerializationError` - Failed to convert register value to bytes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi, IndexedRegister, WritableRegister, ToByteArray};
+    /// # #[register(0x06u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct LedOnOff { on: u16, off: u16 }
+    /// # impl IndexedRegister for LedOnOff {
+    /// #     type Index = u8;
+    /// #     fn indexed_id(channel: Self::Index) -> Self::IdType { Self::ID + 4 * channel }
+    /// # }
+    /// # impl ToByteArray for LedOnOff {
+    /// #     type Array = [u8; 4];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    /// #         let [on_l, on_h] = self.on.to_le_bytes();
+    /// #         let [off_l, off_h] = self.off.to_le_bytes();
+    /// #         Ok([on_l, on_h, off_l, off_h])
+    /// #     }
+    /// # }
+    /// async fn set_all_channels<D: SpiDevice>(device: &mut D, off: u16) {
+    ///     for channel in 0..16 {
+    ///         spi::r#async::write_register_at(device, channel, LedOnOff { on: 0, off }).await.unwrap();
+    ///     }
+    /// }
+    /// ```
+    pub async fn write_register_at<D, R>(
+        device: &mut D,
+        index: R::Index,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: WritableRegister + IndexedRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = R::writeable_indexed_id(index).to_id_bytes();
+
+        device
+            .transaction(&mut [
+                embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
+                embedded_hal_async::spi::Operation::Write(buf.as_ref()),
+            ])
+            .await
+            .map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "spi: write register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        Ok(())
+    }
+
+    /// Write a contiguous block of registers to a SPI device in a single transaction.
+    ///
+    /// Many devices automatically increment their internal register pointer for each byte written
+    /// while chip select remains asserted, allowing a run of consecutive registers to be written
+    /// with a single transaction of `[start_id, b0, b1, ...]`. `R` is a register type whose ID is
+    /// the address of the first register in the block and whose [`Array`](ToByteArray::Array)
+    /// spans the entire block.
+    ///
+    /// This behaves identically to [`write_register`], but the dedicated name makes the reliance on
+    /// the device's auto-increment behavior explicit in driver code. Devices that do not
+    /// auto-increment their register pointer must not be written with this function.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `register` - The register block value to write
+    ///
+    /// # Errors
+    /// * `WriteRegisterError::BusError` - Communication with the device failed
+    /// * `WriteRegisterError::SerializationError` - Failed to convert register value to bytes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi, WritableRegister, ToByteArray};
+    /// // CTRL1_XL through CTRL4_C
+    /// #[register(0x10u8)]
+    /// #[derive(WritableRegister)]
+    /// struct ControlBlock {
+    ///     ctrl: [u8; 4],
+    /// }
+    ///
+    /// impl ToByteArray for ControlBlock {
+    ///     type Array = [u8; 4];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    ///         Ok(self.ctrl)
+    ///     }
+    /// }
+    ///
+    /// async fn configure<D: SpiDevice>(device: &mut D) {
+    ///     let block = ControlBlock { ctrl: [0x60, 0x00, 0x00, 0x04] };
+    ///     spi::r#async::write_registers_burst(device, block).await.unwrap();
+    /// }
+    /// ```
+    pub async fn write_registers_burst<D, R>(
+        device: &mut D,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: WritableRegister,
+    {
+        write_register(device, register).await
+    }
+
+    /// Write a block of bytes to consecutive registers of a SPI device in a single transaction.
+    ///
+    /// This function performs a single write SPI transaction, sending the starting register ID once
+    /// followed by the entire `payload`. This is useful for loading tables of consecutive
+    /// registers, such as filter coefficients, where defining a register type for the whole block
+    /// is impractical. See [`write_registers_burst`] for a typed alternative.
+    ///
+    /// Some devices only auto-increment their register pointer when a flag bit is set in the
+    /// register address. If `auto_increment` is `Some(mask)`, the mask is OR'd into the first byte
+    /// of the serialized starting register ID. Devices that do not auto-increment their register
+    /// pointer must not be written with this function.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `start_id` - The ID of the first register to be written
+    /// * `payload` - The bytes to write, starting at `start_id`
+    /// * `auto_increment` - An optional flag to set in the first byte of `start_id`
+    ///
+    /// # Errors
+    /// * `D::Error` - Communication with the device failed
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::spi;
+    /// async fn load_coefficients<D: SpiDevice>(device: &mut D, coefficients: &[u8]) {
+    ///     spi::r#async::write_registers_burst_raw(device, 0x30u8, coefficients, Some(0x40)).await.unwrap();
+    /// }
+    /// ```
+    pub async fn write_registers_burst_raw<D, I>(
+        device: &mut D,
+        start_id: I,
+        payload: &[u8],
+        auto_increment: Option<u8>,
+    ) -> Result<(), D::Error>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        I: id::Id,
+    {
+        let reg_id = id::to_bytes_with_flag(start_id, auto_increment);
+
+        device
+            .transaction(&mut [
+                embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
+                embedded_hal_async::spi::Operation::Write(payload),
+            ])
+            .await?;
+
+        trace!(
+            "spi: write registers burst: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            payload
+        );
+
+        Ok(())
+    }
+
+    /// Write a register value to a SPI device and verify it by reading the register back.
+    ///
+    /// This function performs a SPI transaction identical to [`write_register`], followed by a
+    /// second SPI transaction reading the register back. The bytes read back are compared against
+    /// the serialized bytes that were written, which avoids requiring the register type to
+    /// implement [`PartialEq`]. This is useful for registers that may silently ignore writes, such
+    /// as write-protected, EEPROM-backed, or OTP configuration registers.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `register` - The register value to write
+    ///
+    /// # Errors
+    /// * `WriteVerifyError::BusError` - Communication with the device failed
+    /// * `WriteVerifyError::SerializationError` - Failed to convert register value to bytes
+    /// * `WriteVerifyError::VerificationFailed` - The bytes read back differ from the bytes written
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi, ReadableRegister, WritableRegister, FromByteArray, ToByteArray};
+    /// #[register(42u8)]
+    /// #[derive(ReadableRegister, WritableRegister)]
+    /// # struct ConfigRegister{};
+    /// # impl FromByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { Ok(Self{}) }
+    /// # }
+    /// # impl ToByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([0]) }
+    /// # }
+    /// async fn configure<D: SpiDevice>(device: &mut D) {
+    ///     spi::r#async::write_register_verify(device, ConfigRegister{ /* ... */}).await.unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn write_register_verify<D, R>(
+        device: &mut D,
+        register: R,
+    ) -> Result<(), WriteVerifyError<D::Error, <R as ToByteArray>::Error, <R as ToByteArray>::Array>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: WritableRegister + ReadableRegister,
+    {
+        let written = register
+            .to_register_bytes()
+            .map_err(WriteVerifyError::SerializationError)?;
+        let mut read_back = <R as ToByteArray>::Array::new();
+
+        let write_id = R::writeable_id().to_id_bytes();
+        let read_id = R::readable_id().to_id_bytes();
+
+        device
+            .transaction(&mut [
+                embedded_hal_async::spi::Operation::Write(write_id.as_ref()),
+                embedded_hal_async::spi::Operation::Write(written.as_ref()),
+            ])
+            .await
+            .map_err(WriteVerifyError::BusError)?;
+
+        let mut scratch = [0; MAX_DUMMY_BYTES];
+        let operations: &mut [embedded_hal_async::spi::Operation<'_, u8>] =
+            match dummy_operation::<R>(&mut scratch) {
+                None => &mut [
+                    embedded_hal_async::spi::Operation::Write(read_id.as_ref()),
+                    embedded_hal_async::spi::Operation::Read(read_back.as_mut()),
+                ],
+                Some(dummy) => &mut [
+                    embedded_hal_async::spi::Operation::Write(read_id.as_ref()),
+                    dummy,
+                    embedded_hal_async::spi::Operation::Read(read_back.as_mut()),
+                ],
+            };
+
+        device
+            .transaction(operations)
+            .await
+            .map_err(WriteVerifyError::BusError)?;
+
+        trace!(
+            "spi: write register verify: id={:02X?} bytes={:02X?} read_back={:02X?}",
+            write_id.as_ref(),
+            written.as_ref(),
+            read_back.as_ref()
+        );
+
+        if written.as_ref() == read_back.as_ref() {
+            Ok(())
+        } else {
+            Err(WriteVerifyError::VerificationFailed { written, read_back })
+        }
+    }
+
+    /// Write a register value to a SPI device and verify it by reading the register back.
+    ///
+    /// This function writes the register using [`write_register`], then reads it back using
+    /// [`read_register`] and compares the deserialized value against the value that was written
+    /// using [`PartialEq`]. Unlike [`write_register_verify`], the comparison is performed on
+    /// register values rather than raw bytes, allowing a [`PartialEq`] implementation to ignore
+    /// volatile or reserved bits.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `register` - The register value to write
+    ///
+    /// # Errors
+    /// * `VerifyError::BusError` - Communication with the device failed
+    /// * `VerifyError::SerializationError` - Failed to convert register value to bytes
+    /// * `VerifyError::DeserializationError` - Failed to convert received bytes into register value
+    /// * `VerifyError::Mismatch` - The register value read back differs from the value written
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{register, spi, ReadableRegister, WritableRegister, FromByteArray, ToByteArray};
+    /// #[register(42u8)]
+    /// #[derive(ReadableRegister, WritableRegister, Clone, PartialEq, Debug)]
+    /// # struct ConfigRegister { value: u8 }
+    /// # impl FromByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self { value: bytes[0] }) }
+    /// # }
+    /// # impl ToByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.value]) }
+    /// # }
+    /// async fn configure<D: SpiDevice>(device: &mut D) {
+    ///     spi::r#async::write_register_verified(device, ConfigRegister { value: 0x0F }).await.unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn write_register_verified<D, R>(
+        device: &mut D,
+        register: R,
+    ) -> Result<(), VerifyError<D::Error, <R as ToByteArray>::Error, <R as FromByteArray>::Error, R>>
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        R: WritableRegister + ReadableRegister + Clone + PartialEq,
+    {
+        write_register(device, register.clone()).await?;
+        let read: R = read_register(device).await?;
+
+        if read == register {
+            Ok(())
+        } else {
+            Err(VerifyError::Mismatch {
+                wrote: register,
+                read,
+            })
+        }
+    }
+
+    /// Invoke a command on a SPI device and receive its response.
+    ///
+    /// This function performs a complete command transaction:
+    /// 1. Sends the command ID
+    /// 2. Sends the serialized command parameters
+    /// 3. Reads the status byte, if [`Command::STATUS_BYTE`] is set, followed by the command response
+    ///
+    /// Command parameters or responses with a length of zero, such as [`NoParameters`](crate::NoParameters),
+    /// are omitted from the transaction.
+    ///
+    /// The entire operation is atomic, using the device's transaction capability to
+    /// ensure all steps occur without interruption.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `cmd` - The command to invoke
+    ///
+    /// # Errors
+    /// * `CommandError::BusError` - Communication with the device failed
+    /// * `CommandError::SerializationError` - Failed to convert command parameters to bytes
+    /// * `CommandError::DeserializationError` - Failed to convert received bytes into response parameters
+    /// * `CommandError::ValidationError` - The response was rejected by [`Command::validate_response`]
+    /// * `CommandError::DeviceError` - The device responded with a nonzero status byte
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{NoParameters, spi, Command, FromByteArray, ToByteArray};
+    /// # struct SelfTestCommand;
+    /// # struct SelfTestResponse;
+    /// # impl Command for SelfTestCommand {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = SelfTestResponse;
+    /// #     fn id() -> Self::IdType { 0xF0 }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { todo!() }
+    /// # }
+    /// # impl FromByteArray for SelfTestResponse {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { Ok(Self) }
+    /// # }
+    /// async fn perform_self_test<D: SpiDevice>(device: &mut D) {
+    ///     let result: SelfTestResponse = spi::r#async::invoke_command(device, SelfTestCommand).await.unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_command<D, C>(
+        device: &mut D,
+        cmd: C,
+    ) -> Result<
+        C::ResponseParameters,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        C: Command,
+    {
+        check_response::<C>();
+
+        let mut cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+
+        let mut status = [0; 1];
+        let status_buf: &mut [u8] = if C::STATUS_BYTE { &mut status } else { &mut [] };
+
+        let mut reg_id = C::id().to_id_bytes();
+        let (id, parameters) = merge_id::<C>(reg_id.as_mut(), cmd_buf.as_mut());
+        let (mut operations, len) = command_operations(
+            C::preamble(),
+            id,
+            C::sub_id(),
+            parameters,
+            C::postamble(),
+            status_buf,
+            resp_buf.as_mut(),
+        );
+
+        device
+            .transaction(&mut operations[..len])
+            .await
+            .map_err(CommandError::BusError)?;
+
+        trace!(
+            "spi: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            reg_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
+        if status[0] != 0 {
+            return Err(CommandError::DeviceError(status[0]));
+        }
+
+        C::validate_response(resp_buf.as_ref()).map_err(CommandError::ValidationError)?;
+        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
+    }
+
+    /// Invoke a command on a SPI device, receiving its response within the same full-duplex frame.
+    ///
+    /// Rather than writing the command and then reading the response, this function performs a
+    /// single [`Transfer`](embedded_hal_async::spi::Operation::Transfer) that clocks out the command
+    /// ID, any [`Command::sub_id`], and the serialized command parameters, while simultaneously
+    /// capturing the received bytes. The frame is padded with zeros if the status byte and response
+    /// are longer than the command. The status byte, if [`Command::STATUS_BYTE`] is set, and the
+    /// response are taken from the tail of the received bytes, thus a response of the same length as
+    /// the command parameters is received while the parameters are clocked out. This saves a frame
+    /// on devices that respond within the command frame, as many full-duplex devices do.
+    ///
+    /// Any [`Command::preamble`] and [`Command::postamble`] are written before and after the frame,
+    /// within the same transaction. The lengths of the command ID and command parameters, and of
+    /// the status byte and response, must each not exceed [`MAX_TRANSFER_BYTES`], which is enforced
+    /// at compile time.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `cmd` - The command to invoke
+    ///
+    /// # Errors
+    /// * `CommandError::BusError` - Communication with the device failed
+    /// * `CommandError::SerializationError` - Failed to convert command parameters to bytes
+    /// * `CommandError::DeserializationError` - Failed to convert received bytes into response parameters
+    /// * `CommandError::ValidationError` - The response was rejected by [`Command::validate_response`]
+    /// * `CommandError::DeviceError` - The device responded with a nonzero status byte
+    ///
+    /// # Panics
+    /// Panics if the combined length of the command ID, sub-ID, and command parameters exceeds
+    /// [`MAX_TRANSFER_BYTES`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{spi, Command};
+    /// # struct ReadPosition;
+    /// # impl Command for ReadPosition {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = u16;
+    /// #     type ResponseParameters = u16;
+    /// #     fn id() -> Self::IdType { 0x3F }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { 0 }
+    /// # }
+    /// async fn position<D: SpiDevice>(device: &mut D) -> u16 {
+    ///     spi::r#async::invoke_command_duplex(device, ReadPosition).await.unwrap()
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_command_duplex<D, C>(
+        device: &mut D,
+        cmd: C,
+    ) -> Result<
+        C::ResponseParameters,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        C: Command,
+    {
+        check_response::<C>();
+        const {
+            assert!(
+                <C::IdType as id::Id>::Array::LEN
+                    + <C::CommandParameters as ToByteArray>::Array::LEN
+                    <= MAX_TRANSFER_BYTES,
+                "the command ID and parameters exceed MAX_TRANSFER_BYTES"
+            );
+            assert!(
+                C::STATUS_BYTE as usize + <C::ResponseParameters as FromByteArray>::Array::LEN
+                    <= MAX_TRANSFER_BYTES,
+                "the status byte and response exceed MAX_TRANSFER_BYTES"
+            );
+        };
+
+        let mut cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+
+        let mut reg_id = C::id().to_id_bytes();
+        let (id, parameters) = merge_id::<C>(reg_id.as_mut(), cmd_buf.as_mut());
+
+        let sub_id = C::sub_id();
+        let write_len = id.len() + sub_id.len() + parameters.len();
+        assert!(
+            write_len <= MAX_TRANSFER_BYTES,
+            "the command ID, sub-ID, and parameters exceed MAX_TRANSFER_BYTES"
+        );
+        let read_len =
+            usize::from(C::STATUS_BYTE) + <C::ResponseParameters as FromByteArray>::Array::LEN;
+        let len = write_len.max(read_len);
+
+        let mut tx = [0; MAX_TRANSFER_BYTES];
+        let mut rx = [0; MAX_TRANSFER_BYTES];
+        tx[..id.len()].copy_from_slice(id);
+        tx[id.len()..id.len() + sub_id.len()].copy_from_slice(sub_id);
+        tx[id.len() + sub_id.len()..write_len].copy_from_slice(parameters);
+
+        // The preamble and postamble are skipped when empty
+        let mut operations = [
+            Operation::Write(C::preamble()),
+            Operation::Transfer(&mut rx[..len], &tx[..len]),
+            Operation::Write(C::postamble()),
+        ];
+        let start = usize::from(C::preamble().is_empty());
+        let end = 3 - usize::from(C::postamble().is_empty());
+
+        device
+            .transaction(&mut operations[start..end])
+            .await
+            .map_err(CommandError::BusError)?;
+
+        let (status, response) = rx[len - read_len..len].split_at(usize::from(C::STATUS_BYTE));
+        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+        resp_buf.as_mut().copy_from_slice(response);
+
+        trace!(
+            "spi: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            reg_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
+        if let Some(&status) = status.first().filter(|&&status| status != 0) {
+            return Err(CommandError::DeviceError(status));
+        }
+
+        C::validate_response(resp_buf.as_ref()).map_err(CommandError::ValidationError)?;
+        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
+    }
+
+    /// Invoke a command on a SPI device and map its response into another type.
+    ///
+    /// This function invokes the command using [`invoke_command`], then transforms the parsed
+    /// response using the provided closure. This allows the response to be converted into a domain
+    /// type without defining a separate wrapper type.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `cmd` - The command to invoke
+    /// * `f` - The closure applied to the parsed response
+    ///
+    /// # Errors
+    /// * `CommandError::BusError` - Communication with the device failed
+    /// * `CommandError::SerializationError` - Failed to convert command parameters to bytes
+    /// * `CommandError::DeserializationError` - Failed to convert received bytes into response parameters
+    /// * `CommandError::ValidationError` - The response was rejected by [`Command::validate_response`]
+    /// * `CommandError::DeviceError` - The device responded with a nonzero status byte
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{spi, Command, FromByteArray, NoParameters};
+    /// # struct ReadTemperature;
+    /// # impl Command for ReadTemperature {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = u16;
+    /// #     fn id() -> Self::IdType { 0xE3 }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// async fn temperature<D: SpiDevice>(device: &mut D) -> f32 {
+    ///     spi::r#async::invoke_command_map(device, ReadTemperature, |raw| {
+    ///         -46.85 + 175.72 * f32::from(raw) / 65536.0
+    ///     })
+    ///     .await
+    ///     .unwrap()
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_command_map<D, C, T, F>(
+        device: &mut D,
+        cmd: C,
+        f: F,
+    ) -> Result<
+        T,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        C: Command,
+        F: FnOnce(C::ResponseParameters) -> T,
+    {
+        invoke_command(device, cmd).await.map(f)
+    }
+
+    /// Invoke a command on a SPI device, waiting for the command's response delay before
+    /// reading its response.
+    ///
+    /// Some devices require time to process a command, such as to perform a measurement, before
+    /// the response may be read. This function performs the command invocation as two transactions:
+    /// 1. Sends the command ID and the serialized command parameters
+    /// 2. Waits [`Command::RESPONSE_DELAY_US`] microseconds
+    /// 3. Reads the status byte, if [`Command::STATUS_BYTE`] is set, followed by the command response
+    ///
+    /// If [`Command::RESPONSE_DELAY_US`] is zero, this function behaves exactly like
+    /// [`invoke_command`], performing a single transaction.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `delay` - The delay provider used to wait for the response
+    /// * `cmd` - The command to invoke
+    ///
+    /// # Errors
+    /// * `CommandError::BusError` - Communication with the device failed
+    /// * `CommandError::SerializationError` - Failed to convert command parameters to bytes
+    /// * `CommandError::DeserializationError` - Failed to convert received bytes into response parameters
+    /// * `CommandError::ValidationError` - The response was rejected by [`Command::validate_response`]
+    /// * `CommandError::DeviceError` - The device responded with a nonzero status byte
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::{delay::DelayNs, spi::SpiDevice};
+    /// # use regiface::{NoParameters, spi, Command, FromByteArray};
+    /// # struct MeasureHighPrecision;
+    /// # struct Measurement;
+    /// # impl Command for MeasureHighPrecision {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = Measurement;
+    /// #     const RESPONSE_DELAY_US: u32 = 8_300;
+    /// #     fn id() -> Self::IdType { 0xFD }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// # impl FromByteArray for Measurement {
+    /// #     type Array = [u8; 6];
+    /// #     type Error = ();
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { Ok(Self) }
+    /// # }
+    /// async fn measure<D: SpiDevice, T: DelayNs>(device: &mut D, delay: &mut T) {
+    ///     let result: Measurement = spi::r#async::invoke_command_delayed(device, delay, MeasureHighPrecision).await.unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_command_delayed<D, T, C>(
+        device: &mut D,
+        delay: &mut T,
+        cmd: C,
+    ) -> Result<
+        C::ResponseParameters,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        T: embedded_hal_async::delay::DelayNs,
+        C: Command,
+    {
+        check_response::<C>();
+
+        if C::RESPONSE_DELAY_US == 0 {
+            return invoke_command(device, cmd).await;
+        }
+
+        let mut cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+
+        let mut status = [0; 1];
+        let status_buf: &mut [u8] = if C::STATUS_BYTE { &mut status } else { &mut [] };
+
+        let mut reg_id = C::id().to_id_bytes();
+        let (id, parameters) = merge_id::<C>(reg_id.as_mut(), cmd_buf.as_mut());
+        let (mut operations, len) = command_operations(
+            C::preamble(),
+            id,
+            C::sub_id(),
+            parameters,
+            C::postamble(),
+            &mut [],
+            &mut [],
+        );
+
+        device
+            .transaction(&mut operations[..len])
+            .await
+            .map_err(CommandError::BusError)?;
+
+        delay.delay_us(C::RESPONSE_DELAY_US).await;
+
+        let (mut operations, len) = read_operations(status_buf, resp_buf.as_mut());
+        if len > 0 {
+            device
+                .transaction(&mut operations[..len])
+                .await
+                .map_err(CommandError::BusError)?;
+        }
+
+        trace!(
+            "spi: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            reg_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
+        if status[0] != 0 {
+            return Err(CommandError::DeviceError(status[0]));
+        }
+
+        C::validate_response(resp_buf.as_ref()).map_err(CommandError::ValidationError)?;
+        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
+    }
+
+    /// Invoke a [`DynamicCommand`] on a SPI device and receive its response.
+    ///
+    /// This function behaves exactly like [`invoke_command`], except that the command ID is
+    /// obtained from the command instance via [`DynamicCommand::id`] rather than from the command
+    /// type.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `cmd` - The command to invoke
+    ///
+    /// # Errors
+    /// * `CommandError::BusError` - Communication with the device failed
+    /// * `CommandError::SerializationError` - Failed to convert command parameters to bytes
+    /// * `CommandError::DeserializationError` - Failed to convert received bytes into response parameters
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use regiface::{spi, DynamicCommand, NoParameters};
+    /// # struct SetChannel { channel: u8, value: u16 }
+    /// # impl DynamicCommand for SetChannel {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = u16;
+    /// #     type ResponseParameters = NoParameters;
+    /// #     fn id(&self) -> Self::IdType { 0x30 | self.channel }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { self.value }
+    /// # }
+    /// async fn set_all_channels<D: SpiDevice>(device: &mut D, value: u16) {
+    ///     for channel in 0..4 {
+    ///         spi::r#async::invoke_dynamic_command(device, SetChannel { channel, value }).await.unwrap();
+    ///     }
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub async fn invoke_dynamic_command<D, C>(
+        device: &mut D,
+        cmd: C,
+    ) -> Result<
+        C::ResponseParameters,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        D: embedded_hal_async::spi::SpiDevice,
+        C: DynamicCommand,
+    {
+        let reg_id = cmd.id().to_id_bytes();
+        let cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+
+        let (mut operations, len) = command_operations(
+            &[],
+            reg_id.as_ref(),
+            &[],
+            cmd_buf.as_ref(),
+            &[],
+            &mut [],
+            resp_buf.as_mut(),
+        );
+
+        device
+            .transaction(&mut operations[..len])
+            .await
+            .map_err(CommandError::BusError)?;
+
+        trace!(
+            "spi: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            reg_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
+        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
+    }
+}
+
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use super::*;
+    use crate::retry::{retry_policy_blocking, Policy};
+    use embedded_hal::spi::Operation;
+
+    /// Builds the operations for invoking a command: the preamble, the command ID, the sub-ID, the
+    /// command parameters, and the postamble, followed by a read of the status byte and command
+    /// response
+    ///
+    /// Empty preambles, command IDs, sub-IDs, command parameters, postambles, status bytes, and
+    /// responses are omitted, thus only the first `len` of the returned operations are to be
+    /// performed.
+    fn command_operations<'a>(
+        preamble: &'a [u8],
+        id: &'a [u8],
+        sub_id: &'a [u8],
+        parameters: &'a [u8],
+        postamble: &'a [u8],
+        status: &'a mut [u8],
+        response: &'a mut [u8],
+    ) -> ([Operation<'a, u8>; 7], usize) {
+        let mut operations = [
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+            Operation::Write(&[]),
+        ];
+        let mut len = 0;
+
+        for write in [preamble, id, sub_id, parameters, postamble] {
+            if !write.is_empty() {
+                operations[len] = Operation::Write(write);
+                len += 1;
+            }
+        }
+
+        let (reads, reads_len) = read_operations(status, response);
+        for read in reads.into_iter().take(reads_len) {
+            operations[len] = read;
+            len += 1;
+        }
+
+        (operations, len)
+    }
+
+    /// Builds the operations for reading a command response: a read of the status byte, followed
+    /// by a read of the command response
+    ///
+    /// Empty status bytes and responses are omitted, thus only the first `len` of the returned
+    /// operations are to be performed.
+    fn read_operations<'a>(
+        status: &'a mut [u8],
+        response: &'a mut [u8],
+    ) -> ([Operation<'a, u8>; 2], usize) {
+        let mut operations = [Operation::Write(&[]), Operation::Write(&[])];
+        let mut len = 0;
+
+        if !status.is_empty() {
+            operations[len] = Operation::Read(status);
+            len += 1;
+        }
+
+        if !response.is_empty() {
+            operations[len] = Operation::Read(response);
+            len += 1;
+        }
+
+        (operations, len)
+    }
+
+    /// Read a register value from a SPI device.
+    ///
+    /// Blocking variant of `r#async::read_register`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::spi::SpiDevice;
+    /// # use regiface::{register, spi, ReadableRegister, FromByteArray};
+    /// # #[register(42u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct TemperatureRegister;
+    /// # impl FromByteArray for TemperatureRegister {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = ();
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> {todo!()}
+    /// # }
+    /// fn read_temp<D: SpiDevice>(device: &mut D) {
+    ///     let temp: TemperatureRegister = spi::blocking::read_register(device).unwrap();
+    /// }
+    /// ```
+    pub fn read_register<D, R>(device: &mut D) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: ReadableRegister,
+    {
+        let mut buf = <R as FromByteArray>::Array::new();
+
+        let reg_id = R::readable_id().to_id_bytes();
+
+        let mut scratch = [0; MAX_DUMMY_BYTES];
+        let operations: &mut [embedded_hal::spi::Operation<'_, u8>] =
+            match dummy_operation::<R>(&mut scratch) {
+                None => &mut [
+                    embedded_hal::spi::Operation::Write(reg_id.as_ref()),
+                    embedded_hal::spi::Operation::Read(buf.as_mut()),
+                ],
+                Some(dummy) => &mut [
+                    embedded_hal::spi::Operation::Write(reg_id.as_ref()),
+                    dummy,
+                    embedded_hal::spi::Operation::Read(buf.as_mut()),
+                ],
+            };
+
+        device
+            .transaction(operations)
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "spi: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from a SPI device, reading a fixed number of bytes regardless of
+    /// the size of the register.
+    ///
+    /// Blocking variant of `r#async::read_register_sized`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+    /// # use regiface::{register, spi, ReadableRegister, FromByteArray};
+    /// # #[register(0x10u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct Status(u16);
+    /// # impl FromByteArray for Status {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(u16::from_be_bytes(bytes))) }
+    /// # }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         let [Operation::Write([0x10]), Operation::Read(buf @ [_, _, _, _])] = ops else {
+    /// #             panic!("unexpected operations");
+    /// #         };
+    /// #         buf.copy_from_slice(&[0x12, 0x34, 0xAA, 0xBB]);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// // Reads [0x12, 0x34, 0xAA, 0xBB], deserializing the register from [0x12, 0x34]
+    /// let status = spi::blocking::read_register_sized::<_, Status, 4>(&mut device).unwrap();
+    /// assert_eq!(status.0, 0x1234);
+    /// ```
+    pub fn read_register_sized<D, R, const READ_LEN: usize>(
+        device: &mut D,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: ReadableRegister,
+    {
+        const {
+            assert!(
+                READ_LEN >= <R as FromByteArray>::Array::LEN,
+                "READ_LEN must be at least the length of the register's FromByteArray::Array"
+            )
+        };
+
+        let mut buf = [0; READ_LEN];
+
+        let reg_id = R::readable_id().to_id_bytes();
+
+        let mut scratch = [0; MAX_DUMMY_BYTES];
+        let operations: &mut [embedded_hal::spi::Operation<'_, u8>] =
+            match dummy_operation::<R>(&mut scratch) {
+                None => &mut [
+                    embedded_hal::spi::Operation::Write(reg_id.as_ref()),
+                    embedded_hal::spi::Operation::Read(&mut buf),
+                ],
+                Some(dummy) => &mut [
+                    embedded_hal::spi::Operation::Write(reg_id.as_ref()),
+                    dummy,
+                    embedded_hal::spi::Operation::Read(&mut buf),
+                ],
+            };
+
+        device
+            .transaction(operations)
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "spi: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            &buf[..]
+        );
+
+        // Only the leading bytes of the oversized read make up the register value
+        let mut bytes = <R as FromByteArray>::Array::new();
+        bytes
+            .as_mut()
+            .copy_from_slice(&buf[..<R as FromByteArray>::Array::LEN]);
+
+        R::from_bytes(bytes).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from a SPI device using a single full-duplex transfer.
+    ///
+    /// Blocking variant of `r#async::read_register_transfer`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+    /// # use regiface::{register, spi, ReadableRegister, FromByteArray};
+    /// # #[register(0x01u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct Sample(u16);
+    /// # impl FromByteArray for Sample {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(u16::from_be_bytes(bytes))) }
+    /// # }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         let [Operation::Transfer(rx, [0x01, 0x00, 0x00])] = ops else {
+    /// #             panic!("unexpected operations");
+    /// #         };
+    /// #         rx.copy_from_slice(&[0xFF, 0x12, 0x34]);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// // Transfers [0x01, 0x00, 0x00] while receiving [0xFF, 0x12, 0x34]
+    /// let sample: Sample = spi::blocking::read_register_transfer(&mut device).unwrap();
+    /// assert_eq!(sample.0, 0x1234);
+    /// ```
+    pub fn read_register_transfer<D, R>(
+        device: &mut D,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: ReadableRegister,
+    {
+        const {
+            assert!(
+                <<R as crate::Register>::IdType as id::Id>::Array::LEN
+                    + <R as FromByteArray>::Array::LEN
+                    <= MAX_TRANSFER_BYTES,
+                "the register ID and value exceed MAX_TRANSFER_BYTES"
+            )
+        };
+
+        let reg_id = R::readable_id().to_id_bytes();
+        let id_len = reg_id.as_ref().len();
+        let data_start = id_len + dummy_count::<R>();
+        let len = data_start + <R as FromByteArray>::Array::LEN;
+
+        let mut tx = [0; MAX_TRANSFER_BYTES + MAX_DUMMY_BYTES];
+        let mut rx = [0; MAX_TRANSFER_BYTES + MAX_DUMMY_BYTES];
+        tx[..id_len].copy_from_slice(reg_id.as_ref());
+
+        device
+            .transaction(&mut [embedded_hal::spi::Operation::Transfer(
+                &mut rx[..len],
+                &tx[..len],
+            )])
+            .map_err(ReadRegisterError::BusError)?;
+
+        let mut buf = <R as FromByteArray>::Array::new();
+        buf.as_mut().copy_from_slice(&rx[data_start..len]);
+
+        trace!(
+            "spi: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from a SPI device using the given addressing convention.
+    ///
+    /// Blocking variant of `r#async::read_register_with`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::spi::SpiDevice;
+    /// # use regiface::{register, spi::{self, SpiAddressing}, ReadableRegister, FromByteArray};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct WhoAmI(u8);
+    /// # impl FromByteArray for WhoAmI {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// const ADDRESSING: SpiAddressing = SpiAddressing::read_flag(0x80);
+    ///
+    /// fn who_am_i<D: SpiDevice>(device: &mut D) -> u8 {
+    ///     // Transmits 0x8F as the register ID
+    ///     let id: WhoAmI = spi::blocking::read_register_with(device, ADDRESSING).unwrap();
+    ///     id.0
+    /// }
+    /// ```
+    pub fn read_register_with<D, R>(
+        device: &mut D,
+        addressing: SpiAddressing,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: ReadableRegister,
+    {
+        let mut buf = <R as FromByteArray>::Array::new();
+
+        let reg_id = addressing.read.apply(R::readable_id());
+
+        let mut scratch = [0; MAX_DUMMY_BYTES];
+        let operations: &mut [embedded_hal::spi::Operation<'_, u8>] =
+            match dummy_operation::<R>(&mut scratch) {
+                None => &mut [
+                    embedded_hal::spi::Operation::Write(reg_id.as_ref()),
+                    embedded_hal::spi::Operation::Read(buf.as_mut()),
+                ],
+                Some(dummy) => &mut [
+                    embedded_hal::spi::Operation::Write(reg_id.as_ref()),
+                    dummy,
+                    embedded_hal::spi::Operation::Read(buf.as_mut()),
+                ],
+            };
+
+        device
+            .transaction(operations)
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "spi: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from a 3-wire (half-duplex) SPI device using the given addressing
+    /// convention.
+    ///
+    /// Blocking variant of `r#async::read_register_3wire`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+    /// # use regiface::{register, spi::{self, DummyMode, SpiAddressing}, ReadableRegister, FromByteArray};
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         let [Operation::Write([0xA8]), Operation::Read([_]), Operation::Read(buf)] = ops else { panic!() };
+    /// #         buf.copy_from_slice(&[0x34, 0x12]);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// #[register(0x28u8)]
+    /// #[derive(Debug)]
+    /// struct OutX(i16);
+    ///
+    /// impl ReadableRegister for OutX {
+    ///     fn dummy_bytes() -> usize {
+    ///         1
+    ///     }
+    ///
+    ///     // Ignored in 3-wire mode, as the host no longer drives the data line
+    ///     fn dummy_mode() -> DummyMode {
+    ///         DummyMode::Write
+    ///     }
+    /// }
+    ///
+    /// impl FromByteArray for OutX {
+    ///     type Array = [u8; 2];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+    ///         Ok(Self(i16::from_le_bytes(bytes)))
+    ///     }
+    /// }
+    ///
+    /// const ADDRESSING: SpiAddressing = SpiAddressing::read_flag(0x80);
+    ///
+    /// // Writes 0xA8 as the register ID, then reads the dummy byte and the register value
+    /// let out_x: OutX = spi::blocking::read_register_3wire(&mut device, ADDRESSING).unwrap();
+    /// assert_eq!(out_x.0, 0x1234);
+    /// ```
+    pub fn read_register_3wire<D, R>(
+        device: &mut D,
+        addressing: SpiAddressing,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: ReadableRegister,
+    {
+        let mut buf = <R as FromByteArray>::Array::new();
+
+        let reg_id = addressing.read.apply(R::readable_id());
+
+        let mut scratch = [0; MAX_DUMMY_BYTES];
+        let dummy = dummy_count::<R>();
+        let operations: &mut [embedded_hal::spi::Operation<'_, u8>] = if dummy == 0 {
+            &mut [
+                embedded_hal::spi::Operation::Write(reg_id.as_ref()),
+                embedded_hal::spi::Operation::Read(buf.as_mut()),
+            ]
+        } else {
+            &mut [
+                embedded_hal::spi::Operation::Write(reg_id.as_ref()),
+                embedded_hal::spi::Operation::Read(&mut scratch[..dummy]),
+                embedded_hal::spi::Operation::Read(buf.as_mut()),
+            ]
+        };
+
+        device
+            .transaction(operations)
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "spi: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from a SPI device using 16-bit words.
+    ///
+    /// Blocking variant of `r#async::read_register_word`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+    /// # use regiface::{register, spi::{self, WordLayout}, ReadableRegister, FromByteArray};
+    /// # #[register(0x2u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct Status(u16);
+    /// # impl FromByteArray for Status {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(u16::from_be_bytes(bytes))) }
+    /// # }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice<u16> for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u16>]) -> Result<(), Self::Error> {
+    /// #         let [Operation::Write([0x9000]), Operation::Read([frame])] = ops else { panic!() };
+    /// #         *frame = 0x1234;
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// // Bit 15 flags a read, the register ID occupies bits 14..11, and the register value occupies
+    /// // bits 10..0
+    /// const LAYOUT: WordLayout = WordLayout::new(11, 4, 0, 11).with_read_flag(0x8000);
+    ///
+    /// // Transmits 0x9000 as the read frame, then extracts the value from the received frame
+    /// let status: Status = spi::blocking::read_register_word(&mut device, LAYOUT).unwrap();
+    /// assert_eq!(status.0, 0x234);
+    /// ```
+    pub fn read_register_word<D, R>(
+        device: &mut D,
+        layout: WordLayout,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice<u16>,
+        R: ReadableRegister,
+    {
+        let mut buf = <R as FromByteArray>::Array::new();
+
+        let reg_id = R::readable_id().to_id_bytes();
+        let request = [layout.read_frame(reg_id.as_ref())];
+        let mut response = [0u16];
+
+        device
+            .transaction(&mut [
+                embedded_hal::spi::Operation::Write(&request),
+                embedded_hal::spi::Operation::Read(&mut response),
+            ])
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "spi: read register: id={:02X?} frame={:04X?}",
+            reg_id.as_ref(),
+            response[0]
+        );
+
+        layout.read_value(response[0], buf.as_mut());
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read an instance of an indexed register value from a SPI device.
+    ///
+    /// Blocking variant of `r#async::read_register_at`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::spi::SpiDevice;
+    /// # use regiface::{register, spi, IndexedRegister, ReadableRegister, FromByteArray};
+    /// # #[register(0x06u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct LedOnOff { on: u16, off: u16 }
+    /// # impl IndexedRegister for LedOnOff {
+    /// #     type Index = u8;
+    /// #     fn indexed_id(channel: Self::Index) -> Self::IdType { Self::ID + 4 * channel }
+    /// # }
+    /// # impl FromByteArray for LedOnOff {
+    /// #     type Array = [u8; 4];
+    /// #     type Error = ();
+    /// #     fn from_bytes(b: Self::Array) -> Result<Self, Self::Error> {
+    /// #         Ok(Self { on: u16::from_le_bytes([b[0], b[1]]), off: u16::from_le_bytes([b[2], b[3]]) })
+    /// #     }
+    /// # }
+    /// fn read_channel<D: SpiDevice>(device: &mut D, channel: u8) -> u16 {
+    ///     let led: LedOnOff = spi::blocking::read_register_at(device, channel).unwrap();
+    ///     led.off
+    /// }
+    /// ```
+    pub fn read_register_at<D, R>(
+        device: &mut D,
+        index: R::Index,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: ReadableRegister + IndexedRegister,
+    {
+        let mut buf = <R as FromByteArray>::Array::new();
+
+        let reg_id = R::readable_indexed_id(index).to_id_bytes();
+
+        let mut scratch = [0; MAX_DUMMY_BYTES];
+        let operations: &mut [embedded_hal::spi::Operation<'_, u8>] =
+            match dummy_operation::<R>(&mut scratch) {
+                None => &mut [
+                    embedded_hal::spi::Operation::Write(reg_id.as_ref()),
+                    embedded_hal::spi::Operation::Read(buf.as_mut()),
+                ],
+                Some(dummy) => &mut [
+                    embedded_hal::spi::Operation::Write(reg_id.as_ref()),
+                    dummy,
+                    embedded_hal::spi::Operation::Read(buf.as_mut()),
+                ],
+            };
+
+        device
+            .transaction(operations)
+            .map_err(ReadRegisterError::BusError)?;
+
+        trace!(
+            "spi: read register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+    }
+
+    /// Read a register value from a SPI device, along with the number of bytes read.
+    ///
+    /// Blocking variant of `r#async::read_register_counted`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::spi::SpiDevice;
+    /// # use regiface::{register, spi, ReadableRegister, FromByteArray};
+    /// # #[register(42u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct FifoData;
+    /// # impl FromByteArray for FifoData {
+    /// #     type Array = [u8; 32];
+    /// #     type Error = ();
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { todo!() }
+    /// # }
+    /// fn read_fifo<D: SpiDevice>(device: &mut D) {
+    ///     let (data, count): (FifoData, usize) = spi::blocking::read_register_counted(device).unwrap();
+    /// }
+    /// ```
+    pub fn read_register_counted<D, R>(
+        device: &mut D,
+    ) -> Result<(R, usize), ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: ReadableRegister,
+    {
+        let count = <R as FromByteArray>::Array::new().as_ref().len();
+        let register = read_register(device)?;
+
+        Ok((register, count))
+    }
+
+    /// Read a contiguous block of registers from a SPI device in a single transaction.
+    ///
+    /// Blocking variant of `r#async::read_registers_burst`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+    /// # use regiface::{register, spi, ReadableRegister, FromByteArray};
+    /// // OUTX_L_A, OUTX_H_A, OUTY_L_A, OUTY_H_A, OUTZ_L_A, OUTZ_H_A
+    /// #[register(0xA8u8)]
+    /// #[derive(ReadableRegister)]
+    /// struct AccelSample {
+    ///     x: i16,
+    ///     y: i16,
+    ///     z: i16,
+    /// }
+    ///
+    /// impl FromByteArray for AccelSample {
+    ///     type Array = [u8; 6];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+    ///         Ok(Self {
+    ///             x: i16::from_le_bytes([bytes[0], bytes[1]]),
+    ///             y: i16::from_le_bytes([bytes[2], bytes[3]]),
+    ///             z: i16::from_le_bytes([bytes[4], bytes[5]]),
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         assert!(matches!(ops, [Operation::Write([0xA8]), Operation::Read([_, _, _, _, _, _])]));
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// // A single transaction writing 0xA8 followed by a 6 byte read
+    /// let sample: AccelSample = spi::blocking::read_registers_burst(&mut device).unwrap();
+    /// ```
+    pub fn read_registers_burst<D, R>(
+        device: &mut D,
+    ) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: ReadableRegister,
+    {
+        read_register(device)
+    }
+
+    /// Read a number of samples from a FIFO data register of an SPI device.
+    ///
+    /// Blocking variant of `r#async::read_fifo`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::spi::{ErrorKind, ErrorType, Operation, SpiDevice};
+    /// use regiface::{errors::FifoError, register, spi, FifoMode, FromByteArray, ReadableRegister};
+    ///
+    /// // The receive holding register of a UART bridge
+    /// #[register(0x00u8)]
+    /// #[derive(ReadableRegister)]
+    /// struct Rhr(u8);
+    ///
+    /// impl FromByteArray for Rhr {
+    ///     type Array = [u8; 1];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+    ///         Ok(Self(bytes[0]))
+    ///     }
+    /// }
+    /// # struct Bridge { transactions: usize }
+    /// # impl ErrorType for Bridge { type Error = ErrorKind; }
+    /// # impl SpiDevice for Bridge {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         self.transactions += 1;
+    /// #         if self.transactions == 3 { return Err(ErrorKind::Other); }
+    /// #         let [Operation::Write([0x00]), Operation::Read(buf)] = ops else { panic!() };
+    /// #         for (i, byte) in buf.iter_mut().enumerate() { *byte = b'a' + i as u8; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// let mut device = Bridge { transactions: 0 };
+    /// let mut buf = [0; 8];
+    ///
+    /// // Read four bytes in a single transaction
+    /// let read = spi::blocking::read_fifo::<_, Rhr>(&mut device, &mut buf, 4, FifoMode::Burst).unwrap();
+    /// assert_eq!(read, 4);
+    /// assert_eq!(&buf[..4], b"abcd");
+    ///
+    /// // Read one byte per transaction, the second of which fails
+    /// let err = spi::blocking::read_fifo::<_, Rhr>(&mut device, &mut buf, 4, FifoMode::PerSample).unwrap_err();
+    /// assert_eq!(err, FifoError::BusError { error: ErrorKind::Other, samples: 1 });
+    ///
+    /// // The buffer can not hold the requested samples
+    /// let err = spi::blocking::read_fifo::<_, Rhr>(&mut device, &mut buf, 9, FifoMode::Burst).unwrap_err();
+    /// assert_eq!(err, FifoError::BufferTooSmall { required: 9, available: 8 });
+    /// ```
+    pub fn read_fifo<D, R>(
+        device: &mut D,
+        out: &mut [u8],
+        count: usize,
+        mode: FifoMode,
+    ) -> Result<usize, FifoError<D::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: ReadableRegister,
+    {
+        let required = crate::fifo_len::<<R as FromByteArray>::Array, _>(count, out.len())?;
+        if required == 0 {
+            return Ok(0);
+        }
+        let out = &mut out[..required];
+        let len = <R as FromByteArray>::Array::LEN;
+
+        let reg_id = R::readable_id().to_id_bytes();
+
+        let chunk = match mode {
+            FifoMode::Burst => required,
+            FifoMode::PerSample => len,
+        };
+        for (index, samples) in out.chunks_mut(chunk).enumerate() {
+            let mut scratch = [0; MAX_DUMMY_BYTES];
+            let operations: &mut [Operation<'_, u8>] = match dummy_operation::<R>(&mut scratch) {
+                None => &mut [Operation::Write(reg_id.as_ref()), Operation::Read(samples)],
+                Some(dummy) => &mut [
+                    Operation::Write(reg_id.as_ref()),
+                    dummy,
+                    Operation::Read(samples),
+                ],
+            };
+
+            device
+                .transaction(operations)
+                .map_err(|error| FifoError::BusError {
+                    error,
+                    samples: index * chunk / len,
+                })?;
+        }
+
+        trace!(
+            "spi: read fifo: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            out
+        );
+
+        Ok(count)
+    }
+
+    /// Repeatedly read a register from a SPI device until its value satisfies a condition.
+    ///
+    /// Blocking variant of `r#async::poll_register`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::{delay::DelayNs, spi::SpiDevice};
+    /// # use regiface::{register, spi, ReadableRegister, FromByteArray};
+    /// # #[register(0x27u8)]
+    /// # #[derive(ReadableRegister, Debug)]
+    /// # struct Status { ready: bool }
+    /// # impl FromByteArray for Status {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self { ready: bytes[0] & 0x01 != 0 }) }
+    /// # }
+    /// fn wait_until_ready<D: SpiDevice, T: DelayNs>(device: &mut D, delay: &mut T) {
+    ///     let status: Status = spi::blocking::poll_register(device, delay, 1_000, 10, |s: &Status| s.ready).unwrap();
+    /// }
+    /// # struct Device { reads: usize }
+    /// # impl embedded_hal::spi::ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [embedded_hal::spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         self.reads += 1;
+    /// #         if let [_, embedded_hal::spi::Operation::Read(buf)] = ops {
+    /// #             buf[0] = if self.reads < 3 { 0x00 } else { 0x01 };
+    /// #         }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # struct NoDelay;
+    /// # impl DelayNs for NoDelay { fn delay_ns(&mut self, _: u32) {} }
+    /// # let mut device = Device { reads: 0 };
+    /// # wait_until_ready(&mut device, &mut NoDelay);
+    /// // The device reports not-ready twice before becoming ready
+    /// assert_eq!(device.reads, 3);
+    /// ```
+    pub fn poll_register<D, T, R, F>(
+        device: &mut D,
+        delay: &mut T,
+        interval_us: u32,
+        max_attempts: usize,
+        mut condition: F,
+    ) -> Result<R, PollError<D::Error, R::Error, R>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        T: embedded_hal::delay::DelayNs,
+        R: ReadableRegister,
+        F: FnMut(&R) -> bool,
+    {
+        let mut last = None;
+
+        for attempt in 0..max_attempts {
+            if attempt > 0 {
+                delay.delay_us(interval_us);
+            }
+
+            let register: R = read_register(device)?;
+            if condition(&register) {
+                return Ok(register);
+            }
+            last = Some(register);
+        }
+
+        Err(PollError::Timeout { last })
+    }
+
+    /// Periodically read a register value from an SPI device as an [`Iterator`].
+    ///
+    /// The blocking counterpart of the async `poll_stream`, available with the `stream` feature.
+    /// The register is read by the first call to [`Iterator::next`], and each subsequent call
+    /// waits `period_us` microseconds before reading it again. Errors are yielded like any other
+    /// item rather than ending the iterator, which never ends on its own.
+    ///
+    /// # Parameters
+    /// * `device` - The SPI device to communicate with
+    /// * `delay` - The delay provider used to wait between reads
+    /// * `period_us` - The number of microseconds to wait between reads
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::spi::ErrorKind;
+    /// # use embedded_hal::delay::DelayNs;
+    /// use regiface::{register, spi, FromByteArray, ReadableRegister};
+    /// # #[register(0x05u8)]
+    /// # #[derive(ReadableRegister, Debug)]
+    /// # struct Temperature(u8);
+    /// # impl FromByteArray for Temperature {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # struct NoDelay;
+    /// # impl DelayNs for NoDelay { fn delay_ns(&mut self, _: u32) {} }
+    /// # struct Sensor(u8);
+    /// # impl embedded_hal::spi::ErrorType for Sensor { type Error = ErrorKind; }
+    /// # impl embedded_hal::spi::SpiDevice for Sensor {
+    /// #     fn transaction(&mut self, ops: &mut [embedded_hal::spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         self.0 += 1;
+    /// #         if self.0 == 2 { return Err(ErrorKind::Overrun); }
+    /// #         if let [_, embedded_hal::spi::Operation::Read(buf)] = ops { buf[0] = 20 + self.0; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let (mut device, mut delay) = (Sensor(0), NoDelay);
+    /// let mut samples = spi::blocking::poll_iter::<_, _, Temperature>(&mut device, &mut delay, 1_000_000);
+    ///
+    /// // A failed read is yielded, and sampling continues
+    /// assert_eq!(samples.next().unwrap().unwrap().0, 21);
+    /// assert!(samples.next().unwrap().is_err());
+    /// assert_eq!(samples.next().unwrap().unwrap().0, 23);
+    /// ```
+    pub fn poll_iter<'a, D, T, R>(
+        device: &'a mut D,
+        delay: &'a mut T,
+        period_us: u32,
+    ) -> impl Iterator<Item = Result<R, ReadRegisterError<D::Error, R::Error>>> + 'a
+    where
+        D: embedded_hal::spi::SpiDevice,
+        T: embedded_hal::delay::DelayNs,
+        R: ReadableRegister + 'a,
+    {
+        let mut first = true;
+        core::iter::from_fn(move || {
+            if !core::mem::take(&mut first) {
+                delay.delay_us(period_us);
+            }
+            Some(read_register(device))
+        })
+    }
+
+    /// Read a register value from an SPI device, retrying according to a [`Policy`].
+    ///
+    /// Blocking variant of `r#async::read_register_retry`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::{delay::DelayNs, spi::{ErrorKind, ErrorType, Operation, SpiDevice}};
+    /// use regiface::{errors::{ReadRegisterError, RetryError}, register, spi, retry::Policy, FromByteArray, ReadableRegister};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister, Debug, PartialEq)]
+    /// # struct ChipId(u8);
+    /// # impl FromByteArray for ChipId {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # struct NoDelay;
+    /// # impl DelayNs for NoDelay { fn delay_ns(&mut self, _: u32) {} }
+    /// # struct Device { faults: u8, attempts: u8 }
+    /// # impl ErrorType for Device { type Error = ErrorKind; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         self.attempts += 1;
+    /// #         if self.faults > 0 {
+    /// #             self.faults -= 1;
+    /// #             return Err(ErrorKind::Overrun);
+    /// #         }
+    /// #         if let [.., Operation::Read(buf)] = ops { buf.fill(0x6A); }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// let policy = Policy::exponential(3, 100, 2);
+    ///
+    /// // The first two attempts fail, the third succeeds
+    /// let mut device = Device { faults: 2, attempts: 0 };
+    /// let id: ChipId = spi::blocking::read_register_retry(&mut device, &mut NoDelay, &policy).unwrap();
+    /// assert_eq!(id, ChipId(0x6A));
+    /// assert_eq!(device.attempts, 3);
+    ///
+    /// // Every attempt fails, thus the last error is returned
+    /// let mut device = Device { faults: 3, attempts: 0 };
+    /// assert_eq!(
+    ///     spi::blocking::read_register_retry::<_, _, ChipId>(&mut device, &mut NoDelay, &policy),
+    ///     Err(RetryError { error: ReadRegisterError::BusError(ErrorKind::Overrun), attempts: 3 })
+    /// );
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn read_register_retry<D, T, R>(
+        device: &mut D,
+        delay: &mut T,
+        policy: &Policy,
+    ) -> Result<R, RetryError<ReadRegisterError<D::Error, R::Error>>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        T: embedded_hal::delay::DelayNs,
+        R: ReadableRegister,
+    {
+        retry_policy_blocking(policy, delay, || read_register(device))
+    }
+
+    /// Write a register value to an SPI device, retrying according to a [`Policy`].
+    ///
+    /// Blocking variant of `r#async::write_register_retry`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::{delay::DelayNs, spi::SpiDevice};
+    /// # use regiface::{register, spi, retry::Policy, ToByteArray, WritableRegister};
+    /// # #[register(0x20u8)]
+    /// # #[derive(WritableRegister, Clone)]
+    /// # struct Control(u8);
+    /// # impl ToByteArray for Control {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.0]) }
+    /// # }
+    /// fn power_up<D: SpiDevice, T: DelayNs>(device: &mut D, delay: &mut T) {
+    ///     let policy = Policy::fixed(3, 1_000);
+    ///     spi::blocking::write_register_retry(device, Control(0x01), delay, &policy).unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn write_register_retry<D, T, R>(
+        device: &mut D,
+        register: R,
+        delay: &mut T,
+        policy: &Policy,
+    ) -> Result<(), RetryError<WriteRegisterError<D::Error, R::Error>>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        T: embedded_hal::delay::DelayNs,
+        R: WritableRegister + Clone,
+    {
+        retry_policy_blocking(policy, delay, || write_register(device, register.clone()))
+    }
+
+    /// Invoke a command on an SPI device, retrying according to a [`Policy`].
+    ///
+    /// Blocking variant of `r#async::invoke_command_retry`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::{delay::DelayNs, spi::{ErrorKind, ErrorType, Operation, SpiDevice}};
+    /// use regiface::{spi, retry::Policy, Command, NoParameters};
+    /// # #[derive(Clone)]
+    /// # struct ReadSerial;
+    /// # impl Command for ReadSerial {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = u8;
+    /// #     fn id() -> Self::IdType { 0x36 }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// # struct NoDelay;
+    /// # impl DelayNs for NoDelay { fn delay_ns(&mut self, _: u32) {} }
+    /// # struct Device { faults: u8, attempts: u8 }
+    /// # impl ErrorType for Device { type Error = ErrorKind; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         self.attempts += 1;
+    /// #         if self.faults > 0 {
+    /// #             self.faults -= 1;
+    /// #             return Err(ErrorKind::Overrun);
+    /// #         }
+    /// #         if let [.., Operation::Read(buf)] = ops { buf.fill(0x6A); }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// let policy = Policy::exponential(3, 1_000, 4);
+    ///
+    /// // The first attempt overruns
+    /// let mut device = Device { faults: 1, attempts: 0 };
+    /// let serial = spi::blocking::invoke_command_retry(&mut device, ReadSerial, &mut NoDelay, &policy).unwrap();
+    /// assert_eq!(serial, 0x6A);
+    /// assert_eq!(device.attempts, 2);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn invoke_command_retry<D, T, C>(
+        device: &mut D,
+        cmd: C,
+        delay: &mut T,
+        policy: &Policy,
+    ) -> Result<
+        C::ResponseParameters,
+        RetryError<
+            CommandError<
+                D::Error,
+                <C::CommandParameters as ToByteArray>::Error,
+                <C::ResponseParameters as FromByteArray>::Error,
+            >,
+        >,
+    >
+    where
+        D: embedded_hal::spi::SpiDevice,
+        T: embedded_hal::delay::DelayNs,
+        C: Command + Clone,
+    {
+        retry_policy_blocking(policy, delay, || invoke_command(device, cmd.clone()))
+    }
+
+    /// Verify the identity of a SPI device by reading a register and comparing it to an expected value.
+    ///
+    /// Blocking variant of `r#async::verify_device_id`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::spi::{Operation, SpiDevice};
+    /// # use regiface::{errors::WhoAmIError, register, spi, FromByteArray, ReadableRegister};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister, Debug, PartialEq)]
+    /// # struct ChipId(u8);
+    /// # impl FromByteArray for ChipId {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # struct Bus(u8);
+    /// # impl embedded_hal::spi::ErrorType for Bus { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Bus {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         if let [_, Operation::Read(buf)] = ops { buf[0] = self.0; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// let mut device = Bus(0x69);
+    /// assert_eq!(
+    ///     spi::blocking::verify_device_id(&mut device, ChipId(0x6A)),
+    ///     Err(WhoAmIError::Mismatch { expected: ChipId(0x6A), found: ChipId(0x69) })
+    /// );
+    /// ```
+    pub fn verify_device_id<D, R>(
+        device: &mut D,
+        expected: R,
+    ) -> Result<R, WhoAmIError<D::Error, R::Error, R>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: ReadableRegister + PartialEq,
+    {
+        let found: R = read_register(device)?;
+        if found != expected {
+            return Err(WhoAmIError::Mismatch { expected, found });
+        }
+        Ok(found)
+    }
+
+    /// Verify the identity of a SPI device by reading its [`WhoAmI`] register.
+    ///
+    /// Blocking variant of `r#async::verify_whoami`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::spi::{Operation, SpiDevice};
+    /// # use regiface::{errors::WhoAmIError, register, spi, FromByteArray, ReadableRegister, WhoAmI};
+    /// # #[register(0x0Fu8)]
+    /// # #[derive(ReadableRegister, Debug, PartialEq)]
+    /// # struct ChipId(u8);
+    /// # impl FromByteArray for ChipId {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # impl WhoAmI for ChipId {
+    /// #     fn expected() -> Self { Self(0x6A) }
+    /// # }
+    /// # struct Bus(u8);
+    /// # impl embedded_hal::spi::ErrorType for Bus { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Bus {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         if let [_, Operation::Read(buf)] = ops { buf[0] = self.0; }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    ///
+    /// let mut device = Bus(0x6A);
+    /// assert_eq!(spi::blocking::verify_whoami::<_, ChipId>(&mut device), Ok(ChipId(0x6A)));
+    ///
+    /// // A different device is selected
+    /// let mut device = Bus(0x69);
+    /// assert_eq!(
+    ///     spi::blocking::verify_whoami::<_, ChipId>(&mut device),
+    ///     Err(WhoAmIError::Mismatch { expected: ChipId(0x6A), found: ChipId(0x69) })
+    /// );
+    /// ```
+    pub fn verify_whoami<D, R>(device: &mut D) -> Result<R, WhoAmIError<D::Error, R::Error, R>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: WhoAmI,
+    {
+        verify_device_id(device, R::expected())
+    }
+
+    /// Write a register value to a SPI device.
+    ///
+    /// Blocking variant of `r#async::write_register`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::spi::SpiDevice;
+    /// # use regiface::{register, spi, WritableRegister, ToByteArray};
+    /// # #[register(42u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct ConfigRegister{};
+    /// # impl ToByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([0]) }
+    /// # }
+    /// fn configure<D: SpiDevice>(device: &mut D) {
+    ///     spi::blocking::write_register(device, ConfigRegister{ /* ... */}).unwrap();
+    /// }
+    /// ```
+    pub fn write_register<D, R>(
+        device: &mut D,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: WritableRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = R::writeable_id().to_id_bytes();
+
+        device
+            .transaction(&mut [
+                embedded_hal::spi::Operation::Write(reg_id.as_ref()),
+                embedded_hal::spi::Operation::Write(buf.as_ref()),
+            ])
+            .map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "spi: write register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        Ok(())
+    }
+
+    /// Write a number of samples to a FIFO data register of a SPI device.
+    ///
+    /// Blocking variant of `r#async::write_fifo`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::spi::{ErrorKind, ErrorType, Operation, SpiDevice};
+    /// use regiface::{errors::FifoError, register, spi, ToByteArray, WritableRegister};
+    ///
+    /// // The input FIFO of a DAC, holding 16-bit samples
+    /// #[register(0x0Du8)]
+    /// #[derive(WritableRegister)]
+    /// struct Fifo(u16);
+    ///
+    /// impl ToByteArray for Fifo {
+    ///     type Array = [u8; 2];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    ///         Ok(self.0.to_be_bytes())
+    ///     }
+    /// }
+    /// # struct Dac { transactions: usize }
+    /// # impl ErrorType for Dac { type Error = ErrorKind; }
+    /// # impl SpiDevice for Dac {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         self.transactions += 1;
+    /// #         match (self.transactions, &*ops) {
+    /// #             (1, [Operation::Write([0x0D]), Operation::Write([1, 2, 3, 4])]) => Ok(()),
+    /// #             (2, [Operation::Write([0x0D]), Operation::Write([5, 6])]) => Ok(()),
+    /// #             (3, [Operation::Write([0x0D]), Operation::Write([1, 2, 3, 4])]) => Ok(()),
+    /// #             (4, _) => Err(ErrorKind::Other),
+    /// #             _ => panic!(),
+    /// #         }
+    /// #     }
+    /// # }
+    /// let mut device = Dac { transactions: 0 };
+    /// let samples = [1, 2, 3, 4, 5, 6];
+    ///
+    /// // Write three samples, at most two per transaction of the register ID and samples
+    /// let written = spi::blocking::write_fifo::<_, Fifo>(&mut device, &samples, 3, 5).unwrap();
+    /// assert_eq!(written, 3);
+    ///
+    /// // The second transaction fails, after the first two samples were committed
+    /// let err = spi::blocking::write_fifo::<_, Fifo>(&mut device, &samples, 3, 5).unwrap_err();
+    /// assert_eq!(err, FifoError::BusError { error: ErrorKind::Other, samples: 2 });
+    ///
+    /// // A transaction can not hold the register ID and a sample
+    /// let err = spi::blocking::write_fifo::<_, Fifo>(&mut device, &samples, 3, 2).unwrap_err();
+    /// assert_eq!(err, FifoError::TransactionTooSmall { required: 3, maximum: 2 });
+    /// ```
+    pub fn write_fifo<D, R>(
+        device: &mut D,
+        data: &[u8],
+        count: usize,
+        max_transaction_len: usize,
+    ) -> Result<usize, FifoError<D::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: WritableRegister,
+    {
+        let required = crate::fifo_len::<<R as ToByteArray>::Array, _>(count, data.len())?;
+        let len = <R as ToByteArray>::Array::LEN;
+
+        let reg_id = R::writeable_id().to_id_bytes();
+        let per_transaction =
+            crate::fifo_samples_per_transaction(reg_id.as_ref().len(), len, max_transaction_len)?;
+
+        if required == 0 {
+            return Ok(0);
+        }
+        let data = &data[..required];
+
+        for (index, samples) in data.chunks(per_transaction * len).enumerate() {
+            device
+                .transaction(&mut [
+                    embedded_hal::spi::Operation::Write(reg_id.as_ref()),
+                    embedded_hal::spi::Operation::Write(samples),
+                ])
+                .map_err(|error| FifoError::BusError {
+                    error,
+                    samples: index * per_transaction,
+                })?;
+        }
+
+        trace!(
+            "spi: write fifo: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            data
+        );
+
+        Ok(count)
+    }
+
+    /// Write a register value to a SPI device using the given addressing convention.
+    ///
+    /// Blocking variant of `r#async::write_register_with`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+    /// # use regiface::{register, spi::{self, SpiAddressing}, WritableRegister, ToByteArray};
+    /// # #[register(0xA0u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct Ctrl(u8);
+    /// # impl ToByteArray for Ctrl {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.0]) }
+    /// # }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         assert!(matches!(ops, [Operation::Write([0x20]), Operation::Write([0x67])]));
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// const ADDRESSING: SpiAddressing = SpiAddressing::read_flag(0x80);
+    ///
+    /// // The read flag is cleared, transmitting 0x20 as the register ID
+    /// spi::blocking::write_register_with(&mut device, ADDRESSING, Ctrl(0x67)).unwrap();
+    /// ```
+    pub fn write_register_with<D, R>(
+        device: &mut D,
+        addressing: SpiAddressing,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: WritableRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = addressing.write.apply(R::writeable_id());
+
+        device
+            .transaction(&mut [
+                embedded_hal::spi::Operation::Write(reg_id.as_ref()),
+                embedded_hal::spi::Operation::Write(buf.as_ref()),
+            ])
+            .map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "spi: write register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        Ok(())
+    }
+
+    /// Write a register value to a SPI device using 16-bit words.
+    ///
+    /// Blocking variant of `r#async::write_register_word`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+    /// # use regiface::{register, spi::{self, WordLayout}, WritableRegister, ToByteArray};
+    /// # #[register(0x3u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct DacOutput(u16);
+    /// # impl ToByteArray for DacOutput {
+    /// #     type Array = [u8; 2];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok(self.0.to_be_bytes()) }
+    /// # }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice<u16> for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u16>]) -> Result<(), Self::Error> {
+    /// #         assert!(matches!(ops, [Operation::Write([0x3800])]));
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// // The register ID occupies bits 15..12, the register value occupies bits 11..0
+    /// const LAYOUT: WordLayout = WordLayout::new(12, 4, 0, 12);
+    ///
+    /// // Transmits 0x3800 as the write frame
+    /// spi::blocking::write_register_word(&mut device, LAYOUT, DacOutput(0x800)).unwrap();
+    /// ```
+    pub fn write_register_word<D, R>(
+        device: &mut D,
+        layout: WordLayout,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice<u16>,
+        R: WritableRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = R::writeable_id().to_id_bytes();
+        let frame = [layout.write_frame(reg_id.as_ref(), buf.as_ref())];
+
+        device.write(&frame).map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "spi: write register: id={:02X?} frame={:04X?}",
+            reg_id.as_ref(),
+            frame[0]
+        );
+
+        Ok(())
+    }
+
+    /// Write an instance of an indexed register value to a SPI device.
+    ///
+    /// Blocking variant of `r#async::write_register_at`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::spi::SpiDevice;
+    /// # use regiface::{register, spi, IndexedRegister, WritableRegister, ToByteArray};
+    /// # #[register(0x06u8)]
+    /// # #[derive(WritableRegister)]
+    /// # struct LedOnOff { on: u16, off: u16 }
+    /// # impl IndexedRegister for LedOnOff {
+    /// #     type Index = u8;
+    /// #     fn indexed_id(channel: Self::Index) -> Self::IdType { Self::ID + 4 * channel }
+    /// # }
+    /// # impl ToByteArray for LedOnOff {
+    /// #     type Array = [u8; 4];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    /// #         let [on_l, on_h] = self.on.to_le_bytes();
+    /// #         let [off_l, off_h] = self.off.to_le_bytes();
+    /// #         Ok([on_l, on_h, off_l, off_h])
+    /// #     }
+    /// # }
+    /// fn set_all_channels<D: SpiDevice>(device: &mut D, off: u16) {
+    ///     for channel in 0..16 {
+    ///         spi::blocking::write_register_at(device, channel, LedOnOff { on: 0, off }).unwrap();
+    ///     }
+    /// }
+    /// ```
+    pub fn write_register_at<D, R>(
+        device: &mut D,
+        index: R::Index,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: WritableRegister + IndexedRegister,
+    {
+        let buf = register
+            .to_register_bytes()
+            .map_err(WriteRegisterError::SerializationError)?;
+
+        let reg_id = R::writeable_indexed_id(index).to_id_bytes();
+
+        device
+            .transaction(&mut [
+                embedded_hal::spi::Operation::Write(reg_id.as_ref()),
+                embedded_hal::spi::Operation::Write(buf.as_ref()),
+            ])
+            .map_err(WriteRegisterError::BusError)?;
+
+        trace!(
+            "spi: write register: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            buf.as_ref()
+        );
+
+        Ok(())
+    }
+
+    /// Write a contiguous block of registers to a SPI device in a single transaction.
+    ///
+    /// Blocking variant of `r#async::write_registers_burst`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::spi::SpiDevice;
+    /// # use regiface::{register, spi, WritableRegister, ToByteArray};
+    /// // CTRL1_XL through CTRL4_C
+    /// #[register(0x10u8)]
+    /// #[derive(WritableRegister)]
+    /// struct ControlBlock {
+    ///     ctrl: [u8; 4],
+    /// }
+    ///
+    /// impl ToByteArray for ControlBlock {
+    ///     type Array = [u8; 4];
+    ///     type Error = core::convert::Infallible;
+    ///
+    ///     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+    ///         Ok(self.ctrl)
+    ///     }
+    /// }
+    ///
+    /// fn configure<D: SpiDevice>(device: &mut D) {
+    ///     let block = ControlBlock { ctrl: [0x60, 0x00, 0x00, 0x04] };
+    ///     spi::blocking::write_registers_burst(device, block).unwrap();
+    /// }
+    /// ```
+    pub fn write_registers_burst<D, R>(
+        device: &mut D,
+        register: R,
+    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: WritableRegister,
+    {
+        write_register(device, register)
+    }
+
+    /// Write a block of bytes to consecutive registers of a SPI device in a single transaction.
+    ///
+    /// Blocking variant of `r#async::write_registers_burst_raw`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::spi::SpiDevice;
+    /// # use regiface::spi;
+    /// fn load_coefficients<D: SpiDevice>(device: &mut D, coefficients: &[u8]) {
+    ///     spi::blocking::write_registers_burst_raw(device, 0x30u8, coefficients, Some(0x40)).unwrap();
+    /// }
+    /// ```
+    pub fn write_registers_burst_raw<D, I>(
+        device: &mut D,
+        start_id: I,
+        payload: &[u8],
+        auto_increment: Option<u8>,
+    ) -> Result<(), D::Error>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        I: id::Id,
+    {
+        let reg_id = id::to_bytes_with_flag(start_id, auto_increment);
+
+        device.transaction(&mut [
+            embedded_hal::spi::Operation::Write(reg_id.as_ref()),
+            embedded_hal::spi::Operation::Write(payload),
+        ])?;
+
+        trace!(
+            "spi: write registers burst: id={:02X?} bytes={:02X?}",
+            reg_id.as_ref(),
+            payload
+        );
+
+        Ok(())
+    }
+
+    /// Write a register value to a SPI device and verify it by reading the register back.
+    ///
+    /// Blocking variant of `r#async::write_register_verify`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use embedded_hal::spi::SpiDevice;
+    /// # use regiface::{register, spi, ReadableRegister, WritableRegister, FromByteArray, ToByteArray};
+    /// #[register(42u8)]
+    /// #[derive(ReadableRegister, WritableRegister)]
+    /// # struct ConfigRegister;
+    /// # impl FromByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { Ok(Self) }
+    /// # }
+    /// # impl ToByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([0]) }
+    /// # }
+    /// fn configure<D: SpiDevice>(device: &mut D) {
+    ///     spi::blocking::write_register_verify(device, ConfigRegister{ /* ... */}).unwrap();
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn write_register_verify<D, R>(
+        device: &mut D,
+        register: R,
+    ) -> Result<(), WriteVerifyError<D::Error, <R as ToByteArray>::Error, <R as ToByteArray>::Array>>
+    where
+        D: embedded_hal::spi::SpiDevice,
+        R: WritableRegister + ReadableRegister,
+    {
+        let written = register
+            .to_register_bytes()
+            .map_err(WriteVerifyError::SerializationError)?;
+        let mut read_back = <R as ToByteArray>::Array::new();
 
-        // Register ID types have compiler enforced infallible byte conversions, thus this unwrap is safe
-        let reg_id = R::readable_id().to_bytes().unwrap();
+        let write_id = R::writeable_id().to_id_bytes();
+        let read_id = R::readable_id().to_id_bytes();
 
         device
             .transaction(&mut [
-                embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
-                embedded_hal_async::spi::Operation::Read(buf.as_mut()),
+                embedded_hal::spi::Operation::Write(write_id.as_ref()),
+                embedded_hal::spi::Operation::Write(written.as_ref()),
             ])
-            .await
-            .map_err(ReadRegisterError::BusError)?;
+            .map_err(WriteVerifyError::BusError)?;
 
-        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+        let mut scratch = [0; MAX_DUMMY_BYTES];
+        let operations: &mut [embedded_hal::spi::Operation<'_, u8>] =
+            match dummy_operation::<R>(&mut scratch) {
+                None => &mut [
+                    embedded_hal::spi::Operation::Write(read_id.as_ref()),
+                    embedded_hal::spi::Operation::Read(read_back.as_mut()),
+                ],
+                Some(dummy) => &mut [
+                    embedded_hal::spi::Operation::Write(read_id.as_ref()),
+                    dummy,
+                    embedded_hal::spi::Operation::Read(read_back.as_mut()),
+                ],
+            };
+
+        device
+            .transaction(operations)
+            .map_err(WriteVerifyError::BusError)?;
+
+        trace!(
+            "spi: write register verify: id={:02X?} bytes={:02X?} read_back={:02X?}",
+            write_id.as_ref(),
+            written.as_ref(),
+            read_back.as_ref()
+        );
+
+        if written.as_ref() == read_back.as_ref() {
+            Ok(())
+        } else {
+            Err(WriteVerifyError::VerificationFailed { written, read_back })
+        }
     }
 
-    /// Write a register value to a SPI device.
-    ///
-    /// This function performs a SPI transaction, sending both the register ID
-    /// and the serialized register value. The operation is atomic, using the device's
-    /// transaction capability to ensure both writes occur without interruption.
+    /// Write a register value to a SPI device and verify it by reading the register back.
     ///
-    /// # Parameters
-    /// * `device` - The SPI device to communicate with
-    /// * `register` - The register value to write
-    ///
-    /// # Errors
-    /// * `WriteRegisterError::BusError` - Communication with the device failed
-    /// * `WriteRegisterError::SerializationError` - Failed to convert register value to bytes
+    /// Blocking variant of `r#async::write_register_verified`.
+    /// See the async function documentation for detailed behavior description.
     ///
     /// # Example
     /// ```no_run
-    /// # use embedded_hal_async::spi::SpiDevice;
-    /// # use regiface::{register, spi, WritableRegister, ToByteArray};
-    /// # #[register(1u8)]
-    /// # #[derive(WritableRegister)]
-    /// # struct ConfigRegister{};
+    /// # use embedded_hal::spi::SpiDevice;
+    /// # use regiface::{register, spi, ReadableRegister, WritableRegister, FromByteArray, ToByteArray};
+    /// #[register(42u8)]
+    /// #[derive(ReadableRegister, WritableRegister, Clone, PartialEq, Debug)]
+    /// # struct ConfigRegister { value: u8 }
+    /// # impl FromByteArray for ConfigRegister {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = ();
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self { value: bytes[0] }) }
+    /// # }
     /// # impl ToByteArray for ConfigRegister {
     /// #     type Array = [u8; 1];
     /// #     type Error = ();
-    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([0]) }
+    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.value]) }
     /// # }
-    /// async fn configure<D: SpiDevice>(device: &mut D) {
-    ///     spi::r#async::write_register(device, ConfigRegister{/* ... */}).await.unwrap();
+    /// fn configure<D: SpiDevice>(device: &mut D) {
+    ///     spi::blocking::write_register_verified(device, ConfigRegister { value: 0x0F }).unwrap();
     /// }
     /// ```
-    pub async fn write_register<D, R>(
+    #[allow(clippy::type_complexity)]
+    pub fn write_register_verified<D, R>(
         device: &mut D,
         register: R,
-    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+    ) -> Result<(), VerifyError<D::Error, <R as ToByteArray>::Error, <R as FromByteArray>::Error, R>>
     where
-        D: embedded_hal_async::spi::SpiDevice,
-        R: WritableRegister,
+        D: embedded_hal::spi::SpiDevice,
+        R: WritableRegister + ReadableRegister + Clone + PartialEq,
     {
-        let buf = register
-            .to_bytes()
-            .map_err(WriteRegisterError::SerializationError)?;
+        write_register(device, register.clone())?;
+        let read: R = read_register(device)?;
 
-        // Register ID types have compiler enforced infallible byte conversions, thus this unwrap is safe
-        let reg_id = R::writeable_id().to_bytes().unwrap();
-
-        device
-            .transaction(&mut [
-                embedded_hal_async::spi::Operation::Write(reg_id.as_ref()),
-                embedded_hal_async::spi::Operation::Write(buf.as_ref()),
-            ])
-            .await
-            .map_err(WriteRegisterError::BusError)
+        if read == register {
+            Ok(())
+        } else {
+            Err(VerifyError::Mismatch {
+                wrote: register,
+                read,
+            })
+        }
     }
 
     /// Invoke a command on a SPI device and receive its response.
     ///
-    /// This function performs a complete command transaction:
-    /// 1. Sends the command ID
-    /// 2. Sends the serialized command parameters
-    /// 3. Reads the command response
-    ///
-    /// The entire operation is atomic, using the device's transaction capability to
-    /// ensure all steps occur without interruption.
-    ///
-    /// # Parameters
-    /// * `device` - The SPI device to communicate with
-    /// * `cmd` - The command to invoke
-    ///
-    /// # Errors
-    /// * `CommandError::BusError` - Communication with the device failed
-    /// * `CommandError::SerializationError` - Failed to convert command parameters to bytes
-    /// * `CommandError::DeserializationError` - Failed to convert received bytes into response parameters
+    /// Blocking variant of `r#async::invoke_command`.
+    /// See the async function documentation for detailed behavior description.
     ///
     /// # Example
     /// ```no_run
-    /// # use embedded_hal_async::spi::SpiDevice;
+    /// # use embedded_hal::spi::SpiDevice;
     /// # use regiface::{NoParameters, spi, Command, FromByteArray, ToByteArray};
-    /// # struct SelfTestCommand;
+    /// # struct SelfTestCommand{};
     /// # struct SelfTestResponse;
     /// # impl Command for SelfTestCommand {
     /// #     type IdType = u8;
@@ -155,14 +4961,61 @@ pub mod r#async {
     /// # impl FromByteArray for SelfTestResponse {
     /// #     type Array = [u8; 1];
     /// #     type Error = ();
-    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { Ok(Self) }
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { todo!() }
     /// # }
-    /// async fn perform_self_test<D: SpiDevice>(device: &mut D) {
-    ///     let result: SelfTestResponse = spi::r#async::invoke_command(device, SelfTestCommand).await.unwrap();
+    /// fn perform_self_test<D: SpiDevice>(device: &mut D) {
+    ///     let result: SelfTestResponse = spi::blocking::invoke_command(device, SelfTestCommand { /* ... */}).unwrap();
     /// }
     /// ```
+    ///
+    /// Command parameters or responses with a length of zero are omitted from the transaction:
+    ///
+    /// ```
+    /// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+    /// # use regiface::{spi, Command, NoParameters};
+    /// # macro_rules! command {
+    /// #     ($name:ident, $id:literal, $params:ty, $resp:ty) => {
+    /// #         struct $name($params);
+    /// #         impl Command for $name {
+    /// #             type IdType = u8;
+    /// #             type CommandParameters = $params;
+    /// #             type ResponseParameters = $resp;
+    /// #             fn id() -> Self::IdType { $id }
+    /// #             fn invoking_parameters(self) -> Self::CommandParameters { self.0 }
+    /// #         }
+    /// #     };
+    /// # }
+    /// # command!(Reset, 0x01, NoParameters, NoParameters);
+    /// # command!(SetMode, 0x02, u8, NoParameters);
+    /// # command!(GetStatus, 0x03, NoParameters, u8);
+    /// # command!(Exchange, 0x04, u8, u8);
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         match ops {
+    /// #             [Operation::Write([0x01])] => {}
+    /// #             [Operation::Write([0x02]), Operation::Write([0x0A])] => {}
+    /// #             [Operation::Write([0x03]), Operation::Read([_])] => {}
+    /// #             [Operation::Write([0x04]), Operation::Write([0x0B]), Operation::Read([_])] => {}
+    /// #             _ => panic!("unexpected operations"),
+    /// #         }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// # let device = &mut device;
+    /// // [Write([0x01])]
+    /// spi::blocking::invoke_command(device, Reset(NoParameters::default())).unwrap();
+    /// // [Write([0x02]), Write([0x0A])]
+    /// spi::blocking::invoke_command(device, SetMode(0x0A)).unwrap();
+    /// // [Write([0x03]), Read([_])]
+    /// spi::blocking::invoke_command(device, GetStatus(NoParameters::default())).unwrap();
+    /// // [Write([0x04]), Write([0x0B]), Read([_])]
+    /// spi::blocking::invoke_command(device, Exchange(0x0B)).unwrap();
+    /// ```
     #[allow(clippy::type_complexity)]
-    pub async fn invoke_command<D, C>(
+    pub fn invoke_command<D, C>(
         device: &mut D,
         cmd: C,
     ) -> Result<
@@ -174,148 +5027,383 @@ pub mod r#async {
         >,
     >
     where
-        D: embedded_hal_async::spi::SpiDevice,
+        D: embedded_hal::spi::SpiDevice,
         C: Command,
     {
-        let cmd_buf = cmd
+        check_response::<C>();
+
+        let mut cmd_buf = cmd
             .invoking_parameters()
             .to_bytes()
             .map_err(CommandError::SerializationError)?;
         let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
 
-        // Register ID types have compiler enforced infallible byte conversions, thus this unwrap is safe
-        let reg_id = unsafe { C::id().to_bytes().unwrap_unchecked() };
+        let mut status = [0; 1];
+        let status_buf: &mut [u8] = if C::STATUS_BYTE { &mut status } else { &mut [] };
+
+        let mut reg_id = C::id().to_id_bytes();
+        let (id, parameters) = merge_id::<C>(reg_id.as_mut(), cmd_buf.as_mut());
+        let (mut operations, len) = command_operations(
+            C::preamble(),
+            id,
+            C::sub_id(),
+            parameters,
+            C::postamble(),
+            status_buf,
+            resp_buf.as_mut(),
+        );
 
         device
-            .transaction(&mut [
-                embedded_hal::spi::Operation::Write(reg_id.as_ref()),
-                embedded_hal::spi::Operation::Write(cmd_buf.as_ref()),
-                embedded_hal::spi::Operation::Read(resp_buf.as_mut()),
-            ])
-            .await
+            .transaction(&mut operations[..len])
             .map_err(CommandError::BusError)?;
 
+        trace!(
+            "spi: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            reg_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
+        if status[0] != 0 {
+            return Err(CommandError::DeviceError(status[0]));
+        }
+
+        C::validate_response(resp_buf.as_ref()).map_err(CommandError::ValidationError)?;
         C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
     }
-}
-
-pub mod blocking {
-    use super::*;
 
-    /// Read a register value from a SPI device.
+    /// Invoke a command on a SPI device, receiving its response within the same full-duplex frame.
     ///
-    /// Blocking variant of [`read_register`](crate::spi::async::read_register).
+    /// Blocking variant of `r#async::invoke_command_duplex`.
     /// See the async function documentation for detailed behavior description.
     ///
     /// # Example
-    /// ```no_run
-    /// # use embedded_hal::spi::SpiDevice;
-    /// # use regiface::{register, spi, ReadableRegister, FromByteArray};
-    /// # #[register(42u8)]
-    /// # #[derive(ReadableRegister)]
-    /// # struct TemperatureRegister;
-    /// # impl FromByteArray for TemperatureRegister {
-    /// #     type Array = [u8; 2];
-    /// #     type Error = ();
-    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> {todo!()}
+    /// ```
+    /// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+    /// # use regiface::{spi, Command};
+    /// # struct SetSpeed(u16);
+    /// # impl Command for SetSpeed {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = u16;
+    /// #     type ResponseParameters = u16;
+    /// #     fn id() -> Self::IdType { 0x05 }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { self.0 }
     /// # }
-    /// fn read_temp<D: SpiDevice>(device: &mut D) {
-    ///     let temp: TemperatureRegister = spi::blocking::read_register(device).unwrap();
-    /// }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         let [Operation::Transfer(rx, [0x05, 0x12, 0x34])] = ops else {
+    /// #             panic!("unexpected operations");
+    /// #         };
+    /// #         rx.copy_from_slice(&[0xFF, 0x0A, 0xBC]);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// // Transfers [0x05, 0x12, 0x34] while receiving [0xFF, 0x0A, 0xBC]
+    /// let previous = spi::blocking::invoke_command_duplex(&mut device, SetSpeed(0x1234)).unwrap();
+    /// assert_eq!(previous, 0x0ABC);
     /// ```
-    pub fn read_register<D, R>(device: &mut D) -> Result<R, ReadRegisterError<D::Error, R::Error>>
+    #[allow(clippy::type_complexity)]
+    pub fn invoke_command_duplex<D, C>(
+        device: &mut D,
+        cmd: C,
+    ) -> Result<
+        C::ResponseParameters,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
     where
         D: embedded_hal::spi::SpiDevice,
-        R: ReadableRegister,
+        C: Command,
     {
-        let mut buf = <R as FromByteArray>::Array::new();
+        check_response::<C>();
+        const {
+            assert!(
+                <C::IdType as id::Id>::Array::LEN
+                    + <C::CommandParameters as ToByteArray>::Array::LEN
+                    <= MAX_TRANSFER_BYTES,
+                "the command ID and parameters exceed MAX_TRANSFER_BYTES"
+            );
+            assert!(
+                C::STATUS_BYTE as usize + <C::ResponseParameters as FromByteArray>::Array::LEN
+                    <= MAX_TRANSFER_BYTES,
+                "the status byte and response exceed MAX_TRANSFER_BYTES"
+            );
+        };
+
+        let mut cmd_buf = cmd
+            .invoking_parameters()
+            .to_bytes()
+            .map_err(CommandError::SerializationError)?;
+
+        let mut reg_id = C::id().to_id_bytes();
+        let (id, parameters) = merge_id::<C>(reg_id.as_mut(), cmd_buf.as_mut());
+
+        let sub_id = C::sub_id();
+        let write_len = id.len() + sub_id.len() + parameters.len();
+        assert!(
+            write_len <= MAX_TRANSFER_BYTES,
+            "the command ID, sub-ID, and parameters exceed MAX_TRANSFER_BYTES"
+        );
+        let read_len =
+            usize::from(C::STATUS_BYTE) + <C::ResponseParameters as FromByteArray>::Array::LEN;
+        let len = write_len.max(read_len);
 
-        // Register ID types have compiler enforced infallible byte conversions, thus this unwrap is safe
-        let reg_id = unsafe { R::readable_id().to_bytes().unwrap_unchecked() };
+        let mut tx = [0; MAX_TRANSFER_BYTES];
+        let mut rx = [0; MAX_TRANSFER_BYTES];
+        tx[..id.len()].copy_from_slice(id);
+        tx[id.len()..id.len() + sub_id.len()].copy_from_slice(sub_id);
+        tx[id.len() + sub_id.len()..write_len].copy_from_slice(parameters);
+
+        // The preamble and postamble are skipped when empty
+        let mut operations = [
+            Operation::Write(C::preamble()),
+            Operation::Transfer(&mut rx[..len], &tx[..len]),
+            Operation::Write(C::postamble()),
+        ];
+        let start = usize::from(C::preamble().is_empty());
+        let end = 3 - usize::from(C::postamble().is_empty());
 
         device
-            .transaction(&mut [
-                embedded_hal::spi::Operation::Write(reg_id.as_ref()),
-                embedded_hal::spi::Operation::Read(buf.as_mut()),
-            ])
-            .map_err(ReadRegisterError::BusError)?;
+            .transaction(&mut operations[start..end])
+            .map_err(CommandError::BusError)?;
 
-        R::from_bytes(buf).map_err(ReadRegisterError::DeserializationError)
+        let (status, response) = rx[len - read_len..len].split_at(usize::from(C::STATUS_BYTE));
+        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+        resp_buf.as_mut().copy_from_slice(response);
+
+        trace!(
+            "spi: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            reg_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
+        if let Some(&status) = status.first().filter(|&&status| status != 0) {
+            return Err(CommandError::DeviceError(status));
+        }
+
+        C::validate_response(resp_buf.as_ref()).map_err(CommandError::ValidationError)?;
+        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
     }
 
-    /// Write a register value to a SPI device.
+    /// Invoke a command on a SPI device and map its response into another type.
     ///
-    /// Blocking variant of [`write_register`](crate::spi::async::write_register).
+    /// Blocking variant of `r#async::invoke_command_map`.
     /// See the async function documentation for detailed behavior description.
     ///
     /// # Example
-    /// ```no_run
-    /// # use embedded_hal::spi::SpiDevice;
-    /// # use regiface::{register, spi, WritableRegister, ToByteArray};
-    /// # #[register(42u8)]
-    /// # #[derive(WritableRegister)]
-    /// # struct ConfigRegister{};
-    /// # impl ToByteArray for ConfigRegister {
-    /// #     type Array = [u8; 1];
+    /// ```
+    /// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+    /// # use regiface::{spi, Command, FromByteArray, NoParameters};
+    /// # struct ReadTemperature;
+    /// # impl Command for ReadTemperature {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = u16;
+    /// #     fn id() -> Self::IdType { 0xE3 }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         if let [Operation::Write([0xE3]), Operation::Read(buf)] = ops {
+    /// #             buf.copy_from_slice(&[0x01, 0xF4]);
+    /// #         }
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # let mut device = Device;
+    /// // The device responds with the raw value 500, in tenths of a degree
+    /// let celsius = spi::blocking::invoke_command_map(&mut device, ReadTemperature, |raw| {
+    ///     f32::from(raw) / 10.0
+    /// })
+    /// .unwrap();
+    /// assert_eq!(celsius, 50.0);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn invoke_command_map<D, C, T, F>(
+        device: &mut D,
+        cmd: C,
+        f: F,
+    ) -> Result<
+        T,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
+    where
+        D: embedded_hal::spi::SpiDevice,
+        C: Command,
+        F: FnOnce(C::ResponseParameters) -> T,
+    {
+        invoke_command(device, cmd).map(f)
+    }
+
+    /// Invoke a command on a SPI device, waiting for the command's response delay before
+    /// reading its response.
+    ///
+    /// Blocking variant of `r#async::invoke_command_delayed`.
+    /// See the async function documentation for detailed behavior description.
+    ///
+    /// # Example
+    /// ```
+    /// # use embedded_hal::{delay::DelayNs, spi::{ErrorType, Operation, SpiDevice}};
+    /// # use regiface::{NoParameters, spi, Command, FromByteArray};
+    /// # struct MeasureHighPrecision;
+    /// # struct Measurement;
+    /// # impl Command for MeasureHighPrecision {
+    /// #     type IdType = u8;
+    /// #     type CommandParameters = NoParameters;
+    /// #     type ResponseParameters = Measurement;
+    /// #     const RESPONSE_DELAY_US: u32 = 8_300;
+    /// #     fn id() -> Self::IdType { 0xFD }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { NoParameters::default() }
+    /// # }
+    /// # impl FromByteArray for Measurement {
+    /// #     type Array = [u8; 6];
     /// #     type Error = ();
-    /// #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([0]) }
+    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { Ok(Self) }
     /// # }
-    /// fn configure<D: SpiDevice>(device: &mut D) {
-    ///     spi::blocking::write_register(device, ConfigRegister{ /* ... */}).unwrap();
+    /// # #[derive(Default)]
+    /// # struct Device { transactions: usize }
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         match (self.transactions, ops) {
+    /// #             (0, [Operation::Write([0xFD])]) => {}
+    /// #             (1, [Operation::Read([_, _, _, _, _, _])]) => {}
+    /// #             _ => panic!("unexpected operations"),
+    /// #         }
+    /// #         self.transactions += 1;
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # #[derive(Default)]
+    /// # struct Delay { ns: u32 }
+    /// # impl DelayNs for Delay { fn delay_ns(&mut self, ns: u32) { self.ns += ns; } }
+    /// fn measure<D: SpiDevice, T: DelayNs>(device: &mut D, delay: &mut T) {
+    ///     let result: Measurement = spi::blocking::invoke_command_delayed(device, delay, MeasureHighPrecision).unwrap();
     /// }
+    /// # let mut device = Device::default();
+    /// # let mut delay = Delay::default();
+    /// # measure(&mut device, &mut delay);
+    /// # assert_eq!(device.transactions, 2);
+    /// # assert_eq!(delay.ns, 8_300_000);
     /// ```
-    pub fn write_register<D, R>(
+    #[allow(clippy::type_complexity)]
+    pub fn invoke_command_delayed<D, T, C>(
         device: &mut D,
-        register: R,
-    ) -> Result<(), WriteRegisterError<D::Error, R::Error>>
+        delay: &mut T,
+        cmd: C,
+    ) -> Result<
+        C::ResponseParameters,
+        CommandError<
+            D::Error,
+            <C::CommandParameters as ToByteArray>::Error,
+            <C::ResponseParameters as FromByteArray>::Error,
+        >,
+    >
     where
         D: embedded_hal::spi::SpiDevice,
-        R: WritableRegister,
+        T: embedded_hal::delay::DelayNs,
+        C: Command,
     {
-        let buf = register
+        check_response::<C>();
+
+        if C::RESPONSE_DELAY_US == 0 {
+            return invoke_command(device, cmd);
+        }
+
+        let mut cmd_buf = cmd
+            .invoking_parameters()
             .to_bytes()
-            .map_err(WriteRegisterError::SerializationError)?;
+            .map_err(CommandError::SerializationError)?;
+        let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
+
+        let mut status = [0; 1];
+        let status_buf: &mut [u8] = if C::STATUS_BYTE { &mut status } else { &mut [] };
 
-        // Register ID types have compiler enforced infallible byte conversions, thus this unwrap is safe
-        let reg_id = unsafe { R::writeable_id().to_bytes().unwrap_unchecked() };
+        let mut reg_id = C::id().to_id_bytes();
+        let (id, parameters) = merge_id::<C>(reg_id.as_mut(), cmd_buf.as_mut());
+        let (mut operations, len) = command_operations(
+            C::preamble(),
+            id,
+            C::sub_id(),
+            parameters,
+            C::postamble(),
+            &mut [],
+            &mut [],
+        );
 
         device
-            .transaction(&mut [
-                embedded_hal::spi::Operation::Write(reg_id.as_ref()),
-                embedded_hal::spi::Operation::Write(buf.as_ref()),
-            ])
-            .map_err(WriteRegisterError::BusError)
+            .transaction(&mut operations[..len])
+            .map_err(CommandError::BusError)?;
+
+        delay.delay_us(C::RESPONSE_DELAY_US);
+
+        let (mut operations, len) = read_operations(status_buf, resp_buf.as_mut());
+        if len > 0 {
+            device
+                .transaction(&mut operations[..len])
+                .map_err(CommandError::BusError)?;
+        }
+
+        trace!(
+            "spi: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            reg_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
+        if status[0] != 0 {
+            return Err(CommandError::DeviceError(status[0]));
+        }
+
+        C::validate_response(resp_buf.as_ref()).map_err(CommandError::ValidationError)?;
+        C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
     }
 
-    /// Invoke a command on a SPI device and receive its response.
+    /// Invoke a [`DynamicCommand`] on a SPI device and receive its response.
     ///
-    /// Blocking variant of [`invoke_command`](crate::spi::async::invoke_command).
+    /// Blocking variant of `r#async::invoke_dynamic_command`.
     /// See the async function documentation for detailed behavior description.
     ///
     /// # Example
-    /// ```no_run
-    /// # use embedded_hal::spi::SpiDevice;
-    /// # use regiface::{NoParameters, spi, Command, FromByteArray, ToByteArray};
-    /// # struct SelfTestCommand{};
-    /// # struct SelfTestResponse;
-    /// # impl Command for SelfTestCommand {
+    /// ```
+    /// # use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+    /// # use regiface::{spi, DynamicCommand, NoParameters};
+    /// # struct SetChannel { channel: u8, value: u16 }
+    /// # impl DynamicCommand for SetChannel {
     /// #     type IdType = u8;
-    /// #     type CommandParameters = NoParameters;
-    /// #     type ResponseParameters = SelfTestResponse;
-    /// #     fn id() -> Self::IdType { 0xF0 }
-    /// #     fn invoking_parameters(self) -> Self::CommandParameters { todo!() }
+    /// #     type CommandParameters = u16;
+    /// #     type ResponseParameters = NoParameters;
+    /// #     fn id(&self) -> Self::IdType { 0x30 | self.channel }
+    /// #     fn invoking_parameters(self) -> Self::CommandParameters { self.value }
     /// # }
-    /// # impl FromByteArray for SelfTestResponse {
-    /// #     type Array = [u8; 1];
-    /// #     type Error = ();
-    /// #     fn from_bytes(_: Self::Array) -> Result<Self, Self::Error> { todo!() }
+    /// # struct Device;
+    /// # impl ErrorType for Device { type Error = core::convert::Infallible; }
+    /// # impl SpiDevice for Device {
+    /// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+    /// #         assert!(matches!(ops, [Operation::Write([0x32]), Operation::Write([0x0F, 0xFF])]));
+    /// #         Ok(())
+    /// #     }
     /// # }
-    /// fn perform_self_test<D: SpiDevice>(device: &mut D) {
-    ///     let result: SelfTestResponse = spi::blocking::invoke_command(device, SelfTestCommand { /* ... */}).unwrap();
-    /// }
+    /// # let mut device = Device;
+    /// # let device = &mut device;
+    /// // [Write([0x32]), Write([0x0F, 0xFF])]
+    /// spi::blocking::invoke_dynamic_command(device, SetChannel { channel: 2, value: 0x0FFF }).unwrap();
     /// ```
     #[allow(clippy::type_complexity)]
-    pub fn invoke_command<D, C>(
+    pub fn invoke_dynamic_command<D, C>(
         device: &mut D,
         cmd: C,
     ) -> Result<
@@ -328,25 +5416,36 @@ pub mod blocking {
     >
     where
         D: embedded_hal::spi::SpiDevice,
-        C: Command,
+        C: DynamicCommand,
     {
+        let reg_id = cmd.id().to_id_bytes();
         let cmd_buf = cmd
             .invoking_parameters()
             .to_bytes()
             .map_err(CommandError::SerializationError)?;
         let mut resp_buf = <C::ResponseParameters as FromByteArray>::Array::new();
 
-        // Register ID types have compiler enforced infallible byte conversions, thus this unwrap is safe
-        let reg_id = unsafe { C::id().to_bytes().unwrap_unchecked() };
+        let (mut operations, len) = command_operations(
+            &[],
+            reg_id.as_ref(),
+            &[],
+            cmd_buf.as_ref(),
+            &[],
+            &mut [],
+            resp_buf.as_mut(),
+        );
 
         device
-            .transaction(&mut [
-                embedded_hal::spi::Operation::Write(reg_id.as_ref()),
-                embedded_hal::spi::Operation::Write(cmd_buf.as_ref()),
-                embedded_hal::spi::Operation::Read(resp_buf.as_mut()),
-            ])
+            .transaction(&mut operations[..len])
             .map_err(CommandError::BusError)?;
 
+        trace!(
+            "spi: invoke command: id={:02X?} parameters={:02X?} response={:02X?}",
+            reg_id.as_ref(),
+            cmd_buf.as_ref(),
+            resp_buf.as_ref()
+        );
+
         C::ResponseParameters::from_bytes(resp_buf).map_err(CommandError::DeserializationError)
     }
 }
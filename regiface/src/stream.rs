@@ -0,0 +1,50 @@
+//! A [`Stream`] repeatedly performing a sampling operation, used by the `poll_stream` functions
+//! of the bus modules
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use futures_core::Stream;
+
+/// A [`Stream`] yielding the result of a sampling future, then creating the next future from the
+/// state the previous one returned
+///
+/// Returning the state, such as the bus and delay, from each future allows every future to borrow
+/// it in turn, without the sampler borrowing from itself.
+pub(crate) struct Sampler<F, N> {
+    sample: F,
+    next: N,
+}
+
+impl<F, N> Sampler<F, N> {
+    /// Create a new [`Sampler`] performing `sample` first, then each future created by `next`
+    pub(crate) fn new<I, S>(sample: F, next: N) -> Self
+    where
+        F: Future<Output = (I, S)>,
+        N: FnMut(S) -> F,
+    {
+        Self { sample, next }
+    }
+}
+
+impl<F, N, I, S> Stream for Sampler<F, N>
+where
+    F: Future<Output = (I, S)>,
+    N: FnMut(S) -> F,
+{
+    type Item = I;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: `sample` is structurally pinned. It is never moved out of the sampler, only
+        // replaced in place with `Pin::set`, and `next` is never pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        let mut sample = unsafe { Pin::new_unchecked(&mut this.sample) };
+
+        let (item, state) = ready!(sample.as_mut().poll(cx));
+        sample.set((this.next)(state));
+        Poll::Ready(Some(item))
+    }
+}
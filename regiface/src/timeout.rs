@@ -0,0 +1,115 @@
+//! A utility for bounding the duration of async register operations
+//!
+//! The async functions provided by the [`i2c`](crate::i2c), [`spi`](crate::spi), and
+//! [`qspi`](crate::qspi) modules await the HAL without any bound on how long a transaction may take,
+//! thus a device that never responds, or a bus that has locked up, may stall a task indefinitely.
+//! [`with_timeout`] races any such operation against a delay, surfacing a
+//! [`TimeoutError::Elapsed`] if the delay completes first. The async [`i2c`](crate::i2c) and
+//! [`spi`](crate::spi) modules additionally provide `read_register_timeout`,
+//! `write_register_timeout`, and `invoke_command_timeout` for the most common operations.
+//!
+//! The delay is provided through the [`DelayNs`](embedded_hal_async::delay::DelayNs) trait, thus
+//! any async timer with an `embedded-hal-async` implementation, such as `embassy-time`, may be used
+//! without tying this crate to a specific executor.
+
+use core::{
+    future::{poll_fn, Future},
+    pin::pin,
+    task::Poll,
+};
+
+use crate::errors::TimeoutError;
+
+/// Await an operation, abandoning it if it does not complete within the given number of
+/// microseconds.
+///
+/// The operation and delay are polled together, with the operation polled first. If the operation
+/// completes, its result is returned, with any error wrapped in [`TimeoutError::Error`]. If the
+/// delay completes first, the operation is dropped and [`TimeoutError::Elapsed`] is returned.
+///
+/// Dropping the operation cancels it at whatever point it had reached, which may be in the middle
+/// of a bus transaction. When [`TimeoutError::Elapsed`] is returned, the state of both the device
+/// and the bus is unknown: a register write may or may not have taken effect, and the bus may
+/// require recovery, such as by resetting the peripheral, before further use.
+///
+/// # Parameters
+/// * `delay` - The delay used to measure the timeout
+/// * `timeout_us` - The maximum number of microseconds to wait for the operation to complete
+/// * `operation` - The operation to perform
+///
+/// # Errors
+/// * `TimeoutError::Error` - The operation completed with an error
+/// * `TimeoutError::Elapsed` - The operation did not complete within `timeout_us` microseconds
+///
+/// # Example
+/// ```
+/// # use embedded_hal_async::delay::DelayNs;
+/// # use regiface::{errors::TimeoutError, timeout::with_timeout};
+/// # struct NoDelay;
+/// # impl DelayNs for NoDelay { async fn delay_ns(&mut self, _: u32) {} }
+/// # let mut delay = NoDelay;
+/// # let fut = core::pin::pin!(async {
+/// // An operation that completes is unaffected by the timeout
+/// let value = with_timeout(&mut delay, 1_000, async { Ok::<_, ()>(0x42) }).await;
+/// assert_eq!(value, Ok(0x42));
+///
+/// // An operation that never completes is abandoned once the timeout elapses
+/// let stalled = core::future::pending::<Result<u8, ()>>();
+/// let value = with_timeout(&mut delay, 1_000, stalled).await;
+/// assert_eq!(value, Err(TimeoutError::Elapsed));
+/// # });
+/// # let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+/// # assert!(core::future::Future::poll(fut, &mut cx).is_ready());
+/// ```
+///
+/// Any of the async bus operations may be wrapped:
+/// ```no_run
+/// # use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+/// # use regiface::{errors::TimeoutError, i2c, register, timeout::with_timeout, ReadableRegister, FromByteArray};
+/// # #[register(0x0Fu8)]
+/// # #[derive(ReadableRegister)]
+/// # struct WhoAmI(u8);
+/// # impl FromByteArray for WhoAmI {
+/// #     type Array = [u8; 1];
+/// #     type Error = ();
+/// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+/// # }
+/// async fn who_am_i<D: I2c<u8>, T: DelayNs>(device: &mut D, delay: &mut T) -> Option<u8> {
+///     let read = i2c::r#async::read_register::<_, _, WhoAmI>(device, 0x19);
+///     match with_timeout(delay, 10_000, read).await {
+///         Ok(id) => Some(id.0),
+///         Err(TimeoutError::Error(_)) => None,
+///         Err(TimeoutError::Elapsed) => {
+///             // The bus may be left mid-transaction and should be recovered here
+///             None
+///         }
+///     }
+/// }
+/// ```
+pub async fn with_timeout<T, E, F, D>(
+    delay: &mut D,
+    timeout_us: u32,
+    operation: F,
+) -> Result<T, TimeoutError<E>>
+where
+    F: Future<Output = Result<T, E>>,
+    D: embedded_hal_async::delay::DelayNs,
+{
+    let mut operation = pin!(operation);
+    let mut elapsed = pin!(delay.delay_us(timeout_us));
+
+    poll_fn(|cx| {
+        if let Poll::Ready(result) = operation.as_mut().poll(cx) {
+            return Poll::Ready(result.map_err(TimeoutError::Error));
+        }
+
+        elapsed.as_mut().poll(cx).map(|()| {
+            trace!(
+                "timeout: operation did not complete within {}us",
+                timeout_us
+            );
+            Err(TimeoutError::Elapsed)
+        })
+    })
+    .await
+}
@@ -0,0 +1,359 @@
+//! A builder for sequences of register operations
+//!
+//! Device bring-up commonly consists of a fixed sequence of register reads, register writes, and
+//! commands. A [`Transaction`] accumulates such a sequence of typed steps, which is then performed
+//! in order with the `execute` method of the `RegisterBus` traits, returning a tuple holding the
+//! result of each step. Every step is performed regardless of whether earlier steps failed.
+//!
+//! Each step is performed as its own bus transaction. To prevent other users of a shared bus from
+//! interleaving with the sequence, the sequence should be executed while holding exclusive access
+//! to the underlying bus, as described in the [`i2c`](crate::i2c#uninterrupted-sequences) module.
+//!
+//! A transaction may hold up to 12 steps.
+//!
+//! # Example
+#![cfg_attr(feature = "blocking", doc = "```")]
+#![cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+//! # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+//! use regiface::{
+//!     device::{blocking::RegisterBus, I2cInterface},
+//!     register,
+//!     transaction::Transaction,
+//!     Command, FromByteArray, NoParameters, ReadableRegister, ToByteArray, WritableRegister,
+//! };
+//!
+//! #[register(0x0Fu8)]
+//! #[derive(ReadableRegister, Debug, PartialEq)]
+//! struct WhoAmI(u8);
+//!
+//! impl FromByteArray for WhoAmI {
+//!     type Array = [u8; 1];
+//!     type Error = core::convert::Infallible;
+//!
+//!     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+//!         Ok(Self(bytes[0]))
+//!     }
+//! }
+//!
+//! #[register(0x20u8)]
+//! #[derive(WritableRegister)]
+//! struct Ctrl(u8);
+//!
+//! impl ToByteArray for Ctrl {
+//!     type Array = [u8; 1];
+//!     type Error = core::convert::Infallible;
+//!
+//!     fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+//!         Ok([self.0])
+//!     }
+//! }
+//!
+//! struct Reset;
+//!
+//! impl Command for Reset {
+//!     type IdType = u8;
+//!     type CommandParameters = NoParameters;
+//!     type ResponseParameters = NoParameters;
+//!
+//!     fn id() -> Self::IdType {
+//!         0x7E
+//!     }
+//!
+//!     fn invoking_parameters(self) -> Self::CommandParameters {
+//!         NoParameters::default()
+//!     }
+//! }
+//! # #[derive(Default)]
+//! # struct Bus(Vec<u8>);
+//! # impl ErrorType for Bus { type Error = core::convert::Infallible; }
+//! # impl I2c for Bus {
+//! #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+//! #         let Operation::Write(id) = &ops[0] else { unreachable!() };
+//! #         self.0.push(id[0]);
+//! #         if let Some(Operation::Read(buf)) = ops.last_mut() { buf[0] = 0x33; }
+//! #         Ok(())
+//! #     }
+//! # }
+//!
+//! let bring_up = Transaction::new()
+//!     .invoke(Reset)
+//!     .read::<WhoAmI>()
+//!     .write(Ctrl(0x47));
+//!
+//! let mut device = I2cInterface::new(Bus::default(), 0x19);
+//! let (reset, id, ctrl) = device.execute(bring_up);
+//!
+//! assert!(reset.is_ok());
+//! assert_eq!(id.unwrap(), WhoAmI(0x33));
+//! assert!(ctrl.is_ok());
+//! # assert_eq!(device.bus_mut().0, [0x7E, 0x0F, 0x20]);
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::{
+    errors::{CommandResult, ReadResult, WriteResult},
+    Command, ReadableRegister, WritableRegister,
+};
+
+/// A sequence of register operations, built by appending steps
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Transaction<S = ()> {
+    pub(crate) steps: S,
+}
+
+impl Transaction {
+    /// Create a new [`Transaction`] without any steps
+    pub fn new() -> Self {
+        Self { steps: () }
+    }
+}
+
+impl<S> Transaction<S> {
+    /// Append a step reading the register `R`
+    pub fn read<R: ReadableRegister>(self) -> Transaction<S::Output>
+    where
+        S: Push<ReadStep<R>>,
+    {
+        Transaction {
+            steps: self.steps.push(ReadStep(PhantomData)),
+        }
+    }
+
+    /// Append a step writing the given register value
+    pub fn write<R: WritableRegister>(self, register: R) -> Transaction<S::Output>
+    where
+        S: Push<WriteStep<R>>,
+    {
+        Transaction {
+            steps: self.steps.push(WriteStep(register)),
+        }
+    }
+
+    /// Append a step invoking the given command
+    pub fn invoke<C: Command>(self, cmd: C) -> Transaction<S::Output>
+    where
+        S: Push<InvokeStep<C>>,
+    {
+        Transaction {
+            steps: self.steps.push(InvokeStep(cmd)),
+        }
+    }
+}
+
+/// A step reading the register `R`
+#[derive(Debug)]
+pub struct ReadStep<R>(PhantomData<R>);
+
+/// A step writing a value of the register `R`
+#[derive(Debug)]
+pub struct WriteStep<R>(R);
+
+/// A step invoking the command `C`
+#[derive(Debug)]
+pub struct InvokeStep<C>(C);
+
+/// A single step of a [`Transaction`]
+pub trait Step {
+    /// The result of performing the step over a bus with error type `E`
+    type Output<E>;
+}
+
+impl<R: ReadableRegister> Step for ReadStep<R> {
+    type Output<E> = ReadResult<R, E>;
+}
+
+impl<R: WritableRegister> Step for WriteStep<R> {
+    type Output<E> = WriteResult<R, E>;
+}
+
+impl<C: Command> Step for InvokeStep<C> {
+    type Output<E> = CommandResult<C, E>;
+}
+
+/// The steps of a [`Transaction`], implemented for tuples of up to 12 [`Step`]s
+pub trait Steps {
+    /// The results of performing each step over a bus with error type `E`
+    type Output<E>;
+}
+
+/// A tuple of steps to which another step may be appended
+pub trait Push<T> {
+    /// The tuple with the step appended
+    type Output;
+
+    /// Append the step to the tuple
+    fn push(self, step: T) -> Self::Output;
+}
+
+macro_rules! impl_push {
+    ($($s:ident),*) => {
+        impl<T, $($s),*> Push<T> for ($($s,)*) {
+            type Output = ($($s,)* T,);
+
+            #[allow(non_snake_case)]
+            fn push(self, step: T) -> Self::Output {
+                let ($($s,)*) = self;
+                ($($s,)* step,)
+            }
+        }
+    };
+}
+
+macro_rules! impl_steps {
+    ($($s:ident),+) => {
+        impl<$($s: Step),+> Steps for ($($s,)+) {
+            type Output<E> = ($($s::Output<E>,)+);
+        }
+    };
+}
+
+impl_push!();
+impl_push!(S1);
+impl_push!(S1, S2);
+impl_push!(S1, S2, S3);
+impl_push!(S1, S2, S3, S4);
+impl_push!(S1, S2, S3, S4, S5);
+impl_push!(S1, S2, S3, S4, S5, S6);
+impl_push!(S1, S2, S3, S4, S5, S6, S7);
+impl_push!(S1, S2, S3, S4, S5, S6, S7, S8);
+impl_push!(S1, S2, S3, S4, S5, S6, S7, S8, S9);
+impl_push!(S1, S2, S3, S4, S5, S6, S7, S8, S9, S10);
+impl_push!(S1, S2, S3, S4, S5, S6, S7, S8, S9, S10, S11);
+
+impl_steps!(S1);
+impl_steps!(S1, S2);
+impl_steps!(S1, S2, S3);
+impl_steps!(S1, S2, S3, S4);
+impl_steps!(S1, S2, S3, S4, S5);
+impl_steps!(S1, S2, S3, S4, S5, S6);
+impl_steps!(S1, S2, S3, S4, S5, S6, S7);
+impl_steps!(S1, S2, S3, S4, S5, S6, S7, S8);
+impl_steps!(S1, S2, S3, S4, S5, S6, S7, S8, S9);
+impl_steps!(S1, S2, S3, S4, S5, S6, S7, S8, S9, S10);
+impl_steps!(S1, S2, S3, S4, S5, S6, S7, S8, S9, S10, S11);
+impl_steps!(S1, S2, S3, S4, S5, S6, S7, S8, S9, S10, S11, S12);
+
+#[cfg(feature = "async")]
+pub mod r#async {
+    use super::*;
+    use crate::device::r#async::RegisterBus;
+
+    /// A [`Step`] that may be performed over an async [`RegisterBus`]
+    #[allow(async_fn_in_trait)]
+    pub trait RunStep: Step {
+        /// Perform the step
+        async fn run<B: RegisterBus>(self, bus: &mut B) -> Self::Output<B::Error>;
+    }
+
+    /// [`Steps`] that may be performed in order over an async [`RegisterBus`]
+    #[allow(async_fn_in_trait)]
+    pub trait RunSteps: Steps {
+        /// Perform each step in order
+        async fn run<B: RegisterBus>(self, bus: &mut B) -> Self::Output<B::Error>;
+    }
+
+    impl<R: ReadableRegister> RunStep for ReadStep<R> {
+        async fn run<B: RegisterBus>(self, bus: &mut B) -> Self::Output<B::Error> {
+            bus.read_register().await
+        }
+    }
+
+    impl<R: WritableRegister> RunStep for WriteStep<R> {
+        async fn run<B: RegisterBus>(self, bus: &mut B) -> Self::Output<B::Error> {
+            bus.write_register(self.0).await
+        }
+    }
+
+    impl<C: Command> RunStep for InvokeStep<C> {
+        async fn run<B: RegisterBus>(self, bus: &mut B) -> Self::Output<B::Error> {
+            bus.invoke_command(self.0).await
+        }
+    }
+
+    macro_rules! impl_run_steps {
+        ($($s:ident),+) => {
+            impl<$($s: RunStep),+> RunSteps for ($($s,)+) {
+                #[allow(non_snake_case)]
+                async fn run<B: RegisterBus>(self, bus: &mut B) -> Self::Output<B::Error> {
+                    let ($($s,)+) = self;
+                    ($($s.run(bus).await,)+)
+                }
+            }
+        };
+    }
+
+    impl_run_steps!(S1);
+    impl_run_steps!(S1, S2);
+    impl_run_steps!(S1, S2, S3);
+    impl_run_steps!(S1, S2, S3, S4);
+    impl_run_steps!(S1, S2, S3, S4, S5);
+    impl_run_steps!(S1, S2, S3, S4, S5, S6);
+    impl_run_steps!(S1, S2, S3, S4, S5, S6, S7);
+    impl_run_steps!(S1, S2, S3, S4, S5, S6, S7, S8);
+    impl_run_steps!(S1, S2, S3, S4, S5, S6, S7, S8, S9);
+    impl_run_steps!(S1, S2, S3, S4, S5, S6, S7, S8, S9, S10);
+    impl_run_steps!(S1, S2, S3, S4, S5, S6, S7, S8, S9, S10, S11);
+    impl_run_steps!(S1, S2, S3, S4, S5, S6, S7, S8, S9, S10, S11, S12);
+}
+
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use super::*;
+    use crate::device::blocking::RegisterBus;
+
+    /// A [`Step`] that may be performed over a blocking [`RegisterBus`]
+    pub trait RunStep: Step {
+        /// Perform the step
+        fn run<B: RegisterBus>(self, bus: &mut B) -> Self::Output<B::Error>;
+    }
+
+    /// [`Steps`] that may be performed in order over a blocking [`RegisterBus`]
+    pub trait RunSteps: Steps {
+        /// Perform each step in order
+        fn run<B: RegisterBus>(self, bus: &mut B) -> Self::Output<B::Error>;
+    }
+
+    impl<R: ReadableRegister> RunStep for ReadStep<R> {
+        fn run<B: RegisterBus>(self, bus: &mut B) -> Self::Output<B::Error> {
+            bus.read_register()
+        }
+    }
+
+    impl<R: WritableRegister> RunStep for WriteStep<R> {
+        fn run<B: RegisterBus>(self, bus: &mut B) -> Self::Output<B::Error> {
+            bus.write_register(self.0)
+        }
+    }
+
+    impl<C: Command> RunStep for InvokeStep<C> {
+        fn run<B: RegisterBus>(self, bus: &mut B) -> Self::Output<B::Error> {
+            bus.invoke_command(self.0)
+        }
+    }
+
+    macro_rules! impl_run_steps {
+        ($($s:ident),+) => {
+            impl<$($s: RunStep),+> RunSteps for ($($s,)+) {
+                #[allow(non_snake_case)]
+                fn run<B: RegisterBus>(self, bus: &mut B) -> Self::Output<B::Error> {
+                    let ($($s,)+) = self;
+                    ($($s.run(bus),)+)
+                }
+            }
+        };
+    }
+
+    impl_run_steps!(S1);
+    impl_run_steps!(S1, S2);
+    impl_run_steps!(S1, S2, S3);
+    impl_run_steps!(S1, S2, S3, S4);
+    impl_run_steps!(S1, S2, S3, S4, S5);
+    impl_run_steps!(S1, S2, S3, S4, S5, S6);
+    impl_run_steps!(S1, S2, S3, S4, S5, S6, S7);
+    impl_run_steps!(S1, S2, S3, S4, S5, S6, S7, S8);
+    impl_run_steps!(S1, S2, S3, S4, S5, S6, S7, S8, S9);
+    impl_run_steps!(S1, S2, S3, S4, S5, S6, S7, S8, S9, S10);
+    impl_run_steps!(S1, S2, S3, S4, S5, S6, S7, S8, S9, S10, S11);
+    impl_run_steps!(S1, S2, S3, S4, S5, S6, S7, S8, S9, S10, S11, S12);
+}
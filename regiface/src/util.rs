@@ -0,0 +1,565 @@
+//! Helpers for saving and restoring the contents of a set of registers
+//!
+//! When debugging a device in the field, or preserving its configuration across a firmware update,
+//! it is useful to capture the raw contents of its registers and later write them back. The
+//! `dump_registers` functions read each register of a set, passing its ID and raw bytes to a
+//! callback, while the `restore_registers` functions write previously captured bytes back to each
+//! register of a set.
+//!
+//! A set of registers is a tuple of register types, such as `(Seconds, Minutes, Hours)`, of up to
+//! 12 registers. Registers are read and written as raw bytes, thus their
+//! [`FromByteArray`] and [`ToByteArray`] implementations are bypassed, and are identified by the
+//! serialized [`Register::id`], regardless of any read or write modifiers applied to it.
+//!
+//! Each register is accessed separately, and a failure to access one register does not prevent
+//! the remaining registers from being accessed. The outcome of each register is passed to a
+//! callback, and the number of registers that succeeded and failed is returned as a [`Summary`].
+//!
+//! # Example
+#![cfg_attr(feature = "blocking", doc = "```")]
+#![cfg_attr(not(feature = "blocking"), doc = "```ignore")]
+//! # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+//! use regiface::{
+//!     device::I2cInterface,
+//!     errors::RegisterId,
+//!     register,
+//!     util::{blocking::{dump_registers, restore_registers}, Summary},
+//!     FromByteArray, ReadableRegister, ToByteArray, WritableRegister,
+//! };
+//!
+//! #[register(0x00u8)]
+//! #[derive(ReadableRegister, WritableRegister)]
+//! struct Seconds(u8);
+//!
+//! #[register(0x01u8)]
+//! #[derive(ReadableRegister, WritableRegister)]
+//! struct Minutes(u8);
+//! # impl FromByteArray for Seconds {
+//! #     type Array = [u8; 1];
+//! #     type Error = core::convert::Infallible;
+//! #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+//! # }
+//! # impl ToByteArray for Seconds {
+//! #     type Array = [u8; 1];
+//! #     type Error = core::convert::Infallible;
+//! #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.0]) }
+//! # }
+//! # impl FromByteArray for Minutes {
+//! #     type Array = [u8; 1];
+//! #     type Error = core::convert::Infallible;
+//! #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+//! # }
+//! # impl ToByteArray for Minutes {
+//! #     type Array = [u8; 1];
+//! #     type Error = core::convert::Infallible;
+//! #     fn to_bytes(self) -> Result<Self::Array, Self::Error> { Ok([self.0]) }
+//! # }
+//! # struct Rtc([u8; 2]);
+//! # impl ErrorType for Rtc { type Error = core::convert::Infallible; }
+//! # impl I2c for Rtc {
+//! #     fn transaction(&mut self, _: u8, ops: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+//! #         match ops {
+//! #             [Operation::Write(id), Operation::Read(buf)] => buf[0] = self.0[usize::from(id[0])],
+//! #             [Operation::Write(id), Operation::Write(value)] => self.0[usize::from(id[0])] = value[0],
+//! #             _ => unreachable!(),
+//! #         }
+//! #         Ok(())
+//! #     }
+//! # }
+//! # let rtc = Rtc([0x30, 0x15]);
+//!
+//! let mut device = I2cInterface::new(rtc, 0x68);
+//!
+//! // Save the contents of each register
+//! let mut saved = [(RegisterId::default(), [0u8; 1]); 2];
+//! let mut index = 0;
+//! let summary = dump_registers::<(Seconds, Minutes), _, _>(&mut device, |id, bytes| {
+//!     saved[index] = (id, bytes.unwrap().try_into().unwrap());
+//!     index += 1;
+//! });
+//! assert_eq!(summary, Summary { succeeded: 2, failed: 0 });
+//! assert_eq!(saved[1], (RegisterId::new(0x01u8), [0x15]));
+//!
+//! // Write the saved contents back
+//! # device.bus_mut().0 = [0; 2];
+//! let entries = saved.each_ref().map(|(id, bytes)| (*id, bytes.as_slice()));
+//! let summary = restore_registers::<(Seconds, Minutes), _, _>(&mut device, &entries, |_, result| {
+//!     result.unwrap();
+//! });
+//! assert_eq!(summary, Summary { succeeded: 2, failed: 0 });
+//! # assert_eq!(device.bus_mut().0, [0x30, 0x15]);
+//! ```
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+use core::convert::Infallible;
+
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+#[cfg(any(feature = "blocking", feature = "async", doc))]
+use crate::{
+    byte_array::ByteArray as _, errors::RegisterId, FromByteArray, ReadableRegister, Register,
+    ToByteArray, WritableRegister,
+};
+
+/// The number of registers of a set that were successfully and unsuccessfully accessed
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Summary {
+    /// The number of registers successfully accessed
+    pub succeeded: usize,
+    /// The number of registers that could not be accessed
+    pub failed: usize,
+}
+
+impl Summary {
+    /// Records the outcome of accessing a single register
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    fn record(&mut self, succeeded: bool) {
+        if succeeded {
+            self.succeeded += 1;
+        } else {
+            self.failed += 1;
+        }
+    }
+}
+
+/// Error that can occur when restoring a single register
+///
+/// Generic over the bus error type `B`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum RestoreError<B> {
+    /// An error occurred while communicating over the bus
+    BusError(B),
+    /// No entry was provided for the register
+    Missing,
+    /// The entry provided for the register does not match the register's length
+    LengthMismatch {
+        /// The length of the register
+        expected: usize,
+        /// The length of the provided entry
+        actual: usize,
+    },
+}
+
+/// The raw contents of the register `R`, read with the ID and dummy bytes of `R`
+#[cfg(any(feature = "blocking", feature = "async"))]
+struct RawRead<R: FromByteArray>(<R as FromByteArray>::Array);
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+impl<R: Register + FromByteArray> Register for RawRead<R> {
+    type IdType = R::IdType;
+
+    fn id() -> Self::IdType {
+        R::id()
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+impl<R: FromByteArray> FromByteArray for RawRead<R> {
+    type Error = Infallible;
+    type Array = <R as FromByteArray>::Array;
+
+    fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> {
+        Ok(Self(bytes))
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+impl<R: ReadableRegister> ReadableRegister for RawRead<R> {
+    fn readable_id() -> Self::IdType {
+        R::readable_id()
+    }
+
+    fn dummy_bytes() -> usize {
+        R::dummy_bytes()
+    }
+
+    fn dummy_mode() -> crate::spi::DummyMode {
+        R::dummy_mode()
+    }
+}
+
+/// The raw contents of the register `R`, written with the ID and masks of `R`
+#[cfg(any(feature = "blocking", feature = "async"))]
+struct RawWrite<R: ToByteArray>(<R as ToByteArray>::Array);
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+impl<R: Register + ToByteArray> Register for RawWrite<R> {
+    type IdType = R::IdType;
+    const RESERVED_MASK: u128 = R::RESERVED_MASK;
+    const REQUIRED_MASK: u128 = R::REQUIRED_MASK;
+
+    fn id() -> Self::IdType {
+        R::id()
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+impl<R: ToByteArray> ToByteArray for RawWrite<R> {
+    type Error = Infallible;
+    type Array = <R as ToByteArray>::Array;
+
+    fn to_bytes(self) -> Result<Self::Array, Self::Error> {
+        Ok(self.0)
+    }
+}
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+impl<R: WritableRegister> WritableRegister for RawWrite<R> {
+    fn writeable_id() -> Self::IdType {
+        R::writeable_id()
+    }
+}
+
+/// Finds the entry of the register `R`, copying it into the register's array
+#[cfg(any(feature = "blocking", feature = "async"))]
+fn entry<R: WritableRegister, B>(
+    entries: &[(RegisterId, &[u8])],
+) -> Result<RawWrite<R>, RestoreError<B>> {
+    let id = RegisterId::new(R::id());
+    let (_, bytes) = entries
+        .iter()
+        .find(|(entry, _)| *entry == id)
+        .ok_or(RestoreError::Missing)?;
+
+    let mut raw = <R as ToByteArray>::Array::new();
+    if raw.as_ref().len() != bytes.len() {
+        return Err(RestoreError::LengthMismatch {
+            expected: raw.as_ref().len(),
+            actual: bytes.len(),
+        });
+    }
+    raw.as_mut().copy_from_slice(bytes);
+    Ok(RawWrite(raw))
+}
+
+#[cfg(feature = "async")]
+pub mod r#async {
+    use super::*;
+    use crate::{
+        device::r#async::RegisterBus,
+        errors::{ReadRegisterError, WriteRegisterError},
+    };
+
+    /// A set of readable registers, implemented for tuples of up to 12 [`ReadableRegister`]s
+    #[allow(async_fn_in_trait)]
+    pub trait ReadableSet {
+        /// Read each register of the set, passing its ID and raw contents to `report`
+        async fn dump<B, F>(bus: &mut B, report: F) -> Summary
+        where
+            B: RegisterBus,
+            F: FnMut(RegisterId, Result<&[u8], B::Error>);
+    }
+
+    /// A set of writable registers, implemented for tuples of up to 12 [`WritableRegister`]s
+    #[allow(async_fn_in_trait)]
+    pub trait WritableSet {
+        /// Write each register of the set from `entries`, passing its ID and outcome to `report`
+        async fn restore<B, F>(bus: &mut B, entries: &[(RegisterId, &[u8])], report: F) -> Summary
+        where
+            B: RegisterBus,
+            F: FnMut(RegisterId, Result<(), RestoreError<B::Error>>);
+    }
+
+    async fn dump_one<B, R, F>(bus: &mut B, report: &mut F) -> bool
+    where
+        B: RegisterBus,
+        R: ReadableRegister,
+        F: FnMut(RegisterId, Result<&[u8], B::Error>),
+    {
+        let id = RegisterId::new(R::id());
+        match bus.read_register::<RawRead<R>>().await {
+            Ok(raw) => {
+                report(id, Ok(raw.0.as_ref()));
+                true
+            }
+            Err(ReadRegisterError::BusError(e)) => {
+                report(id, Err(e));
+                false
+            }
+            Err(ReadRegisterError::DeserializationError(never)) => match never {},
+        }
+    }
+
+    async fn restore_one<B, R, F>(
+        bus: &mut B,
+        entries: &[(RegisterId, &[u8])],
+        report: &mut F,
+    ) -> bool
+    where
+        B: RegisterBus,
+        R: WritableRegister,
+        F: FnMut(RegisterId, Result<(), RestoreError<B::Error>>),
+    {
+        let id = RegisterId::new(R::id());
+        let result = match entry::<R, B::Error>(entries) {
+            Ok(raw) => bus.write_register(raw).await.map_err(|err| match err {
+                WriteRegisterError::BusError(e) => RestoreError::BusError(e),
+                WriteRegisterError::SerializationError(never) => match never {},
+            }),
+            Err(e) => Err(e),
+        };
+        let succeeded = result.is_ok();
+        report(id, result);
+        succeeded
+    }
+
+    macro_rules! impl_sets {
+        ($($r:ident),+) => {
+            impl<$($r: ReadableRegister),+> ReadableSet for ($($r,)+) {
+                async fn dump<B, F>(bus: &mut B, mut report: F) -> Summary
+                where
+                    B: RegisterBus,
+                    F: FnMut(RegisterId, Result<&[u8], B::Error>),
+                {
+                    let mut summary = Summary::default();
+                    $(summary.record(dump_one::<B, $r, F>(bus, &mut report).await);)+
+                    summary
+                }
+            }
+
+            impl<$($r: WritableRegister),+> WritableSet for ($($r,)+) {
+                async fn restore<B, F>(
+                    bus: &mut B,
+                    entries: &[(RegisterId, &[u8])],
+                    mut report: F,
+                ) -> Summary
+                where
+                    B: RegisterBus,
+                    F: FnMut(RegisterId, Result<(), RestoreError<B::Error>>),
+                {
+                    let mut summary = Summary::default();
+                    $(summary.record(restore_one::<B, $r, F>(bus, entries, &mut report).await);)+
+                    summary
+                }
+            }
+        };
+    }
+
+    impl_sets!(R1);
+    impl_sets!(R1, R2);
+    impl_sets!(R1, R2, R3);
+    impl_sets!(R1, R2, R3, R4);
+    impl_sets!(R1, R2, R3, R4, R5);
+    impl_sets!(R1, R2, R3, R4, R5, R6);
+    impl_sets!(R1, R2, R3, R4, R5, R6, R7);
+    impl_sets!(R1, R2, R3, R4, R5, R6, R7, R8);
+    impl_sets!(R1, R2, R3, R4, R5, R6, R7, R8, R9);
+    impl_sets!(R1, R2, R3, R4, R5, R6, R7, R8, R9, R10);
+    impl_sets!(R1, R2, R3, R4, R5, R6, R7, R8, R9, R10, R11);
+    impl_sets!(R1, R2, R3, R4, R5, R6, R7, R8, R9, R10, R11, R12);
+
+    /// Read the raw contents of each register of the set `S` from a device.
+    ///
+    /// Each register is read separately with its readable ID, and its serialized
+    /// [`Register::id`] and raw contents, or the bus error that occurred while reading it, are
+    /// passed to `report`. A failure to read one register does not prevent the remaining registers
+    /// from being read.
+    ///
+    /// # Parameters
+    /// * `bus` - The register interface of the device to read from
+    /// * `report` - Called with the ID and outcome of each register, in the order of the set
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use regiface::{device::r#async::RegisterBus, register, util, FromByteArray, ReadableRegister};
+    /// # #[register(0x00u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct Seconds(u8);
+    /// # #[register(0x01u8)]
+    /// # #[derive(ReadableRegister)]
+    /// # struct Minutes(u8);
+    /// # impl FromByteArray for Seconds {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// # impl FromByteArray for Minutes {
+    /// #     type Array = [u8; 1];
+    /// #     type Error = core::convert::Infallible;
+    /// #     fn from_bytes(bytes: Self::Array) -> Result<Self, Self::Error> { Ok(Self(bytes[0])) }
+    /// # }
+    /// async fn count_readable<B: RegisterBus>(device: &mut B) -> usize {
+    ///     util::r#async::dump_registers::<(Seconds, Minutes), _, _>(device, |_, _| {})
+    ///         .await
+    ///         .succeeded
+    /// }
+    /// ```
+    pub async fn dump_registers<S, B, F>(bus: &mut B, report: F) -> Summary
+    where
+        S: ReadableSet,
+        B: RegisterBus,
+        F: FnMut(RegisterId, Result<&[u8], B::Error>),
+    {
+        S::dump(bus, report).await
+    }
+
+    /// Write previously saved contents to each register of the set `S` of a device.
+    ///
+    /// Each register is written separately with its writable ID, from the entry of `entries`
+    /// matching its serialized [`Register::id`], such as those passed to the callback of
+    /// [`dump_registers`]. The outcome of each register is passed to `report`, and a failure to
+    /// restore one register does not prevent the remaining registers from being restored. The
+    /// reserved and required bits of each register are applied to the written contents.
+    ///
+    /// # Parameters
+    /// * `bus` - The register interface of the device to write to
+    /// * `entries` - The ID and raw contents of each register to restore
+    /// * `report` - Called with the ID and outcome of each register, in the order of the set
+    ///
+    /// # Errors
+    /// The following errors are passed to `report` for the register they occurred on:
+    /// * `RestoreError::BusError` - Communication with the device failed
+    /// * `RestoreError::Missing` - No entry was provided for the register
+    /// * `RestoreError::LengthMismatch` - The entry does not match the length of the register
+    pub async fn restore_registers<S, B, F>(
+        bus: &mut B,
+        entries: &[(RegisterId, &[u8])],
+        report: F,
+    ) -> Summary
+    where
+        S: WritableSet,
+        B: RegisterBus,
+        F: FnMut(RegisterId, Result<(), RestoreError<B::Error>>),
+    {
+        S::restore(bus, entries, report).await
+    }
+}
+
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    use super::*;
+    use crate::{
+        device::blocking::RegisterBus,
+        errors::{ReadRegisterError, WriteRegisterError},
+    };
+
+    /// A set of readable registers, implemented for tuples of up to 12 [`ReadableRegister`]s
+    pub trait ReadableSet {
+        /// Read each register of the set, passing its ID and raw contents to `report`
+        fn dump<B, F>(bus: &mut B, report: F) -> Summary
+        where
+            B: RegisterBus,
+            F: FnMut(RegisterId, Result<&[u8], B::Error>);
+    }
+
+    /// A set of writable registers, implemented for tuples of up to 12 [`WritableRegister`]s
+    pub trait WritableSet {
+        /// Write each register of the set from `entries`, passing its ID and outcome to `report`
+        fn restore<B, F>(bus: &mut B, entries: &[(RegisterId, &[u8])], report: F) -> Summary
+        where
+            B: RegisterBus,
+            F: FnMut(RegisterId, Result<(), RestoreError<B::Error>>);
+    }
+
+    fn dump_one<B, R, F>(bus: &mut B, report: &mut F) -> bool
+    where
+        B: RegisterBus,
+        R: ReadableRegister,
+        F: FnMut(RegisterId, Result<&[u8], B::Error>),
+    {
+        let id = RegisterId::new(R::id());
+        match bus.read_register::<RawRead<R>>() {
+            Ok(raw) => {
+                report(id, Ok(raw.0.as_ref()));
+                true
+            }
+            Err(ReadRegisterError::BusError(e)) => {
+                report(id, Err(e));
+                false
+            }
+            Err(ReadRegisterError::DeserializationError(never)) => match never {},
+        }
+    }
+
+    fn restore_one<B, R, F>(bus: &mut B, entries: &[(RegisterId, &[u8])], report: &mut F) -> bool
+    where
+        B: RegisterBus,
+        R: WritableRegister,
+        F: FnMut(RegisterId, Result<(), RestoreError<B::Error>>),
+    {
+        let id = RegisterId::new(R::id());
+        let result = match entry::<R, B::Error>(entries) {
+            Ok(raw) => bus.write_register(raw).map_err(|err| match err {
+                WriteRegisterError::BusError(e) => RestoreError::BusError(e),
+                WriteRegisterError::SerializationError(never) => match never {},
+            }),
+            Err(e) => Err(e),
+        };
+        let succeeded = result.is_ok();
+        report(id, result);
+        succeeded
+    }
+
+    macro_rules! impl_sets {
+        ($($r:ident),+) => {
+            impl<$($r: ReadableRegister),+> ReadableSet for ($($r,)+) {
+                fn dump<B, F>(bus: &mut B, mut report: F) -> Summary
+                where
+                    B: RegisterBus,
+                    F: FnMut(RegisterId, Result<&[u8], B::Error>),
+                {
+                    let mut summary = Summary::default();
+                    $(summary.record(dump_one::<B, $r, F>(bus, &mut report));)+
+                    summary
+                }
+            }
+
+            impl<$($r: WritableRegister),+> WritableSet for ($($r,)+) {
+                fn restore<B, F>(
+                    bus: &mut B,
+                    entries: &[(RegisterId, &[u8])],
+                    mut report: F,
+                ) -> Summary
+                where
+                    B: RegisterBus,
+                    F: FnMut(RegisterId, Result<(), RestoreError<B::Error>>),
+                {
+                    let mut summary = Summary::default();
+                    $(summary.record(restore_one::<B, $r, F>(bus, entries, &mut report));)+
+                    summary
+                }
+            }
+        };
+    }
+
+    impl_sets!(R1);
+    impl_sets!(R1, R2);
+    impl_sets!(R1, R2, R3);
+    impl_sets!(R1, R2, R3, R4);
+    impl_sets!(R1, R2, R3, R4, R5);
+    impl_sets!(R1, R2, R3, R4, R5, R6);
+    impl_sets!(R1, R2, R3, R4, R5, R6, R7);
+    impl_sets!(R1, R2, R3, R4, R5, R6, R7, R8);
+    impl_sets!(R1, R2, R3, R4, R5, R6, R7, R8, R9);
+    impl_sets!(R1, R2, R3, R4, R5, R6, R7, R8, R9, R10);
+    impl_sets!(R1, R2, R3, R4, R5, R6, R7, R8, R9, R10, R11);
+    impl_sets!(R1, R2, R3, R4, R5, R6, R7, R8, R9, R10, R11, R12);
+
+    /// Blocking variant of `r#async::dump_registers`. See the async
+    /// function documentation for detailed behavior description.
+    pub fn dump_registers<S, B, F>(bus: &mut B, report: F) -> Summary
+    where
+        S: ReadableSet,
+        B: RegisterBus,
+        F: FnMut(RegisterId, Result<&[u8], B::Error>),
+    {
+        S::dump(bus, report)
+    }
+
+    /// Blocking variant of `r#async::restore_registers`. See the async
+    /// function documentation for detailed behavior description.
+    pub fn restore_registers<S, B, F>(
+        bus: &mut B,
+        entries: &[(RegisterId, &[u8])],
+        report: F,
+    ) -> Summary
+    where
+        S: WritableSet,
+        B: RegisterBus,
+        F: FnMut(RegisterId, Result<(), RestoreError<B::Error>>),
+    {
+        S::restore(bus, entries, report)
+    }
+}